@@ -1,16 +1,34 @@
 use hap::{
     accessory::{television, Category, Information},
+    characteristic::{input_source_type, is_configured},
+    service::input_source,
     transport::{IpTransport, Transport},
     Config,
 };
 
 fn main() {
-    let television = television::new(Information {
+    let mut television = television::new(Information {
         name: "Acme TV".into(),
         ..Default::default()
     })
     .unwrap();
 
+    for name in &["HDMI 1", "HDMI 2"] {
+        let mut input_source = input_source::new();
+        input_source.inner.configured_name.set_value(name.to_string()).unwrap();
+        input_source
+            .inner
+            .input_source_type
+            .set_valid_value(input_source_type::Value::Hdmi)
+            .unwrap();
+        input_source
+            .inner
+            .is_configured
+            .set_valid_value(is_configured::Value::Configured)
+            .unwrap();
+        television.inner.add_input_source(input_source);
+    }
+
     let mut ip_transport = IpTransport::new(Config {
         name: "Acme TV".into(),
         category: Category::Television,
@@ -0,0 +1,8 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use hap::fuzzing;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = fuzzing::decode_frames(&[0; 32], data);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use hap::characteristic::{
+    stream::{SelectedRtpStreamConfig, SetupEndpointsRequest},
+    Tlv8,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = SetupEndpointsRequest::from_tlv8(data);
+    let _ = SelectedRtpStreamConfig::from_tlv8(data);
+});
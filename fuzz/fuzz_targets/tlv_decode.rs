@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use hap::protocol::tlv;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = tlv::decode(data);
+    let _ = tlv::decode_list(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use hap::fuzzing;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = fuzzing::write_characteristics(data);
+});
@@ -0,0 +1,408 @@
+use handlebars::{Context, Handlebars, Helper, Output, RenderContext, RenderError, Renderable};
+use serde_json::{Map, Value};
+
+use crate::metadata::Characteristic;
+
+/// Returns the parameter of a helper at the given index.
+fn param<'a>(h: &'a Helper, index: usize) -> Result<&'a Value, RenderError> {
+    h.param(index)
+        .map(|p| p.value())
+        .ok_or_else(|| RenderError::new(format!("missing parameter {} of the `{}` helper", index, h.name())))
+}
+
+/// Returns the string parameter of a helper at the given index.
+fn str_param<'a>(h: &'a Helper, index: usize) -> Result<&'a str, RenderError> {
+    param(h, index)?
+        .as_str()
+        .ok_or_else(|| RenderError::new(format!("parameter {} of the `{}` helper isn't a string", index, h.name())))
+}
+
+/// Returns the object parameter of a helper at the given index.
+fn object_param<'a>(h: &'a Helper, index: usize) -> Result<&'a Map<String, Value>, RenderError> {
+    param(h, index)?
+        .as_object()
+        .ok_or_else(|| RenderError::new(format!("parameter {} of the `{}` helper isn't an object", index, h.name())))
+}
+
+/// Returns the array parameter of a helper at the given index.
+fn array_param<'a>(h: &'a Helper, index: usize) -> Result<&'a Vec<Value>, RenderError> {
+    param(h, index)?
+        .as_array()
+        .ok_or_else(|| RenderError::new(format!("parameter {} of the `{}` helper isn't an array", index, h.name())))
+}
+
+/// Returns the Characteristics parameter of a helper at the given index.
+fn characteristics_param(h: &Helper, index: usize) -> Result<Vec<Characteristic>, RenderError> {
+    serde_json::from_value(param(h, index)?.clone())
+        .map_err(|e| RenderError::new(format!("invalid Characteristics of the `{}` helper: {}", h.name(), e)))
+}
+
+pub(crate) fn if_eq_helper<'reg, 'rc>(
+    h: &Helper<'reg, 'rc>,
+    r: &'reg Handlebars,
+    c: &Context,
+    rc: &mut RenderContext<'reg>,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let first = param(h, 0)?;
+    let second = param(h, 1)?;
+    let tmpl = if first == second { h.template() } else { h.inverse() };
+    match tmpl {
+        Some(t) => t.render(r, c, rc, out),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn trim_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let s = str_param(h, 0)?;
+    let trim = s.replace(" ", "").replace(".", "_").replace("-", "");
+    out.write(&trim)?;
+    Ok(())
+}
+
+pub(crate) fn file_name_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let s = str_param(h, 0)?;
+    out.write(&file_name(s))?;
+    Ok(())
+}
+
+/// Converts the name of a Characteristic or Service to the name of its module.
+pub(crate) fn file_name(name: &str) -> String {
+    name.replace(" ", "_").replace(".", "_").replace("-", "").to_lowercase()
+}
+
+pub(crate) fn type_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let s = str_param(h, 0)?;
+    match s {
+        "bool" => {
+            out.write("bool")?;
+        },
+        "uint8" => {
+            out.write("u8")?;
+        },
+        "uint16" => {
+            out.write("u16")?;
+        },
+        "uint32" => {
+            out.write("u32")?;
+        },
+        "uint64" => {
+            out.write("u64")?;
+        },
+        "int" => {
+            out.write("i32")?;
+        },
+        "int32" => {
+            out.write("i32")?;
+        },
+        "float" => {
+            out.write("f32")?;
+        },
+        "string" => {
+            out.write("String")?;
+        },
+        "tlv8" => {
+            out.write("Vec<u8>")?;
+        },
+        "data" => {
+            out.write("Vec<u8>")?;
+        },
+        _ => {
+            return Err(RenderError::new("Unknown Characteristic format"));
+        },
+    }
+    Ok(())
+}
+
+pub(crate) fn format_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let s = str_param(h, 0)?;
+    match s {
+        "bool" => {
+            out.write("Format::Bool")?;
+        },
+        "uint8" => {
+            out.write("Format::UInt8")?;
+        },
+        "uint16" => {
+            out.write("Format::UInt16")?;
+        },
+        "uint32" => {
+            out.write("Format::UInt32")?;
+        },
+        "uint64" => {
+            out.write("Format::UInt64")?;
+        },
+        "int" => {
+            out.write("Format::Int32")?;
+        },
+        "int32" => {
+            out.write("Format::Int32")?;
+        },
+        "float" => {
+            out.write("Format::Float")?;
+        },
+        "string" => {
+            out.write("Format::String")?;
+        },
+        "tlv8" => {
+            out.write("Format::Tlv8")?;
+        },
+        "data" => {
+            out.write("Format::Data")?;
+        },
+        _ => {
+            return Err(RenderError::new("Unknown Characteristic format"));
+        },
+    }
+    Ok(())
+}
+
+pub(crate) fn unit_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let s = str_param(h, 0)?;
+    match s {
+        "percentage" => {
+            out.write("Unit::Percentage")?;
+        },
+        "arcdegrees" => {
+            out.write("Unit::ArcDegrees")?;
+        },
+        "celsius" => {
+            out.write("Unit::Celsius")?;
+        },
+        "lux" => {
+            out.write("Unit::Lux")?;
+        },
+        "seconds" => {
+            out.write("Unit::Seconds")?;
+        },
+        _ => {
+            return Err(RenderError::new("Unknown Characteristic unit"));
+        },
+    }
+    Ok(())
+}
+
+pub(crate) fn uuid_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let s = str_param(h, 0)?;
+    out.write(&shorten_uuid(s))?;
+    Ok(())
+}
+
+pub(crate) fn valid_values_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let param = object_param(h, 0)?;
+    let mut output = String::from("vec![\n");
+    for (key, val) in param {
+        output.push_str(&format!("            {}, // {}\n", key, val));
+    }
+    output.push_str("        ]");
+    out.write(&output)?;
+    Ok(())
+}
+
+pub(crate) fn valid_value_variants_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let param = object_param(h, 0)?;
+    let name = str_param(h, 1)?;
+    for (value, variant, description) in valid_values(param, name)? {
+        out.write(&format!("    /// {}.\n    {} = {},\n", description, variant, value))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn valid_value_arms_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let param = object_param(h, 0)?;
+    let name = str_param(h, 1)?;
+    for (value, variant, _) in valid_values(param, name)? {
+        out.write(&format!("            {} => Ok(Value::{}),\n", value, variant))?;
+    }
+    Ok(())
+}
+
+/// Returns the valid values of a Characteristic ordered by value, along with their enum variant
+/// names and descriptions.
+fn valid_values(
+    param: &Map<String, Value>,
+    characteristic_name: &str,
+) -> Result<Vec<(i64, String, String)>, RenderError> {
+    let mut valid_values = param
+        .iter()
+        .map(|(key, val)| {
+            let value = key.parse::<i64>().map_err(|_| {
+                RenderError::new(format!("invalid valid value {} of the {} Characteristic", key, characteristic_name))
+            })?;
+            let description = val.as_str().ok_or_else(|| {
+                RenderError::new(format!(
+                    "description of the valid value {} of the {} Characteristic isn't a string",
+                    key, characteristic_name
+                ))
+            })?;
+            Ok((value, variant_name(description, characteristic_name), description.to_owned()))
+        })
+        .collect::<Result<Vec<_>, RenderError>>()?;
+    valid_values.sort_by_key(|v| v.0);
+    Ok(valid_values)
+}
+
+/// Converts the description of a valid value to an enum variant name, e.g. "Secured Physically,
+/// Interior" to `SecuredPhysicallyInterior`. Names starting with a digit are prefixed with the last
+/// word of the Characteristic name, e.g. "2.5 μm" of Air Particulate Size to `Size2_5Micrometers`.
+fn variant_name(description: &str, characteristic_name: &str) -> String {
+    let description = description.replace("μm", "Micrometers").replace(".", "_");
+    let mut name = description
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|word| !word.is_empty())
+        .map(|word| format!("{}{}", word[..1].to_ascii_uppercase(), &word[1..]))
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, characteristic_name.rsplit(' ').next().unwrap_or_default());
+    }
+    name
+}
+
+pub(crate) fn perms_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    for param in array_param(h, 0)? {
+        match param.as_str() {
+            Some("read") => {
+                out.write("\n            Perm::PairedRead,")?;
+            },
+            Some("write") => {
+                out.write("\n            Perm::PairedWrite,")?;
+            },
+            Some("cnotify") => {
+                out.write("\n            Perm::Events,")?;
+            },
+            Some("timedWrite") => {
+                out.write("\n            Perm::TimedWrite,")?;
+            },
+            Some("writeResponse") => {
+                out.write("\n            Perm::WriteResponse,")?;
+            },
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn float_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    if str_param(h, 0)? == "float" {
+        out.write("_f32")?;
+    }
+    Ok(())
+}
+
+fn shorten_uuid(id: &str) -> String {
+    id.split("-").collect::<Vec<&str>>()[0]
+        .trim_start_matches('0')
+        .to_owned()
+}
+
+pub(crate) fn characteristic_name_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let id = str_param(h, 0)?;
+    for c in characteristics_param(h, 1)? {
+        if c.id == id {
+            let name = c.name.replace(" ", "").replace(".", "_").replace("-", "");
+            out.write(&name)?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn characteristic_file_name_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let id = str_param(h, 0)?;
+    for c in characteristics_param(h, 1)? {
+        if c.id == id {
+            let name = c.name.replace(" ", "_").replace(".", "_").replace("-", "").to_lowercase();
+            out.write(&name)?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn snake_case_helper(
+    h: &Helper,
+    _: &Handlebars,
+    _: &Context,
+    _: &mut RenderContext,
+    out: &mut dyn Output,
+) -> Result<(), RenderError> {
+    let name = str_param(h, 0)?.replace(" ", "_").replace(".", "_").replace("-", "").to_lowercase();
+    out.write(&name)?;
+    Ok(())
+}
@@ -0,0 +1,423 @@
+//! Code generator of the Characteristic, Service and Accessory modules of the `hap` crate.
+//!
+//! Besides generating the modules of the `hap` crate from the metadata predefined by Apple, the
+//! generator can be used to generate modules for user-supplied metadata, e.g. of vendor-specific
+//! Characteristics and Services, from the build script of another crate:
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     println!("cargo:rerun-if-changed=metadata.json");
+//!     hap_codegen::generate(&["metadata.json"], std::env::var("OUT_DIR").unwrap()).unwrap();
+//! }
+//! ```
+//!
+//! The generated `characteristic`, `service` and `accessory` modules are included with:
+//!
+//! ```ignore
+//! pub mod generated {
+//!     include!(concat!(env!("OUT_DIR"), "/hap_generated.rs"));
+//! }
+//! ```
+//!
+//! They re-export the modules of the `hap` crate, so the Services of the metadata can use the
+//! predefined Characteristics by UUID. User-defined types get a `HapType::Custom` with their full
+//! UUID.
+//!
+//! The same is available from the command line with `hap-codegen <out dir> <metadata file>...`.
+
+use std::{
+    error,
+    fmt,
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+use handlebars::{Handlebars, RenderError};
+use serde_json::json;
+use uuid::Uuid;
+
+mod helpers;
+mod metadata;
+mod templates;
+
+pub use crate::metadata::{Category, Characteristic, Constraints, Metadata, Service};
+
+/// Metadata of the Characteristics, Services and categories predefined by Apple.
+pub const DEFAULT_METADATA: &str = include_str!("../default.metadata.json");
+
+/// Sources of the generator the generated code depends on besides the metadata. Used by the build
+/// script of the `hap` crate to regenerate its modules whenever the templates or helpers change.
+#[doc(hidden)]
+pub const GENERATOR_SOURCES: &[&str] = &[
+    include_str!("helpers.rs"),
+    include_str!("lib.rs"),
+    include_str!("metadata.rs"),
+    include_str!("templates.rs"),
+];
+
+/// Name of the file generated into the output directory.
+pub const GENERATED_FILE_NAME: &str = "hap_generated.rs";
+
+/// Predefined Services that are only used alongside other Services and therefore aren't generated
+/// as standalone Accessories.
+const NON_ACCESSORY_SERVICES: &[&str] = &[
+    "Accessory Information",
+    "Access Code",
+    "Audio Stream Management",
+    "Battery Service",
+    "Camera Operating Mode",
+    "Camera Recording Management",
+    "Camera RTP Stream Management",
+    "Data Stream Transport Management",
+    "Doorbell",
+    "Faucet",
+    "Filter Maintenance",
+    "Irrigation System",
+    "Lock Management",
+    "Lock Mechanism",
+    "Microphone",
+    "NFC Access",
+    "Service Label",
+    "Siri",
+    "Slat",
+    "Speaker",
+    "Target Control",
+    "Target Control Management",
+    "Television",
+    "Wi-Fi Transport",
+];
+
+/// Error of the code generation.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Render(RenderError),
+    Metadata(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Json(e) => write!(f, "invalid metadata: {}", e),
+            Error::Render(e) => write!(f, "couldn't render template: {}", e),
+            Error::Metadata(e) => write!(f, "invalid metadata: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error { Error::Io(e) }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Error { Error::Json(e) }
+}
+
+impl From<RenderError> for Error {
+    fn from(e: RenderError) -> Error { Error::Render(e) }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Crate the generated code is part of.
+struct Target {
+    /// Path of the module the `characteristic`, `service` and `accessory` modules are part of,
+    /// relative to the generated modules.
+    root: &'static str,
+    /// Specifies if the generated types are identified by `HapType::Custom`.
+    custom: bool,
+}
+
+/// Target of the modules of the `hap` crate itself.
+const HAP: Target = Target {
+    root: "crate",
+    custom: false,
+};
+
+/// Target of the modules generated into a user crate.
+const USER: Target = Target {
+    root: "super::super",
+    custom: true,
+};
+
+/// Renders the handlebars templates of the Characteristic, Service and Accessory modules.
+pub struct Generator {
+    handlebars: Handlebars,
+}
+
+impl Generator {
+    /// Creates a new `Generator`.
+    pub fn new() -> Generator {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("if_eq", Box::new(helpers::if_eq_helper));
+        handlebars.register_helper("trim", Box::new(helpers::trim_helper));
+        handlebars.register_helper("file_name", Box::new(helpers::file_name_helper));
+        handlebars.register_helper("format", Box::new(helpers::format_helper));
+        handlebars.register_helper("type", Box::new(helpers::type_helper));
+        handlebars.register_helper("unit", Box::new(helpers::unit_helper));
+        handlebars.register_helper("uuid", Box::new(helpers::uuid_helper));
+        handlebars.register_helper("valid_values", Box::new(helpers::valid_values_helper));
+        handlebars.register_helper("valid_value_variants", Box::new(helpers::valid_value_variants_helper));
+        handlebars.register_helper("valid_value_arms", Box::new(helpers::valid_value_arms_helper));
+        handlebars.register_helper("perms", Box::new(helpers::perms_helper));
+        handlebars.register_helper("float", Box::new(helpers::float_helper));
+        handlebars.register_helper("characteristic_name", Box::new(helpers::characteristic_name_helper));
+        handlebars.register_helper(
+            "characteristic_file_name",
+            Box::new(helpers::characteristic_file_name_helper),
+        );
+        handlebars.register_helper("snake_case", Box::new(helpers::snake_case_helper));
+        for (name, template) in &[
+            ("categories", templates::CATEGORIES),
+            ("hap_type", templates::HAP_TYPE),
+            ("characteristic", templates::CHARACTERISTIC),
+            ("characteristic_mod", templates::CHARACTERISTIC_MOD),
+            ("service", templates::SERVICE),
+            ("service_mod", templates::SERVICE_MOD),
+            ("accessory", templates::ACCESSORY),
+            ("accessory_mod", templates::ACCESSORY_MOD),
+        ] {
+            handlebars
+                .register_template_string(name, template)
+                .expect("couldn't register template");
+        }
+
+        Generator { handlebars }
+    }
+
+    /// Generates the `characteristic`, `service` and `accessory` modules of user-supplied metadata
+    /// into `hap_generated.rs` in the given directory and returns the path of the generated file.
+    ///
+    /// Every Service of the metadata is generated as a standalone Accessory as well. Services can
+    /// use the Characteristics of the metadata and the ones predefined by Apple.
+    pub fn generate<P: AsRef<Path>>(&self, metadata: &Metadata, out_dir: P) -> Result<PathBuf> {
+        let mut characteristics = metadata.characteristics.clone();
+        for c in Metadata::hap_default().characteristics {
+            if !characteristics.iter().any(|d| d.id == c.id) {
+                characteristics.push(c);
+            }
+        }
+        for c in &metadata.characteristics {
+            if c.properties.is_none() {
+                return Err(Error::Metadata(format!("missing Properties of the {} Characteristic", c.name)));
+            }
+        }
+        for s in &metadata.services {
+            for id in s.required_characteristics.iter().chain(&s.optional_characteristics) {
+                if !characteristics.iter().any(|c| &c.id == id) {
+                    return Err(Error::Metadata(format!(
+                        "unknown Characteristic {} of the {} Service",
+                        id, s.name
+                    )));
+                }
+            }
+        }
+
+        let mut generated = String::from(
+            "// THIS FILE IS AUTO-GENERATED\n\n#[allow(unused_imports)]\nuse hap::{event, Error, HapType, Result, \
+             Uuid};\n",
+        );
+        generated.push_str("\npub mod characteristic {\n    pub use hap::characteristic::*;\n");
+        for c in &metadata.characteristics {
+            let characteristic = self.render_characteristic(c, &USER)?;
+            push_module(&mut generated, &c.name, &characteristic);
+        }
+        generated.push_str("}\n\npub mod service {\n    pub use hap::service::*;\n");
+        for s in &metadata.services {
+            let service = self.render_service(s, &characteristics, &USER)?;
+            push_module(&mut generated, &s.name, &service);
+        }
+        generated.push_str("}\n\npub mod accessory {\n    pub use hap::accessory::*;\n");
+        for s in &metadata.services {
+            let accessory = self.render_accessory(s, &characteristics, &USER)?;
+            push_module(&mut generated, &s.name, &accessory);
+        }
+        generated.push_str("}\n");
+
+        let path = out_dir.as_ref().join(GENERATED_FILE_NAME);
+        fs::write(&path, generated)?;
+        Ok(path)
+    }
+
+    /// Generates the `HapType`, the categories and the Characteristic, Service and Accessory
+    /// modules of the `hap` crate into its source directory.
+    #[doc(hidden)]
+    pub fn generate_hap_sources<P: AsRef<Path>>(&self, metadata: &Metadata, src_dir: P) -> Result<()> {
+        let src_dir = src_dir.as_ref();
+
+        let categories = self.handlebars.render("categories", metadata)?;
+        fs::write(src_dir.join("accessory/category.rs"), categories)?;
+
+        let hap_type = self.handlebars.render("hap_type", metadata)?;
+        fs::write(src_dir.join("hap_type.rs"), hap_type)?;
+
+        let characteristics_dir = src_dir.join("characteristic/generated");
+        fs::remove_dir_all(&characteristics_dir)?;
+        fs::create_dir_all(&characteristics_dir)?;
+        for c in &metadata.characteristics {
+            let characteristic = self.render_characteristic(c, &HAP)?;
+            fs::write(
+                characteristics_dir.join(format!("{}.rs", helpers::file_name(&c.name))),
+                characteristic,
+            )?;
+        }
+        let characteristic_mod = self.handlebars.render(
+            "characteristic_mod",
+            &json!({ "characteristics": &metadata.characteristics }),
+        )?;
+        fs::write(characteristics_dir.join("mod.rs"), characteristic_mod)?;
+
+        let services_dir = src_dir.join("service/generated");
+        let accessories_dir = src_dir.join("accessory/generated");
+        fs::remove_dir_all(&services_dir)?;
+        fs::remove_dir_all(&accessories_dir)?;
+        fs::create_dir_all(&services_dir)?;
+        fs::create_dir_all(&accessories_dir)?;
+        let mut accessory_names = vec![];
+        for s in &metadata.services {
+            let service_file_name = helpers::file_name(&s.name);
+            let service = self.render_service(s, &metadata.characteristics, &HAP)?;
+            fs::write(services_dir.join(format!("{}.rs", service_file_name)), service)?;
+
+            if !NON_ACCESSORY_SERVICES.contains(&s.name.as_str()) {
+                let accessory = self.render_accessory(s, &metadata.characteristics, &HAP)?;
+                fs::write(accessories_dir.join(format!("{}.rs", service_file_name)), accessory)?;
+                accessory_names.push(service_file_name);
+            }
+        }
+        let service_mod = self.handlebars.render(
+            "service_mod",
+            &json!({"services": &metadata.services, "characteristics": &metadata.characteristics}),
+        )?;
+        fs::write(services_dir.join("mod.rs"), service_mod)?;
+        let accessory_mod = self
+            .handlebars
+            .render("accessory_mod", &json!({ "accessories": accessory_names }))?;
+        fs::write(accessories_dir.join("mod.rs"), accessory_mod)?;
+
+        Ok(())
+    }
+
+    fn render_characteristic(&self, characteristic: &Characteristic, target: &Target) -> Result<String> {
+        let uuid = if target.custom {
+            Some(uuid_expr(&characteristic.id, target)?)
+        } else {
+            None
+        };
+        let hap_type = hap_type_expr(&characteristic.name, &characteristic.id, target)?;
+        let characteristic = serde_json::to_value(characteristic)?;
+        Ok(self.handlebars.render(
+            "characteristic",
+            &json!({
+                "configured": is_configured(&characteristic),
+                "characteristic": characteristic,
+                "root": target.root,
+                "custom": target.custom,
+                "hap_type": hap_type,
+                "uuid": uuid,
+            }),
+        )?)
+    }
+
+    fn render_service(&self, service: &Service, characteristics: &[Characteristic], target: &Target) -> Result<String> {
+        Ok(self.handlebars.render(
+            "service",
+            &json!({
+                "service": service,
+                "characteristics": characteristics,
+                "root": target.root,
+                "hap_type": hap_type_expr(&service.name, &service.id, target)?,
+                "custom": target.custom,
+            }),
+        )?)
+    }
+
+    fn render_accessory(
+        &self,
+        service: &Service,
+        characteristics: &[Characteristic],
+        target: &Target,
+    ) -> Result<String> {
+        Ok(self.handlebars.render(
+            "accessory",
+            &json!({
+                "service": service,
+                "characteristics": characteristics,
+                "root": target.root,
+                "custom": target.custom,
+            }),
+        )?)
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Generator { Generator::new() }
+}
+
+/// Generates the `characteristic`, `service` and `accessory` modules of the given metadata files
+/// into `hap_generated.rs` in the given directory, usually the `OUT_DIR` of a build script. Returns
+/// the path of the generated file.
+pub fn generate<P: AsRef<Path>, Q: AsRef<Path>>(metadata_files: &[P], out_dir: Q) -> Result<PathBuf> {
+    let mut metadata = Metadata::default();
+    for file in metadata_files {
+        metadata.extend(Metadata::from_file(file)?);
+    }
+    Generator::new().generate(&metadata, out_dir)
+}
+
+/// Returns the expression of the `HapType` of a Characteristic or Service.
+fn hap_type_expr(name: &str, id: &str, target: &Target) -> Result<String> {
+    if target.custom {
+        Ok(format!("HapType::Custom({})", uuid_expr(id, target)?))
+    } else {
+        Ok(format!("HapType::{}", name.replace(" ", "").replace(".", "_").replace("-", "")))
+    }
+}
+
+/// Returns the expression of the full UUID of a Characteristic or Service.
+fn uuid_expr(id: &str, target: &Target) -> Result<String> {
+    let uuid = Uuid::parse_str(id).map_err(|e| Error::Metadata(format!("invalid UUID {}: {}", id, e)))?;
+    let bytes = uuid
+        .as_bytes()
+        .iter()
+        .map(|b| format!("0x{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(format!("{}::Uuid::from_bytes([{}])", target.root, bytes))
+}
+
+/// Returns whether a Characteristic has a unit or constraints, which the template of user
+/// Characteristics sets after creating the Characteristic. Mirrors the truthiness of handlebars'
+/// `{{#if value includeZero=true}}`.
+fn is_configured(characteristic: &serde_json::Value) -> bool {
+    let is_set = |value: &serde_json::Value| match value {
+        serde_json::Value::Null => false,
+        serde_json::Value::Bool(b) => *b,
+        serde_json::Value::Number(_) => true,
+        serde_json::Value::String(s) => !s.is_empty(),
+        serde_json::Value::Array(a) => !a.is_empty(),
+        serde_json::Value::Object(o) => !o.is_empty(),
+    };
+    is_set(&characteristic["Unit"])
+        || [
+            "MaximumValue",
+            "MinimumValue",
+            "StepValue",
+            "MaximumLength",
+            "MaximumDataLength",
+            "ValidValues",
+        ]
+        .iter()
+        .any(|constraint| is_set(&characteristic["Constraints"][*constraint]))
+}
+
+/// Appends a generated module to the contents of `hap_generated.rs`.
+fn push_module(generated: &mut String, name: &str, contents: &str) {
+    generated.push_str(&format!("\n    pub mod {} {{\n{}\n    }}\n", helpers::file_name(name), contents));
+}
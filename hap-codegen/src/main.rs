@@ -0,0 +1,21 @@
+//! Generates the `characteristic`, `service` and `accessory` modules of user-supplied metadata.
+//!
+//! Usage: `hap-codegen <out dir> <metadata file>...`
+
+use std::{env, process};
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 2 {
+        eprintln!("usage: hap-codegen <out dir> <metadata file>...");
+        process::exit(2);
+    }
+
+    match hap_codegen::generate(&args[1..], &args[0]) {
+        Ok(path) => println!("generated {}", path.display()),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        },
+    }
+}
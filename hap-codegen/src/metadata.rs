@@ -0,0 +1,96 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Result;
+
+/// Metadata of the HAP Characteristics, Services and Accessory categories, as found in
+/// `default.metadata.json`. Every section may be omitted in user metadata files.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(rename = "Categories", default)]
+    pub categories: Vec<Category>,
+    #[serde(rename = "Characteristics", default)]
+    pub characteristics: Vec<Characteristic>,
+    #[serde(rename = "Services", default)]
+    pub services: Vec<Service>,
+}
+
+impl Metadata {
+    /// Parses metadata from JSON.
+    pub fn from_slice(json: &[u8]) -> Result<Metadata> { Ok(serde_json::from_slice(json)?) }
+
+    /// Reads metadata from a JSON file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Metadata> { Metadata::from_slice(&fs::read(path)?) }
+
+    /// Returns the metadata of the Characteristics, Services and categories predefined by Apple,
+    /// from which the modules of the `hap` crate are generated.
+    pub fn hap_default() -> Metadata {
+        Metadata::from_slice(crate::DEFAULT_METADATA.as_bytes()).expect("couldn't parse default metadata")
+    }
+
+    /// Appends the categories, Characteristics and Services of another metadata set.
+    pub fn extend(&mut self, other: Metadata) {
+        self.categories.extend(other.categories);
+        self.characteristics.extend(other.characteristics);
+        self.services.extend(other.services);
+    }
+}
+
+/// Accessory category.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Category {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Category")]
+    pub number: u8,
+}
+
+/// Characteristic definition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Characteristic {
+    #[serde(rename = "UUID")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Format")]
+    pub format: String,
+    #[serde(rename = "Unit")]
+    pub unit: Option<String>,
+    #[serde(rename = "Constraints")]
+    pub constraints: Option<Constraints>,
+    #[serde(rename = "Permissions")]
+    pub permissions: Option<Vec<String>>,
+    #[serde(rename = "Properties")]
+    pub properties: Option<Vec<String>>,
+}
+
+/// Constraints of the value of a Characteristic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Constraints {
+    #[serde(rename = "ValidValues")]
+    pub valid_values: Option<HashMap<String, String>>,
+    #[serde(rename = "MaximumValue")]
+    pub max_value: Option<serde_json::Value>,
+    #[serde(rename = "MinimumValue")]
+    pub min_value: Option<serde_json::Value>,
+    #[serde(rename = "StepValue")]
+    pub step_value: Option<serde_json::Value>,
+    #[serde(rename = "MaximumLength")]
+    pub max_len: Option<u16>,
+    #[serde(rename = "MaximumDataLength")]
+    pub max_data_len: Option<u32>,
+}
+
+/// Service definition, referencing its Characteristics by UUID.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Service {
+    #[serde(rename = "UUID")]
+    pub id: String,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "RequiredCharacteristics")]
+    pub required_characteristics: Vec<String>,
+    #[serde(rename = "OptionalCharacteristics", default)]
+    pub optional_characteristics: Vec<String>,
+}
@@ -0,0 +1,410 @@
+pub(crate) static CATEGORIES: &str = "// THIS FILE IS AUTO-GENERATED\n
+/// HAP Accessory category.
+#[derive(Copy, Clone)]
+pub enum Category {
+{{#each Categories as |c|}}\
+\t{{trim c.Name}} = {{c.Category}},
+{{/each}}\
+}
+";
+
+pub(crate) static HAP_TYPE: &str = "// THIS FILE IS AUTO-GENERATED\n
+use serde::ser::{Serialize, Serializer};
+use uuid::Uuid;
+
+/// HAP Service and Characteristic type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HapType {
+    #[default]
+    Unknown,
+    /// Custom type of a vendor-specific Service or Characteristic, identified by its full UUID.
+    Custom(Uuid),
+{{#each Characteristics as |c|}}\
+\t{{trim c.Name}},
+{{/each}}\
+{{#each Services as |s|}}\
+\t{{trim s.Name}},
+{{/each}}\
+}
+
+impl HapType {
+    /// Converts a `HapType` to its corresponding UUID string. Apple-defined types are converted to
+    /// their shortened form, custom types to their full upper-case form.
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(self) -> String {
+        match self {
+            HapType::Unknown => \"unknown\".into(),
+            HapType::Custom(uuid) => uuid.to_hyphenated().to_string().to_uppercase(),
+{{#each Characteristics as |c|}}\
+\t\t\tHapType::{{trim c.Name}} => \"{{uuid c.UUID}}\".into(),
+{{/each}}\
+{{#each Services as |s|}}\
+\t\t\tHapType::{{trim s.Name}} => \"{{uuid s.UUID}}\".into(),
+{{/each}}\
+\t\t}
+    }
+}
+
+impl Serialize for HapType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+";
+
+pub(crate) static CHARACTERISTIC: &str = "// THIS FILE IS AUTO-GENERATED\n
+{{#if characteristic.Constraints.ValidValues}}use std::convert::TryFrom;\n\n{{/if}}\
+{{#if custom}}\
+use {{root}}::characteristic::{custom, Characteristic, Format, Perm{{#if characteristic.Unit}}, Unit{{/if}}};
+{{else}}\
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm{{#if characteristic.Unit}}, Unit{{/if}}};
+{{/if}}\
+{{#if characteristic.Constraints.ValidValues}}\
+use {{root}}::{characteristic::ValidValue, Error, {{#if custom}}HapType, {{/if}}Result};\n{{/if}}
+/// {{characteristic.Name}} Characteristic.
+pub type {{trim characteristic.Name}} = Characteristic<{{type characteristic.Format}}>;
+
+/// Creates a new {{characteristic.Name}} Characteristic.
+pub fn new() -> {{trim characteristic.Name}} {
+{{#if custom}}    {{#if configured}}let mut characteristic = {{/if}}custom::new::<{{type characteristic.Format}}>({{uuid}}, {{format characteristic.Format}}, vec![{{perms characteristic.Properties}}
+    ]).expect(\"invalid format of the custom Characteristic\"){{#if configured}};{{/if}}\
+        {{#if characteristic.Unit}}\n    characteristic.set_unit(Some({{unit characteristic.Unit}})).expect(\"couldn't access characteristic\");{{/if}}\
+        {{#if characteristic.Constraints.MaximumValue includeZero=true}}\n    characteristic.set_max_value(Some({{characteristic.Constraints.MaximumValue}}{{float characteristic.Format}})).expect(\"couldn't access characteristic\");{{/if}}\
+        {{#if characteristic.Constraints.MinimumValue includeZero=true}}\n    characteristic.set_min_value(Some({{characteristic.Constraints.MinimumValue}}{{float characteristic.Format}})).expect(\"couldn't access characteristic\");{{/if}}\
+        {{#if characteristic.Constraints.StepValue includeZero=true}}\n    characteristic.set_step_value(Some({{characteristic.Constraints.StepValue}}{{float characteristic.Format}})).expect(\"couldn't access characteristic\");{{/if}}\
+        {{#if characteristic.Constraints.MaximumLength includeZero=true}}\n    characteristic.set_max_len(Some({{characteristic.Constraints.MaximumLength}})).expect(\"couldn't access characteristic\");{{/if}}\
+        {{#if characteristic.Constraints.MaximumDataLength includeZero=true}}\n    characteristic.set_max_data_len(Some({{characteristic.Constraints.MaximumDataLength}})).expect(\"couldn't access characteristic\");{{/if}}\
+        {{#if characteristic.Constraints.ValidValues includeZero=true}}\n    characteristic.set_valid_values(Some({{valid_values characteristic.Constraints.ValidValues}})).expect(\"couldn't access characteristic\");{{/if}}
+{{#if configured}}    characteristic\n{{/if}}\
+{{else}}    Characteristic::new(Inner::<{{type characteristic.Format}}> {
+        hap_type: {{hap_type}},
+        format: {{format characteristic.Format}},
+        perms: vec![{{perms characteristic.Properties}}
+        ],\
+        {{#if characteristic.Unit}}\n        unit: Some({{unit characteristic.Unit}}),{{/if}}\
+        {{#if characteristic.Constraints.MaximumValue includeZero=true}}\n        max_value: Some({{characteristic.Constraints.MaximumValue}}{{float characteristic.Format}}),{{/if}}\
+        {{#if characteristic.Constraints.MinimumValue includeZero=true}}\n        min_value: Some({{characteristic.Constraints.MinimumValue}}{{float characteristic.Format}}),{{/if}}\
+        {{#if characteristic.Constraints.StepValue includeZero=true}}\n        step_value: Some({{characteristic.Constraints.StepValue}}{{float characteristic.Format}}),{{/if}}\
+        {{#if characteristic.Constraints.MaximumLength includeZero=true}}\n        max_len: Some({{characteristic.Constraints.MaximumLength}}{{float characteristic.Format}}),{{/if}}\
+        {{#if characteristic.Constraints.MaximumDataLength includeZero=true}}\n        max_data_len: Some({{characteristic.Constraints.MaximumDataLength}}{{float characteristic.Format}}),{{/if}}\
+        {{#if characteristic.Constraints.ValidValues includeZero=true}}\n        valid_values: Some({{valid_values characteristic.Constraints.ValidValues}}),{{/if}}
+        ..Default::default()
+    })
+{{/if}}\
+}
+{{#if characteristic.Constraints.ValidValues}}
+/// Valid values of the {{characteristic.Name}} Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr({{type characteristic.Format}})]
+pub enum Value {
+{{valid_value_variants characteristic.Constraints.ValidValues characteristic.Name}}\
+}
+
+impl From<Value> for {{type characteristic.Format}} {
+    fn from(value: Value) -> {{type characteristic.Format}} { value as {{type characteristic.Format}} }
+}
+
+impl TryFrom<{{type characteristic.Format}}> for Value {
+    type Error = Error;
+
+    fn try_from(value: {{type characteristic.Format}}) -> Result<Value> {
+        match value {
+{{valid_value_arms characteristic.Constraints.ValidValues characteristic.Name}}            _ => Err(Error::from_str(\"invalid value of the {{characteristic.Name}} Characteristic\")),
+        }
+    }
+}
+
+impl ValidValue<{{type characteristic.Format}}> for Value {
+    fn hap_type() -> HapType { {{hap_type}} }
+}
+{{/if}}\
+";
+
+pub(crate) static CHARACTERISTIC_MOD: &str = "// THIS FILE IS AUTO-GENERATED
+
+use crate::{characteristic::HapCharacteristic, HapType};
+{{#each characteristics as |c|}}\npub mod {{file_name c.Name}};{{/each}}
+
+/// Creates a new Characteristic of the given `HapType`. Returns `None` if the `HapType` isn't a
+/// Characteristic type.
+pub fn new_by_type(hap_type: HapType) -> Option<Box<dyn HapCharacteristic + Send>> {
+    match hap_type {
+{{#each characteristics as |c|}}\
+\t\tHapType::{{trim c.Name}} => Some(Box::new({{file_name c.Name}}::new())),
+{{/each}}\
+\t\t_ => None,
+    }
+}
+";
+
+pub(crate) static SERVICE: &str = "// THIS FILE IS AUTO-GENERATED\n
+use {{root}}::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+{{#each service.RequiredCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\t{{characteristic_file_name r ../../this.characteristics}},
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+{{#each service.OptionalCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\t{{characteristic_file_name r ../../this.characteristics}},
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\t},
+    HapType,
+};
+
+/// {{service.Name}} Service.
+pub type {{trim service.Name}} = Service<{{trim service.Name}}Inner>;
+{{#unless custom}}
+impl Default for {{trim service.Name}} {
+    fn default() -> {{trim service.Name}} { new() }
+}
+{{/unless}}
+/// Inner type of the {{service.Name}} Service.
+#[derive(Default)]
+pub struct {{trim service.Name}}Inner {
+    /// ID of the {{service.Name}} Service.
+    id: u64,
+    /// `HapType` of the {{service.Name}} Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+{{#each service.RequiredCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t/// {{c.Name}} Characteristic.
+\tpub {{characteristic_file_name r ../../this.characteristics}}: {{characteristic_file_name r ../../this.characteristics}}::{{characteristic_name r ../../this.characteristics}},
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\n{{#each service.OptionalCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t/// {{c.Name}} Characteristic.
+\tpub {{characteristic_file_name r ../../this.characteristics}}: Option<{{characteristic_file_name r ../../this.characteristics}}::{{characteristic_name r ../../this.characteristics}}>,
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\n\t/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+\tpub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for {{trim service.Name}}Inner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+{{#each service.RequiredCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\t\t&self.{{characteristic_file_name r ../../this.characteristics}},
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\t\t];
+{{#each service.OptionalCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\tif let Some(c) = &self.{{characteristic_file_name r ../../this.characteristics}} {
+\t\t\tcharacteristics.push(c);
+\t\t}
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\t\tfor c in &self.additional_characteristics {
+\t\t\tcharacteristics.push(c.as_ref());
+\t\t}
+\t\tcharacteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+{{#each service.RequiredCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\t\t&mut self.{{characteristic_file_name r ../../this.characteristics}},
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\t\t];
+{{#each service.OptionalCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\tif let Some(c) = &mut self.{{characteristic_file_name r ../../this.characteristics}} {
+\t\t\tcharacteristics.push(c);
+\t\t}
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\t\tfor c in &mut self.additional_characteristics {
+\t\t\tcharacteristics.push(c.as_mut());
+\t\t}
+\t\tcharacteristics
+    }
+}
+
+/// Creates a new {{service.Name}} Service.
+pub fn new() -> {{trim service.Name}} {
+    {{trim service.Name}}::new({{trim service.Name}}Inner {
+        hap_type: {{hap_type}},
+{{#each service.RequiredCharacteristics as |r|}}\
+{{#each ../this.characteristics as |c|}}\
+{{#if_eq r c.UUID}}\
+\t\t{{characteristic_file_name r ../../this.characteristics}}: {{characteristic_file_name r ../../this.characteristics}}::new(),
+{{/if_eq}}\
+{{/each}}\
+{{/each}}\
+\t\t..Default::default()
+    })
+}
+";
+
+pub(crate) static SERVICE_MOD: &str = "// THIS FILE IS AUTO-GENERATED
+
+use crate::HapType;
+{{#each services as |s|}}\npub mod {{file_name s.Name}};{{/each}}
+
+/// Returns the `HapType`s of the required Characteristics of a Service of the given `HapType`.
+/// Returns `None` if the `HapType` isn't a Service type.
+pub fn required_characteristics(hap_type: HapType) -> Option<Vec<HapType>> {
+    match hap_type {
+{{#each services as |s|}}\
+\t\tHapType::{{trim s.Name}} => Some(vec![\
+{{#each s.RequiredCharacteristics as |r|}}\
+{{#if @index}}, {{/if}}HapType::{{characteristic_name r ../../characteristics}}\
+{{/each}}\
+]),
+{{/each}}\
+\t\t_ => None,
+    }
+}
+";
+
+pub(crate) static ACCESSORY: &str = "// THIS FILE IS AUTO-GENERATED\n
+use {{root}}::{
+\taccessory::{self, HapAccessory, HapAccessoryService, Accessory, Information},
+\tservice::{HapService, accessory_information::AccessoryInformation, {{snake_case service.Name}}},
+\tevent::EventEmitterPtr,
+\tResult,
+};
+
+/// {{service.Name}} Accessory.
+pub type {{trim service.Name}} = Accessory<{{trim service.Name}}Inner>;
+
+/// Inner type of the {{service.Name}} Accessory.
+{{#unless custom}}#[derive(Default)]
+{{/unless}}pub struct {{trim service.Name}}Inner {
+    /// ID of the {{service.Name}} Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// {{service.Name}} Service.
+    pub {{snake_case service.Name}}: {{snake_case service.Name}}::{{trim service.Name}},
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+impl HapAccessory for {{trim service.Name}}Inner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_services(&self) -> Vec<&dyn HapAccessoryService> {
+        let mut services: Vec<&dyn HapAccessoryService> = vec![
+            &self.accessory_information,
+            &self.{{snake_case service.Name}},
+        ];
+        for s in &self.additional_services {
+            services.push(s.as_ref());
+        }
+        services
+    }
+
+    fn get_mut_services(&mut self) -> Vec<&mut dyn HapAccessoryService> {
+        let mut services: Vec<&mut dyn HapAccessoryService> = vec![
+            &mut self.accessory_information,
+            &mut self.{{snake_case service.Name}},
+        ];
+        for s in &mut self.additional_services {
+            services.push(s.as_mut());
+        }
+        services
+    }
+
+    fn get_mut_information(&mut self) -> &mut AccessoryInformation {
+        &mut self.accessory_information
+    }
+
+    fn init_iids(&mut self, accessory_id: u64, event_emitter: EventEmitterPtr) -> Result<()> {
+        accessory::init_service_iids(self.get_mut_services(), accessory_id, event_emitter)
+    }
+}
+
+/// Creates a new {{service.Name}} Accessory.
+pub fn new(information: Information) -> Result<{{trim service.Name}}> {
+    let mut {{snake_case service.Name}} = {{snake_case service.Name}}::new();
+    {{snake_case service.Name}}.set_primary(true);
+    Ok({{trim service.Name}}::new({{trim service.Name}}Inner {
+        accessory_information: information.to_service()?,
+        {{snake_case service.Name}},
+{{#if custom}}        id: 0,
+        additional_services: Vec::new(),
+{{else}}        ..Default::default()
+{{/if}}    }))
+}
+";
+
+pub(crate) static ACCESSORY_MOD: &str = "// THIS FILE IS AUTO-GENERATED
+{{#each accessories as |a|}}\npub mod {{a}};{{/each}}
+";
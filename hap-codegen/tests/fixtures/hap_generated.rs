@@ -0,0 +1,329 @@
+// THIS FILE IS AUTO-GENERATED
+
+#[allow(unused_imports)]
+use hap::{event, Error, HapType, Result, Uuid};
+
+pub mod characteristic {
+    pub use hap::characteristic::*;
+
+    pub mod total_consumption {
+// THIS FILE IS AUTO-GENERATED
+
+use super::super::characteristic::{custom, Characteristic, Format, Perm};
+
+/// Total Consumption Characteristic.
+pub type TotalConsumption = Characteristic<f32>;
+
+/// Creates a new Total Consumption Characteristic.
+pub fn new() -> TotalConsumption {
+    let mut characteristic = custom::new::<f32>(super::super::Uuid::from_bytes([0xE8, 0x63, 0xF1, 0x0C, 0x07, 0x9E, 0x48, 0xFF, 0x8F, 0x27, 0x9C, 0x26, 0x05, 0xA2, 0x9F, 0x52]), Format::Float, vec![
+            Perm::PairedRead,
+            Perm::Events,
+    ]).expect("invalid format of the custom Characteristic");
+    characteristic.set_max_value(Some(1000000_f32)).expect("couldn't access characteristic");
+    characteristic.set_min_value(Some(0_f32)).expect("couldn't access characteristic");
+    characteristic.set_step_value(Some(0.1_f32)).expect("couldn't access characteristic");
+    characteristic
+}
+
+    }
+
+    pub mod firmware_channel {
+// THIS FILE IS AUTO-GENERATED
+
+use super::super::characteristic::{custom, Characteristic, Format, Perm};
+
+/// Firmware Channel Characteristic.
+pub type FirmwareChannel = Characteristic<String>;
+
+/// Creates a new Firmware Channel Characteristic.
+pub fn new() -> FirmwareChannel {
+    custom::new::<String>(super::super::Uuid::from_bytes([0xB3, 0xE0, 0xE8, 0xA2, 0x1D, 0x4B, 0x4C, 0x1E, 0x9E, 0x1A, 0x5A, 0x8F, 0x3C, 0x2D, 0x7E, 0x10]), Format::String, vec![
+            Perm::PairedRead,
+    ]).expect("invalid format of the custom Characteristic")
+}
+
+    }
+
+    pub mod metering_mode {
+// THIS FILE IS AUTO-GENERATED
+
+use std::convert::TryFrom;
+
+use super::super::characteristic::{custom, Characteristic, Format, Perm};
+use super::super::{characteristic::ValidValue, Error, HapType, Result};
+
+/// Metering Mode Characteristic.
+pub type MeteringMode = Characteristic<u8>;
+
+/// Creates a new Metering Mode Characteristic.
+pub fn new() -> MeteringMode {
+    let mut characteristic = custom::new::<u8>(super::super::Uuid::from_bytes([0xB3, 0xE0, 0xE8, 0xA2, 0x1D, 0x4B, 0x4C, 0x1E, 0x9E, 0x1A, 0x5A, 0x8F, 0x3C, 0x2D, 0x7E, 0x11]), Format::UInt8, vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
+    ]).expect("invalid format of the custom Characteristic");
+    characteristic.set_valid_values(Some(vec![
+            0, // "Off"
+            1, // "Eco"
+            2, // "Full"
+        ])).expect("couldn't access characteristic");
+    characteristic
+}
+
+/// Valid values of the Metering Mode Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Off.
+    Off = 0,
+    /// Eco.
+    Eco = 1,
+    /// Full.
+    Full = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Off),
+            1 => Ok(Value::Eco),
+            2 => Ok(Value::Full),
+            _ => Err(Error::from_str("invalid value of the Metering Mode Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::Custom(super::super::Uuid::from_bytes([0xB3, 0xE0, 0xE8, 0xA2, 0x1D, 0x4B, 0x4C, 0x1E, 0x9E, 0x1A, 0x5A, 0x8F, 0x3C, 0x2D, 0x7E, 0x11])) }
+}
+
+    }
+}
+
+pub mod service {
+    pub use hap::service::*;
+
+    pub mod power_meter {
+// THIS FILE IS AUTO-GENERATED
+
+use super::super::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		total_consumption,
+		firmware_channel,
+		metering_mode,
+		name,
+	},
+    HapType,
+};
+
+/// Power Meter Service.
+pub type PowerMeter = Service<PowerMeterInner>;
+
+/// Inner type of the Power Meter Service.
+#[derive(Default)]
+pub struct PowerMeterInner {
+    /// ID of the Power Meter Service.
+    id: u64,
+    /// `HapType` of the Power Meter Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Total Consumption Characteristic.
+	pub total_consumption: total_consumption::TotalConsumption,
+
+	/// Firmware Channel Characteristic.
+	pub firmware_channel: Option<firmware_channel::FirmwareChannel>,
+	/// Metering Mode Characteristic.
+	pub metering_mode: Option<metering_mode::MeteringMode>,
+	/// Name Characteristic.
+	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for PowerMeterInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.total_consumption,
+		];
+		if let Some(c) = &self.firmware_channel {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.metering_mode {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.name {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.total_consumption,
+		];
+		if let Some(c) = &mut self.firmware_channel {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.metering_mode {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.name {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Power Meter Service.
+pub fn new() -> PowerMeter {
+    PowerMeter::new(PowerMeterInner {
+        hap_type: HapType::Custom(super::super::Uuid::from_bytes([0xB3, 0xE0, 0xE8, 0xA2, 0x1D, 0x4B, 0x4C, 0x1E, 0x9E, 0x1A, 0x5A, 0x8F, 0x3C, 0x2D, 0x7E, 0x20])),
+		total_consumption: total_consumption::new(),
+		..Default::default()
+    })
+}
+
+    }
+}
+
+pub mod accessory {
+    pub use hap::accessory::*;
+
+    pub mod power_meter {
+// THIS FILE IS AUTO-GENERATED
+
+use super::super::{
+	accessory::{self, HapAccessory, HapAccessoryService, Accessory, Information},
+	service::{HapService, accessory_information::AccessoryInformation, power_meter},
+	event::EventEmitterPtr,
+	Result,
+};
+
+/// Power Meter Accessory.
+pub type PowerMeter = Accessory<PowerMeterInner>;
+
+/// Inner type of the Power Meter Accessory.
+pub struct PowerMeterInner {
+    /// ID of the Power Meter Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Power Meter Service.
+    pub power_meter: power_meter::PowerMeter,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+impl HapAccessory for PowerMeterInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_services(&self) -> Vec<&dyn HapAccessoryService> {
+        let mut services: Vec<&dyn HapAccessoryService> = vec![
+            &self.accessory_information,
+            &self.power_meter,
+        ];
+        for s in &self.additional_services {
+            services.push(s.as_ref());
+        }
+        services
+    }
+
+    fn get_mut_services(&mut self) -> Vec<&mut dyn HapAccessoryService> {
+        let mut services: Vec<&mut dyn HapAccessoryService> = vec![
+            &mut self.accessory_information,
+            &mut self.power_meter,
+        ];
+        for s in &mut self.additional_services {
+            services.push(s.as_mut());
+        }
+        services
+    }
+
+    fn get_mut_information(&mut self) -> &mut AccessoryInformation {
+        &mut self.accessory_information
+    }
+
+    fn init_iids(&mut self, accessory_id: u64, event_emitter: EventEmitterPtr) -> Result<()> {
+        accessory::init_service_iids(self.get_mut_services(), accessory_id, event_emitter)
+    }
+}
+
+/// Creates a new Power Meter Accessory.
+pub fn new(information: Information) -> Result<PowerMeter> {
+    let mut power_meter = power_meter::new();
+    power_meter.set_primary(true);
+    Ok(PowerMeter::new(PowerMeterInner {
+        accessory_information: information.to_service()?,
+        power_meter,
+        id: 0,
+        additional_services: Vec::new(),
+    }))
+}
+
+    }
+}
@@ -0,0 +1,80 @@
+//! Tests of the code generated for user-supplied metadata. The snapshot in `tests/fixtures` is
+//! compiled and linted against the `hap` crate by its `codegen` tests.
+
+use std::{env, fs, path::PathBuf};
+
+use hap_codegen::{Error, Generator, Metadata, GENERATED_FILE_NAME};
+
+fn fixture(name: &str) -> PathBuf { PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name) }
+
+fn out_dir(name: &str) -> PathBuf {
+    let out_dir = env::temp_dir().join(format!("hap-codegen-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&out_dir).unwrap();
+    out_dir
+}
+
+/// Compares the code generated for `user.metadata.json` to `hap_generated.rs`. The snapshot is
+/// regenerated with `cargo run -p hap-codegen -- tests/fixtures tests/fixtures/user.metadata.json`
+/// from the `hap-codegen` directory.
+#[test]
+fn user_metadata_matches_snapshot() {
+    let out_dir = out_dir("snapshot");
+    let path = hap_codegen::generate(&[fixture("user.metadata.json")], &out_dir).unwrap();
+    let generated = fs::read_to_string(&path).unwrap();
+    fs::remove_dir_all(&out_dir).unwrap();
+
+    let snapshot = fs::read_to_string(fixture(GENERATED_FILE_NAME)).unwrap();
+    assert!(
+        generated == snapshot,
+        "generated code differs from the snapshot, regenerate it if the change is intended"
+    );
+}
+
+#[test]
+fn characteristics_without_properties_are_rejected() {
+    let metadata = Metadata::from_slice(
+        br#"{
+            "Characteristics": [
+                {
+                    "UUID": "B3E0E8A2-1D4B-4C1E-9E1A-5A8F3C2D7E10",
+                    "Name": "Firmware Channel",
+                    "Format": "string"
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let out_dir = out_dir("properties");
+    let res = Generator::new().generate(&metadata, &out_dir);
+    fs::remove_dir_all(&out_dir).unwrap();
+    match res {
+        Err(Error::Metadata(e)) => assert_eq!(e, "missing Properties of the Firmware Channel Characteristic"),
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn unknown_formats_are_rejected() {
+    let metadata = Metadata::from_slice(
+        br#"{
+            "Characteristics": [
+                {
+                    "UUID": "B3E0E8A2-1D4B-4C1E-9E1A-5A8F3C2D7E10",
+                    "Name": "Firmware Channel",
+                    "Format": "uint128",
+                    "Properties": ["read"]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let out_dir = out_dir("format");
+    let res = Generator::new().generate(&metadata, &out_dir);
+    fs::remove_dir_all(&out_dir).unwrap();
+    match res {
+        Err(Error::Render(_)) => {},
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
@@ -0,0 +1,370 @@
+//! Derive macros for the `HapAccessory` and `HapService` traits of the `hap` crate.
+//!
+//! Use them through the re-exports `hap::accessory::HapAccessory` and `hap::service::HapService`.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input,
+    Data,
+    DeriveInput,
+    Error,
+    Field,
+    Fields,
+    GenericArgument,
+    Ident,
+    Meta,
+    NestedMeta,
+    PathArguments,
+    Type,
+};
+
+/// Fields of an Accessory inner type that aren't Services.
+const ACCESSORY_FIELDS: &[&str] = &["id", "accessory_information"];
+/// Fields of a Service inner type that aren't Characteristics.
+const SERVICE_FIELDS: &[&str] = &["id", "hap_type", "hidden", "primary", "linked_services"];
+
+/// Derives `HapAccessory` for the inner type of an Accessory.
+///
+/// The struct needs an `id: u64` field and an `accessory_information: AccessoryInformation` field.
+/// Every other field is a Service of the Accessory, either a single Service, an `Option` or a `Vec`
+/// of Services, each of them possibly boxed. Fields marked with `#[hap(skip)]` are left out. The
+/// Accessory Information Service always comes first, the other Services follow in the order of
+/// their fields.
+#[proc_macro_derive(HapAccessory, attributes(hap))]
+pub fn derive_hap_accessory(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_hap_accessory(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `HapService` for the inner type of a Service.
+///
+/// The struct needs the fields `id: u64`, `hap_type: HapType`, `hidden: bool`, `primary: bool` and
+/// `linked_services: Vec<u64>`. Every other field is a Characteristic of the Service, either a
+/// single Characteristic, an `Option` or a `Vec` of Characteristics, each of them possibly boxed.
+/// Fields marked with `#[hap(skip)]` are left out.
+#[proc_macro_derive(HapService, attributes(hap))]
+pub fn derive_hap_service(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_hap_service(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_hap_accessory(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let services = member_fields(&input, ACCESSORY_FIELDS)?;
+    let push_services = services.iter().map(|f| f.push(false));
+    let push_mut_services = services.iter().map(|f| f.push(true));
+
+    Ok(quote! {
+        impl #impl_generics ::hap::accessory::HapAccessory for #name #ty_generics #where_clause {
+            fn get_id(&self) -> u64 { self.id }
+
+            fn set_id(&mut self, id: u64) { self.id = id; }
+
+            fn get_services(&self) -> Vec<&dyn ::hap::accessory::HapAccessoryService> {
+                let mut members: Vec<&dyn ::hap::accessory::HapAccessoryService> =
+                    vec![&self.accessory_information];
+                #(#push_services)*
+                members
+            }
+
+            fn get_mut_services(&mut self) -> Vec<&mut dyn ::hap::accessory::HapAccessoryService> {
+                let mut members: Vec<&mut dyn ::hap::accessory::HapAccessoryService> =
+                    vec![&mut self.accessory_information];
+                #(#push_mut_services)*
+                members
+            }
+
+            fn get_mut_information(
+                &mut self,
+            ) -> &mut ::hap::service::accessory_information::AccessoryInformation {
+                &mut self.accessory_information
+            }
+
+            fn init_iids(
+                &mut self,
+                accessory_id: u64,
+                event_emitter: ::hap::event::EventEmitterPtr,
+            ) -> ::hap::Result<()> {
+                ::hap::accessory::init_service_iids(
+                    ::hap::accessory::HapAccessory::get_mut_services(self),
+                    accessory_id,
+                    event_emitter,
+                )
+            }
+        }
+    })
+}
+
+fn expand_hap_service(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let characteristics = member_fields(&input, SERVICE_FIELDS)?;
+    let push_characteristics = characteristics.iter().map(|f| f.push(false));
+    let push_mut_characteristics = characteristics.iter().map(|f| f.push(true));
+
+    Ok(quote! {
+        impl #impl_generics ::hap::service::HapService for #name #ty_generics #where_clause {
+            fn get_id(&self) -> u64 { self.id }
+
+            fn set_id(&mut self, id: u64) { self.id = id; }
+
+            fn get_type(&self) -> ::hap::HapType { self.hap_type }
+
+            fn get_hidden(&self) -> bool { self.hidden }
+
+            fn set_hidden(&mut self, hidden: bool) { self.hidden = hidden; }
+
+            fn get_primary(&self) -> bool { self.primary }
+
+            fn set_primary(&mut self, primary: bool) { self.primary = primary; }
+
+            fn get_linked_services(&self) -> Vec<u64> { self.linked_services.clone() }
+
+            fn set_linked_services(&mut self, linked_services: Vec<u64>) { self.linked_services = linked_services; }
+
+            fn get_characteristics(&self) -> Vec<&dyn ::hap::characteristic::HapCharacteristic> {
+                let mut members: Vec<&dyn ::hap::characteristic::HapCharacteristic> = Vec::new();
+                #(#push_characteristics)*
+                members
+            }
+
+            fn get_mut_characteristics(&mut self) -> Vec<&mut dyn ::hap::characteristic::HapCharacteristic> {
+                let mut members: Vec<&mut dyn ::hap::characteristic::HapCharacteristic> = Vec::new();
+                #(#push_mut_characteristics)*
+                members
+            }
+        }
+    })
+}
+
+/// How a member, i.e. a Service of an Accessory or a Characteristic of a Service, is held by a
+/// field.
+#[derive(Debug, PartialEq)]
+enum Container {
+    Single,
+    Option,
+    Vec,
+}
+
+/// A field holding members.
+struct MemberField {
+    ident: Ident,
+    container: Container,
+    boxed: bool,
+}
+
+impl MemberField {
+    /// Returns the statements pushing the members of the field to `members`.
+    fn push(&self, mutable: bool) -> TokenStream2 {
+        let ident = &self.ident;
+        let member = match (self.boxed, mutable) {
+            (false, _) => quote!(m),
+            (true, false) => quote!(m.as_ref()),
+            (true, true) => quote!(m.as_mut()),
+        };
+        let reference = if mutable { quote!(&mut) } else { quote!(&) };
+        match self.container {
+            Container::Single => quote! {
+                {
+                    let m = #reference self.#ident;
+                    members.push(#member);
+                }
+            },
+            Container::Option => quote! {
+                if let Some(m) = #reference self.#ident {
+                    members.push(#member);
+                }
+            },
+            Container::Vec => quote! {
+                for m in #reference self.#ident {
+                    members.push(#member);
+                }
+            },
+        }
+    }
+}
+
+/// Returns the member fields of a struct, i.e. all named fields besides the given ones and the ones
+/// marked with `#[hap(skip)]`.
+fn member_fields(input: &DeriveInput, non_member_fields: &[&str]) -> syn::Result<Vec<MemberField>> {
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "expected a struct with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "expected a struct with named fields")),
+    };
+
+    let mut member_fields = Vec::new();
+    for field in fields {
+        let ident = field.ident.clone().expect("named field without ident");
+        if non_member_fields.iter().any(|f| ident == f) || is_skipped(field)? {
+            continue;
+        }
+        let (container, inner) = match generic_argument(&field.ty, "Option") {
+            Some(inner) => (Container::Option, inner),
+            None => match generic_argument(&field.ty, "Vec") {
+                Some(inner) => (Container::Vec, inner),
+                None => (Container::Single, &field.ty),
+            },
+        };
+        member_fields.push(MemberField {
+            ident,
+            container,
+            boxed: generic_argument(inner, "Box").is_some(),
+        });
+    }
+    Ok(member_fields)
+}
+
+/// Returns whether a field is marked with `#[hap(skip)]`.
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in &field.attrs {
+        if !attr.path.is_ident("hap") {
+            continue;
+        }
+        if let Meta::List(list) = attr.parse_meta()? {
+            for nested in &list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => skipped = true,
+                    _ => return Err(Error::new_spanned(nested, "unknown hap attribute")),
+                }
+            }
+        }
+    }
+    Ok(skipped)
+}
+
+/// Returns the generic argument of a type like `Option<T>` if the last segment of its path is the
+/// given wrapper.
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match *ty {
+        Type::Path(ref path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != wrapper {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref arguments) if arguments.args.len() == 1 => match arguments.args[0] {
+            GenericArgument::Type(ref inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn member_fields_are_classified() {
+        let input: DeriveInput = parse_quote! {
+            struct FaucetInner {
+                id: u64,
+                accessory_information: AccessoryInformation,
+                faucet: Faucet,
+                heater_cooler: Option<HeaterCooler>,
+                valves: Vec<Valve>,
+                boxed: Box<Valve>,
+                optional_boxed: Option<Box<Valve>>,
+                boxed_vec: Vec<Box<Valve>>,
+                #[hap(skip)]
+                state: u8,
+            }
+        };
+
+        let fields = member_fields(&input, ACCESSORY_FIELDS).unwrap();
+        let fields = fields
+            .iter()
+            .map(|f| (f.ident.to_string(), &f.container, f.boxed))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec![
+            ("faucet".into(), &Container::Single, false),
+            ("heater_cooler".into(), &Container::Option, false),
+            ("valves".into(), &Container::Vec, false),
+            ("boxed".into(), &Container::Single, true),
+            ("optional_boxed".into(), &Container::Option, true),
+            ("boxed_vec".into(), &Container::Vec, true),
+        ]);
+    }
+
+    #[test]
+    fn boxed_options_are_pushed_as_references() {
+        let field = MemberField {
+            ident: parse_quote!(valve),
+            container: Container::Option,
+            boxed: true,
+        };
+
+        let expected = quote! {
+            if let Some(m) = &self.valve {
+                members.push(m.as_ref());
+            }
+        };
+        assert_eq!(field.push(false).to_string(), expected.to_string());
+        let expected = quote! {
+            if let Some(m) = &mut self.valve {
+                members.push(m.as_mut());
+            }
+        };
+        assert_eq!(field.push(true).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn skipped_fields_are_left_out_of_the_expansion() {
+        let input: DeriveInput = parse_quote! {
+            struct LightbulbInner {
+                id: u64,
+                hap_type: HapType,
+                hidden: bool,
+                primary: bool,
+                linked_services: Vec<u64>,
+                on: On,
+                #[hap(skip)]
+                internal: On,
+            }
+        };
+
+        let expanded = expand_hap_service(input).unwrap().to_string();
+        assert!(expanded.contains(&quote!(self.on).to_string()));
+        assert!(!expanded.contains(&quote!(self.internal).to_string()));
+    }
+
+    #[test]
+    fn unknown_attributes_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            struct LightbulbInner {
+                id: u64,
+                #[hap(rename = "power")]
+                on: On,
+            }
+        };
+
+        let err = member_fields(&input, SERVICE_FIELDS).err().unwrap();
+        assert_eq!(err.to_string(), "unknown hap attribute");
+    }
+
+    #[test]
+    fn tuple_structs_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            struct LightbulbInner(u64, On);
+        };
+
+        let err = expand_hap_accessory(input).err().unwrap();
+        assert_eq!(err.to_string(), "expected a struct with named fields");
+    }
+}
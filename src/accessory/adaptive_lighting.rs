@@ -0,0 +1,575 @@
+//! Adaptive Lighting for lightbulbs with Brightness and Color Temperature Characteristics.
+//!
+//! Attaching an `AdaptiveLighting` controller to a Lightbulb Service adds the Characteristic Value
+//! Transition Characteristics to it. A HomeKit controller then writes a transition curve of color
+//! temperatures over the course of the day, which is followed on every call to
+//! `AdaptiveLighting::update`, adjusted to the current brightness. Adaptive Lighting ends when the
+//! curve ends, when the controller ends it or when the color of the lightbulb is changed otherwise.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use hap::{
+//!     accessory::{adaptive_lighting, lightbulb, Information},
+//!     characteristic::{brightness, color_temperature},
+//! };
+//!
+//! let mut lightbulb = lightbulb::new(Information {
+//!     name: "Lightbulb".into(),
+//!     ..Default::default()
+//! })
+//! .unwrap();
+//! lightbulb.inner.lightbulb.inner.brightness = Some(brightness::new());
+//! lightbulb.inner.lightbulb.inner.color_temperature = Some(color_temperature::new());
+//!
+//! let adaptive_lighting = adaptive_lighting::attach(&mut lightbulb.inner.lightbulb).unwrap();
+//! adaptive_lighting::spawn(&adaptive_lighting, Duration::from_secs(1));
+//! ```
+
+use std::{
+    convert::TryFrom,
+    sync::{Arc, Mutex, Weak},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    characteristic::{
+        brightness::Brightness,
+        characteristic_value_active_transition_count::{self, CharacteristicValueActiveTransitionCount},
+        characteristic_value_transition_control,
+        color_temperature::ColorTemperature,
+        hue::Hue,
+        saturation::Saturation,
+        supported_characteristic_value_transition_configuration,
+        tlv8::{Nested, Repeated},
+        transition::{
+            SupportedTransition,
+            SupportedTransitionConfig,
+            TransitionControl,
+            TransitionControlResponse,
+            TransitionCurve,
+            TransitionEntry,
+            TransitionParameters,
+            TransitionStatus,
+            TRANSITION_TYPE_BRIGHTNESS,
+            TRANSITION_TYPE_COLOR_TEMPERATURE,
+        },
+        Readable,
+        Tlv8,
+        Updatable,
+    },
+    service::lightbulb::Lightbulb,
+    Error,
+    HapType,
+    Result,
+};
+
+/// Milliseconds from the Unix epoch to 2001-01-01 00:00:00 UTC, the epoch of transition start times.
+const EPOCH_2001_MILLIS: u64 = 978_307_200_000;
+
+/// Update interval used if the controller doesn't specify one.
+const DEFAULT_UPDATE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// `Clock` provides the current time to an `AdaptiveLighting` controller. It can be replaced to
+/// follow transitions in tests without waiting for them.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// `Clock` returning the system time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime { SystemTime::now() }
+}
+
+/// Reference-counting pointer to an `AdaptiveLighting` controller.
+pub type AdaptiveLightingPtr = Arc<Mutex<AdaptiveLighting>>;
+
+/// Transition written by the controller.
+struct ActiveTransition {
+    iid: u64,
+    parameters: TransitionParameters,
+    start: SystemTime,
+    entries: Vec<TransitionEntry>,
+    min_multiplier: f32,
+    max_multiplier: f32,
+    update_interval: Duration,
+}
+
+impl ActiveTransition {
+    /// Returns the value and adjustment factor of the curve at the given number of milliseconds
+    /// since the start of the transition, or `None` if the curve has ended.
+    fn curve_point(&self, elapsed: u64) -> Option<(f32, f32)> {
+        let mut reached: u64 = 0;
+        let mut previous: Option<&TransitionEntry> = None;
+        for entry in &self.entries {
+            let transition_start = reached;
+            reached = reached.saturating_add(entry.transition_offset);
+            if elapsed < reached {
+                return Some(match previous {
+                    Some(previous) => {
+                        let progress = (elapsed - transition_start) as f32 / entry.transition_offset as f32;
+                        (
+                            interpolate(previous.value, entry.value, progress),
+                            interpolate(previous.adjustment_factor, entry.adjustment_factor, progress),
+                        )
+                    },
+                    None => (entry.value, entry.adjustment_factor),
+                });
+            }
+            reached = reached.saturating_add(entry.duration.unwrap_or(0));
+            if elapsed < reached {
+                return Some((entry.value, entry.adjustment_factor));
+            }
+            previous = Some(entry);
+        }
+        None
+    }
+
+    /// Returns the milliseconds since the start of the transition.
+    fn elapsed(&self, now: SystemTime) -> u64 {
+        let elapsed = now.duration_since(self.start).unwrap_or_default();
+        u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+    }
+}
+
+fn interpolate(from: f32, to: f32, progress: f32) -> f32 { from + (to - from) * progress }
+
+/// Values of the lightbulb after the last update of the color temperature.
+struct LastUpdate {
+    time: SystemTime,
+    brightness: i32,
+    color_temperature: u32,
+    hue: Option<f32>,
+    saturation: Option<f32>,
+}
+
+/// Adaptive Lighting controller of a Lightbulb Service.
+pub struct AdaptiveLighting {
+    clock: Box<dyn Clock + Send>,
+    brightness: Brightness,
+    color_temperature: ColorTemperature,
+    hue: Option<Hue>,
+    saturation: Option<Saturation>,
+    active_transition_count: CharacteristicValueActiveTransitionCount,
+    transition: Option<ActiveTransition>,
+    last_update: Option<LastUpdate>,
+    response: Vec<u8>,
+}
+
+impl AdaptiveLighting {
+    /// Returns whether a transition is active.
+    pub fn is_active(&self) -> bool { self.transition.is_some() }
+
+    /// Ends the active transition.
+    pub fn disable(&mut self) -> Result<()> {
+        if self.transition.take().is_some() {
+            self.active_transition_count.set_value(0)?;
+        }
+        self.last_update = None;
+        self.response = Vec::new();
+        Ok(())
+    }
+
+    /// Sets the Color Temperature Characteristic to the value of the active transition at the
+    /// current time. The value is updated in the update interval requested by the controller and
+    /// whenever the brightness changes. Ends the transition if its curve has ended or if the color
+    /// of the lightbulb has been changed since the last update.
+    pub fn update(&mut self) -> Result<()> {
+        let now = self.clock.now();
+        let brightness = self.brightness.get_value()?;
+        let color_temperature = self.color_temperature.get_value()?;
+        let hue = match self.hue {
+            Some(ref mut hue) => Some(hue.get_value()?),
+            None => None,
+        };
+        let saturation = match self.saturation {
+            Some(ref mut saturation) => Some(saturation.get_value()?),
+            None => None,
+        };
+
+        let new_color_temperature = match self.transition {
+            Some(ref transition) => {
+                if let Some(ref last) = self.last_update {
+                    if last.color_temperature != color_temperature || last.hue != hue || last.saturation != saturation {
+                        return self.disable();
+                    }
+                    let due = now.duration_since(last.time).unwrap_or_default() >= transition.update_interval;
+                    if !due && last.brightness == brightness {
+                        return Ok(());
+                    }
+                }
+                match transition.curve_point(transition.elapsed(now)) {
+                    Some((value, adjustment_factor)) => {
+                        let multiplier = (brightness as f32)
+                            .max(transition.min_multiplier)
+                            .min(transition.max_multiplier);
+                        let min = self.color_temperature.get_min_value()?.map_or(0.0, |min| min as f32);
+                        let max = self
+                            .color_temperature
+                            .get_max_value()?
+                            .map_or(f32::MAX, |max| max as f32);
+                        (value + adjustment_factor * multiplier).round().max(min).min(max) as u32
+                    },
+                    None => return self.disable(),
+                }
+            },
+            None => return Ok(()),
+        };
+
+        if new_color_temperature != color_temperature {
+            self.color_temperature.set_value(new_color_temperature)?;
+        }
+        self.last_update = Some(LastUpdate {
+            time: now,
+            brightness,
+            color_temperature: new_color_temperature,
+            hue,
+            saturation,
+        });
+        Ok(())
+    }
+
+    /// Handles a value written to the Characteristic Value Transition Control Characteristic and
+    /// stores the response.
+    fn handle_control(&mut self, value: &[u8]) -> Result<()> {
+        self.response = Vec::new();
+        let control = TransitionControl::from_tlv8(value)?;
+        let color_temperature_iid = self.color_temperature.get_id()?;
+
+        if let Some(Nested(update)) = control.update {
+            let Nested(config) = update.config;
+            if config.iid != color_temperature_iid {
+                return Err(Error::from_str("transitions are only supported for the color temperature"));
+            }
+            match (config.parameters, config.curve) {
+                (Some(Nested(parameters)), Some(Nested(curve))) => {
+                    let update_interval = config
+                        .update_interval
+                        .map(|interval| Duration::from_millis(u64::from(interval)))
+                        .unwrap_or(DEFAULT_UPDATE_INTERVAL);
+                    self.enable(config.iid, parameters, curve, update_interval)?;
+                },
+                _ => self.disable()?,
+            }
+        } else if let Some(Nested(read)) = control.read {
+            if read.iid != color_temperature_iid {
+                return Ok(());
+            }
+        }
+
+        if let Some(ref transition) = self.transition {
+            self.response = TransitionControlResponse {
+                status: Some(Nested(TransitionStatus {
+                    iid: transition.iid,
+                    parameters: Nested(transition.parameters.clone()),
+                    time_since_start: transition.elapsed(self.clock.now()),
+                })),
+            }
+            .to_tlv8();
+        }
+        Ok(())
+    }
+
+    fn enable(
+        &mut self,
+        iid: u64,
+        parameters: TransitionParameters,
+        curve: TransitionCurve,
+        update_interval: Duration,
+    ) -> Result<()> {
+        if curve.adjustment_iid != self.brightness.get_id()? {
+            return Err(Error::from_str("transitions can only be adjusted by the brightness"));
+        }
+        let entries = curve.entries.0.into_iter().map(|Nested(entry)| entry).collect::<Vec<_>>();
+        if entries.is_empty() {
+            return Err(Error::from_str("transition curve without entries"));
+        }
+        let Nested(range) = curve.adjustment_multiplier_range;
+        let start = EPOCH_2001_MILLIS
+            .checked_add(parameters.start_time)
+            .and_then(|millis| UNIX_EPOCH.checked_add(Duration::from_millis(millis)))
+            .ok_or_else(|| Error::from_str("transition start time out of range"))?;
+
+        let was_active = self.transition.is_some();
+        self.transition = Some(ActiveTransition {
+            iid,
+            start,
+            parameters,
+            entries,
+            min_multiplier: range.min as f32,
+            max_multiplier: range.max as f32,
+            update_interval,
+        });
+        self.last_update = None;
+        if !was_active {
+            self.active_transition_count.set_value(1)?;
+        }
+        self.update()
+    }
+}
+
+/// `Updatable` of the Characteristic Value Transition Control Characteristic.
+struct TransitionControlUpdatable(AdaptiveLightingPtr);
+
+impl Updatable<Vec<u8>> for TransitionControlUpdatable {
+    fn try_on_update(&mut self, _: &Vec<u8>, new_val: &Vec<u8>, _: HapType) -> Result<()> {
+        self.0
+            .lock()
+            .expect("couldn't access adaptive lighting")
+            .handle_control(new_val)
+    }
+}
+
+/// `Readable` of the Characteristic Value Transition Control Characteristic, returning the response
+/// to the last write.
+struct TransitionControlReadable(AdaptiveLightingPtr);
+
+impl Readable<Vec<u8>> for TransitionControlReadable {
+    fn on_read(&mut self, _: HapType) -> Option<Vec<u8>> {
+        Some(self.0.lock().expect("couldn't access adaptive lighting").response.clone())
+    }
+}
+
+/// `Readable` of the Supported Characteristic Value Transition Configuration Characteristic. The
+/// IIDs of the Characteristics are only known once the Accessory has been added to a transport.
+struct SupportedTransitionsReadable {
+    brightness: Brightness,
+    color_temperature: ColorTemperature,
+}
+
+impl Readable<Vec<u8>> for SupportedTransitionsReadable {
+    fn on_read(&mut self, _: HapType) -> Option<Vec<u8>> {
+        let config = SupportedTransitionConfig {
+            transitions: Repeated(vec![
+                Nested(SupportedTransition {
+                    iid: self.brightness.get_id().ok()?,
+                    transition_type: TRANSITION_TYPE_BRIGHTNESS,
+                }),
+                Nested(SupportedTransition {
+                    iid: self.color_temperature.get_id().ok()?,
+                    transition_type: TRANSITION_TYPE_COLOR_TEMPERATURE,
+                }),
+            ]),
+        };
+        Some(config.to_tlv8())
+    }
+}
+
+/// Attaches an `AdaptiveLighting` controller using the system time to a Lightbulb Service. The
+/// Service needs to have Brightness and Color Temperature Characteristics.
+pub fn attach(lightbulb: &mut Lightbulb) -> Result<AdaptiveLightingPtr> { attach_with_clock(lightbulb, SystemClock) }
+
+/// Attaches an `AdaptiveLighting` controller using the given `Clock` to a Lightbulb Service. The
+/// Service needs to have Brightness and Color Temperature Characteristics.
+pub fn attach_with_clock(
+    lightbulb: &mut Lightbulb,
+    clock: impl Clock + Send + 'static,
+) -> Result<AdaptiveLightingPtr> {
+    let service = &mut lightbulb.inner;
+    let (brightness, color_temperature) = match (&service.brightness, &service.color_temperature) {
+        (Some(brightness), Some(color_temperature)) => (brightness.clone(), color_temperature.clone()),
+        _ => {
+            return Err(Error::from_str(
+                "adaptive lighting requires Brightness and Color Temperature Characteristics",
+            ))
+        },
+    };
+    let active_transition_count = characteristic_value_active_transition_count::new();
+
+    let adaptive_lighting = Arc::new(Mutex::new(AdaptiveLighting {
+        clock: Box::new(clock),
+        brightness: brightness.clone(),
+        color_temperature: color_temperature.clone(),
+        hue: service.hue.clone(),
+        saturation: service.saturation.clone(),
+        active_transition_count: active_transition_count.clone(),
+        transition: None,
+        last_update: None,
+        response: Vec::new(),
+    }));
+
+    let mut transition_control = characteristic_value_transition_control::new();
+    transition_control.set_updatable(TransitionControlUpdatable(adaptive_lighting.clone()))?;
+    transition_control.set_readable(TransitionControlReadable(adaptive_lighting.clone()))?;
+    let mut supported_transitions = supported_characteristic_value_transition_configuration::new();
+    supported_transitions.set_readable(SupportedTransitionsReadable {
+        brightness,
+        color_temperature,
+    })?;
+
+    service.characteristic_value_transition_control = Some(transition_control);
+    service.supported_characteristic_value_transition_configuration = Some(supported_transitions);
+    service.characteristic_value_active_transition_count = Some(active_transition_count);
+
+    Ok(adaptive_lighting)
+}
+
+/// Spawns a thread calling `AdaptiveLighting::update` every `interval` until the `AdaptiveLighting`
+/// controller is dropped along with its Lightbulb Service.
+pub fn spawn(adaptive_lighting: &AdaptiveLightingPtr, interval: Duration) -> thread::JoinHandle<()> {
+    let adaptive_lighting: Weak<Mutex<AdaptiveLighting>> = Arc::downgrade(adaptive_lighting);
+    thread::spawn(move || {
+        while let Some(adaptive_lighting) = adaptive_lighting.upgrade() {
+            let _ = adaptive_lighting
+                .lock()
+                .expect("couldn't access adaptive lighting")
+                .update();
+            drop(adaptive_lighting);
+            thread::sleep(interval);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        characteristic::{
+            brightness,
+            color_temperature,
+            transition::{AdjustmentMultiplierRange, TransitionConfig, UpdateTransition},
+        },
+        service::lightbulb,
+    };
+
+    #[derive(Clone)]
+    struct MockClock(Arc<Mutex<SystemTime>>);
+
+    impl Clock for MockClock {
+        fn now(&self) -> SystemTime { *self.0.lock().unwrap() }
+    }
+
+    fn transition(entries: Vec<TransitionEntry>) -> ActiveTransition {
+        ActiveTransition {
+            iid: 1,
+            parameters: TransitionParameters::default(),
+            start: UNIX_EPOCH,
+            entries,
+            min_multiplier: 10.0,
+            max_multiplier: 100.0,
+            update_interval: DEFAULT_UPDATE_INTERVAL,
+        }
+    }
+
+    fn entry(value: f32, transition_offset: u64, duration: Option<u64>) -> TransitionEntry {
+        TransitionEntry {
+            adjustment_factor: 0.0,
+            value,
+            transition_offset,
+            duration,
+        }
+    }
+
+    #[test]
+    fn curve_point_interpolates_between_entries() {
+        let transition = transition(vec![entry(100.0, 0, Some(1000)), entry(200.0, 1000, None)]);
+
+        assert_eq!(transition.curve_point(0), Some((100.0, 0.0)));
+        assert_eq!(transition.curve_point(999), Some((100.0, 0.0)));
+        assert_eq!(transition.curve_point(1500), Some((150.0, 0.0)));
+        assert_eq!(transition.curve_point(2000), None);
+    }
+
+    #[test]
+    fn curve_point_skips_entries_with_zero_offset() {
+        let transition = transition(vec![
+            entry(100.0, 0, Some(1000)),
+            entry(200.0, 0, Some(1000)),
+            entry(300.0, 0, None),
+        ]);
+
+        assert_eq!(transition.curve_point(500), Some((100.0, 0.0)));
+        assert_eq!(transition.curve_point(1500), Some((200.0, 0.0)));
+        assert_eq!(transition.curve_point(2000), None);
+    }
+
+    #[test]
+    fn curve_point_ends_after_the_curve() {
+        let transition = transition(vec![entry(100.0, 1000, Some(1000))]);
+
+        assert_eq!(transition.curve_point(1999), Some((100.0, 0.0)));
+        assert_eq!(transition.curve_point(2000), None);
+        assert_eq!(transition.curve_point(u64::MAX), None);
+    }
+
+    #[test]
+    fn curve_point_saturates_on_overflowing_offsets() {
+        let transition = transition(vec![entry(100.0, u64::MAX, Some(u64::MAX)), entry(200.0, u64::MAX, None)]);
+
+        assert_eq!(transition.curve_point(u64::MAX - 1), Some((100.0, 0.0)));
+        assert_eq!(transition.curve_point(u64::MAX), None);
+    }
+
+    #[test]
+    fn curve_point_without_entries() {
+        assert_eq!(transition(Vec::new()).curve_point(0), None);
+    }
+
+    #[test]
+    fn elapsed_is_zero_before_the_start() {
+        let mut transition = transition(Vec::new());
+        transition.start = UNIX_EPOCH + Duration::from_secs(1);
+
+        assert_eq!(transition.elapsed(UNIX_EPOCH), 0);
+        assert_eq!(transition.elapsed(UNIX_EPOCH + Duration::from_millis(1500)), 500);
+    }
+
+    #[test]
+    fn writes_equal_to_the_last_response_are_handled() {
+        let clock = MockClock(Arc::new(Mutex::new(UNIX_EPOCH + Duration::from_millis(EPOCH_2001_MILLIS))));
+        let mut lightbulb = lightbulb::new();
+        let mut brightness = brightness::new();
+        let mut color_temperature = color_temperature::new();
+        brightness.set_id(2).unwrap();
+        color_temperature.set_id(3).unwrap();
+        lightbulb.inner.brightness = Some(brightness);
+        lightbulb.inner.color_temperature = Some(color_temperature);
+        let adaptive_lighting = attach_with_clock(&mut lightbulb, clock.clone()).unwrap();
+        let mut transition_control = lightbulb
+            .inner
+            .characteristic_value_transition_control
+            .clone()
+            .unwrap();
+
+        let control = TransitionControl {
+            read: None,
+            update: Some(Nested(UpdateTransition {
+                config: Nested(TransitionConfig {
+                    iid: 3,
+                    parameters: Some(Nested(TransitionParameters {
+                        transition_id: vec![1; 16],
+                        start_time: 0,
+                        unknown: None,
+                    })),
+                    curve: Some(Nested(TransitionCurve {
+                        entries: Repeated(vec![Nested(entry(200.0, 0, Some(10_000)))]),
+                        adjustment_iid: 2,
+                        adjustment_multiplier_range: Nested(AdjustmentMultiplierRange { min: 10, max: 100 }),
+                    })),
+                    update_interval: None,
+                    notify_interval_threshold: None,
+                }),
+            })),
+        };
+        transition_control.set_value(control.to_tlv8()).unwrap();
+        assert!(adaptive_lighting.lock().unwrap().is_active());
+
+        // reading the response doesn't pass it back to the controller as a write
+        let response = transition_control.get_value().unwrap();
+        let status = TransitionControlResponse::from_tlv8(&response).unwrap().status.unwrap().0;
+        assert_eq!(status.time_since_start, 0);
+        assert_eq!(transition_control.get_value().unwrap(), response);
+
+        // the status of the response reads as a read request for the same Characteristic
+        *clock.0.lock().unwrap() += Duration::from_millis(1000);
+        transition_control.set_value(response.clone()).unwrap();
+        let response = transition_control.get_value().unwrap();
+        let status = TransitionControlResponse::from_tlv8(&response).unwrap().status.unwrap().0;
+        assert_eq!(status.time_since_start, 1000);
+    }
+}
@@ -31,4 +31,8 @@ pub enum Category {
 	ShowerSystems = 30,
 	Television = 31,
 	RemoteControl = 32,
+	Router = 33,
+	AudioReceiver = 34,
+	TVSetTopBox = 35,
+	TVStreamingStick = 36,
 }
@@ -0,0 +1,49 @@
+use crate::{
+    accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
+    characteristic::valve_type,
+    service::{accessory_information::AccessoryInformation, faucet, valve, HapService},
+    Result,
+};
+
+/// Faucet Accessory.
+pub type Faucet = Accessory<FaucetInner>;
+
+/// Inner type of the Faucet Accessory.
+#[derive(Default, HapAccessory)]
+pub struct FaucetInner {
+    /// ID of the Faucet Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Faucet Service.
+    pub faucet: faucet::Faucet,
+    /// Valve Services, linked to the Faucet Service.
+    pub valves: Vec<valve::Valve>,
+    /// Additional Services of the Accessory, e.g. a Heater Cooler Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+impl FaucetInner {
+    /// Adds a Valve Service and links it to the Faucet Service. The Valve is marked as a water
+    /// faucet valve.
+    pub fn add_valve(&mut self, mut valve: valve::Valve) -> Result<()> {
+        valve.inner.valve_type.set_valid_value(valve_type::Value::WaterFaucet)?;
+        self.faucet.add_linked_service(&valve);
+        self.valves.push(valve);
+        Ok(())
+    }
+}
+
+/// Creates a new Faucet Accessory with a single Valve.
+pub fn new(information: Information) -> Result<Faucet> {
+    let mut faucet = faucet::new();
+    faucet.set_primary(true);
+    let mut inner = FaucetInner {
+        accessory_information: information.to_service()?,
+        faucet,
+        ..Default::default()
+    };
+    inner.add_valve(valve::new())?;
+    Ok(Faucet::new(inner))
+}
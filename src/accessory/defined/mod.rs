@@ -1,5 +1,10 @@
 pub mod bridge;
+pub mod faucet;
 pub mod ip_camera;
 pub mod lock;
+pub mod programmable_remote;
+pub mod slat;
+pub mod speaker;
+pub mod sprinkler;
 pub mod television;
 pub mod video_doorbell;
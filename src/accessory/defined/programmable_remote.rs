@@ -0,0 +1,56 @@
+use crate::{
+    accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
+    characteristic::{service_label_index, service_label_namespace},
+    service::{accessory_information::AccessoryInformation, service_label, stateless_programmable_switch, HapService},
+    Error,
+    Result,
+};
+
+/// Programmable Remote Accessory, a remote with multiple programmable buttons.
+pub type ProgrammableRemote = Accessory<ProgrammableRemoteInner>;
+
+/// Inner type of the Programmable Remote Accessory.
+#[derive(Default, HapAccessory)]
+pub struct ProgrammableRemoteInner {
+    /// ID of the Programmable Remote Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Service Label Service, linked to the buttons.
+    pub service_label: service_label::ServiceLabel,
+    /// Stateless Programmable Switch Services of the buttons, labeled with their position.
+    pub buttons: Vec<stateless_programmable_switch::StatelessProgrammableSwitch>,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+/// Creates a new Programmable Remote Accessory with the given number of buttons, labeled `1` to
+/// `buttons`.
+pub fn new(information: Information, buttons: u8) -> Result<ProgrammableRemote> {
+    if buttons == 0 {
+        return Err(Error::from_str("a programmable remote needs at least one button"));
+    }
+
+    let mut service_label = service_label::new();
+    service_label
+        .inner
+        .service_label_namespace
+        .set_valid_value(service_label_namespace::Value::ArabicNumerals)?;
+    let mut inner = ProgrammableRemoteInner {
+        accessory_information: information.to_service()?,
+        ..Default::default()
+    };
+    for index in 1..=buttons {
+        let mut button = stateless_programmable_switch::new();
+        button.set_primary(index == 1);
+        let mut label_index = service_label_index::new();
+        label_index.set_value(index)?;
+        button.inner.service_label_index = Some(label_index);
+        service_label.add_linked_service(&button);
+        inner.buttons.push(button);
+    }
+    inner.service_label = service_label;
+
+    Ok(ProgrammableRemote::new(inner))
+}
@@ -0,0 +1,33 @@
+use crate::{
+    accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
+    service::{accessory_information::AccessoryInformation, slat, HapService},
+    Result,
+};
+
+/// Slat Accessory.
+pub type Slat = Accessory<SlatInner>;
+
+/// Inner type of the Slat Accessory.
+#[derive(Default, HapAccessory)]
+pub struct SlatInner {
+    /// ID of the Slat Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Slat Service.
+    pub slat: slat::Slat,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+/// Creates a new Slat Accessory.
+pub fn new(information: Information) -> Result<Slat> {
+    let mut slat = slat::new();
+    slat.set_primary(true);
+    Ok(Slat::new(SlatInner {
+        accessory_information: information.to_service()?,
+        slat,
+        ..Default::default()
+    }))
+}
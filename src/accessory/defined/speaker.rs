@@ -0,0 +1,35 @@
+use crate::{
+    accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
+    characteristic::volume,
+    service::{accessory_information::AccessoryInformation, speaker, HapService},
+    Result,
+};
+
+/// Speaker Accessory.
+pub type Speaker = Accessory<SpeakerInner>;
+
+/// Inner type of the Speaker Accessory.
+#[derive(Default, HapAccessory)]
+pub struct SpeakerInner {
+    /// ID of the Speaker Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Speaker Service.
+    pub speaker: speaker::Speaker,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+/// Creates a new Speaker Accessory with a Volume Characteristic.
+pub fn new(information: Information) -> Result<Speaker> {
+    let mut speaker = speaker::new();
+    speaker.set_primary(true);
+    speaker.inner.volume = Some(volume::new());
+    Ok(Speaker::new(SpeakerInner {
+        accessory_information: information.to_service()?,
+        speaker,
+        ..Default::default()
+    }))
+}
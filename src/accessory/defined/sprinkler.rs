@@ -0,0 +1,62 @@
+use crate::{
+    accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
+    characteristic::{is_configured, service_label_index, valve_type},
+    service::{accessory_information::AccessoryInformation, irrigation_system, valve, HapService},
+    Error,
+    Result,
+};
+
+/// Sprinkler Accessory.
+pub type Sprinkler = Accessory<SprinklerInner>;
+
+/// Inner type of the Sprinkler Accessory.
+#[derive(Default, HapAccessory)]
+pub struct SprinklerInner {
+    /// ID of the Sprinkler Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Irrigation System Service.
+    pub irrigation_system: irrigation_system::IrrigationSystem,
+    /// Valve Services of the sprinkler zones, linked to the Irrigation System Service.
+    pub valves: Vec<valve::Valve>,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+impl SprinklerInner {
+    /// Adds a Valve Service for a sprinkler zone and links it to the Irrigation System Service. The
+    /// Valve is marked as a configured irrigation valve labeled with its position.
+    pub fn add_valve(&mut self, mut valve: valve::Valve) -> Result<()> {
+        if self.valves.len() >= u8::MAX as usize {
+            return Err(Error::from_str("a sprinkler can't have more than 255 zones"));
+        }
+        valve.inner.valve_type.set_valid_value(valve_type::Value::Irrigation)?;
+        let mut configured = is_configured::new();
+        configured.set_valid_value(is_configured::Value::Configured)?;
+        valve.inner.is_configured = Some(configured);
+        let mut label_index = service_label_index::new();
+        label_index.set_value(self.valves.len() as u8 + 1)?;
+        valve.inner.service_label_index = Some(label_index);
+
+        self.irrigation_system.add_linked_service(&valve);
+        self.valves.push(valve);
+        Ok(())
+    }
+}
+
+/// Creates a new Sprinkler Accessory with the given number of zones.
+pub fn new(information: Information, zones: u8) -> Result<Sprinkler> {
+    let mut irrigation_system = irrigation_system::new();
+    irrigation_system.set_primary(true);
+    let mut inner = SprinklerInner {
+        accessory_information: information.to_service()?,
+        irrigation_system,
+        ..Default::default()
+    };
+    for _ in 0..zones {
+        inner.add_valve(valve::new())?;
+    }
+    Ok(Sprinkler::new(inner))
+}
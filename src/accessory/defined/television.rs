@@ -1,7 +1,6 @@
 use crate::{
     accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
-    event::EventEmitterPtr,
-    service::{accessory_information::AccessoryInformation, speaker, television, HapService},
+    service::{accessory_information::AccessoryInformation, input_source, speaker, television, HapService},
     Result,
 };
 
@@ -9,7 +8,7 @@ use crate::{
 pub type Television = Accessory<TelevisionInner>;
 
 /// Inner type of the Television Accessory.
-#[derive(Default)]
+#[derive(Default, HapAccessory)]
 pub struct TelevisionInner {
     /// ID of the Television Accessory.
     id: u64,
@@ -20,36 +19,17 @@ pub struct TelevisionInner {
     pub television: television::Television,
     /// Speaker Service.
     pub speaker: speaker::Speaker,
+    /// Input Source Services, linked to the Television Service.
+    pub input_sources: Vec<input_source::InputSource>,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
 }
 
-impl HapAccessory for TelevisionInner {
-    fn get_id(&self) -> u64 { self.id }
-
-    fn set_id(&mut self, id: u64) { self.id = id; }
-
-    fn get_services(&self) -> Vec<&dyn HapAccessoryService> {
-        vec![&self.accessory_information, &self.television, &self.speaker]
-    }
-
-    fn get_mut_services(&mut self) -> Vec<&mut dyn HapAccessoryService> {
-        vec![&mut self.accessory_information, &mut self.television, &mut self.speaker]
-    }
-
-    fn get_mut_information(&mut self) -> &mut AccessoryInformation { &mut self.accessory_information }
-
-    fn init_iids(&mut self, accessory_id: u64, event_emitter: EventEmitterPtr) -> Result<()> {
-        let mut next_iid = 1;
-        for service in self.get_mut_services() {
-            service.set_id(next_iid);
-            next_iid += 1;
-            for characteristic in service.get_mut_characteristics() {
-                characteristic.set_id(next_iid)?;
-                characteristic.set_accessory_id(accessory_id)?;
-                characteristic.set_event_emitter(Some(event_emitter.clone()))?;
-                next_iid += 1;
-            }
-        }
-        Ok(())
+impl TelevisionInner {
+    /// Adds an Input Source Service and links it to the Television Service.
+    pub fn add_input_source(&mut self, input_source: input_source::InputSource) {
+        self.television.add_linked_service(&input_source);
+        self.input_sources.push(input_source);
     }
 }
 
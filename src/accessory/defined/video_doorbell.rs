@@ -1,6 +1,5 @@
 use crate::{
     accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
-    event::EventEmitterPtr,
     service::{
         accessory_information::AccessoryInformation,
         camera_rtp_stream_management,
@@ -15,7 +14,7 @@ use crate::{
 pub type VideoDoorbell = Accessory<VideoDoorbellInner>;
 
 /// Inner type of the Video Doorbell Accessory.
-#[derive(Default)]
+#[derive(Default, HapAccessory)]
 pub struct VideoDoorbellInner {
     /// ID of the Video Doorbell Accessory.
     id: u64,
@@ -28,47 +27,8 @@ pub struct VideoDoorbellInner {
     pub speaker: speaker::Speaker,
     /// Microphone Service.
     pub microphone: microphone::Microphone,
-}
-
-impl HapAccessory for VideoDoorbellInner {
-    fn get_id(&self) -> u64 { self.id }
-
-    fn set_id(&mut self, id: u64) { self.id = id; }
-
-    fn get_services(&self) -> Vec<&dyn HapAccessoryService> {
-        vec![
-            &self.accessory_information,
-            &self.camera_rtp_stream_management,
-            &self.speaker,
-            &self.microphone,
-        ]
-    }
-
-    fn get_mut_services(&mut self) -> Vec<&mut dyn HapAccessoryService> {
-        vec![
-            &mut self.accessory_information,
-            &mut self.camera_rtp_stream_management,
-            &mut self.speaker,
-            &mut self.microphone,
-        ]
-    }
-
-    fn get_mut_information(&mut self) -> &mut AccessoryInformation { &mut self.accessory_information }
-
-    fn init_iids(&mut self, accessory_id: u64, event_emitter: EventEmitterPtr) -> Result<()> {
-        let mut next_iid = 1;
-        for service in self.get_mut_services() {
-            service.set_id(next_iid);
-            next_iid += 1;
-            for characteristic in service.get_mut_characteristics() {
-                characteristic.set_id(next_iid)?;
-                characteristic.set_accessory_id(accessory_id)?;
-                characteristic.set_event_emitter(Some(event_emitter.clone()))?;
-                next_iid += 1;
-            }
-        }
-        Ok(())
-    }
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
 }
 
 /// Creates a new Video Doorbell Accessory.
@@ -0,0 +1,65 @@
+use crate::{
+    accessory::{Accessory, HapAccessory, HapAccessoryService, Information},
+    service::{accessory_information::AccessoryInformation, dynamic},
+    HapType,
+    Result,
+};
+
+/// Dynamic Accessory, composed of arbitrary Services at runtime.
+pub type DynamicAccessory = Accessory<DynamicAccessoryInner>;
+
+/// Inner type of the Dynamic Accessory.
+#[derive(Default, HapAccessory)]
+pub struct DynamicAccessoryInner {
+    /// ID of the Dynamic Accessory.
+    id: u64,
+
+    /// Accessory Information Service.
+    pub accessory_information: AccessoryInformation,
+    /// Services of the Dynamic Accessory besides the Accessory Information Service.
+    pub services: Vec<Box<dyn HapAccessoryService + Send>>,
+}
+
+impl DynamicAccessoryInner {
+    /// Adds a Service to the Accessory.
+    pub fn add_service<S: 'static + HapAccessoryService + Send>(&mut self, service: S) {
+        self.services.push(Box::new(service));
+    }
+
+    /// Adds a new Dynamic Service of the given `HapType` along with its required Characteristics to
+    /// the Accessory.
+    pub fn add_service_by_type(&mut self, hap_type: HapType) -> Result<()> {
+        self.add_service(dynamic::new(hap_type)?);
+        Ok(())
+    }
+}
+
+/// Creates a new Dynamic Accessory without any Services besides the Accessory Information Service.
+///
+/// # Examples
+///
+/// ```
+/// use hap::{
+///     accessory::{dynamic, Information},
+///     service,
+///     HapType,
+/// };
+///
+/// let mut accessory = dynamic::new(Information {
+///     name: "Acme Sensor".into(),
+///     ..Default::default()
+/// })
+/// .unwrap();
+///
+/// accessory.inner.add_service_by_type(HapType::TemperatureSensor).unwrap();
+///
+/// let mut humidity_sensor = service::dynamic::new(HapType::HumiditySensor).unwrap();
+/// humidity_sensor.inner.add_characteristic_by_type(HapType::StatusActive).unwrap();
+/// accessory.inner.add_service(humidity_sensor);
+/// ```
+pub fn new(information: Information) -> Result<DynamicAccessory> {
+    Ok(DynamicAccessory::new(DynamicAccessoryInner {
+        accessory_information: information.to_service()?,
+        ..Default::default()
+    }))
+}
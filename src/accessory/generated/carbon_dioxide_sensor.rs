@@ -1,7 +1,7 @@
 // THIS FILE IS AUTO-GENERATED
 
 use crate::{
-	accessory::{HapAccessory, HapAccessoryService, Accessory, Information},
+	accessory::{self, HapAccessory, HapAccessoryService, Accessory, Information},
 	service::{HapService, accessory_information::AccessoryInformation, carbon_dioxide_sensor},
 	event::EventEmitterPtr,
 	Result,
@@ -20,6 +20,8 @@ pub struct CarbonDioxideSensorInner {
     pub accessory_information: AccessoryInformation,
     /// Carbon Dioxide Sensor Service.
     pub carbon_dioxide_sensor: carbon_dioxide_sensor::CarbonDioxideSensor,
+    /// Additional Services of the Accessory, e.g. a Battery Service.
+    pub additional_services: Vec<Box<dyn HapAccessoryService + Send>>,
 }
 
 impl HapAccessory for CarbonDioxideSensorInner {
@@ -32,17 +34,25 @@ impl HapAccessory for CarbonDioxideSensorInner {
     }
 
     fn get_services(&self) -> Vec<&dyn HapAccessoryService> {
-        vec![
+        let mut services: Vec<&dyn HapAccessoryService> = vec![
             &self.accessory_information,
             &self.carbon_dioxide_sensor,
-        ]
+        ];
+        for s in &self.additional_services {
+            services.push(s.as_ref());
+        }
+        services
     }
 
     fn get_mut_services(&mut self) -> Vec<&mut dyn HapAccessoryService> {
-        vec![
+        let mut services: Vec<&mut dyn HapAccessoryService> = vec![
             &mut self.accessory_information,
             &mut self.carbon_dioxide_sensor,
-        ]
+        ];
+        for s in &mut self.additional_services {
+            services.push(s.as_mut());
+        }
+        services
     }
 
     fn get_mut_information(&mut self) -> &mut AccessoryInformation {
@@ -50,18 +60,7 @@ impl HapAccessory for CarbonDioxideSensorInner {
     }
 
     fn init_iids(&mut self, accessory_id: u64, event_emitter: EventEmitterPtr) -> Result<()> {
-        let mut next_iid = 1;
-        for service in self.get_mut_services() {
-            service.set_id(next_iid);
-            next_iid += 1;
-            for characteristic in service.get_mut_characteristics() {
-                characteristic.set_id(next_iid)?;
-                characteristic.set_accessory_id(accessory_id)?;
-                characteristic.set_event_emitter(Some(event_emitter.clone()))?;
-                next_iid += 1;
-            }
-        }
-        Ok(())
+        accessory::init_service_iids(self.get_mut_services(), accessory_id, event_emitter)
     }
 }
 
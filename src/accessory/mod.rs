@@ -1,4 +1,6 @@
-use erased_serde::{self, __internal_serialize_trait_object, serialize_trait_object};
+use std::collections::HashMap;
+
+use erased_serde::{self, serialize_trait_object};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use crate::{
@@ -8,14 +10,19 @@ use crate::{
         accessory_information::{self, AccessoryInformation},
         HapService,
     },
+    Error,
     Result,
 };
 
+pub mod adaptive_lighting;
+pub mod dynamic;
+
 mod category;
 mod defined;
 mod generated;
 
 pub use crate::accessory::{category::Category, defined::*, generated::*};
+pub use hap_derive::HapAccessory;
 
 /// `HapAccessoryService` is implemented by every `Service` inside of an `Accessory`.
 pub trait HapAccessoryService: HapService + erased_serde::Serialize {}
@@ -44,6 +51,65 @@ pub trait HapAccessory {
     fn init_iids(&mut self, accessory_id: u64, event_emitter: EventEmitterPtr) -> Result<()>;
 }
 
+/// Initializes the Service and Characteristic instance IDs of an Accessory from its Services and
+/// resolves the links between its Services. Used to implement `HapAccessory::init_iids`.
+///
+/// Fails if a Service is linked to a Service that isn't part of the Accessory or to itself.
+pub fn init_service_iids(
+    mut services: Vec<&mut dyn HapAccessoryService>,
+    accessory_id: u64,
+    event_emitter: EventEmitterPtr,
+) -> Result<()> {
+    let mut next_iid = 1;
+    let mut iids = HashMap::new();
+    for service in services.iter_mut() {
+        service.set_id(next_iid);
+        if let Some(reference) = service.get_ref() {
+            iids.insert(reference, next_iid);
+        }
+        next_iid += 1;
+        for characteristic in service.get_mut_characteristics() {
+            characteristic.set_id(next_iid)?;
+            characteristic.set_accessory_id(accessory_id)?;
+            characteristic.set_event_emitter(Some(event_emitter.clone()))?;
+            next_iid += 1;
+        }
+    }
+
+    for service in services.iter_mut() {
+        let linked_refs = service.get_linked_refs();
+        if !linked_refs.is_empty() {
+            // links set by ID are kept alongside the resolved references
+            let mut linked_services = service.get_linked_services();
+            for reference in &linked_refs {
+                let iid = iids
+                    .get(reference)
+                    .cloned()
+                    .ok_or_else(|| Error::from_str("linked Service isn't part of the Accessory"))?;
+                if !linked_services.contains(&iid) {
+                    linked_services.push(iid);
+                }
+            }
+            service.set_linked_services(linked_services);
+        }
+    }
+
+    // links may also be set by ID directly, so every link is validated
+    let service_iids = services.iter().map(|s| s.get_id()).collect::<Vec<_>>();
+    for service in &services {
+        for linked_service in service.get_linked_services() {
+            if linked_service == service.get_id() {
+                return Err(Error::from_str("Service is linked to itself"));
+            }
+            if !service_iids.contains(&linked_service) {
+                return Err(Error::from_str("linked Service isn't part of the Accessory"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// An Accessory. Accessories are the outermost data type defined by the HAP. They are comprised of
 /// services and characteristics.
 pub struct Accessory<T: HapAccessory> {
@@ -52,7 +118,7 @@ pub struct Accessory<T: HapAccessory> {
 
 impl<T: HapAccessory> Accessory<T> {
     /// Creates a new `Accessory`.
-    fn new(inner: T) -> Accessory<T> { Accessory { inner } }
+    pub fn new(inner: T) -> Accessory<T> { Accessory { inner } }
 }
 
 impl<T: HapAccessory> Serialize for Accessory<T> {
@@ -182,3 +248,91 @@ impl Default for Information {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{
+        event::EventEmitter,
+        service::{lightbulb, speaker},
+    };
+
+    fn event_emitter() -> EventEmitterPtr { Arc::new(Mutex::new(EventEmitter::new())) }
+
+    #[test]
+    fn linked_services_are_resolved_to_their_ids() {
+        let mut lightbulb = lightbulb::new();
+        let mut speaker = speaker::new();
+        speaker.add_linked_service(&lightbulb);
+        speaker.add_linked_service(&lightbulb);
+
+        init_service_iids(vec![&mut lightbulb, &mut speaker], 1, event_emitter()).unwrap();
+
+        assert_eq!(lightbulb.get_id(), 1);
+        assert!(speaker.get_id() > lightbulb.get_id());
+        assert_eq!(speaker.get_linked_services(), vec![lightbulb.get_id()]);
+        assert!(lightbulb.get_linked_services().is_empty());
+    }
+
+    #[test]
+    fn links_by_id_and_by_reference_are_merged() {
+        let mut lightbulb = lightbulb::new();
+        let mut other_lightbulb = lightbulb::new();
+        let mut speaker = speaker::new();
+        speaker.add_linked_service(&other_lightbulb);
+        speaker.set_linked_services(vec![1]);
+
+        init_service_iids(
+            vec![&mut lightbulb, &mut other_lightbulb, &mut speaker],
+            1,
+            event_emitter(),
+        )
+        .unwrap();
+
+        assert_eq!(speaker.get_linked_services(), vec![lightbulb.get_id(), other_lightbulb.get_id()]);
+
+        // re-initializing doesn't duplicate the links
+        init_service_iids(
+            vec![&mut lightbulb, &mut other_lightbulb, &mut speaker],
+            1,
+            event_emitter(),
+        )
+        .unwrap();
+        assert_eq!(speaker.get_linked_services(), vec![lightbulb.get_id(), other_lightbulb.get_id()]);
+    }
+
+    #[test]
+    fn links_to_foreign_services_are_rejected() {
+        let foreign = lightbulb::new();
+        let mut lightbulb = lightbulb::new();
+        let mut speaker = speaker::new();
+        speaker.add_linked_service(&foreign);
+
+        let res = init_service_iids(vec![&mut lightbulb, &mut speaker], 1, event_emitter());
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn links_to_themselves_are_rejected() {
+        let mut lightbulb = lightbulb::new();
+        let mut speaker = speaker::new();
+        speaker.set_linked_services(vec![1]);
+        assert!(init_service_iids(vec![&mut lightbulb, &mut speaker], 1, event_emitter()).is_ok());
+
+        // the first Service is assigned the ID 1
+        lightbulb.set_linked_services(vec![1]);
+        let res = init_service_iids(vec![&mut lightbulb, &mut speaker], 1, event_emitter());
+        assert_eq!(res.unwrap_err().to_string(), "Error Service is linked to itself");
+    }
+
+    #[test]
+    fn links_by_unknown_id_are_rejected() {
+        let mut lightbulb = lightbulb::new();
+        lightbulb.set_linked_services(vec![1000]);
+
+        let res = init_service_iids(vec![&mut lightbulb], 1, event_emitter());
+        assert_eq!(res.unwrap_err().to_string(), "Error linked Service isn't part of the Accessory");
+    }
+}
@@ -0,0 +1,114 @@
+//! Custom Characteristics with vendor-specific UUIDs.
+//!
+//! Custom Characteristics can be added to the `additional_characteristics` of any Service or to a
+//! Dynamic Service. Their constraints are set like those of the HAP defined Characteristics, e.g.
+//! with `Characteristic::set_min_value` or `Characteristic::set_unit`.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    characteristic::{Characteristic, Format, Inner, Perm},
+    Error,
+    HapType,
+    Result,
+};
+
+/// `Formatted` is implemented by the value types of Characteristics and tells which `Format`s a
+/// value of the type can be sent as.
+pub trait Formatted {
+    /// Returns whether values of the type can be sent in the given `Format`.
+    fn supports(format: Format) -> bool;
+}
+
+macro_rules! impl_formatted {
+    ($($t:ty => $($format:ident)|+;)+) => {
+        $(
+            impl Formatted for $t {
+                fn supports(format: Format) -> bool { matches!(format, $(Format::$format)|+) }
+            }
+        )+
+    };
+}
+
+impl_formatted! {
+    bool => Bool;
+    u8 => UInt8;
+    u16 => UInt16;
+    u32 => UInt32;
+    u64 => UInt64;
+    i32 => Int32;
+    f32 => Float;
+    String => String;
+    Vec<u8> => Tlv8 | Data;
+}
+
+/// Creates a new custom Characteristic of the given UUID, `Format` and `Perm`s. Returns an error if
+/// values of type `T` can't be sent in the given `Format`, e.g. an `f32` as `Format::Bool`.
+///
+/// # Examples
+///
+/// ```
+/// use hap::{
+///     characteristic::{custom, Format, Perm},
+///     service::outlet,
+/// };
+/// use uuid::Uuid;
+///
+/// // total energy consumption in kWh as understood by the Eve app
+/// let mut total_consumption = custom::new::<f32>(
+///     Uuid::parse_str("E863F10C-079E-48FF-8F27-9C2605A29F52").unwrap(),
+///     Format::Float,
+///     vec![Perm::PairedRead, Perm::Events],
+/// )
+/// .unwrap();
+/// total_consumption.set_min_value(Some(0.0)).unwrap();
+/// total_consumption.set_description(Some("Total Consumption".into())).unwrap();
+///
+/// let mut outlet = outlet::new();
+/// outlet.inner.additional_characteristics.push(Box::new(total_consumption));
+/// ```
+pub fn new<T>(uuid: Uuid, format: Format, perms: Vec<Perm>) -> Result<Characteristic<T>>
+where
+    T: Formatted + Default + Clone + Serialize,
+    for<'de> T: Deserialize<'de>,
+{
+    if !T::supports(format) {
+        return Err(Error::from_str("the format doesn't match the value type of the Characteristic"));
+    }
+    Ok(Characteristic::new(Inner::<T> {
+        hap_type: HapType::Custom(uuid),
+        format,
+        perms,
+        ..Default::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uuid() -> Uuid { Uuid::parse_str("E863F10C-079E-48FF-8F27-9C2605A29F52").unwrap() }
+
+    #[test]
+    fn formats_matching_the_value_type_are_accepted() {
+        assert!(new::<bool>(uuid(), Format::Bool, vec![]).is_ok());
+        assert!(new::<u8>(uuid(), Format::UInt8, vec![]).is_ok());
+        assert!(new::<u64>(uuid(), Format::UInt64, vec![]).is_ok());
+        assert!(new::<i32>(uuid(), Format::Int32, vec![]).is_ok());
+        assert!(new::<f32>(uuid(), Format::Float, vec![]).is_ok());
+        assert!(new::<String>(uuid(), Format::String, vec![]).is_ok());
+        assert!(new::<Vec<u8>>(uuid(), Format::Tlv8, vec![]).is_ok());
+        assert!(new::<Vec<u8>>(uuid(), Format::Data, vec![]).is_ok());
+    }
+
+    #[test]
+    fn formats_not_matching_the_value_type_are_rejected() {
+        assert!(new::<f32>(uuid(), Format::Bool, vec![]).is_err());
+        assert!(new::<bool>(uuid(), Format::UInt8, vec![]).is_err());
+        assert!(new::<u8>(uuid(), Format::UInt16, vec![]).is_err());
+        assert!(new::<u32>(uuid(), Format::Int32, vec![]).is_err());
+        assert!(new::<String>(uuid(), Format::Data, vec![]).is_err());
+        assert!(new::<Vec<u8>>(uuid(), Format::String, vec![]).is_err());
+    }
+}
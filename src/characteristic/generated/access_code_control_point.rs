@@ -0,0 +1,20 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Access Code Control Point Characteristic.
+pub type AccessCodeControlPoint = Characteristic<Vec<u8>>;
+
+/// Creates a new Access Code Control Point Characteristic.
+pub fn new() -> AccessCodeControlPoint {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::AccessCodeControlPoint,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::WriteResponse,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Access Code Supported Configuration Characteristic.
+pub type AccessCodeSupportedConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Access Code Supported Configuration Characteristic.
+pub fn new() -> AccessCodeSupportedConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::AccessCodeSupportedConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
@@ -11,8 +11,8 @@ pub fn new() -> AccessoryFlags {
         hap_type: HapType::AccessoryFlags,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Active Characteristic.
 pub type Active = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> Active {
         hap_type: HapType::Active,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Inactive"
-			1, // "Active"
-		]),
+        valid_values: Some(vec![
+            0, // "Inactive"
+            1, // "Active"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Active Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Inactive.
+    Inactive = 0,
+    /// Active.
+    Active = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Inactive),
+            1 => Ok(Value::Active),
+            _ => Err(Error::from_str("invalid value of the Active Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::Active }
+}
@@ -11,11 +11,11 @@ pub fn new() -> ActiveIdentifier {
         hap_type: HapType::ActiveIdentifier,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		min_value: Some(0),
+        min_value: Some(0),
         ..Default::default()
     })
 }
@@ -11,9 +11,9 @@ pub fn new() -> AdministratorOnlyAccess {
         hap_type: HapType::AdministratorOnlyAccess,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
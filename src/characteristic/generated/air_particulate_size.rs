@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Air Particulate Size Characteristic.
 pub type AirParticulateSize = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> AirParticulateSize {
         hap_type: HapType::AirParticulateSize,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "2.5 μm"
-			1, // "10 μm"
-		]),
+        valid_values: Some(vec![
+            0, // "2.5 μm"
+            1, // "10 μm"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Air Particulate Size Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// 2.5 μm.
+    Size2_5Micrometers = 0,
+    /// 10 μm.
+    Size10Micrometers = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Size2_5Micrometers),
+            1 => Ok(Value::Size10Micrometers),
+            _ => Err(Error::from_str("invalid value of the Air Particulate Size Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::AirParticulateSize }
+}
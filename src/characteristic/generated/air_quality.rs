@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Air Quality Characteristic.
 pub type AirQuality = Characteristic<u8>;
@@ -11,17 +14,59 @@ pub fn new() -> AirQuality {
         hap_type: HapType::AirQuality,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Unknown"
-			1, // "Excellent"
-			2, // "Good"
-			3, // "Fair"
-			4, // "Inferior"
-			5, // "Poor"
-		]),
+        valid_values: Some(vec![
+            0, // "Unknown"
+            1, // "Excellent"
+            2, // "Good"
+            3, // "Fair"
+            4, // "Inferior"
+            5, // "Poor"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Air Quality Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Unknown.
+    Unknown = 0,
+    /// Excellent.
+    Excellent = 1,
+    /// Good.
+    Good = 2,
+    /// Fair.
+    Fair = 3,
+    /// Inferior.
+    Inferior = 4,
+    /// Poor.
+    Poor = 5,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Unknown),
+            1 => Ok(Value::Excellent),
+            2 => Ok(Value::Good),
+            3 => Ok(Value::Fair),
+            4 => Ok(Value::Inferior),
+            5 => Ok(Value::Poor),
+            _ => Err(Error::from_str("invalid value of the Air Quality Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::AirQuality }
+}
@@ -11,9 +11,9 @@ pub fn new() -> AudioFeedback {
         hap_type: HapType::AudioFeedback,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
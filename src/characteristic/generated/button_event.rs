@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Button Event Characteristic.
+pub type ButtonEvent = Characteristic<Vec<u8>>;
+
+/// Creates a new Button Event Characteristic.
+pub fn new() -> ButtonEvent {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::ButtonEvent,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
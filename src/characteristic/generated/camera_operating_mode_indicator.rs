@@ -0,0 +1,21 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Camera Operating Mode Indicator Characteristic.
+pub type CameraOperatingModeIndicator = Characteristic<bool>;
+
+/// Creates a new Camera Operating Mode Indicator Characteristic.
+pub fn new() -> CameraOperatingModeIndicator {
+    Characteristic::new(Inner::<bool> {
+        hap_type: HapType::CameraOperatingModeIndicator,
+        format: Format::Bool,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
+            Perm::TimedWrite,
+        ],
+        ..Default::default()
+    })
+}
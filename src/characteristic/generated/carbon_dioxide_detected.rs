@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Carbon Dioxide Detected Characteristic.
 pub type CarbonDioxideDetected = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> CarbonDioxideDetected {
         hap_type: HapType::CarbonDioxideDetected,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "CO2 Levels Normal"
-			1, // "CO2 Levels Abnormal"
-		]),
+        valid_values: Some(vec![
+            0, // "CO2 Levels Normal"
+            1, // "CO2 Levels Abnormal"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Carbon Dioxide Detected Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// CO2 Levels Normal.
+    CO2LevelsNormal = 0,
+    /// CO2 Levels Abnormal.
+    CO2LevelsAbnormal = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::CO2LevelsNormal),
+            1 => Ok(Value::CO2LevelsAbnormal),
+            _ => Err(Error::from_str("invalid value of the Carbon Dioxide Detected Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CarbonDioxideDetected }
+}
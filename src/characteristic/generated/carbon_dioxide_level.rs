@@ -11,11 +11,11 @@ pub fn new() -> CarbonDioxideLevel {
         hap_type: HapType::CarbonDioxideLevel,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(100000 as f32),
-		min_value: Some(0 as f32),
+        max_value: Some(100000_f32),
+        min_value: Some(0_f32),
         ..Default::default()
     })
 }
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Carbon Monoxide Detected Characteristic.
 pub type CarbonMonoxideDetected = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> CarbonMonoxideDetected {
         hap_type: HapType::CarbonMonoxideDetected,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "CO Levels Normal"
-			1, // "CO Levels Abnormal"
-		]),
+        valid_values: Some(vec![
+            0, // "CO Levels Normal"
+            1, // "CO Levels Abnormal"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Carbon Monoxide Detected Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// CO Levels Normal.
+    COLevelsNormal = 0,
+    /// CO Levels Abnormal.
+    COLevelsAbnormal = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::COLevelsNormal),
+            1 => Ok(Value::COLevelsAbnormal),
+            _ => Err(Error::from_str("invalid value of the Carbon Monoxide Detected Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CarbonMonoxideDetected }
+}
@@ -11,11 +11,11 @@ pub fn new() -> CarbonMonoxidePeakLevel {
         hap_type: HapType::CarbonMonoxidePeakLevel,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(100 as f32),
-		min_value: Some(0 as f32),
+        max_value: Some(100_f32),
+        min_value: Some(0_f32),
         ..Default::default()
     })
 }
@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Characteristic Value Active Transition Count Characteristic.
+pub type CharacteristicValueActiveTransitionCount = Characteristic<u8>;
+
+/// Creates a new Characteristic Value Active Transition Count Characteristic.
+pub fn new() -> CharacteristicValueActiveTransitionCount {
+    Characteristic::new(Inner::<u8> {
+        hap_type: HapType::CharacteristicValueActiveTransitionCount,
+        format: Format::UInt8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,20 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Characteristic Value Transition Control Characteristic.
+pub type CharacteristicValueTransitionControl = Characteristic<Vec<u8>>;
+
+/// Creates a new Characteristic Value Transition Control Characteristic.
+pub fn new() -> CharacteristicValueTransitionControl {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::CharacteristicValueTransitionControl,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::WriteResponse,
+        ],
+        ..Default::default()
+    })
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Charging State Characteristic.
 pub type ChargingState = Characteristic<u8>;
@@ -11,14 +14,47 @@ pub fn new() -> ChargingState {
         hap_type: HapType::ChargingState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Not Charging"
-			1, // "Charging"
-			2, // "Not Chargeable"
-		]),
+        valid_values: Some(vec![
+            0, // "Not Charging"
+            1, // "Charging"
+            2, // "Not Chargeable"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Charging State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Not Charging.
+    NotCharging = 0,
+    /// Charging.
+    Charging = 1,
+    /// Not Chargeable.
+    NotChargeable = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NotCharging),
+            1 => Ok(Value::Charging),
+            2 => Ok(Value::NotChargeable),
+            _ => Err(Error::from_str("invalid value of the Charging State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ChargingState }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Closed Captions Characteristic.
 pub type ClosedCaptions = Characteristic<u8>;
@@ -11,17 +14,47 @@ pub fn new() -> ClosedCaptions {
         hap_type: HapType::ClosedCaptions,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		max_value: Some(1),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Disabled"
-			1, // "Enabled"
-		]),
+        max_value: Some(1),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Disabled"
+            1, // "Enabled"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Closed Captions Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Disabled.
+    Disabled = 0,
+    /// Enabled.
+    Enabled = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Disabled),
+            1 => Ok(Value::Enabled),
+            _ => Err(Error::from_str("invalid value of the Closed Captions Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ClosedCaptions }
+}
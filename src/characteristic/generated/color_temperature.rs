@@ -11,13 +11,13 @@ pub fn new() -> ColorTemperature {
         hap_type: HapType::ColorTemperature,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		max_value: Some(500),
-		min_value: Some(140),
-		step_value: Some(1),
+        max_value: Some(500),
+        min_value: Some(140),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Configuration State Characteristic.
+pub type ConfigurationState = Characteristic<u16>;
+
+/// Creates a new Configuration State Characteristic.
+pub fn new() -> ConfigurationState {
+    Characteristic::new(Inner::<u16> {
+        hap_type: HapType::ConfigurationState,
+        format: Format::UInt16,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
@@ -11,9 +11,9 @@ pub fn new() -> ConfiguredName {
         hap_type: HapType::ConfiguredName,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Contact Sensor State Characteristic.
 pub type ContactSensorState = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> ContactSensorState {
         hap_type: HapType::ContactSensorState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Contact Detected"
-			1, // "Contact Not Detected"
-		]),
+        valid_values: Some(vec![
+            0, // "Contact Detected"
+            1, // "Contact Not Detected"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Contact Sensor State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Contact Detected.
+    ContactDetected = 0,
+    /// Contact Not Detected.
+    ContactNotDetected = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::ContactDetected),
+            1 => Ok(Value::ContactNotDetected),
+            _ => Err(Error::from_str("invalid value of the Contact Sensor State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ContactSensorState }
+}
@@ -11,14 +11,14 @@ pub fn new() -> CoolingThresholdTemperature {
         hap_type: HapType::CoolingThresholdTemperature,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::Celsius),
-		max_value: Some(35 as f32),
-		min_value: Some(10 as f32),
-		step_value: Some(0.1 as f32),
+        unit: Some(Unit::Celsius),
+        max_value: Some(35_f32),
+        min_value: Some(10_f32),
+        step_value: Some(0.1_f32),
         ..Default::default()
     })
 }
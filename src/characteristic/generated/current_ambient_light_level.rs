@@ -11,12 +11,12 @@ pub fn new() -> CurrentAmbientLightLevel {
         hap_type: HapType::CurrentAmbientLightLevel,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		unit: Some(Unit::Lux),
-		max_value: Some(100000 as f32),
-		min_value: Some(0.0001 as f32),
+        unit: Some(Unit::Lux),
+        max_value: Some(100000_f32),
+        min_value: Some(0.0001_f32),
         ..Default::default()
     })
 }
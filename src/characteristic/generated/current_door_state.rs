@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Current Door State Characteristic.
 pub type CurrentDoorState = Characteristic<u8>;
@@ -11,16 +14,55 @@ pub fn new() -> CurrentDoorState {
         hap_type: HapType::CurrentDoorState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Open"
-			1, // "Closed"
-			2, // "Opening"
-			3, // "Closing"
-			4, // "Stopped"
-		]),
+        valid_values: Some(vec![
+            0, // "Open"
+            1, // "Closed"
+            2, // "Opening"
+            3, // "Closing"
+            4, // "Stopped"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Current Door State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Open.
+    Open = 0,
+    /// Closed.
+    Closed = 1,
+    /// Opening.
+    Opening = 2,
+    /// Closing.
+    Closing = 3,
+    /// Stopped.
+    Stopped = 4,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Open),
+            1 => Ok(Value::Closed),
+            2 => Ok(Value::Opening),
+            3 => Ok(Value::Closing),
+            4 => Ok(Value::Stopped),
+            _ => Err(Error::from_str("invalid value of the Current Door State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CurrentDoorState }
+}
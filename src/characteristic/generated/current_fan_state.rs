@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Current Fan State Characteristic.
 pub type CurrentFanState = Characteristic<u8>;
@@ -11,14 +14,47 @@ pub fn new() -> CurrentFanState {
         hap_type: HapType::CurrentFanState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Inactive"
-			1, // "Idle"
-			2, // "Blowing Air"
-		]),
+        valid_values: Some(vec![
+            0, // "Inactive"
+            1, // "Idle"
+            2, // "Blowing Air"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Current Fan State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Inactive.
+    Inactive = 0,
+    /// Idle.
+    Idle = 1,
+    /// Blowing Air.
+    BlowingAir = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Inactive),
+            1 => Ok(Value::Idle),
+            2 => Ok(Value::BlowingAir),
+            _ => Err(Error::from_str("invalid value of the Current Fan State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CurrentFanState }
+}
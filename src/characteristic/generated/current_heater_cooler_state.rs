@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Current Heater Cooler State Characteristic.
 pub type CurrentHeaterCoolerState = Characteristic<u8>;
@@ -11,15 +14,51 @@ pub fn new() -> CurrentHeaterCoolerState {
         hap_type: HapType::CurrentHeaterCoolerState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Inactive"
-			1, // "Idle"
-			2, // "Heating"
-			3, // "Cooling"
-		]),
+        valid_values: Some(vec![
+            0, // "Inactive"
+            1, // "Idle"
+            2, // "Heating"
+            3, // "Cooling"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Current Heater Cooler State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Inactive.
+    Inactive = 0,
+    /// Idle.
+    Idle = 1,
+    /// Heating.
+    Heating = 2,
+    /// Cooling.
+    Cooling = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Inactive),
+            1 => Ok(Value::Idle),
+            2 => Ok(Value::Heating),
+            3 => Ok(Value::Cooling),
+            _ => Err(Error::from_str("invalid value of the Current Heater Cooler State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CurrentHeaterCoolerState }
+}
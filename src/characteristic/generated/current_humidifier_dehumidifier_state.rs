@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Current Humidifier Dehumidifier State Characteristic.
 pub type CurrentHumidifierDehumidifierState = Characteristic<u8>;
@@ -11,15 +14,51 @@ pub fn new() -> CurrentHumidifierDehumidifierState {
         hap_type: HapType::CurrentHumidifierDehumidifierState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Inactive"
-			1, // "Idle"
-			2, // "Humidifying"
-			3, // "Dehumidifying"
-		]),
+        valid_values: Some(vec![
+            0, // "Inactive"
+            1, // "Idle"
+            2, // "Humidifying"
+            3, // "Dehumidifying"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Current Humidifier Dehumidifier State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Inactive.
+    Inactive = 0,
+    /// Idle.
+    Idle = 1,
+    /// Humidifying.
+    Humidifying = 2,
+    /// Dehumidifying.
+    Dehumidifying = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Inactive),
+            1 => Ok(Value::Idle),
+            2 => Ok(Value::Humidifying),
+            3 => Ok(Value::Dehumidifying),
+            _ => Err(Error::from_str("invalid value of the Current Humidifier Dehumidifier State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CurrentHumidifierDehumidifierState }
+}
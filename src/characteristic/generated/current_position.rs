@@ -11,13 +11,13 @@ pub fn new() -> CurrentPosition {
         hap_type: HapType::CurrentPosition,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		unit: Some(Unit::Percentage),
-		max_value: Some(100),
-		min_value: Some(0),
-		step_value: Some(1),
+        unit: Some(Unit::Percentage),
+        max_value: Some(100),
+        min_value: Some(0),
+        step_value: Some(1),
         ..Default::default()
     })
 }
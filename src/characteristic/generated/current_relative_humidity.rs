@@ -11,13 +11,13 @@ pub fn new() -> CurrentRelativeHumidity {
         hap_type: HapType::CurrentRelativeHumidity,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		unit: Some(Unit::Percentage),
-		max_value: Some(100 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(1 as f32),
+        unit: Some(Unit::Percentage),
+        max_value: Some(100_f32),
+        min_value: Some(0_f32),
+        step_value: Some(1_f32),
         ..Default::default()
     })
 }
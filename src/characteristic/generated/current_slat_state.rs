@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Current Slat State Characteristic.
 pub type CurrentSlatState = Characteristic<u8>;
@@ -11,14 +14,47 @@ pub fn new() -> CurrentSlatState {
         hap_type: HapType::CurrentSlatState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Fixed"
-			1, // "Jammed"
-			2, // "Swinging"
-		]),
+        valid_values: Some(vec![
+            0, // "Fixed"
+            1, // "Jammed"
+            2, // "Swinging"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Current Slat State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Fixed.
+    Fixed = 0,
+    /// Jammed.
+    Jammed = 1,
+    /// Swinging.
+    Swinging = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Fixed),
+            1 => Ok(Value::Jammed),
+            2 => Ok(Value::Swinging),
+            _ => Err(Error::from_str("invalid value of the Current Slat State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CurrentSlatState }
+}
@@ -11,13 +11,13 @@ pub fn new() -> CurrentTemperature {
         hap_type: HapType::CurrentTemperature,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		unit: Some(Unit::Celsius),
-		max_value: Some(100 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(0.1 as f32),
+        unit: Some(Unit::Celsius),
+        max_value: Some(100_f32),
+        min_value: Some(0_f32),
+        step_value: Some(0.1_f32),
         ..Default::default()
     })
 }
@@ -11,13 +11,13 @@ pub fn new() -> CurrentTiltAngle {
         hap_type: HapType::CurrentTiltAngle,
         format: Format::Int32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		unit: Some(Unit::ArcDegrees),
-		max_value: Some(90),
-		min_value: Some(-90),
-		step_value: Some(1),
+        unit: Some(Unit::ArcDegrees),
+        max_value: Some(90),
+        min_value: Some(-90),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Current Transport Characteristic.
+pub type CurrentTransport = Characteristic<bool>;
+
+/// Creates a new Current Transport Characteristic.
+pub fn new() -> CurrentTransport {
+    Characteristic::new(Inner::<bool> {
+        hap_type: HapType::CurrentTransport,
+        format: Format::Bool,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
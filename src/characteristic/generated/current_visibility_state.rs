@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Current Visibility State Characteristic.
 pub type CurrentVisibilityState = Characteristic<u8>;
@@ -11,16 +14,46 @@ pub fn new() -> CurrentVisibilityState {
         hap_type: HapType::CurrentVisibilityState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(3),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Shown"
-			1, // "Hidden"
-		]),
+        max_value: Some(3),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Shown"
+            1, // "Hidden"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Current Visibility State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Shown.
+    Shown = 0,
+    /// Hidden.
+    Hidden = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Shown),
+            1 => Ok(Value::Hidden),
+            _ => Err(Error::from_str("invalid value of the Current Visibility State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::CurrentVisibilityState }
+}
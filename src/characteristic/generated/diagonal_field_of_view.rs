@@ -0,0 +1,22 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm, Unit};
+
+/// Diagonal Field Of View Characteristic.
+pub type DiagonalFieldOfView = Characteristic<f32>;
+
+/// Creates a new Diagonal Field Of View Characteristic.
+pub fn new() -> DiagonalFieldOfView {
+    Characteristic::new(Inner::<f32> {
+        hap_type: HapType::DiagonalFieldOfView,
+        format: Format::Float,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        unit: Some(Unit::ArcDegrees),
+        max_value: Some(360_f32),
+        min_value: Some(0_f32),
+        ..Default::default()
+    })
+}
@@ -11,9 +11,9 @@ pub fn new() -> DigitalZoom {
         hap_type: HapType::DigitalZoom,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
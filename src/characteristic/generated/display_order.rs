@@ -11,9 +11,9 @@ pub fn new() -> DisplayOrder {
         hap_type: HapType::DisplayOrder,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -0,0 +1,57 @@
+// THIS FILE IS AUTO-GENERATED
+
+use std::convert::TryFrom;
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
+
+/// Event Snapshots Active Characteristic.
+pub type EventSnapshotsActive = Characteristic<u8>;
+
+/// Creates a new Event Snapshots Active Characteristic.
+pub fn new() -> EventSnapshotsActive {
+    Characteristic::new(Inner::<u8> {
+        hap_type: HapType::EventSnapshotsActive,
+        format: Format::UInt8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
+        ],
+        valid_values: Some(vec![
+            0, // "Disable"
+            1, // "Enable"
+        ]),
+        ..Default::default()
+    })
+}
+
+/// Valid values of the Event Snapshots Active Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Disable.
+    Disable = 0,
+    /// Enable.
+    Enable = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Disable),
+            1 => Ok(Value::Enable),
+            _ => Err(Error::from_str("invalid value of the Event Snapshots Active Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::EventSnapshotsActive }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Filter Change Indication Characteristic.
 pub type FilterChangeIndication = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> FilterChangeIndication {
         hap_type: HapType::FilterChangeIndication,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Filter OK"
-			1, // "Change Filter"
-		]),
+        valid_values: Some(vec![
+            0, // "Filter OK"
+            1, // "Change Filter"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Filter Change Indication Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Filter OK.
+    FilterOK = 0,
+    /// Change Filter.
+    ChangeFilter = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::FilterOK),
+            1 => Ok(Value::ChangeFilter),
+            _ => Err(Error::from_str("invalid value of the Filter Change Indication Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::FilterChangeIndication }
+}
@@ -11,7 +11,7 @@ pub fn new() -> FirmwareRevision {
         hap_type: HapType::FirmwareRevision,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
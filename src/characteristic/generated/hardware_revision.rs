@@ -11,7 +11,7 @@ pub fn new() -> HardwareRevision {
         hap_type: HapType::HardwareRevision,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
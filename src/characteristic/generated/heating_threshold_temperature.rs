@@ -11,14 +11,14 @@ pub fn new() -> HeatingThresholdTemperature {
         hap_type: HapType::HeatingThresholdTemperature,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::Celsius),
-		max_value: Some(25 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(0.1 as f32),
+        unit: Some(Unit::Celsius),
+        max_value: Some(25_f32),
+        min_value: Some(0_f32),
+        step_value: Some(0.1_f32),
         ..Default::default()
     })
 }
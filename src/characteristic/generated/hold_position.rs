@@ -11,7 +11,7 @@ pub fn new() -> HoldPosition {
         hap_type: HapType::HoldPosition,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
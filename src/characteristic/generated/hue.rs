@@ -11,14 +11,14 @@ pub fn new() -> Hue {
         hap_type: HapType::Hue,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::ArcDegrees),
-		max_value: Some(360 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(1 as f32),
+        unit: Some(Unit::ArcDegrees),
+        max_value: Some(360_f32),
+        min_value: Some(0_f32),
+        step_value: Some(1_f32),
         ..Default::default()
     })
 }
@@ -11,10 +11,10 @@ pub fn new() -> Identifier {
         hap_type: HapType::Identifier,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
-		min_value: Some(0),
-		step_value: Some(1),
+        min_value: Some(0),
+        step_value: Some(1),
         ..Default::default()
     })
 }
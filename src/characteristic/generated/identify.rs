@@ -11,7 +11,7 @@ pub fn new() -> Identify {
         hap_type: HapType::Identify,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
@@ -11,9 +11,9 @@ pub fn new() -> ImageMirroring {
         hap_type: HapType::ImageMirroring,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
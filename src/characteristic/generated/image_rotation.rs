@@ -11,14 +11,14 @@ pub fn new() -> ImageRotation {
         hap_type: HapType::ImageRotation,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::ArcDegrees),
-		max_value: Some(270 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(90 as f32),
+        unit: Some(Unit::ArcDegrees),
+        max_value: Some(270_f32),
+        min_value: Some(0_f32),
+        step_value: Some(90_f32),
         ..Default::default()
     })
 }
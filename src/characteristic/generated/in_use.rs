@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// In Use Characteristic.
 pub type InUse = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> InUse {
         hap_type: HapType::InUse,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Not in use"
-			1, // "In use"
-		]),
+        valid_values: Some(vec![
+            0, // "Not in use"
+            1, // "In use"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the In Use Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Not in use.
+    NotInUse = 0,
+    /// In use.
+    InUse = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NotInUse),
+            1 => Ok(Value::InUse),
+            _ => Err(Error::from_str("invalid value of the In Use Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::InUse }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Input Device Type Characteristic.
 pub type InputDeviceType = Characteristic<u8>;
@@ -11,20 +14,62 @@ pub fn new() -> InputDeviceType {
         hap_type: HapType::InputDeviceType,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(5),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Other"
-			1, // "Tv"
-			2, // "Recording"
-			3, // "Tuner"
-			4, // "Playback"
-			5, // "AudioSystem"
-		]),
+        max_value: Some(5),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Other"
+            1, // "Tv"
+            2, // "Recording"
+            3, // "Tuner"
+            4, // "Playback"
+            5, // "AudioSystem"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Input Device Type Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Other.
+    Other = 0,
+    /// Tv.
+    Tv = 1,
+    /// Recording.
+    Recording = 2,
+    /// Tuner.
+    Tuner = 3,
+    /// Playback.
+    Playback = 4,
+    /// AudioSystem.
+    AudioSystem = 5,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Other),
+            1 => Ok(Value::Tv),
+            2 => Ok(Value::Recording),
+            3 => Ok(Value::Tuner),
+            4 => Ok(Value::Playback),
+            5 => Ok(Value::AudioSystem),
+            _ => Err(Error::from_str("invalid value of the Input Device Type Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::InputDeviceType }
+}
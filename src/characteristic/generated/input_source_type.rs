@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Input Source Type Characteristic.
 pub type InputSourceType = Characteristic<u8>;
@@ -11,25 +14,82 @@ pub fn new() -> InputSourceType {
         hap_type: HapType::InputSourceType,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(10),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Other"
-			1, // "HomeScreen"
-			10, // "Application"
-			2, // "Tuner"
-			3, // "Hdmi"
-			4, // "CompositeVideo"
-			5, // "SVideo"
-			6, // "ComponentVideo"
-			7, // "Dvi"
-			8, // "Airplay"
-			9, // "Usb"
-		]),
+        max_value: Some(10),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Other"
+            1, // "HomeScreen"
+            10, // "Application"
+            2, // "Tuner"
+            3, // "Hdmi"
+            4, // "CompositeVideo"
+            5, // "SVideo"
+            6, // "ComponentVideo"
+            7, // "Dvi"
+            8, // "Airplay"
+            9, // "Usb"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Input Source Type Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Other.
+    Other = 0,
+    /// HomeScreen.
+    HomeScreen = 1,
+    /// Tuner.
+    Tuner = 2,
+    /// Hdmi.
+    Hdmi = 3,
+    /// CompositeVideo.
+    CompositeVideo = 4,
+    /// SVideo.
+    SVideo = 5,
+    /// ComponentVideo.
+    ComponentVideo = 6,
+    /// Dvi.
+    Dvi = 7,
+    /// Airplay.
+    Airplay = 8,
+    /// Usb.
+    Usb = 9,
+    /// Application.
+    Application = 10,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Other),
+            1 => Ok(Value::HomeScreen),
+            2 => Ok(Value::Tuner),
+            3 => Ok(Value::Hdmi),
+            4 => Ok(Value::CompositeVideo),
+            5 => Ok(Value::SVideo),
+            6 => Ok(Value::ComponentVideo),
+            7 => Ok(Value::Dvi),
+            8 => Ok(Value::Airplay),
+            9 => Ok(Value::Usb),
+            10 => Ok(Value::Application),
+            _ => Err(Error::from_str("invalid value of the Input Source Type Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::InputSourceType }
+}
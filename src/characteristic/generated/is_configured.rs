@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Is Configured Characteristic.
 pub type IsConfigured = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> IsConfigured {
         hap_type: HapType::IsConfigured,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Not Configured"
-			1, // "Configured"
-		]),
+        valid_values: Some(vec![
+            0, // "Not Configured"
+            1, // "Configured"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Is Configured Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Not Configured.
+    NotConfigured = 0,
+    /// Configured.
+    Configured = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NotConfigured),
+            1 => Ok(Value::Configured),
+            _ => Err(Error::from_str("invalid value of the Is Configured Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::IsConfigured }
+}
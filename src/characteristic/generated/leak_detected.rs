@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Leak Detected Characteristic.
 pub type LeakDetected = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> LeakDetected {
         hap_type: HapType::LeakDetected,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Leak Not Detected"
-			1, // "Leak Detected"
-		]),
+        valid_values: Some(vec![
+            0, // "Leak Not Detected"
+            1, // "Leak Detected"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Leak Detected Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Leak Not Detected.
+    LeakNotDetected = 0,
+    /// Leak Detected.
+    LeakDetected = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::LeakNotDetected),
+            1 => Ok(Value::LeakDetected),
+            _ => Err(Error::from_str("invalid value of the Leak Detected Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::LeakDetected }
+}
@@ -11,7 +11,7 @@ pub fn new() -> LockControlPoint {
         hap_type: HapType::LockControlPoint,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Lock Current State Characteristic.
 pub type LockCurrentState = Characteristic<u8>;
@@ -11,15 +14,51 @@ pub fn new() -> LockCurrentState {
         hap_type: HapType::LockCurrentState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Unsecured"
-			1, // "Secured"
-			2, // "Jammed"
-			3, // "Unknown"
-		]),
+        valid_values: Some(vec![
+            0, // "Unsecured"
+            1, // "Secured"
+            2, // "Jammed"
+            3, // "Unknown"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Lock Current State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Unsecured.
+    Unsecured = 0,
+    /// Secured.
+    Secured = 1,
+    /// Jammed.
+    Jammed = 2,
+    /// Unknown.
+    Unknown = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Unsecured),
+            1 => Ok(Value::Secured),
+            2 => Ok(Value::Jammed),
+            3 => Ok(Value::Unknown),
+            _ => Err(Error::from_str("invalid value of the Lock Current State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::LockCurrentState }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Lock Last Known Action Characteristic.
 pub type LockLastKnownAction = Characteristic<u8>;
@@ -11,20 +14,71 @@ pub fn new() -> LockLastKnownAction {
         hap_type: HapType::LockLastKnownAction,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Secured Physically, Interior"
-			1, // "Unsecured Physically, Interior"
-			2, // "Secured Physically, Exterior"
-			3, // "Unsecured Physically, Exterior"
-			4, // "Secured by Keypad"
-			5, // "Unsecured by Keypad"
-			6, // "Secured Remotely"
-			7, // "Unsecured Remotely"
-			8, // "Secured by Auto Secure Timeout"
-		]),
+        valid_values: Some(vec![
+            0, // "Secured Physically, Interior"
+            1, // "Unsecured Physically, Interior"
+            2, // "Secured Physically, Exterior"
+            3, // "Unsecured Physically, Exterior"
+            4, // "Secured by Keypad"
+            5, // "Unsecured by Keypad"
+            6, // "Secured Remotely"
+            7, // "Unsecured Remotely"
+            8, // "Secured by Auto Secure Timeout"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Lock Last Known Action Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Secured Physically, Interior.
+    SecuredPhysicallyInterior = 0,
+    /// Unsecured Physically, Interior.
+    UnsecuredPhysicallyInterior = 1,
+    /// Secured Physically, Exterior.
+    SecuredPhysicallyExterior = 2,
+    /// Unsecured Physically, Exterior.
+    UnsecuredPhysicallyExterior = 3,
+    /// Secured by Keypad.
+    SecuredByKeypad = 4,
+    /// Unsecured by Keypad.
+    UnsecuredByKeypad = 5,
+    /// Secured Remotely.
+    SecuredRemotely = 6,
+    /// Unsecured Remotely.
+    UnsecuredRemotely = 7,
+    /// Secured by Auto Secure Timeout.
+    SecuredByAutoSecureTimeout = 8,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::SecuredPhysicallyInterior),
+            1 => Ok(Value::UnsecuredPhysicallyInterior),
+            2 => Ok(Value::SecuredPhysicallyExterior),
+            3 => Ok(Value::UnsecuredPhysicallyExterior),
+            4 => Ok(Value::SecuredByKeypad),
+            5 => Ok(Value::UnsecuredByKeypad),
+            6 => Ok(Value::SecuredRemotely),
+            7 => Ok(Value::UnsecuredRemotely),
+            8 => Ok(Value::SecuredByAutoSecureTimeout),
+            _ => Err(Error::from_str("invalid value of the Lock Last Known Action Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::LockLastKnownAction }
+}
@@ -11,11 +11,11 @@ pub fn new() -> LockManagementAutoSecurityTimeout {
         hap_type: HapType::LockManagementAutoSecurityTimeout,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::Seconds),
+        unit: Some(Unit::Seconds),
         ..Default::default()
     })
 }
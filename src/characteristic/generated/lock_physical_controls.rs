@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Lock Physical Controls Characteristic.
 pub type LockPhysicalControls = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> LockPhysicalControls {
         hap_type: HapType::LockPhysicalControls,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Control Lock Disabled"
-			1, // "Control Lock Enabled"
-		]),
+        valid_values: Some(vec![
+            0, // "Control Lock Disabled"
+            1, // "Control Lock Enabled"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Lock Physical Controls Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Control Lock Disabled.
+    ControlLockDisabled = 0,
+    /// Control Lock Enabled.
+    ControlLockEnabled = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::ControlLockDisabled),
+            1 => Ok(Value::ControlLockEnabled),
+            _ => Err(Error::from_str("invalid value of the Lock Physical Controls Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::LockPhysicalControls }
+}
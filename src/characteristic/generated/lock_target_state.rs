@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Lock Target State Characteristic.
 pub type LockTargetState = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> LockTargetState {
         hap_type: HapType::LockTargetState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Unsecured"
-			1, // "Secured"
-		]),
+        valid_values: Some(vec![
+            0, // "Unsecured"
+            1, // "Secured"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Lock Target State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Unsecured.
+    Unsecured = 0,
+    /// Secured.
+    Secured = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Unsecured),
+            1 => Ok(Value::Secured),
+            _ => Err(Error::from_str("invalid value of the Lock Target State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::LockTargetState }
+}
@@ -11,8 +11,8 @@ pub fn new() -> Logs {
         hap_type: HapType::Logs,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Manually Disabled Characteristic.
+pub type ManuallyDisabled = Characteristic<bool>;
+
+/// Creates a new Manually Disabled Characteristic.
+pub fn new() -> ManuallyDisabled {
+    Characteristic::new(Inner::<bool> {
+        hap_type: HapType::ManuallyDisabled,
+        format: Format::Bool,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
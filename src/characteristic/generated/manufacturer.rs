@@ -11,7 +11,7 @@ pub fn new() -> Manufacturer {
         hap_type: HapType::Manufacturer,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
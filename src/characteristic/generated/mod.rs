@@ -1,5 +1,7 @@
 // THIS FILE IS AUTO-GENERATED
 
+use crate::{characteristic::HapCharacteristic, HapType};
+
 pub mod accessory_flags;
 pub mod active;
 pub mod active_identifier;
@@ -142,3 +144,213 @@ pub mod volume;
 pub mod volume_control_type;
 pub mod volume_selector;
 pub mod water_level;
+pub mod characteristic_value_transition_control;
+pub mod supported_characteristic_value_transition_configuration;
+pub mod characteristic_value_active_transition_count;
+pub mod target_control_supported_configuration;
+pub mod target_control_list;
+pub mod button_event;
+pub mod selected_audio_stream_configuration;
+pub mod supported_data_stream_transport_configuration;
+pub mod setup_data_stream_transport;
+pub mod siri_input_type;
+pub mod current_transport;
+pub mod wifi_capabilities;
+pub mod wifi_configuration_control;
+pub mod access_code_supported_configuration;
+pub mod access_code_control_point;
+pub mod configuration_state;
+pub mod nfc_access_control_point;
+pub mod nfc_access_supported_configuration;
+pub mod homekit_camera_active;
+pub mod third_party_camera_active;
+pub mod camera_operating_mode_indicator;
+pub mod event_snapshots_active;
+pub mod diagonal_field_of_view;
+pub mod periodic_snapshots_active;
+pub mod recording_audio_active;
+pub mod manually_disabled;
+pub mod supported_camera_recording_configuration;
+pub mod supported_video_recording_configuration;
+pub mod supported_audio_recording_configuration;
+pub mod selected_camera_recording_configuration;
+
+/// Creates a new Characteristic of the given `HapType`. Returns `None` if the `HapType` isn't a
+/// Characteristic type.
+pub fn new_by_type(hap_type: HapType) -> Option<Box<dyn HapCharacteristic + Send>> {
+    match hap_type {
+		HapType::AccessoryFlags => Some(Box::new(accessory_flags::new())),
+		HapType::Active => Some(Box::new(active::new())),
+		HapType::ActiveIdentifier => Some(Box::new(active_identifier::new())),
+		HapType::AdministratorOnlyAccess => Some(Box::new(administrator_only_access::new())),
+		HapType::AirParticulateDensity => Some(Box::new(air_particulate_density::new())),
+		HapType::AirParticulateSize => Some(Box::new(air_particulate_size::new())),
+		HapType::AirQuality => Some(Box::new(air_quality::new())),
+		HapType::AudioFeedback => Some(Box::new(audio_feedback::new())),
+		HapType::BatteryLevel => Some(Box::new(battery_level::new())),
+		HapType::Brightness => Some(Box::new(brightness::new())),
+		HapType::CarbonDioxideDetected => Some(Box::new(carbon_dioxide_detected::new())),
+		HapType::CarbonDioxideLevel => Some(Box::new(carbon_dioxide_level::new())),
+		HapType::CarbonDioxidePeakLevel => Some(Box::new(carbon_dioxide_peak_level::new())),
+		HapType::CarbonMonoxideDetected => Some(Box::new(carbon_monoxide_detected::new())),
+		HapType::CarbonMonoxideLevel => Some(Box::new(carbon_monoxide_level::new())),
+		HapType::CarbonMonoxidePeakLevel => Some(Box::new(carbon_monoxide_peak_level::new())),
+		HapType::ChargingState => Some(Box::new(charging_state::new())),
+		HapType::ClosedCaptions => Some(Box::new(closed_captions::new())),
+		HapType::ConfiguredName => Some(Box::new(configured_name::new())),
+		HapType::DisplayOrder => Some(Box::new(display_order::new())),
+		HapType::ColorTemperature => Some(Box::new(color_temperature::new())),
+		HapType::ContactSensorState => Some(Box::new(contact_sensor_state::new())),
+		HapType::CoolingThresholdTemperature => Some(Box::new(cooling_threshold_temperature::new())),
+		HapType::CurrentAirPurifierState => Some(Box::new(current_air_purifier_state::new())),
+		HapType::CurrentAmbientLightLevel => Some(Box::new(current_ambient_light_level::new())),
+		HapType::CurrentDoorState => Some(Box::new(current_door_state::new())),
+		HapType::CurrentFanState => Some(Box::new(current_fan_state::new())),
+		HapType::CurrentHeaterCoolerState => Some(Box::new(current_heater_cooler_state::new())),
+		HapType::CurrentHeatingCoolingState => Some(Box::new(current_heating_cooling_state::new())),
+		HapType::CurrentHorizontalTiltAngle => Some(Box::new(current_horizontal_tilt_angle::new())),
+		HapType::CurrentHumidifierDehumidifierState => Some(Box::new(current_humidifier_dehumidifier_state::new())),
+		HapType::CurrentMediaState => Some(Box::new(current_media_state::new())),
+		HapType::TargetMediaState => Some(Box::new(target_media_state::new())),
+		HapType::CurrentPosition => Some(Box::new(current_position::new())),
+		HapType::CurrentRelativeHumidity => Some(Box::new(current_relative_humidity::new())),
+		HapType::CurrentSlatState => Some(Box::new(current_slat_state::new())),
+		HapType::CurrentTemperature => Some(Box::new(current_temperature::new())),
+		HapType::CurrentTiltAngle => Some(Box::new(current_tilt_angle::new())),
+		HapType::CurrentVerticalTiltAngle => Some(Box::new(current_vertical_tilt_angle::new())),
+		HapType::DigitalZoom => Some(Box::new(digital_zoom::new())),
+		HapType::FilterChangeIndication => Some(Box::new(filter_change_indication::new())),
+		HapType::FilterLifeLevel => Some(Box::new(filter_life_level::new())),
+		HapType::FirmwareRevision => Some(Box::new(firmware_revision::new())),
+		HapType::HardwareRevision => Some(Box::new(hardware_revision::new())),
+		HapType::HeatingThresholdTemperature => Some(Box::new(heating_threshold_temperature::new())),
+		HapType::HoldPosition => Some(Box::new(hold_position::new())),
+		HapType::Hue => Some(Box::new(hue::new())),
+		HapType::Identify => Some(Box::new(identify::new())),
+		HapType::InputSourceType => Some(Box::new(input_source_type::new())),
+		HapType::InputDeviceType => Some(Box::new(input_device_type::new())),
+		HapType::Identifier => Some(Box::new(identifier::new())),
+		HapType::CurrentVisibilityState => Some(Box::new(current_visibility_state::new())),
+		HapType::TargetVisibilityState => Some(Box::new(target_visibility_state::new())),
+		HapType::ImageMirroring => Some(Box::new(image_mirroring::new())),
+		HapType::ImageRotation => Some(Box::new(image_rotation::new())),
+		HapType::InUse => Some(Box::new(in_use::new())),
+		HapType::IsConfigured => Some(Box::new(is_configured::new())),
+		HapType::LeakDetected => Some(Box::new(leak_detected::new())),
+		HapType::LockControlPoint => Some(Box::new(lock_control_point::new())),
+		HapType::LockCurrentState => Some(Box::new(lock_current_state::new())),
+		HapType::LockLastKnownAction => Some(Box::new(lock_last_known_action::new())),
+		HapType::LockManagementAutoSecurityTimeout => Some(Box::new(lock_management_auto_security_timeout::new())),
+		HapType::LockPhysicalControls => Some(Box::new(lock_physical_controls::new())),
+		HapType::LockTargetState => Some(Box::new(lock_target_state::new())),
+		HapType::Logs => Some(Box::new(logs::new())),
+		HapType::Manufacturer => Some(Box::new(manufacturer::new())),
+		HapType::Model => Some(Box::new(model::new())),
+		HapType::MotionDetected => Some(Box::new(motion_detected::new())),
+		HapType::Mute => Some(Box::new(mute::new())),
+		HapType::Name => Some(Box::new(name::new())),
+		HapType::NightVision => Some(Box::new(night_vision::new())),
+		HapType::NitrogenDioxideDensity => Some(Box::new(nitrogen_dioxide_density::new())),
+		HapType::ObstructionDetected => Some(Box::new(obstruction_detected::new())),
+		HapType::OccupancyDetected => Some(Box::new(occupancy_detected::new())),
+		HapType::On => Some(Box::new(on::new())),
+		HapType::OpticalZoom => Some(Box::new(optical_zoom::new())),
+		HapType::OutletInUse => Some(Box::new(outlet_in_use::new())),
+		HapType::OzoneDensity => Some(Box::new(ozone_density::new())),
+		HapType::PairSetup => Some(Box::new(pair_setup::new())),
+		HapType::PairVerify => Some(Box::new(pair_verify::new())),
+		HapType::PairingFeatures => Some(Box::new(pairing_features::new())),
+		HapType::PairingPairings => Some(Box::new(pairing_pairings::new())),
+		HapType::PM10Density => Some(Box::new(pm10_density::new())),
+		HapType::PM2_5Density => Some(Box::new(pm2_5_density::new())),
+		HapType::PositionState => Some(Box::new(position_state::new())),
+		HapType::PictureMode => Some(Box::new(picture_mode::new())),
+		HapType::PowerModeSelection => Some(Box::new(power_mode_selection::new())),
+		HapType::ProgramMode => Some(Box::new(program_mode::new())),
+		HapType::ProgrammableSwitchEvent => Some(Box::new(programmable_switch_event::new())),
+		HapType::RemoteKey => Some(Box::new(remote_key::new())),
+		HapType::RelativeHumidityDehumidifierThreshold => Some(Box::new(relative_humidity_dehumidifier_threshold::new())),
+		HapType::RelativeHumidityHumidifierThreshold => Some(Box::new(relative_humidity_humidifier_threshold::new())),
+		HapType::RemainingDuration => Some(Box::new(remaining_duration::new())),
+		HapType::ResetFilterIndication => Some(Box::new(reset_filter_indication::new())),
+		HapType::RotationDirection => Some(Box::new(rotation_direction::new())),
+		HapType::RotationSpeed => Some(Box::new(rotation_speed::new())),
+		HapType::Saturation => Some(Box::new(saturation::new())),
+		HapType::SecuritySystemAlarmType => Some(Box::new(security_system_alarm_type::new())),
+		HapType::SecuritySystemCurrentState => Some(Box::new(security_system_current_state::new())),
+		HapType::SecuritySystemTargetState => Some(Box::new(security_system_target_state::new())),
+		HapType::SelectedRTPStreamConfiguration => Some(Box::new(selected_rtp_stream_configuration::new())),
+		HapType::SerialNumber => Some(Box::new(serial_number::new())),
+		HapType::ServiceLabelIndex => Some(Box::new(service_label_index::new())),
+		HapType::ServiceLabelNamespace => Some(Box::new(service_label_namespace::new())),
+		HapType::SetDuration => Some(Box::new(set_duration::new())),
+		HapType::SetupEndpoints => Some(Box::new(setup_endpoints::new())),
+		HapType::SlatType => Some(Box::new(slat_type::new())),
+		HapType::SleepDiscoveryMode => Some(Box::new(sleep_discovery_mode::new())),
+		HapType::SmokeDetected => Some(Box::new(smoke_detected::new())),
+		HapType::StatusActive => Some(Box::new(status_active::new())),
+		HapType::StatusFault => Some(Box::new(status_fault::new())),
+		HapType::StatusJammed => Some(Box::new(status_jammed::new())),
+		HapType::StatusLowBattery => Some(Box::new(status_low_battery::new())),
+		HapType::StatusTampered => Some(Box::new(status_tampered::new())),
+		HapType::StreamingStatus => Some(Box::new(streaming_status::new())),
+		HapType::SulphurDioxideDensity => Some(Box::new(sulphur_dioxide_density::new())),
+		HapType::SupportedAudioStreamConfiguration => Some(Box::new(supported_audio_stream_configuration::new())),
+		HapType::SupportedRTPConfiguration => Some(Box::new(supported_rtp_configuration::new())),
+		HapType::SupportedVideoStreamConfiguration => Some(Box::new(supported_video_stream_configuration::new())),
+		HapType::SwingMode => Some(Box::new(swing_mode::new())),
+		HapType::TargetAirPurifierState => Some(Box::new(target_air_purifier_state::new())),
+		HapType::TargetAirQuality => Some(Box::new(target_air_quality::new())),
+		HapType::TargetDoorState => Some(Box::new(target_door_state::new())),
+		HapType::TargetFanState => Some(Box::new(target_fan_state::new())),
+		HapType::TargetHeaterCoolerState => Some(Box::new(target_heater_cooler_state::new())),
+		HapType::TargetHeatingCoolingState => Some(Box::new(target_heating_cooling_state::new())),
+		HapType::TargetHorizontalTiltAngle => Some(Box::new(target_horizontal_tilt_angle::new())),
+		HapType::TargetHumidifierDehumidifierState => Some(Box::new(target_humidifier_dehumidifier_state::new())),
+		HapType::TargetPosition => Some(Box::new(target_position::new())),
+		HapType::TargetRelativeHumidity => Some(Box::new(target_relative_humidity::new())),
+		HapType::TargetSlatState => Some(Box::new(target_slat_state::new())),
+		HapType::TargetTemperature => Some(Box::new(target_temperature::new())),
+		HapType::TargetTiltAngle => Some(Box::new(target_tilt_angle::new())),
+		HapType::TargetVerticalTiltAngle => Some(Box::new(target_vertical_tilt_angle::new())),
+		HapType::TemperatureDisplayUnits => Some(Box::new(temperature_display_units::new())),
+		HapType::ValveType => Some(Box::new(valve_type::new())),
+		HapType::Version => Some(Box::new(version::new())),
+		HapType::VOCDensity => Some(Box::new(voc_density::new())),
+		HapType::Volume => Some(Box::new(volume::new())),
+		HapType::VolumeControlType => Some(Box::new(volume_control_type::new())),
+		HapType::VolumeSelector => Some(Box::new(volume_selector::new())),
+		HapType::WaterLevel => Some(Box::new(water_level::new())),
+		HapType::CharacteristicValueTransitionControl => Some(Box::new(characteristic_value_transition_control::new())),
+		HapType::SupportedCharacteristicValueTransitionConfiguration => Some(Box::new(supported_characteristic_value_transition_configuration::new())),
+		HapType::CharacteristicValueActiveTransitionCount => Some(Box::new(characteristic_value_active_transition_count::new())),
+		HapType::TargetControlSupportedConfiguration => Some(Box::new(target_control_supported_configuration::new())),
+		HapType::TargetControlList => Some(Box::new(target_control_list::new())),
+		HapType::ButtonEvent => Some(Box::new(button_event::new())),
+		HapType::SelectedAudioStreamConfiguration => Some(Box::new(selected_audio_stream_configuration::new())),
+		HapType::SupportedDataStreamTransportConfiguration => Some(Box::new(supported_data_stream_transport_configuration::new())),
+		HapType::SetupDataStreamTransport => Some(Box::new(setup_data_stream_transport::new())),
+		HapType::SiriInputType => Some(Box::new(siri_input_type::new())),
+		HapType::CurrentTransport => Some(Box::new(current_transport::new())),
+		HapType::WiFiCapabilities => Some(Box::new(wifi_capabilities::new())),
+		HapType::WiFiConfigurationControl => Some(Box::new(wifi_configuration_control::new())),
+		HapType::AccessCodeSupportedConfiguration => Some(Box::new(access_code_supported_configuration::new())),
+		HapType::AccessCodeControlPoint => Some(Box::new(access_code_control_point::new())),
+		HapType::ConfigurationState => Some(Box::new(configuration_state::new())),
+		HapType::NFCAccessControlPoint => Some(Box::new(nfc_access_control_point::new())),
+		HapType::NFCAccessSupportedConfiguration => Some(Box::new(nfc_access_supported_configuration::new())),
+		HapType::HomeKitCameraActive => Some(Box::new(homekit_camera_active::new())),
+		HapType::ThirdPartyCameraActive => Some(Box::new(third_party_camera_active::new())),
+		HapType::CameraOperatingModeIndicator => Some(Box::new(camera_operating_mode_indicator::new())),
+		HapType::EventSnapshotsActive => Some(Box::new(event_snapshots_active::new())),
+		HapType::DiagonalFieldOfView => Some(Box::new(diagonal_field_of_view::new())),
+		HapType::PeriodicSnapshotsActive => Some(Box::new(periodic_snapshots_active::new())),
+		HapType::RecordingAudioActive => Some(Box::new(recording_audio_active::new())),
+		HapType::ManuallyDisabled => Some(Box::new(manually_disabled::new())),
+		HapType::SupportedCameraRecordingConfiguration => Some(Box::new(supported_camera_recording_configuration::new())),
+		HapType::SupportedVideoRecordingConfiguration => Some(Box::new(supported_video_recording_configuration::new())),
+		HapType::SupportedAudioRecordingConfiguration => Some(Box::new(supported_audio_recording_configuration::new())),
+		HapType::SelectedCameraRecordingConfiguration => Some(Box::new(selected_camera_recording_configuration::new())),
+		_ => None,
+    }
+}
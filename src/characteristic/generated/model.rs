@@ -11,7 +11,7 @@ pub fn new() -> Model {
         hap_type: HapType::Model,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
@@ -11,8 +11,8 @@ pub fn new() -> MotionDetected {
         hap_type: HapType::MotionDetected,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -11,9 +11,9 @@ pub fn new() -> Mute {
         hap_type: HapType::Mute,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -11,7 +11,7 @@ pub fn new() -> Name {
         hap_type: HapType::Name,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
@@ -0,0 +1,20 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// NFC Access Control Point Characteristic.
+pub type NFCAccessControlPoint = Characteristic<Vec<u8>>;
+
+/// Creates a new NFC Access Control Point Characteristic.
+pub fn new() -> NFCAccessControlPoint {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::NFCAccessControlPoint,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::WriteResponse,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// NFC Access Supported Configuration Characteristic.
+pub type NFCAccessSupportedConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new NFC Access Supported Configuration Characteristic.
+pub fn new() -> NFCAccessSupportedConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::NFCAccessSupportedConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
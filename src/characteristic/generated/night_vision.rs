@@ -11,9 +11,9 @@ pub fn new() -> NightVision {
         hap_type: HapType::NightVision,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
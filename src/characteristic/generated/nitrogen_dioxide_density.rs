@@ -11,12 +11,12 @@ pub fn new() -> NitrogenDioxideDensity {
         hap_type: HapType::NitrogenDioxideDensity,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(1000 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(1 as f32),
+        max_value: Some(1000_f32),
+        min_value: Some(0_f32),
+        step_value: Some(1_f32),
         ..Default::default()
     })
 }
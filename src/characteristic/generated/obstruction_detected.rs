@@ -11,8 +11,8 @@ pub fn new() -> ObstructionDetected {
         hap_type: HapType::ObstructionDetected,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
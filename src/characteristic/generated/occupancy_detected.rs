@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Occupancy Detected Characteristic.
 pub type OccupancyDetected = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> OccupancyDetected {
         hap_type: HapType::OccupancyDetected,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Occupancy Not Detected"
-			1, // "Occupancy Detected"
-		]),
+        valid_values: Some(vec![
+            0, // "Occupancy Not Detected"
+            1, // "Occupancy Detected"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Occupancy Detected Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Occupancy Not Detected.
+    OccupancyNotDetected = 0,
+    /// Occupancy Detected.
+    OccupancyDetected = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::OccupancyNotDetected),
+            1 => Ok(Value::OccupancyDetected),
+            _ => Err(Error::from_str("invalid value of the Occupancy Detected Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::OccupancyDetected }
+}
@@ -11,9 +11,9 @@ pub fn new() -> OpticalZoom {
         hap_type: HapType::OpticalZoom,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -11,8 +11,8 @@ pub fn new() -> OutletInUse {
         hap_type: HapType::OutletInUse,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
@@ -11,8 +11,8 @@ pub fn new() -> PairSetup {
         hap_type: HapType::PairSetup,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
+            Perm::PairedRead,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
@@ -11,8 +11,8 @@ pub fn new() -> PairVerify {
         hap_type: HapType::PairVerify,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
+            Perm::PairedRead,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
@@ -11,7 +11,7 @@ pub fn new() -> PairingFeatures {
         hap_type: HapType::PairingFeatures,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
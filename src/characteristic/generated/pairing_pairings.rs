@@ -11,8 +11,8 @@ pub fn new() -> PairingPairings {
         hap_type: HapType::PairingPairings,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
+            Perm::PairedRead,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
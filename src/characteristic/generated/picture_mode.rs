@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Picture Mode Characteristic.
 pub type PictureMode = Characteristic<u16>;
@@ -11,23 +14,71 @@ pub fn new() -> PictureMode {
         hap_type: HapType::PictureMode,
         format: Format::UInt16,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		max_value: Some(13),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Other"
-			1, // "Standard"
-			2, // "Calibrated"
-			3, // "CalibratedDark"
-			4, // "Vivid"
-			5, // "Game"
-			6, // "Computer"
-			7, // "Custom"
-		]),
+        max_value: Some(13),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Other"
+            1, // "Standard"
+            2, // "Calibrated"
+            3, // "CalibratedDark"
+            4, // "Vivid"
+            5, // "Game"
+            6, // "Computer"
+            7, // "Custom"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Picture Mode Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Value {
+    /// Other.
+    Other = 0,
+    /// Standard.
+    Standard = 1,
+    /// Calibrated.
+    Calibrated = 2,
+    /// CalibratedDark.
+    CalibratedDark = 3,
+    /// Vivid.
+    Vivid = 4,
+    /// Game.
+    Game = 5,
+    /// Computer.
+    Computer = 6,
+    /// Custom.
+    Custom = 7,
+}
+
+impl From<Value> for u16 {
+    fn from(value: Value) -> u16 { value as u16 }
+}
+
+impl TryFrom<u16> for Value {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Other),
+            1 => Ok(Value::Standard),
+            2 => Ok(Value::Calibrated),
+            3 => Ok(Value::CalibratedDark),
+            4 => Ok(Value::Vivid),
+            5 => Ok(Value::Game),
+            6 => Ok(Value::Computer),
+            7 => Ok(Value::Custom),
+            _ => Err(Error::from_str("invalid value of the Picture Mode Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u16> for Value {
+    fn hap_type() -> HapType { HapType::PictureMode }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Position State Characteristic.
 pub type PositionState = Characteristic<u8>;
@@ -11,14 +14,47 @@ pub fn new() -> PositionState {
         hap_type: HapType::PositionState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Decreasing"
-			1, // "Increasing"
-			2, // "Stopped"
-		]),
+        valid_values: Some(vec![
+            0, // "Decreasing"
+            1, // "Increasing"
+            2, // "Stopped"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Position State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Decreasing.
+    Decreasing = 0,
+    /// Increasing.
+    Increasing = 1,
+    /// Stopped.
+    Stopped = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Decreasing),
+            1 => Ok(Value::Increasing),
+            2 => Ok(Value::Stopped),
+            _ => Err(Error::from_str("invalid value of the Position State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::PositionState }
+}
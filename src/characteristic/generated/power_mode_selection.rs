@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Power Mode Selection Characteristic.
 pub type PowerModeSelection = Characteristic<u8>;
@@ -11,15 +14,45 @@ pub fn new() -> PowerModeSelection {
         hap_type: HapType::PowerModeSelection,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
-		max_value: Some(1),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Show"
-			1, // "Hide"
-		]),
+        max_value: Some(1),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Show"
+            1, // "Hide"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Power Mode Selection Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Show.
+    Show = 0,
+    /// Hide.
+    Hide = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Show),
+            1 => Ok(Value::Hide),
+            _ => Err(Error::from_str("invalid value of the Power Mode Selection Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::PowerModeSelection }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Program Mode Characteristic.
 pub type ProgramMode = Characteristic<u8>;
@@ -11,14 +14,47 @@ pub fn new() -> ProgramMode {
         hap_type: HapType::ProgramMode,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "No program scheduled"
-			1, // "Program scheduled"
-			2, // "Program scheduled (Manual Mode)"
-		]),
+        valid_values: Some(vec![
+            0, // "No program scheduled"
+            1, // "Program scheduled"
+            2, // "Program scheduled (Manual Mode)"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Program Mode Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// No program scheduled.
+    NoProgramScheduled = 0,
+    /// Program scheduled.
+    ProgramScheduled = 1,
+    /// Program scheduled (Manual Mode).
+    ProgramScheduledManualMode = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NoProgramScheduled),
+            1 => Ok(Value::ProgramScheduled),
+            2 => Ok(Value::ProgramScheduledManualMode),
+            _ => Err(Error::from_str("invalid value of the Program Mode Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ProgramMode }
+}
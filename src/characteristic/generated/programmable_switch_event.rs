@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Programmable Switch Event Characteristic.
 pub type ProgrammableSwitchEvent = Characteristic<u8>;
@@ -11,14 +14,47 @@ pub fn new() -> ProgrammableSwitchEvent {
         hap_type: HapType::ProgrammableSwitchEvent,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Single Press"
-			1, // "Double Press"
-			2, // "Long Press"
-		]),
+        valid_values: Some(vec![
+            0, // "Single Press"
+            1, // "Double Press"
+            2, // "Long Press"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Programmable Switch Event Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Single Press.
+    SinglePress = 0,
+    /// Double Press.
+    DoublePress = 1,
+    /// Long Press.
+    LongPress = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::SinglePress),
+            1 => Ok(Value::DoublePress),
+            2 => Ok(Value::LongPress),
+            _ => Err(Error::from_str("invalid value of the Programmable Switch Event Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ProgrammableSwitchEvent }
+}
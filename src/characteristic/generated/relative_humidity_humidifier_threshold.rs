@@ -11,14 +11,14 @@ pub fn new() -> RelativeHumidityHumidifierThreshold {
         hap_type: HapType::RelativeHumidityHumidifierThreshold,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::Percentage),
-		max_value: Some(100 as f32),
-		min_value: Some(0 as f32),
-		step_value: Some(1 as f32),
+        unit: Some(Unit::Percentage),
+        max_value: Some(100_f32),
+        min_value: Some(0_f32),
+        step_value: Some(1_f32),
         ..Default::default()
     })
 }
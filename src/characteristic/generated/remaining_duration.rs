@@ -11,12 +11,12 @@ pub fn new() -> RemainingDuration {
         hap_type: HapType::RemainingDuration,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(3600),
-		min_value: Some(0),
-		step_value: Some(1),
+        max_value: Some(3600),
+        min_value: Some(0),
+        step_value: Some(1),
         ..Default::default()
     })
 }
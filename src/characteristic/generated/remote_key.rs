@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Remote Key Characteristic.
 pub type RemoteKey = Characteristic<u8>;
@@ -11,26 +14,89 @@ pub fn new() -> RemoteKey {
         hap_type: HapType::RemoteKey,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
-		max_value: Some(16),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Rewind"
-			1, // "FastForward"
-			10, // "Exit"
-			11, // "PlayPause"
-			15, // "Info"
-			2, // "NextTrack"
-			3, // "PrevTrack"
-			4, // "ArrowUp"
-			5, // "ArrowDown"
-			6, // "ArrowLeft"
-			7, // "ArrowRight"
-			8, // "Select"
-			9, // "Back"
-		]),
+        max_value: Some(16),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Rewind"
+            1, // "FastForward"
+            10, // "Exit"
+            11, // "PlayPause"
+            15, // "Info"
+            2, // "NextTrack"
+            3, // "PrevTrack"
+            4, // "ArrowUp"
+            5, // "ArrowDown"
+            6, // "ArrowLeft"
+            7, // "ArrowRight"
+            8, // "Select"
+            9, // "Back"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Remote Key Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Rewind.
+    Rewind = 0,
+    /// FastForward.
+    FastForward = 1,
+    /// NextTrack.
+    NextTrack = 2,
+    /// PrevTrack.
+    PrevTrack = 3,
+    /// ArrowUp.
+    ArrowUp = 4,
+    /// ArrowDown.
+    ArrowDown = 5,
+    /// ArrowLeft.
+    ArrowLeft = 6,
+    /// ArrowRight.
+    ArrowRight = 7,
+    /// Select.
+    Select = 8,
+    /// Back.
+    Back = 9,
+    /// Exit.
+    Exit = 10,
+    /// PlayPause.
+    PlayPause = 11,
+    /// Info.
+    Info = 15,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Rewind),
+            1 => Ok(Value::FastForward),
+            2 => Ok(Value::NextTrack),
+            3 => Ok(Value::PrevTrack),
+            4 => Ok(Value::ArrowUp),
+            5 => Ok(Value::ArrowDown),
+            6 => Ok(Value::ArrowLeft),
+            7 => Ok(Value::ArrowRight),
+            8 => Ok(Value::Select),
+            9 => Ok(Value::Back),
+            10 => Ok(Value::Exit),
+            11 => Ok(Value::PlayPause),
+            15 => Ok(Value::Info),
+            _ => Err(Error::from_str("invalid value of the Remote Key Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::RemoteKey }
+}
@@ -11,11 +11,11 @@ pub fn new() -> ResetFilterIndication {
         hap_type: HapType::ResetFilterIndication,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
-		max_value: Some(1),
-		min_value: Some(1),
-		step_value: Some(1),
+        max_value: Some(1),
+        min_value: Some(1),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Rotation Direction Characteristic.
 pub type RotationDirection = Characteristic<i32>;
@@ -11,14 +14,44 @@ pub fn new() -> RotationDirection {
         hap_type: HapType::RotationDirection,
         format: Format::Int32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Clockwise"
-			1, // "Counter-clockwise"
-		]),
+        valid_values: Some(vec![
+            0, // "Clockwise"
+            1, // "Counter-clockwise"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Rotation Direction Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Value {
+    /// Clockwise.
+    Clockwise = 0,
+    /// Counter-clockwise.
+    CounterClockwise = 1,
+}
+
+impl From<Value> for i32 {
+    fn from(value: Value) -> i32 { value as i32 }
+}
+
+impl TryFrom<i32> for Value {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Clockwise),
+            1 => Ok(Value::CounterClockwise),
+            _ => Err(Error::from_str("invalid value of the Rotation Direction Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<i32> for Value {
+    fn hap_type() -> HapType { HapType::RotationDirection }
+}
@@ -11,12 +11,12 @@ pub fn new() -> SecuritySystemAlarmType {
         hap_type: HapType::SecuritySystemAlarmType,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(1),
-		min_value: Some(0),
-		step_value: Some(1),
+        max_value: Some(1),
+        min_value: Some(0),
+        step_value: Some(1),
         ..Default::default()
     })
 }
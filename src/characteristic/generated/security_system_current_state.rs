@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Security System Current State Characteristic.
 pub type SecuritySystemCurrentState = Characteristic<u8>;
@@ -11,16 +14,55 @@ pub fn new() -> SecuritySystemCurrentState {
         hap_type: HapType::SecuritySystemCurrentState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Stay Arm"
-			1, // "Away Arm"
-			2, // "Night Arm"
-			3, // "Disarmed"
-			4, // "Alarm Triggered"
-		]),
+        valid_values: Some(vec![
+            0, // "Stay Arm"
+            1, // "Away Arm"
+            2, // "Night Arm"
+            3, // "Disarmed"
+            4, // "Alarm Triggered"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Security System Current State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Stay Arm.
+    StayArm = 0,
+    /// Away Arm.
+    AwayArm = 1,
+    /// Night Arm.
+    NightArm = 2,
+    /// Disarmed.
+    Disarmed = 3,
+    /// Alarm Triggered.
+    AlarmTriggered = 4,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::StayArm),
+            1 => Ok(Value::AwayArm),
+            2 => Ok(Value::NightArm),
+            3 => Ok(Value::Disarmed),
+            4 => Ok(Value::AlarmTriggered),
+            _ => Err(Error::from_str("invalid value of the Security System Current State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SecuritySystemCurrentState }
+}
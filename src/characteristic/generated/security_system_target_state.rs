@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Security System Target State Characteristic.
 pub type SecuritySystemTargetState = Characteristic<u8>;
@@ -11,16 +14,52 @@ pub fn new() -> SecuritySystemTargetState {
         hap_type: HapType::SecuritySystemTargetState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Stay Arm"
-			1, // "Away Arm"
-			2, // "Night Arm"
-			3, // "Disarm"
-		]),
+        valid_values: Some(vec![
+            0, // "Stay Arm"
+            1, // "Away Arm"
+            2, // "Night Arm"
+            3, // "Disarm"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Security System Target State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Stay Arm.
+    StayArm = 0,
+    /// Away Arm.
+    AwayArm = 1,
+    /// Night Arm.
+    NightArm = 2,
+    /// Disarm.
+    Disarm = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::StayArm),
+            1 => Ok(Value::AwayArm),
+            2 => Ok(Value::NightArm),
+            3 => Ok(Value::Disarm),
+            _ => Err(Error::from_str("invalid value of the Security System Target State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SecuritySystemTargetState }
+}
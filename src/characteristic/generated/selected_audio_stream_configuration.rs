@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Selected Audio Stream Configuration Characteristic.
+pub type SelectedAudioStreamConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Selected Audio Stream Configuration Characteristic.
+pub fn new() -> SelectedAudioStreamConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SelectedAudioStreamConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,20 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Selected Camera Recording Configuration Characteristic.
+pub type SelectedCameraRecordingConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Selected Camera Recording Configuration Characteristic.
+pub fn new() -> SelectedCameraRecordingConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SelectedCameraRecordingConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
@@ -11,8 +11,8 @@ pub fn new() -> SelectedRTPStreamConfiguration {
         hap_type: HapType::SelectedRTPStreamConfiguration,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
+            Perm::PairedRead,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
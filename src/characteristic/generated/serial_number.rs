@@ -11,9 +11,9 @@ pub fn new() -> SerialNumber {
         hap_type: HapType::SerialNumber,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
-		max_len: Some(64),
+        max_len: Some(64),
         ..Default::default()
     })
 }
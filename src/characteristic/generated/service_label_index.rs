@@ -11,11 +11,11 @@ pub fn new() -> ServiceLabelIndex {
         hap_type: HapType::ServiceLabelIndex,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
-		max_value: Some(255),
-		min_value: Some(1),
-		step_value: Some(1),
+        max_value: Some(255),
+        min_value: Some(1),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Service Label Namespace Characteristic.
 pub type ServiceLabelNamespace = Characteristic<u8>;
@@ -11,12 +14,42 @@ pub fn new() -> ServiceLabelNamespace {
         hap_type: HapType::ServiceLabelNamespace,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
-		valid_values: Some(vec![
-			0, // "Dots"
-			1, // "Arabic Numerals"
-		]),
+        valid_values: Some(vec![
+            0, // "Dots"
+            1, // "Arabic Numerals"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Service Label Namespace Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Dots.
+    Dots = 0,
+    /// Arabic Numerals.
+    ArabicNumerals = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Dots),
+            1 => Ok(Value::ArabicNumerals),
+            _ => Err(Error::from_str("invalid value of the Service Label Namespace Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ServiceLabelNamespace }
+}
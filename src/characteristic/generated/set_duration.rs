@@ -11,13 +11,13 @@ pub fn new() -> SetDuration {
         hap_type: HapType::SetDuration,
         format: Format::UInt32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		max_value: Some(3600),
-		min_value: Some(0),
-		step_value: Some(1),
+        max_value: Some(3600),
+        min_value: Some(0),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -0,0 +1,20 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Setup Data Stream Transport Characteristic.
+pub type SetupDataStreamTransport = Characteristic<Vec<u8>>;
+
+/// Creates a new Setup Data Stream Transport Characteristic.
+pub fn new() -> SetupDataStreamTransport {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SetupDataStreamTransport,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::WriteResponse,
+        ],
+        ..Default::default()
+    })
+}
@@ -11,8 +11,8 @@ pub fn new() -> SetupEndpoints {
         hap_type: HapType::SetupEndpoints,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
+            Perm::PairedRead,
+            Perm::PairedWrite,
         ],
         ..Default::default()
     })
@@ -0,0 +1,53 @@
+// THIS FILE IS AUTO-GENERATED
+
+use std::convert::TryFrom;
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
+
+/// Siri Input Type Characteristic.
+pub type SiriInputType = Characteristic<u8>;
+
+/// Creates a new Siri Input Type Characteristic.
+pub fn new() -> SiriInputType {
+    Characteristic::new(Inner::<u8> {
+        hap_type: HapType::SiriInputType,
+        format: Format::UInt8,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        max_value: Some(0),
+        min_value: Some(0),
+        valid_values: Some(vec![
+            0, // "Push Button Triggered Apple TV"
+        ]),
+        ..Default::default()
+    })
+}
+
+/// Valid values of the Siri Input Type Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Push Button Triggered Apple TV.
+    PushButtonTriggeredAppleTV = 0,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::PushButtonTriggeredAppleTV),
+            _ => Err(Error::from_str("invalid value of the Siri Input Type Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SiriInputType }
+}
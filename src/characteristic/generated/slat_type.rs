@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Slat Type Characteristic.
 pub type SlatType = Characteristic<u8>;
@@ -11,12 +14,42 @@ pub fn new() -> SlatType {
         hap_type: HapType::SlatType,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
-		valid_values: Some(vec![
-			0, // "Horizontal"
-			1, // "Vertical"
-		]),
+        valid_values: Some(vec![
+            0, // "Horizontal"
+            1, // "Vertical"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Slat Type Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Horizontal.
+    Horizontal = 0,
+    /// Vertical.
+    Vertical = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Horizontal),
+            1 => Ok(Value::Vertical),
+            _ => Err(Error::from_str("invalid value of the Slat Type Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SlatType }
+}
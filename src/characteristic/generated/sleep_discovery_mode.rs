@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Sleep Discovery Mode Characteristic.
 pub type SleepDiscoveryMode = Characteristic<u8>;
@@ -11,15 +14,45 @@ pub fn new() -> SleepDiscoveryMode {
         hap_type: HapType::SleepDiscoveryMode,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(1),
-		min_value: Some(0),
-		valid_values: Some(vec![
-			0, // "NotDiscoverable"
-			1, // "AlwaysDiscoverable"
-		]),
+        max_value: Some(1),
+        min_value: Some(0),
+        valid_values: Some(vec![
+            0, // "NotDiscoverable"
+            1, // "AlwaysDiscoverable"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Sleep Discovery Mode Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// NotDiscoverable.
+    NotDiscoverable = 0,
+    /// AlwaysDiscoverable.
+    AlwaysDiscoverable = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NotDiscoverable),
+            1 => Ok(Value::AlwaysDiscoverable),
+            _ => Err(Error::from_str("invalid value of the Sleep Discovery Mode Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SleepDiscoveryMode }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Smoke Detected Characteristic.
 pub type SmokeDetected = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> SmokeDetected {
         hap_type: HapType::SmokeDetected,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Smoke Not Detected"
-			1, // "Smoke Detected"
-		]),
+        valid_values: Some(vec![
+            0, // "Smoke Not Detected"
+            1, // "Smoke Detected"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Smoke Detected Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Smoke Not Detected.
+    SmokeNotDetected = 0,
+    /// Smoke Detected.
+    SmokeDetected = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::SmokeNotDetected),
+            1 => Ok(Value::SmokeDetected),
+            _ => Err(Error::from_str("invalid value of the Smoke Detected Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SmokeDetected }
+}
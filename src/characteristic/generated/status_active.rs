@@ -11,8 +11,8 @@ pub fn new() -> StatusActive {
         hap_type: HapType::StatusActive,
         format: Format::Bool,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
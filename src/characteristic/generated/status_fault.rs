@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Status Fault Characteristic.
 pub type StatusFault = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> StatusFault {
         hap_type: HapType::StatusFault,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "No Fault"
-			1, // "General Fault"
-		]),
+        valid_values: Some(vec![
+            0, // "No Fault"
+            1, // "General Fault"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Status Fault Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// No Fault.
+    NoFault = 0,
+    /// General Fault.
+    GeneralFault = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NoFault),
+            1 => Ok(Value::GeneralFault),
+            _ => Err(Error::from_str("invalid value of the Status Fault Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::StatusFault }
+}
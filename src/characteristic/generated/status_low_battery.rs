@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Status Low Battery Characteristic.
 pub type StatusLowBattery = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> StatusLowBattery {
         hap_type: HapType::StatusLowBattery,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Battery Level Normal"
-			1, // "Battery Level Low"
-		]),
+        valid_values: Some(vec![
+            0, // "Battery Level Normal"
+            1, // "Battery Level Low"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Status Low Battery Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Battery Level Normal.
+    BatteryLevelNormal = 0,
+    /// Battery Level Low.
+    BatteryLevelLow = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::BatteryLevelNormal),
+            1 => Ok(Value::BatteryLevelLow),
+            _ => Err(Error::from_str("invalid value of the Status Low Battery Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::StatusLowBattery }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Status Tampered Characteristic.
 pub type StatusTampered = Characteristic<u8>;
@@ -11,13 +14,43 @@ pub fn new() -> StatusTampered {
         hap_type: HapType::StatusTampered,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Not Tampered"
-			1, // "Tampered"
-		]),
+        valid_values: Some(vec![
+            0, // "Not Tampered"
+            1, // "Tampered"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Status Tampered Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Not Tampered.
+    NotTampered = 0,
+    /// Tampered.
+    Tampered = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::NotTampered),
+            1 => Ok(Value::Tampered),
+            _ => Err(Error::from_str("invalid value of the Status Tampered Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::StatusTampered }
+}
@@ -11,8 +11,8 @@ pub fn new() -> StreamingStatus {
         hap_type: HapType::StreamingStatus,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
         ..Default::default()
     })
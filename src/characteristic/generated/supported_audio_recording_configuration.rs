@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Supported Audio Recording Configuration Characteristic.
+pub type SupportedAudioRecordingConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Supported Audio Recording Configuration Characteristic.
+pub fn new() -> SupportedAudioRecordingConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SupportedAudioRecordingConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
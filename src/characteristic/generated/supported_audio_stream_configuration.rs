@@ -11,7 +11,7 @@ pub fn new() -> SupportedAudioStreamConfiguration {
         hap_type: HapType::SupportedAudioStreamConfiguration,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Supported Camera Recording Configuration Characteristic.
+pub type SupportedCameraRecordingConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Supported Camera Recording Configuration Characteristic.
+pub fn new() -> SupportedCameraRecordingConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SupportedCameraRecordingConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Supported Characteristic Value Transition Configuration Characteristic.
+pub type SupportedCharacteristicValueTransitionConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Supported Characteristic Value Transition Configuration Characteristic.
+pub fn new() -> SupportedCharacteristicValueTransitionConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SupportedCharacteristicValueTransitionConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Supported Data Stream Transport Configuration Characteristic.
+pub type SupportedDataStreamTransportConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Supported Data Stream Transport Configuration Characteristic.
+pub fn new() -> SupportedDataStreamTransportConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SupportedDataStreamTransportConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
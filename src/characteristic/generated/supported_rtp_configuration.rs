@@ -11,7 +11,7 @@ pub fn new() -> SupportedRTPConfiguration {
         hap_type: HapType::SupportedRTPConfiguration,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
@@ -0,0 +1,19 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Supported Video Recording Configuration Characteristic.
+pub type SupportedVideoRecordingConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Supported Video Recording Configuration Characteristic.
+pub fn new() -> SupportedVideoRecordingConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::SupportedVideoRecordingConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        ..Default::default()
+    })
+}
@@ -11,7 +11,7 @@ pub fn new() -> SupportedVideoStreamConfiguration {
         hap_type: HapType::SupportedVideoStreamConfiguration,
         format: Format::Tlv8,
         perms: vec![
-			Perm::PairedRead,
+            Perm::PairedRead,
         ],
         ..Default::default()
     })
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Swing Mode Characteristic.
 pub type SwingMode = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> SwingMode {
         hap_type: HapType::SwingMode,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Swing Disabled"
-			1, // "Swing Enabled"
-		]),
+        valid_values: Some(vec![
+            0, // "Swing Disabled"
+            1, // "Swing Enabled"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Swing Mode Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Swing Disabled.
+    SwingDisabled = 0,
+    /// Swing Enabled.
+    SwingEnabled = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::SwingDisabled),
+            1 => Ok(Value::SwingEnabled),
+            _ => Err(Error::from_str("invalid value of the Swing Mode Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::SwingMode }
+}
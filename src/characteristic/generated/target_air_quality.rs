@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Air Quality Characteristic.
 pub type TargetAirQuality = Characteristic<u8>;
@@ -11,15 +14,48 @@ pub fn new() -> TargetAirQuality {
         hap_type: HapType::TargetAirQuality,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Excellent"
-			1, // "Good"
-			2, // "Fair"
-		]),
+        valid_values: Some(vec![
+            0, // "Excellent"
+            1, // "Good"
+            2, // "Fair"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Air Quality Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Excellent.
+    Excellent = 0,
+    /// Good.
+    Good = 1,
+    /// Fair.
+    Fair = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Excellent),
+            1 => Ok(Value::Good),
+            2 => Ok(Value::Fair),
+            _ => Err(Error::from_str("invalid value of the Target Air Quality Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetAirQuality }
+}
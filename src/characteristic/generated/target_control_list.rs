@@ -0,0 +1,20 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Target Control List Characteristic.
+pub type TargetControlList = Characteristic<Vec<u8>>;
+
+/// Creates a new Target Control List Characteristic.
+pub fn new() -> TargetControlList {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::TargetControlList,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::WriteResponse,
+        ],
+        ..Default::default()
+    })
+}
@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Target Control Supported Configuration Characteristic.
+pub type TargetControlSupportedConfiguration = Characteristic<Vec<u8>>;
+
+/// Creates a new Target Control Supported Configuration Characteristic.
+pub fn new() -> TargetControlSupportedConfiguration {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::TargetControlSupportedConfiguration,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
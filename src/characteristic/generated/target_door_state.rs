@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Door State Characteristic.
 pub type TargetDoorState = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> TargetDoorState {
         hap_type: HapType::TargetDoorState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Open"
-			1, // "Closed"
-		]),
+        valid_values: Some(vec![
+            0, // "Open"
+            1, // "Closed"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Door State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Open.
+    Open = 0,
+    /// Closed.
+    Closed = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Open),
+            1 => Ok(Value::Closed),
+            _ => Err(Error::from_str("invalid value of the Target Door State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetDoorState }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Fan State Characteristic.
 pub type TargetFanState = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> TargetFanState {
         hap_type: HapType::TargetFanState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Manual"
-			1, // "Auto"
-		]),
+        valid_values: Some(vec![
+            0, // "Manual"
+            1, // "Auto"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Fan State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Manual.
+    Manual = 0,
+    /// Auto.
+    Auto = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Manual),
+            1 => Ok(Value::Auto),
+            _ => Err(Error::from_str("invalid value of the Target Fan State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetFanState }
+}
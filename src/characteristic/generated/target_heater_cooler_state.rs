@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Heater Cooler State Characteristic.
 pub type TargetHeaterCoolerState = Characteristic<u8>;
@@ -11,15 +14,48 @@ pub fn new() -> TargetHeaterCoolerState {
         hap_type: HapType::TargetHeaterCoolerState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Auto"
-			1, // "Heat"
-			2, // "Cool"
-		]),
+        valid_values: Some(vec![
+            0, // "Auto"
+            1, // "Heat"
+            2, // "Cool"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Heater Cooler State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Auto.
+    Auto = 0,
+    /// Heat.
+    Heat = 1,
+    /// Cool.
+    Cool = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Auto),
+            1 => Ok(Value::Heat),
+            2 => Ok(Value::Cool),
+            _ => Err(Error::from_str("invalid value of the Target Heater Cooler State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetHeaterCoolerState }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Heating Cooling State Characteristic.
 pub type TargetHeatingCoolingState = Characteristic<u8>;
@@ -11,16 +14,52 @@ pub fn new() -> TargetHeatingCoolingState {
         hap_type: HapType::TargetHeatingCoolingState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Off"
-			1, // "Heat"
-			2, // "Cool"
-			3, // "Auto"
-		]),
+        valid_values: Some(vec![
+            0, // "Off"
+            1, // "Heat"
+            2, // "Cool"
+            3, // "Auto"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Heating Cooling State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Off.
+    Off = 0,
+    /// Heat.
+    Heat = 1,
+    /// Cool.
+    Cool = 2,
+    /// Auto.
+    Auto = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Off),
+            1 => Ok(Value::Heat),
+            2 => Ok(Value::Cool),
+            3 => Ok(Value::Auto),
+            _ => Err(Error::from_str("invalid value of the Target Heating Cooling State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetHeatingCoolingState }
+}
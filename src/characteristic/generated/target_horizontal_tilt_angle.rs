@@ -11,14 +11,14 @@ pub fn new() -> TargetHorizontalTiltAngle {
         hap_type: HapType::TargetHorizontalTiltAngle,
         format: Format::Int32,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::ArcDegrees),
-		max_value: Some(90),
-		min_value: Some(-90),
-		step_value: Some(1),
+        unit: Some(Unit::ArcDegrees),
+        max_value: Some(90),
+        min_value: Some(-90),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Humidifier Dehumidifier State Characteristic.
 pub type TargetHumidifierDehumidifierState = Characteristic<u8>;
@@ -11,15 +14,48 @@ pub fn new() -> TargetHumidifierDehumidifierState {
         hap_type: HapType::TargetHumidifierDehumidifierState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Humidifier or Dehumidifier"
-			1, // "Humidifier"
-			2, // "Dehumidifier"
-		]),
+        valid_values: Some(vec![
+            0, // "Humidifier or Dehumidifier"
+            1, // "Humidifier"
+            2, // "Dehumidifier"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Humidifier Dehumidifier State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Humidifier or Dehumidifier.
+    HumidifierOrDehumidifier = 0,
+    /// Humidifier.
+    Humidifier = 1,
+    /// Dehumidifier.
+    Dehumidifier = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::HumidifierOrDehumidifier),
+            1 => Ok(Value::Humidifier),
+            2 => Ok(Value::Dehumidifier),
+            _ => Err(Error::from_str("invalid value of the Target Humidifier Dehumidifier State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetHumidifierDehumidifierState }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Media State Characteristic.
 pub type TargetMediaState = Characteristic<u8>;
@@ -11,18 +14,51 @@ pub fn new() -> TargetMediaState {
         hap_type: HapType::TargetMediaState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		max_value: Some(2),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Play"
-			1, // "Pause"
-			2, // "Stop"
-		]),
+        max_value: Some(2),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Play"
+            1, // "Pause"
+            2, // "Stop"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Media State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Play.
+    Play = 0,
+    /// Pause.
+    Pause = 1,
+    /// Stop.
+    Stop = 2,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Play),
+            1 => Ok(Value::Pause),
+            2 => Ok(Value::Stop),
+            _ => Err(Error::from_str("invalid value of the Target Media State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetMediaState }
+}
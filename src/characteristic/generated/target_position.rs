@@ -11,14 +11,14 @@ pub fn new() -> TargetPosition {
         hap_type: HapType::TargetPosition,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::Percentage),
-		max_value: Some(100),
-		min_value: Some(0),
-		step_value: Some(1),
+        unit: Some(Unit::Percentage),
+        max_value: Some(100),
+        min_value: Some(0),
+        step_value: Some(1),
         ..Default::default()
     })
 }
@@ -11,14 +11,14 @@ pub fn new() -> TargetTemperature {
         hap_type: HapType::TargetTemperature,
         format: Format::Float,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		unit: Some(Unit::Celsius),
-		max_value: Some(38 as f32),
-		min_value: Some(10 as f32),
-		step_value: Some(0.1 as f32),
+        unit: Some(Unit::Celsius),
+        max_value: Some(38_f32),
+        min_value: Some(10_f32),
+        step_value: Some(0.1_f32),
         ..Default::default()
     })
 }
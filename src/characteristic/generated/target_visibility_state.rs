@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Target Visibility State Characteristic.
 pub type TargetVisibilityState = Characteristic<u8>;
@@ -11,17 +14,47 @@ pub fn new() -> TargetVisibilityState {
         hap_type: HapType::TargetVisibilityState,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		max_value: Some(2),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Shown"
-			1, // "Hidden"
-		]),
+        max_value: Some(2),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Shown"
+            1, // "Hidden"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Target Visibility State Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Shown.
+    Shown = 0,
+    /// Hidden.
+    Hidden = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Shown),
+            1 => Ok(Value::Hidden),
+            _ => Err(Error::from_str("invalid value of the Target Visibility State Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TargetVisibilityState }
+}
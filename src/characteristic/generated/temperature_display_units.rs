@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Temperature Display Units Characteristic.
 pub type TemperatureDisplayUnits = Characteristic<u8>;
@@ -11,14 +14,44 @@ pub fn new() -> TemperatureDisplayUnits {
         hap_type: HapType::TemperatureDisplayUnits,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::PairedWrite,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Celsius"
-			1, // "Fahrenheit"
-		]),
+        valid_values: Some(vec![
+            0, // "Celsius"
+            1, // "Fahrenheit"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Temperature Display Units Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Celsius.
+    Celsius = 0,
+    /// Fahrenheit.
+    Fahrenheit = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Celsius),
+            1 => Ok(Value::Fahrenheit),
+            _ => Err(Error::from_str("invalid value of the Temperature Display Units Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::TemperatureDisplayUnits }
+}
@@ -0,0 +1,56 @@
+// THIS FILE IS AUTO-GENERATED
+
+use std::convert::TryFrom;
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
+
+/// Third Party Camera Active Characteristic.
+pub type ThirdPartyCameraActive = Characteristic<u8>;
+
+/// Creates a new Third Party Camera Active Characteristic.
+pub fn new() -> ThirdPartyCameraActive {
+    Characteristic::new(Inner::<u8> {
+        hap_type: HapType::ThirdPartyCameraActive,
+        format: Format::UInt8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::Events,
+        ],
+        valid_values: Some(vec![
+            0, // "Off"
+            1, // "On"
+        ]),
+        ..Default::default()
+    })
+}
+
+/// Valid values of the Third Party Camera Active Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Off.
+    Off = 0,
+    /// On.
+    On = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Off),
+            1 => Ok(Value::On),
+            _ => Err(Error::from_str("invalid value of the Third Party Camera Active Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ThirdPartyCameraActive }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Valve Type Characteristic.
 pub type ValveType = Characteristic<u8>;
@@ -11,15 +14,51 @@ pub fn new() -> ValveType {
         hap_type: HapType::ValveType,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		valid_values: Some(vec![
-			0, // "Generic valve"
-			1, // "Irrigation"
-			2, // "Shower head"
-			3, // "Water faucet"
-		]),
+        valid_values: Some(vec![
+            0, // "Generic valve"
+            1, // "Irrigation"
+            2, // "Shower head"
+            3, // "Water faucet"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Valve Type Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Generic valve.
+    GenericValve = 0,
+    /// Irrigation.
+    Irrigation = 1,
+    /// Shower head.
+    ShowerHead = 2,
+    /// Water faucet.
+    WaterFaucet = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::GenericValve),
+            1 => Ok(Value::Irrigation),
+            2 => Ok(Value::ShowerHead),
+            3 => Ok(Value::WaterFaucet),
+            _ => Err(Error::from_str("invalid value of the Valve Type Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::ValveType }
+}
@@ -11,10 +11,10 @@ pub fn new() -> Version {
         hap_type: HapType::Version,
         format: Format::String,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_len: Some(64),
+        max_len: Some(64),
         ..Default::default()
     })
 }
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Volume Control Type Characteristic.
 pub type VolumeControlType = Characteristic<u8>;
@@ -11,18 +14,54 @@ pub fn new() -> VolumeControlType {
         hap_type: HapType::VolumeControlType,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedRead,
-			Perm::Events,
+            Perm::PairedRead,
+            Perm::Events,
         ],
-		max_value: Some(3),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "None"
-			1, // "Relative"
-			2, // "RelativeWithCurrent"
-			3, // "Absolute"
-		]),
+        max_value: Some(3),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "None"
+            1, // "Relative"
+            2, // "RelativeWithCurrent"
+            3, // "Absolute"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Volume Control Type Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// None.
+    None = 0,
+    /// Relative.
+    Relative = 1,
+    /// RelativeWithCurrent.
+    RelativeWithCurrent = 2,
+    /// Absolute.
+    Absolute = 3,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::None),
+            1 => Ok(Value::Relative),
+            2 => Ok(Value::RelativeWithCurrent),
+            3 => Ok(Value::Absolute),
+            _ => Err(Error::from_str("invalid value of the Volume Control Type Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::VolumeControlType }
+}
@@ -1,6 +1,9 @@
 // THIS FILE IS AUTO-GENERATED
 
+use std::convert::TryFrom;
+
 use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+use crate::{characteristic::ValidValue, Error, Result};
 
 /// Volume Selector Characteristic.
 pub type VolumeSelector = Characteristic<u8>;
@@ -11,15 +14,45 @@ pub fn new() -> VolumeSelector {
         hap_type: HapType::VolumeSelector,
         format: Format::UInt8,
         perms: vec![
-			Perm::PairedWrite,
+            Perm::PairedWrite,
         ],
-		max_value: Some(1),
-		min_value: Some(0),
-		step_value: Some(1),
-		valid_values: Some(vec![
-			0, // "Increment"
-			1, // "Decrement"
-		]),
+        max_value: Some(1),
+        min_value: Some(0),
+        step_value: Some(1),
+        valid_values: Some(vec![
+            0, // "Increment"
+            1, // "Decrement"
+        ]),
         ..Default::default()
     })
 }
+
+/// Valid values of the Volume Selector Characteristic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Value {
+    /// Increment.
+    Increment = 0,
+    /// Decrement.
+    Decrement = 1,
+}
+
+impl From<Value> for u8 {
+    fn from(value: Value) -> u8 { value as u8 }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Value> {
+        match value {
+            0 => Ok(Value::Increment),
+            1 => Ok(Value::Decrement),
+            _ => Err(Error::from_str("invalid value of the Volume Selector Characteristic")),
+        }
+    }
+}
+
+impl ValidValue<u8> for Value {
+    fn hap_type() -> HapType { HapType::VolumeSelector }
+}
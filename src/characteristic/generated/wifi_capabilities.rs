@@ -0,0 +1,18 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Wi-Fi Capabilities Characteristic.
+pub type WiFiCapabilities = Characteristic<u32>;
+
+/// Creates a new Wi-Fi Capabilities Characteristic.
+pub fn new() -> WiFiCapabilities {
+    Characteristic::new(Inner::<u32> {
+        hap_type: HapType::WiFiCapabilities,
+        format: Format::UInt32,
+        perms: vec![
+            Perm::PairedRead,
+        ],
+        ..Default::default()
+    })
+}
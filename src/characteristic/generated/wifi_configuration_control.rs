@@ -0,0 +1,22 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::characteristic::{HapType, Characteristic, Inner, Format, Perm};
+
+/// Wi-Fi Configuration Control Characteristic.
+pub type WiFiConfigurationControl = Characteristic<Vec<u8>>;
+
+/// Creates a new Wi-Fi Configuration Control Characteristic.
+pub fn new() -> WiFiConfigurationControl {
+    Characteristic::new(Inner::<Vec<u8>> {
+        hap_type: HapType::WiFiConfigurationControl,
+        format: Format::Tlv8,
+        perms: vec![
+            Perm::PairedRead,
+            Perm::PairedWrite,
+            Perm::Events,
+            Perm::TimedWrite,
+            Perm::WriteResponse,
+        ],
+        ..Default::default()
+    })
+}
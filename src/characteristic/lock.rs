@@ -0,0 +1,19 @@
+//! Typed TLV8 values of the Lock Management Characteristics.
+//!
+//! A controller writes a `LockControlPointRequest` to the Lock Control Point Characteristic of a
+//! Lock Management Service to read or clear the logs of the lock or to set its current time.
+
+use crate::tlv8_struct;
+
+tlv8_struct! {
+    /// Value written to the Lock Control Point Characteristic. Usually only one of the operations
+    /// is set. Operations without a value are sent as zero-length items.
+    pub struct LockControlPointRequest {
+        /// Requests the log entries since the given time.
+        0x00 => pub read_logs_from_time: Option<Vec<u8>>,
+        /// Requests the logs to be cleared.
+        0x02 => pub clear_logs: Option<Vec<u8>>,
+        /// Sets the current time of the lock.
+        0x03 => pub set_current_time: Option<Vec<u8>>,
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::{Arc, Mutex};
 
-use erased_serde::{self, __internal_serialize_trait_object, serialize_trait_object};
+use base64;
+use erased_serde::{self, serialize_trait_object};
 use serde::{
     ser::{Serialize, SerializeStruct, Serializer},
     Deserialize,
@@ -15,9 +16,20 @@ use crate::{
     Result,
 };
 
+pub mod custom;
+pub mod lock;
+pub mod stream;
+pub mod tlv8;
+pub mod transition;
+pub mod valid_value;
+
 mod generated;
 
-pub use crate::characteristic::generated::*;
+pub use crate::characteristic::{
+    generated::*,
+    tlv8::{Tlv8, Tlv8Field},
+    valid_value::ValidValue,
+};
 
 /// Inner type of a `Characteristic`.
 #[derive(Default)]
@@ -128,8 +140,9 @@ where
                 val = readable.on_read(hap_type);
             }
         }
+        // values returned by the `Readable` aren't remote updates and aren't passed to the `Updatable`
         if let Some(v) = val {
-            self.set_value(v)?;
+            self.store_value(v);
         }
 
         Ok(self.inner.lock().expect("couldn't access characteristic").value.clone())
@@ -154,10 +167,17 @@ where
             let old_val = inner.value.clone();
             let hap_type = inner.hap_type;
             if let Some(ref mut updatable) = inner.updatable {
-                updatable.on_update(&old_val, &val, hap_type);
+                updatable.try_on_update(&old_val, &val, hap_type)?;
             }
         }
 
+        self.store_value(val);
+
+        Ok(())
+    }
+
+    /// Stores a new value and emits an event if event notifications are enabled.
+    fn store_value(&mut self, val: T) {
         {
             let inner = self.inner.lock().expect("couldn't access characteristic");
             if inner.event_notifications == Some(true) {
@@ -166,7 +186,7 @@ where
                         &Event::CharacteristicValueChanged {
                             aid: inner.accessory_id,
                             iid: inner.id,
-                            value: json!(&val),
+                            value: value_to_json(inner.format, &val),
                         },
                     );
                 }
@@ -174,8 +194,6 @@ where
         }
 
         self.inner.lock().expect("couldn't access characteristic").value = val;
-
-        Ok(())
     }
 
     /// Returns the `Unit` of a Characteristic.
@@ -183,6 +201,12 @@ where
         Ok(self.inner.lock().expect("couldn't access characteristic").unit)
     }
 
+    /// Sets the `Unit` of a Characteristic.
+    pub fn set_unit(&mut self, unit: Option<Unit>) -> Result<()> {
+        self.inner.lock().expect("couldn't access characteristic").unit = unit;
+        Ok(())
+    }
+
     /// Returns the maximum value of a Characteristic.
     pub fn get_max_value(&self) -> Result<Option<T>> {
         Ok(self
@@ -236,6 +260,30 @@ where
         Ok(self.inner.lock().expect("couldn't access characteristic").max_len)
     }
 
+    /// Sets the maximum length of a Characteristic.
+    pub fn set_max_len(&mut self, max_len: Option<u16>) -> Result<()> {
+        self.inner.lock().expect("couldn't access characteristic").max_len = max_len;
+        Ok(())
+    }
+
+    /// Sets the maximum data length of a Characteristic.
+    pub fn set_max_data_len(&mut self, max_data_len: Option<u32>) -> Result<()> {
+        self.inner.lock().expect("couldn't access characteristic").max_data_len = max_data_len;
+        Ok(())
+    }
+
+    /// Sets the valid values of a Characteristic.
+    pub fn set_valid_values(&mut self, valid_values: Option<Vec<T>>) -> Result<()> {
+        self.inner.lock().expect("couldn't access characteristic").valid_values = valid_values;
+        Ok(())
+    }
+
+    /// Sets the valid values range of a Characteristic.
+    pub fn set_valid_values_range(&mut self, valid_values_range: Option<[T; 2]>) -> Result<()> {
+        self.inner.lock().expect("couldn't access characteristic").valid_values_range = valid_values_range;
+        Ok(())
+    }
+
     /// Sets a `Readable` on the Characteristic.
     pub fn set_readable(&mut self, readable: impl Readable<T> + 'static + Send) -> Result<()> {
         self.inner.lock().expect("couldn't access characteristic").readable = Some(Box::new(readable));
@@ -271,7 +319,7 @@ impl<T: Default + Clone + Serialize> Serialize for Characteristic<T> {
         }
 
         if inner.perms.contains(&Perm::PairedRead) {
-            state.serialize_field("value", &inner.value)?;
+            state.serialize_field("value", &value_to_json(inner.format, &inner.value))?;
         }
         if let Some(ref unit) = inner.unit {
             state.serialize_field("unit", unit)?;
@@ -361,13 +409,17 @@ where
         self.set_event_notifications(event_notifications)
     }
 
-    fn get_value(&mut self) -> Result<serde_json::Value> { Ok(json!(self.get_value()?)) }
+    fn get_value(&mut self) -> Result<serde_json::Value> {
+        let value = self.get_value()?;
+        Ok(value_to_json(self.get_format()?, &value))
+    }
 
     fn set_value(&mut self, value: serde_json::Value) -> Result<()> {
         let v;
+        let format = self.inner.lock().expect("couldn't access characteristic").format;
         // the controller is setting boolean values
         // either as a boolean or as an integer
-        if self.inner.lock().expect("couldn't access characteristic").format == Format::Bool && value.is_number() {
+        if format == Format::Bool && value.is_number() {
             let num_v: u8 = serde_json::from_value(value)?;
             if num_v == 0 {
                 v = serde_json::from_value(json!(false))?;
@@ -376,6 +428,11 @@ where
             } else {
                 return Err(Error::from_str("invalid value for bool characteristic"));
             }
+        } else if (format == Format::Tlv8 || format == Format::Data) && value.is_string() {
+            // tlv8 and data values are transferred as base64 strings
+            let bytes = base64::decode(value.as_str().unwrap_or(""))
+                .map_err(|_| Error::from_str("invalid base64 value"))?;
+            v = serde_json::from_value(json!(bytes))?;
         } else {
             v = serde_json::from_value(value)?;
         }
@@ -385,24 +442,15 @@ where
     fn get_unit(&self) -> Result<Option<Unit>> { self.get_unit() }
 
     fn get_max_value(&self) -> Result<Option<serde_json::Value>> {
-        Ok(match self.get_max_value()? {
-            Some(v) => Some(json!(v)),
-            None => None,
-        })
+        Ok(self.get_max_value()?.map(|v| json!(v)))
     }
 
     fn get_min_value(&self) -> Result<Option<serde_json::Value>> {
-        Ok(match self.get_min_value()? {
-            Some(v) => Some(json!(v)),
-            None => None,
-        })
+        Ok(self.get_min_value()?.map(|v| json!(v)))
     }
 
     fn get_step_value(&self) -> Result<Option<serde_json::Value>> {
-        Ok(match self.get_step_value()? {
-            Some(v) => Some(json!(v)),
-            None => None,
-        })
+        Ok(self.get_step_value()?.map(|v| json!(v)))
     }
 
     fn get_max_len(&self) -> Result<Option<u16>> { self.get_max_len() }
@@ -412,11 +460,25 @@ where
     }
 }
 
+/// Converts a Characteristic value to JSON. Values of `Format::Tlv8` and `Format::Data` are
+/// encoded as base64 strings.
+fn value_to_json<T: Serialize>(format: Format, value: &T) -> serde_json::Value {
+    let value = json!(value);
+    if format != Format::Tlv8 && format != Format::Data {
+        return value;
+    }
+    match serde_json::from_value::<Vec<u8>>(value.clone()) {
+        Ok(bytes) => json!(base64::encode(&bytes)),
+        Err(_) => value,
+    }
+}
+
 /// `Readable` can be implemented to react to the remote read of a `Characteristic`.
 pub trait Readable<T: Default + Serialize> {
     /// This function is called every time a Controller attempts to read the value of a
     /// `Characteristic`. Returning a `Some(T)` from this function changes the value of the
-    /// `Characteristic` before the Controller reads it so the Controller reads the new value.
+    /// `Characteristic` before the Controller reads it so the Controller reads the new value. The
+    /// new value isn't passed to the `Updatable` of the `Characteristic`.
     fn on_read(&mut self, hap_type: HapType) -> Option<T>;
 }
 
@@ -426,7 +488,15 @@ pub trait Updatable<T: Default + Serialize> {
     /// `Characteristic`. `old_val` is a reference to the current value of the `Characteristic` and
     /// `new_val` is a reference to the value the Controller attempts to change the
     /// `Characteristic`'s to.
-    fn on_update(&mut self, old_val: &T, new_val: &T, hap_type: HapType);
+    fn on_update(&mut self, _old_val: &T, _new_val: &T, _hap_type: HapType) {}
+
+    /// Like `on_update`, but can reject the new value. Returning an error leaves the value of the
+    /// `Characteristic` unchanged and answers the Controller's write with
+    /// `Status::InvalidValueInRequest`. Calls `on_update` by default.
+    fn try_on_update(&mut self, old_val: &T, new_val: &T, hap_type: HapType) -> Result<()> {
+        self.on_update(old_val, new_val, hap_type);
+        Ok(())
+    }
 }
 
 /// Permission of a `Characteristic`.
@@ -444,6 +514,8 @@ pub enum Perm {
     TimedWrite,
     #[serde(rename = "hd")]
     Hidden,
+    #[serde(rename = "wr")]
+    WriteResponse,
 }
 
 /// Unit of a `Characteristic`.
@@ -462,9 +534,10 @@ pub enum Unit {
 }
 
 /// HAP defined format of a `Characteristic`.
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub enum Format {
     #[serde(rename = "string")]
+    #[default]
     String,
     #[serde(rename = "bool")]
     Bool,
@@ -478,14 +551,10 @@ pub enum Format {
     UInt32,
     #[serde(rename = "uint64")]
     UInt64,
-    #[serde(rename = "int32")]
+    #[serde(rename = "int", alias = "int32")]
     Int32,
     #[serde(rename = "tlv8")]
     Tlv8,
     #[serde(rename = "data")]
     Data,
 }
-
-impl Default for Format {
-    fn default() -> Format { Format::String }
-}
@@ -0,0 +1,189 @@
+//! Typed TLV8 values of the Camera RTP Stream Management Characteristics.
+//!
+//! Use them with `Characteristic::get_tlv8_value` and `Characteristic::set_tlv8_value`, e.g. to
+//! set the `SupportedVideoStreamConfiguration` of a camera or to read the `SetupEndpoints`
+//! request of a controller.
+
+use crate::{
+    characteristic::tlv8::{Nested, Repeated},
+    tlv8_struct,
+};
+
+tlv8_struct! {
+    /// Parameters of a video codec.
+    pub struct VideoCodecParameters {
+        /// H.264 profiles. `0` = Constrained Baseline, `1` = Main, `2` = High.
+        0x01 => pub profile_ids: Repeated<u8>,
+        /// H.264 levels. `0` = 3.1, `1` = 3.2, `2` = 4.
+        0x02 => pub levels: Repeated<u8>,
+        /// Packetization modes. `0` = Non-interleaved.
+        0x03 => pub packetization_modes: Repeated<u8>,
+        /// CVO (Coordination of Video Orientation) enabled.
+        0x04 => pub cvo_enabled: Option<u8>,
+        /// CVO ID.
+        0x05 => pub cvo_id: Option<u8>,
+    }
+}
+
+tlv8_struct! {
+    /// Resolution and frame rate of a video stream.
+    pub struct VideoAttributes {
+        0x01 => pub image_width: u16,
+        0x02 => pub image_height: u16,
+        0x03 => pub frame_rate: u8,
+    }
+}
+
+tlv8_struct! {
+    /// A supported video codec along with its parameters and attributes.
+    pub struct VideoCodecConfiguration {
+        /// Codec type. `0` = H.264.
+        0x01 => pub codec_type: u8,
+        0x02 => pub codec_parameters: Nested<VideoCodecParameters>,
+        0x03 => pub attributes: Repeated<Nested<VideoAttributes>>,
+    }
+}
+
+tlv8_struct! {
+    /// Value of the Supported Video Stream Configuration Characteristic.
+    pub struct SupportedVideoStreamConfig {
+        0x01 => pub codec_configurations: Repeated<Nested<VideoCodecConfiguration>>,
+    }
+}
+
+tlv8_struct! {
+    /// Parameters of an audio codec.
+    pub struct AudioCodecParameters {
+        0x01 => pub audio_channels: u8,
+        /// Bit rate mode. `0` = Variable, `1` = Constant.
+        0x02 => pub bit_rate: u8,
+        /// Sample rate. `0` = 8 kHz, `1` = 16 kHz, `2` = 24 kHz.
+        0x03 => pub sample_rate: u8,
+        /// Packet time in milliseconds. Only present in selected configurations.
+        0x04 => pub rtp_time: Option<u8>,
+    }
+}
+
+tlv8_struct! {
+    /// A supported audio codec along with its parameters.
+    pub struct AudioCodecConfiguration {
+        /// Codec type. `2` = AAC-ELD, `3` = Opus.
+        0x01 => pub codec_type: u16,
+        0x02 => pub codec_parameters: Nested<AudioCodecParameters>,
+    }
+}
+
+tlv8_struct! {
+    /// Value of the Supported Audio Stream Configuration Characteristic.
+    pub struct SupportedAudioStreamConfig {
+        0x01 => pub codec_configurations: Repeated<Nested<AudioCodecConfiguration>>,
+        0x02 => pub comfort_noise_support: u8,
+    }
+}
+
+tlv8_struct! {
+    /// Value of the Supported RTP Configuration Characteristic.
+    pub struct SupportedRtpConfig {
+        /// SRTP crypto suites. `0` = AES_CM_128_HMAC_SHA1_80, `1` = AES_256_CM_HMAC_SHA1_80,
+        /// `2` = disabled.
+        0x02 => pub srtp_crypto_suites: Repeated<u8>,
+    }
+}
+
+tlv8_struct! {
+    /// Address and ports of a stream endpoint.
+    pub struct EndpointAddress {
+        /// IP address version. `0` = IPv4, `1` = IPv6.
+        0x01 => pub ip_version: u8,
+        0x02 => pub address: String,
+        0x03 => pub video_rtp_port: u16,
+        0x04 => pub audio_rtp_port: u16,
+    }
+}
+
+tlv8_struct! {
+    /// SRTP parameters of a stream.
+    pub struct SrtpParameters {
+        0x01 => pub crypto_suite: u8,
+        0x02 => pub master_key: Vec<u8>,
+        0x03 => pub master_salt: Vec<u8>,
+    }
+}
+
+tlv8_struct! {
+    /// Setup Endpoints request written by a controller.
+    pub struct SetupEndpointsRequest {
+        0x01 => pub session_id: Vec<u8>,
+        0x03 => pub controller_address: Nested<EndpointAddress>,
+        0x04 => pub video_srtp_parameters: Nested<SrtpParameters>,
+        0x05 => pub audio_srtp_parameters: Nested<SrtpParameters>,
+    }
+}
+
+tlv8_struct! {
+    /// Setup Endpoints response read by a controller.
+    pub struct SetupEndpointsResponse {
+        0x01 => pub session_id: Vec<u8>,
+        /// Status. `0` = Success, `1` = Busy, `2` = Error.
+        0x02 => pub status: u8,
+        0x03 => pub accessory_address: Option<Nested<EndpointAddress>>,
+        0x04 => pub video_srtp_parameters: Option<Nested<SrtpParameters>>,
+        0x05 => pub audio_srtp_parameters: Option<Nested<SrtpParameters>>,
+        0x06 => pub video_ssrc: Option<u32>,
+        0x07 => pub audio_ssrc: Option<u32>,
+    }
+}
+
+tlv8_struct! {
+    /// Session control of a Selected RTP Stream Configuration.
+    pub struct SessionControl {
+        0x01 => pub session_id: Vec<u8>,
+        /// Command. `0` = End, `1` = Start, `2` = Suspend, `3` = Resume, `4` = Reconfigure.
+        0x02 => pub command: u8,
+    }
+}
+
+tlv8_struct! {
+    /// RTP parameters of a selected stream.
+    pub struct RtpParameters {
+        0x01 => pub payload_type: u8,
+        0x02 => pub ssrc: u32,
+        /// Maximum bit rate in kbit/s.
+        0x03 => pub max_bit_rate: u16,
+        /// Minimum RTCP interval in seconds.
+        0x04 => pub min_rtcp_interval: f32,
+        /// Maximum MTU. Only present for video streams.
+        0x05 => pub max_mtu: Option<u16>,
+        /// Comfort noise payload type. Only present for audio streams.
+        0x06 => pub comfort_noise_payload_type: Option<u8>,
+    }
+}
+
+tlv8_struct! {
+    /// Selected video stream parameters.
+    pub struct SelectedVideoParameters {
+        0x01 => pub codec_type: u8,
+        0x02 => pub codec_parameters: Nested<VideoCodecParameters>,
+        0x03 => pub attributes: Nested<VideoAttributes>,
+        0x04 => pub rtp_parameters: Nested<RtpParameters>,
+    }
+}
+
+tlv8_struct! {
+    /// Selected audio stream parameters.
+    pub struct SelectedAudioParameters {
+        0x01 => pub codec_type: u16,
+        0x02 => pub codec_parameters: Nested<AudioCodecParameters>,
+        0x03 => pub rtp_parameters: Nested<RtpParameters>,
+        0x04 => pub comfort_noise: u8,
+    }
+}
+
+tlv8_struct! {
+    /// Value written to the Selected RTP Stream Configuration Characteristic.
+    pub struct SelectedRtpStreamConfig {
+        0x01 => pub session_control: Nested<SessionControl>,
+        0x02 => pub video_parameters: Option<Nested<SelectedVideoParameters>>,
+        0x03 => pub audio_parameters: Option<Nested<SelectedAudioParameters>>,
+    }
+}
@@ -0,0 +1,354 @@
+//! Typed values for Characteristics of `Format::Tlv8`.
+//!
+//! Structs declared with the `tlv8_struct!` macro implement `Tlv8` and can be converted from and
+//! to the raw TLV8 value of a `Characteristic<Vec<u8>>`:
+//!
+//! ```
+//! use hap::{characteristic::Tlv8, tlv8_struct};
+//!
+//! tlv8_struct! {
+//!     /// Example value with a required and an optional item.
+//!     pub struct Example {
+//!         0x01 => pub command: u8,
+//!         0x02 => pub session_id: Option<Vec<u8>>,
+//!     }
+//! }
+//!
+//! let example = Example {
+//!     command: 1,
+//!     session_id: None,
+//! };
+//! let encoded = example.to_tlv8();
+//! assert_eq!(encoded, vec![0x01, 0x01, 0x01]);
+//! assert_eq!(Example::from_tlv8(&encoded).unwrap(), example);
+//! ```
+
+use std::collections::HashMap;
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::{
+    characteristic::Characteristic,
+    protocol::tlv::{self, Reader, Writer},
+    Error,
+    Result,
+};
+
+/// `Tlv8` is implemented by types that can be encoded to and decoded from TLV8 data.
+pub trait Tlv8: Sized {
+    /// Writes the items of the value to a `Writer`.
+    fn write_tlv8(&self, writer: &mut Writer);
+    /// Reads the value from decoded items, grouped by type in the order they occurred.
+    fn read_tlv8(items: &mut HashMap<u8, Vec<Vec<u8>>>) -> Result<Self>;
+
+    /// Encodes the value to TLV8 data.
+    fn to_tlv8(&self) -> Vec<u8> {
+        let mut writer = Writer::new();
+        self.write_tlv8(&mut writer);
+        writer.into_bytes()
+    }
+
+    /// Decodes the value from TLV8 data.
+    fn from_tlv8(data: &[u8]) -> Result<Self> {
+        let mut items = group_items(data)?;
+        Self::read_tlv8(&mut items)
+    }
+}
+
+/// `Tlv8Field` is implemented by the types that can be used as fields of a `tlv8_struct!`.
+///
+/// Integers are encoded little-endian, `Vec<u8>` is a Byte string and `Option`s are omitted when
+/// `None`. Lists are wrapped in `Repeated<T>`, nested structures in `Nested<T>`.
+pub trait Tlv8Field: Sized {
+    /// Writes the field as item(s) of the given type.
+    fn write_field(&self, t: u8, writer: &mut Writer);
+    /// Reads the field from all values of its type.
+    fn read_field(t: u8, values: Vec<Vec<u8>>) -> Result<Self>;
+}
+
+/// Type of the zero-length item delimiting the elements of a `Repeated` field.
+const DELIMITER: u8 = 0x00;
+
+/// Groups the items of TLV8 data by type, keeping repeated items in order. Separators and
+/// delimiters are dropped.
+fn group_items(data: &[u8]) -> Result<HashMap<u8, Vec<Vec<u8>>>> {
+    let mut items: HashMap<u8, Vec<Vec<u8>>> = HashMap::new();
+    for item in Reader::new(data) {
+        let (t, v) = item?;
+        if t != tlv::Type::Separator as u8 && !(t == DELIMITER && v.is_empty()) {
+            items.entry(t).or_default().push(v);
+        }
+    }
+    Ok(items)
+}
+
+/// Returns the value of a non-repeated item. If the item occurs more than once, the last one wins.
+fn single(mut values: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+    values.pop().ok_or(Error::from_str("missing required TLV8 item"))
+}
+
+macro_rules! impl_tlv8_int {
+    ($($t:ty, $len:expr, $read:expr, $write:expr;)+) => {
+        $(
+            impl Tlv8Field for $t {
+                fn write_field(&self, t: u8, writer: &mut Writer) {
+                    let mut buf = [0; $len];
+                    $write(&mut buf, *self);
+                    writer.push(t, &buf);
+                }
+
+                fn read_field(_: u8, values: Vec<Vec<u8>>) -> Result<$t> {
+                    let value = single(values)?;
+                    if value.is_empty() || value.len() > $len {
+                        return Err(Error::from_str("invalid TLV8 integer length"));
+                    }
+                    // integers may be sent with fewer Bytes than their full width
+                    let mut buf = [0; $len];
+                    buf[..value.len()].copy_from_slice(&value);
+                    Ok($read(&buf))
+                }
+            }
+        )+
+    }
+}
+
+impl_tlv8_int! {
+    u8, 1, |b: &[u8]| b[0], |b: &mut [u8], v: u8| b[0] = v;
+    u16, 2, LittleEndian::read_u16, LittleEndian::write_u16;
+    u32, 4, LittleEndian::read_u32, LittleEndian::write_u32;
+    u64, 8, LittleEndian::read_u64, LittleEndian::write_u64;
+}
+
+impl Tlv8Field for f32 {
+    fn write_field(&self, t: u8, writer: &mut Writer) {
+        let mut buf = [0; 4];
+        LittleEndian::write_f32(&mut buf, *self);
+        writer.push(t, &buf);
+    }
+
+    fn read_field(_: u8, values: Vec<Vec<u8>>) -> Result<f32> {
+        let value = single(values)?;
+        if value.len() != 4 {
+            return Err(Error::from_str("invalid TLV8 float length"));
+        }
+        Ok(LittleEndian::read_f32(&value))
+    }
+}
+
+impl Tlv8Field for bool {
+    fn write_field(&self, t: u8, writer: &mut Writer) { writer.push(t, &[*self as u8]); }
+
+    fn read_field(t: u8, values: Vec<Vec<u8>>) -> Result<bool> { Ok(u8::read_field(t, values)? != 0) }
+}
+
+impl Tlv8Field for String {
+    fn write_field(&self, t: u8, writer: &mut Writer) { writer.push(t, self.as_bytes()); }
+
+    fn read_field(_: u8, values: Vec<Vec<u8>>) -> Result<String> {
+        let value = single(values)?;
+        String::from_utf8(value).map_err(|_| Error::from_str("invalid UTF-8 in TLV8 item"))
+    }
+}
+
+impl Tlv8Field for Vec<u8> {
+    fn write_field(&self, t: u8, writer: &mut Writer) { writer.push(t, self); }
+
+    fn read_field(_: u8, values: Vec<Vec<u8>>) -> Result<Vec<u8>> { single(values) }
+}
+
+impl<T: Tlv8Field> Tlv8Field for Option<T> {
+    fn write_field(&self, t: u8, writer: &mut Writer) {
+        if let Some(ref value) = self {
+            value.write_field(t, writer);
+        }
+    }
+
+    fn read_field(t: u8, values: Vec<Vec<u8>>) -> Result<Option<T>> {
+        if values.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(T::read_field(t, values)?))
+    }
+}
+
+/// A list of values, encoded as repeated items of the same type with a zero-length delimiter
+/// item of type `0x00` between them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Repeated<T>(pub Vec<T>);
+
+impl<T: Tlv8Field> Tlv8Field for Repeated<T> {
+    fn write_field(&self, t: u8, writer: &mut Writer) {
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                writer.push(DELIMITER, &[]);
+            }
+            value.write_field(t, writer);
+        }
+    }
+
+    fn read_field(t: u8, values: Vec<Vec<u8>>) -> Result<Repeated<T>> {
+        let values = values
+            .into_iter()
+            .map(|value| T::read_field(t, vec![value]))
+            .collect::<Result<Vec<T>>>()?;
+        Ok(Repeated(values))
+    }
+}
+
+/// Wraps a `Tlv8` value so that it's encoded as a nested TLV8 item.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Nested<T>(pub T);
+
+impl<T: Tlv8> Tlv8Field for Nested<T> {
+    fn write_field(&self, t: u8, writer: &mut Writer) {
+        let mut nested = Writer::new();
+        self.0.write_tlv8(&mut nested);
+        writer.push_nested(t, nested);
+    }
+
+    fn read_field(_: u8, values: Vec<Vec<u8>>) -> Result<Nested<T>> {
+        let value = single(values)?;
+        Ok(Nested(T::from_tlv8(&value)?))
+    }
+}
+
+/// Declares a struct whose fields are mapped to TLV8 item types and implements `Tlv8` for it.
+///
+/// Fields of type `Option<T>` are optional, repeated items are wrapped in `Repeated<T>` and nested
+/// structures in `Nested<T>`.
+#[macro_export]
+macro_rules! tlv8_struct {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* $t:literal => pub $field:ident: $ty:ty,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq)]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl $crate::characteristic::Tlv8 for $name {
+            fn write_tlv8(&self, writer: &mut $crate::protocol::tlv::Writer) {
+                $($crate::characteristic::Tlv8Field::write_field(&self.$field, $t, writer);)*
+            }
+
+            fn read_tlv8(
+                items: &mut ::std::collections::HashMap<u8, Vec<Vec<u8>>>,
+            ) -> $crate::Result<$name> {
+                Ok($name {
+                    $($field: $crate::characteristic::Tlv8Field::read_field(
+                        $t,
+                        items.remove(&$t).unwrap_or_default(),
+                    )?,)*
+                })
+            }
+        }
+    };
+}
+
+impl Characteristic<Vec<u8>> {
+    /// Returns the value of a TLV8 Characteristic decoded to a typed value.
+    pub fn get_tlv8_value<V: Tlv8>(&mut self) -> Result<V> {
+        let value = self.get_value()?;
+        V::from_tlv8(&value)
+    }
+
+    /// Sets the value of a TLV8 Characteristic from a typed value.
+    pub fn set_tlv8_value<V: Tlv8>(&mut self, value: &V) -> Result<()> { self.set_value(value.to_tlv8()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::characteristic::{lock::LockControlPointRequest, lock_control_point, Format, HapCharacteristic, Inner};
+
+    tlv8_struct! {
+        /// Value with a large Byte string.
+        pub struct Blob {
+            0x01 => pub data: Vec<u8>,
+            0x02 => pub len: u16,
+        }
+    }
+
+    tlv8_struct! {
+        /// Value with a repeated and a nested field.
+        pub struct Container {
+            0x01 => pub ids: Repeated<u8>,
+            0x02 => pub blob: Option<Nested<Blob>>,
+        }
+    }
+
+    #[test]
+    fn tlv8_and_data_values_are_base64_encoded_in_json() {
+        let mut tlv8 = lock_control_point::new();
+        HapCharacteristic::set_value(&mut tlv8, json!(base64::encode(&[0x02, 0x00]))).unwrap();
+        assert_eq!(HapCharacteristic::get_value(&mut tlv8).unwrap(), json!("AgA="));
+        assert!(HapCharacteristic::set_value(&mut tlv8, json!("not base64!")).is_err());
+
+        let mut data = Characteristic::new(Inner::<Vec<u8>> {
+            format: Format::Data,
+            ..Default::default()
+        });
+        HapCharacteristic::set_value(&mut data, json!("AQID")).unwrap();
+        assert_eq!(HapCharacteristic::get_value(&mut data).unwrap(), json!("AQID"));
+        assert_eq!(data.get_value().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn lock_control_point_requests() {
+        let mut lock_control_point = lock_control_point::new();
+        lock_control_point
+            .set_tlv8_value(&LockControlPointRequest {
+                clear_logs: Some(Vec::new()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(lock_control_point.get_value().unwrap(), vec![0x02, 0x00]);
+
+        let request = LockControlPointRequest::from_tlv8(&[0x00, 0x04, 1, 2, 3, 4]).unwrap();
+        assert_eq!(request.read_logs_from_time, Some(vec![1, 2, 3, 4]));
+        assert_eq!(request.clear_logs, None);
+        assert_eq!(request.set_current_time, None);
+    }
+
+    #[test]
+    fn repeated_items_are_delimited() {
+        let container = Container {
+            ids: Repeated(vec![1, 2, 3]),
+            blob: None,
+        };
+        let encoded = container.to_tlv8();
+        assert_eq!(encoded, vec![
+            0x01, 0x01, 1, 0x00, 0x00, 0x01, 0x01, 2, 0x00, 0x00, 0x01, 0x01, 3
+        ]);
+        assert_eq!(Container::from_tlv8(&encoded).unwrap(), container);
+
+        let empty = Container::default();
+        assert!(empty.to_tlv8().is_empty());
+        assert_eq!(Container::from_tlv8(&[]).unwrap(), empty);
+    }
+
+    #[test]
+    fn nested_values_longer_than_255_bytes_are_fragmented() {
+        let container = Container {
+            ids: Repeated(vec![7]),
+            blob: Some(Nested(Blob {
+                data: (0..300).map(|i| i as u8).collect(),
+                len: 300,
+            })),
+        };
+        let encoded = container.to_tlv8();
+
+        // the nested value holds a fragmented item itself: 2 + 255 + 2 + 45 + 2 + 2 Bytes
+        assert_eq!(&encoded[3..5], &[0x02, 255]);
+        assert_eq!(&encoded[5..7], &[0x01, 255]);
+        assert_eq!(&encoded[260..262], &[0x02, 53]);
+        assert_eq!(encoded.len(), 3 + 2 + 255 + 2 + 53);
+        assert_eq!(Container::from_tlv8(&encoded).unwrap(), container);
+    }
+}
@@ -0,0 +1,129 @@
+//! Typed TLV8 values of the Characteristic Value Transition Characteristics used for Adaptive
+//! Lighting.
+//!
+//! A controller reads the `SupportedTransitionConfig` of a lightbulb, writes a `TransitionControl`
+//! to its Characteristic Value Transition Control Characteristic and reads the
+//! `TransitionControlResponse` from the write response.
+
+use crate::{
+    characteristic::tlv8::{Nested, Repeated},
+    tlv8_struct,
+};
+
+/// Transition type of a Brightness Characteristic.
+pub const TRANSITION_TYPE_BRIGHTNESS: u8 = 0x01;
+/// Transition type of a Color Temperature Characteristic.
+pub const TRANSITION_TYPE_COLOR_TEMPERATURE: u8 = 0x02;
+
+tlv8_struct! {
+    /// A Characteristic whose value can be transitioned.
+    pub struct SupportedTransition {
+        0x01 => pub iid: u64,
+        /// Transition type. `1` = Brightness, `2` = Color Temperature.
+        0x02 => pub transition_type: u8,
+    }
+}
+
+tlv8_struct! {
+    /// Value of the Supported Characteristic Value Transition Configuration Characteristic.
+    pub struct SupportedTransitionConfig {
+        0x01 => pub transitions: Repeated<Nested<SupportedTransition>>,
+    }
+}
+
+tlv8_struct! {
+    /// Request for the status of the active transition of a Characteristic.
+    pub struct ReadTransition {
+        0x01 => pub iid: u64,
+    }
+}
+
+tlv8_struct! {
+    /// Parameters identifying a transition.
+    pub struct TransitionParameters {
+        /// UUID of the transition.
+        0x01 => pub transition_id: Vec<u8>,
+        /// Start time of the transition in milliseconds since 2001-01-01 00:00:00 UTC.
+        0x02 => pub start_time: u64,
+        /// Undocumented value, returned as is in the `TransitionStatus`.
+        0x03 => pub unknown: Option<Vec<u8>>,
+    }
+}
+
+tlv8_struct! {
+    /// A point of a transition curve.
+    pub struct TransitionEntry {
+        /// Factor of the adjustment multiplier added to the value.
+        0x01 => pub adjustment_factor: f32,
+        0x02 => pub value: f32,
+        /// Milliseconds over which the value is interpolated from the previous entry.
+        0x03 => pub transition_offset: u64,
+        /// Milliseconds the value is held after reaching the entry.
+        0x04 => pub duration: Option<u64>,
+    }
+}
+
+tlv8_struct! {
+    /// Range the adjustment multiplier is clamped to.
+    pub struct AdjustmentMultiplierRange {
+        0x01 => pub min: u32,
+        0x02 => pub max: u32,
+    }
+}
+
+tlv8_struct! {
+    /// Curve of a transition along with the Characteristic adjusting it.
+    pub struct TransitionCurve {
+        0x01 => pub entries: Repeated<Nested<TransitionEntry>>,
+        /// IID of the Characteristic whose value is the adjustment multiplier, e.g. Brightness.
+        0x02 => pub adjustment_iid: u64,
+        0x03 => pub adjustment_multiplier_range: Nested<AdjustmentMultiplierRange>,
+    }
+}
+
+tlv8_struct! {
+    /// Configuration of a transition. A configuration without parameters and curve ends the active
+    /// transition of the Characteristic.
+    pub struct TransitionConfig {
+        0x01 => pub iid: u64,
+        0x02 => pub parameters: Option<Nested<TransitionParameters>>,
+        0x05 => pub curve: Option<Nested<TransitionCurve>>,
+        /// Milliseconds between updates of the value.
+        0x06 => pub update_interval: Option<u16>,
+        /// Minimum milliseconds between event notifications of the value.
+        0x08 => pub notify_interval_threshold: Option<u32>,
+    }
+}
+
+tlv8_struct! {
+    /// Request to start or end a transition.
+    pub struct UpdateTransition {
+        0x01 => pub config: Nested<TransitionConfig>,
+    }
+}
+
+tlv8_struct! {
+    /// Value written to the Characteristic Value Transition Control Characteristic.
+    pub struct TransitionControl {
+        0x01 => pub read: Option<Nested<ReadTransition>>,
+        0x02 => pub update: Option<Nested<UpdateTransition>>,
+    }
+}
+
+tlv8_struct! {
+    /// Status of an active transition.
+    pub struct TransitionStatus {
+        0x01 => pub iid: u64,
+        0x02 => pub parameters: Nested<TransitionParameters>,
+        /// Milliseconds since the start of the transition.
+        0x03 => pub time_since_start: u64,
+    }
+}
+
+tlv8_struct! {
+    /// Write response of the Characteristic Value Transition Control Characteristic. Empty if no
+    /// transition is active.
+    pub struct TransitionControlResponse {
+        0x01 => pub status: Option<Nested<TransitionStatus>>,
+    }
+}
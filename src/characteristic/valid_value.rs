@@ -0,0 +1,109 @@
+//! Typed values for Characteristics with a set of valid values.
+//!
+//! Every generated Characteristic with valid values declares a `Value` enum in its module, which
+//! can be converted from and to the raw value of the Characteristic:
+//!
+//! ```
+//! use hap::characteristic::{valve_type, ValidValue};
+//!
+//! let mut valve_type = valve_type::new();
+//! valve_type.set_valid_value(valve_type::Value::WaterFaucet).unwrap();
+//! assert_eq!(valve_type.get_value().unwrap(), 3);
+//! assert_eq!(
+//!     valve_type.get_valid_value::<valve_type::Value>().unwrap(),
+//!     valve_type::Value::WaterFaucet
+//! );
+//! assert_eq!(valve_type::Value::hap_type(), hap::HapType::ValveType);
+//! ```
+//!
+//! Generated Characteristics are aliases of `Characteristic<T>`, so the compiler can't tell a
+//! Valve Type from any other `u8` Characteristic. Whether a `Value` belongs to a Characteristic is
+//! checked at runtime by comparing their `HapType`s, and a mismatch is reported as an error:
+//!
+//! ```
+//! use hap::characteristic::{active, valve_type};
+//!
+//! let mut valve_type = valve_type::new();
+//! assert!(valve_type.set_valid_value(active::Value::Active).is_err());
+//! assert!(valve_type.get_valid_value::<active::Value>().is_err());
+//! ```
+
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{characteristic::Characteristic, Error, HapType, Result};
+
+/// `ValidValue` is implemented by the generated enums of the valid values of a Characteristic.
+pub trait ValidValue<T>: Into<T> + TryFrom<T, Error = Error> {
+    /// `HapType` of the Characteristic the valid values belong to.
+    fn hap_type() -> HapType;
+}
+
+impl<T: Default + Clone + Serialize> Characteristic<T>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    /// Returns the value of a Characteristic converted to one of its typed valid values. Fails if
+    /// the valid values belong to a Characteristic of another type.
+    pub fn get_valid_value<V: ValidValue<T>>(&mut self) -> Result<V> {
+        self.check_valid_value_type::<V>()?;
+        V::try_from(self.get_value()?)
+    }
+
+    /// Sets the value of a Characteristic from one of its typed valid values. Fails if the valid
+    /// values belong to a Characteristic of another type.
+    pub fn set_valid_value<V: ValidValue<T>>(&mut self, value: V) -> Result<()> {
+        self.check_valid_value_type::<V>()?;
+        self.set_value(value.into())
+    }
+
+    fn check_valid_value_type<V: ValidValue<T>>(&self) -> Result<()> {
+        if V::hap_type() != self.get_type()? {
+            return Err(Error::from_str("valid values don't belong to the characteristic"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::characteristic::{active, target_heater_cooler_state, valve_type};
+
+    #[test]
+    fn valid_values_convert_from_and_to_raw_values() {
+        for raw in 0..=3 {
+            let value = valve_type::Value::try_from(raw).unwrap();
+            assert_eq!(u8::from(value), raw);
+        }
+        assert_eq!(u8::from(valve_type::Value::GenericValve), 0);
+        assert_eq!(u8::from(valve_type::Value::WaterFaucet), 3);
+        assert_eq!(
+            target_heater_cooler_state::Value::try_from(2).unwrap(),
+            target_heater_cooler_state::Value::Cool
+        );
+    }
+
+    #[test]
+    fn invalid_raw_values_are_rejected() {
+        assert!(valve_type::Value::try_from(4).is_err());
+        assert!(valve_type::Value::try_from(u8::MAX).is_err());
+        assert!(active::Value::try_from(2).is_err());
+
+        let mut valve_type = valve_type::new();
+        valve_type.set_value(200).unwrap();
+        assert!(valve_type.get_valid_value::<valve_type::Value>().is_err());
+    }
+
+    #[test]
+    fn valid_values_of_other_characteristics_are_rejected() {
+        let mut valve_type = valve_type::new();
+        valve_type.set_valid_value(valve_type::Value::ShowerHead).unwrap();
+
+        assert!(valve_type.set_valid_value(active::Value::Active).is_err());
+        assert!(valve_type.get_valid_value::<active::Value>().is_err());
+        assert_eq!(valve_type.get_value().unwrap(), 2);
+    }
+}
@@ -2,19 +2,24 @@ use std::{
     collections::hash_map::DefaultHasher,
     env::current_dir,
     hash::{Hash, Hasher},
-    net::IpAddr,
+    net::{IpAddr, Ipv6Addr},
     str,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use eui48::MacAddress;
-use pnet::datalink;
 use rand::{self, Rng};
 
 use crate::{
     accessory::Category,
     db::Storage,
-    transport::bonjour::{FeatureFlag, StatusFlag},
+    protocol::AuthenticatorPtr,
+    transport::{
+        bonjour::{FeatureFlag, StatusFlag},
+        interfaces::Interfaces,
+        mdns::MdnsBackend,
+    },
     Result,
 };
 
@@ -41,8 +46,19 @@ pub struct Config {
     /// Storage path for the persisted data. If no path is specified, the current working directory
     /// is used.
     pub storage_path: String,
-    /// IP address to serve on.
+    /// IP address to serve on. Defaults to `::`, which serves on all IPv4 and IPv6 addresses of the
+    /// selected `interfaces`. `0.0.0.0` serves on their IPv4 addresses only, a specific address only
+    /// on that address.
     pub ip: IpAddr,
+    /// Network interfaces to serve on and announce the accessory on via mDNS if `ip` is unspecified.
+    /// Defaults to `Interfaces::All`.
+    pub interfaces: Interfaces,
+    /// Optional interval in which network interfaces are checked for changed addresses, e.g. after
+    /// moving to another network or a DHCP renewal. On changes, listeners are bound to new addresses
+    /// and the accessory is re-announced via mDNS. Defaults to 5 seconds.
+    pub network_monitor_interval: Option<Duration>,
+    /// mDNS backend used to announce the accessory. Defaults to `MdnsBackend::BuiltIn`.
+    pub mdns_backend: MdnsBackend,
     /// Port to serve on. Defaults to `32000`.
     pub port: u16,
     /// 8 digit pin used for pairing. Defaults to `"11122333"`.
@@ -83,21 +99,40 @@ pub struct Config {
     pub status_flag: StatusFlag, // sf
     /// Bonjour Feature Flag. Currently only used to indicate MFi compliance.
     pub feature_flag: FeatureFlag, // ff
+    /// Optional `Authenticator` used for Pair Setup with MFi authentication. Should be set along
+    /// with `FeatureFlag::MfiCompliant`.
+    pub authenticator: Option<AuthenticatorPtr>,
     /// Optional maximum number of paired controllers.
     pub max_peers: Option<usize>,
+    /// Maximum number of simultaneous TCP connections. HAP requires accessories to support at least
+    /// 8 connections. Defaults to `16`.
+    ///
+    /// If the limit is reached, the oldest connection that hasn't completed Pair Verify yet is closed
+    /// in favor of a new one. New connections are rejected if all connections are verified.
+    pub max_connections: usize,
+    /// Optional maximum number of simultaneous verified connections of a single controller. The
+    /// oldest connections of a controller exceeding the limit are closed. Disabled by default, since
+    /// all devices of a HomeKit user, including the home hubs, share a single controller pairing ID.
+    pub max_controller_connections: Option<usize>,
+    /// Time a connection is given to complete Pair Setup or Pair Verify before it's closed. Defaults
+    /// to 60 seconds.
+    pub handshake_timeout: Duration,
+    /// Optional time after which a verified connection without any traffic is closed. Controllers
+    /// keep their connections open to receive events, so this is disabled by default.
+    pub idle_timeout: Option<Duration>,
     pub version: u64,
     pub config_hash: Option<u64>,
 }
 
 impl Config {
     pub(crate) fn load_from(&mut self, storage: &dyn Storage) -> Result<()> {
-        if let Some(device_id) = storage.get_bytes("device_id").ok() {
+        if let Ok(device_id) = storage.get_bytes("device_id") {
             self.device_id = MacAddress::parse_str(str::from_utf8(&device_id)?)?;
         }
-        if let Some(version) = storage.get_u64("version").ok() {
+        if let Ok(version) = storage.get_u64("version") {
             self.version = version;
         }
-        if let Some(config_hash) = storage.get_u64("config_hash").ok() {
+        if let Ok(config_hash) = storage.get_u64("config_hash") {
             self.config_hash = Some(config_hash);
         }
         Ok(())
@@ -148,6 +183,7 @@ impl Hash for Config {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.storage_path.hash(state);
         self.ip.hash(state);
+        self.interfaces.hash(state);
         self.port.hash(state);
         self.pin.hash(state);
         self.name.hash(state);
@@ -171,7 +207,10 @@ impl Default for Config {
                     .to_str()
                     .expect("couldn't stringify current directory")
             ),
-            ip: current_ip().expect("couldn't determine local IP address"),
+            ip: IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            interfaces: Interfaces::All,
+            network_monitor_interval: Some(Duration::from_secs(5)),
+            mdns_backend: MdnsBackend::BuiltIn,
             port: 32000,
             pin: "11122333".into(),
             name: "Accessory".into(),
@@ -182,7 +221,12 @@ impl Default for Config {
             protocol_version: "1.0".into(),
             status_flag: StatusFlag::NotPaired,
             feature_flag: FeatureFlag::Zero,
+            authenticator: None,
             max_peers: None,
+            max_connections: 16,
+            max_controller_connections: None,
+            handshake_timeout: Duration::from_secs(60),
+            idle_timeout: None,
             version: 0,
             config_hash: None,
         };
@@ -191,20 +235,6 @@ impl Default for Config {
     }
 }
 
-fn current_ip() -> Option<IpAddr> {
-    for iface in datalink::interfaces() {
-        for ip_network in iface.ips {
-            if ip_network.is_ipv4() {
-                let ip = ip_network.ip();
-                if !ip.is_loopback() {
-                    return Some(ip);
-                }
-            }
-        }
-    }
-    None
-}
-
 fn random_mac_address() -> MacAddress {
     let mut rng = rand::thread_rng();
     let eui = rng.gen::<[u8; 6]>();
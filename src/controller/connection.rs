@@ -0,0 +1,280 @@
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    str,
+    time::Duration,
+};
+
+use bytes::BytesMut;
+use tokio::codec::{Decoder, Encoder};
+
+use crate::{transport::tcp::FrameCodec, Error, Result};
+
+/// Maximum size of a message body the controller is willing to buffer.
+const MAX_BODY_LEN: usize = 8 * 1024 * 1024;
+
+/// A message received from an accessory. Either the response to a request or an event.
+#[derive(Debug)]
+pub(crate) struct Message {
+    /// Whether the message is an `EVENT/1.0` message.
+    pub event: bool,
+    /// HTTP status code of the message.
+    pub status: u16,
+    /// Body of the message.
+    pub body: Vec<u8>,
+}
+
+/// TCP connection to an accessory that switches to the encrypted HAP framing after a successful
+/// Pair Verify.
+pub(crate) struct Connection {
+    stream: TcpStream,
+    codec: Option<FrameCodec>,
+    read_buf: BytesMut,
+    buf: Vec<u8>,
+    scanned: usize,
+}
+
+impl Connection {
+    /// Opens a new plaintext `Connection` to the given address.
+    pub fn connect(addr: &SocketAddr, timeout: Duration) -> Result<Connection> {
+        let stream = TcpStream::connect_timeout(addr, timeout)?;
+        stream.set_nodelay(true)?;
+        Ok(Connection {
+            stream,
+            codec: None,
+            read_buf: BytesMut::new(),
+            buf: Vec::new(),
+            scanned: 0,
+        })
+    }
+
+    /// Sets the read timeout of the underlying `TcpStream`.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        self.stream.set_read_timeout(timeout)?;
+        Ok(())
+    }
+
+    /// Switches the `Connection` to encrypted framing using the shared secret of a Pair Verify.
+    pub fn encrypt(&mut self, shared_secret: &[u8]) { self.codec = Some(FrameCodec::controller(shared_secret)); }
+
+    /// Sends an HTTP request and returns the next non-event message.
+    pub fn request(
+        &mut self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &[u8],
+        events: &mut Vec<Message>,
+    ) -> Result<Message> {
+        let mut req = format!("{} {} HTTP/1.1\r\nHost: hap\r\n", method, path);
+        if let Some(content_type) = content_type {
+            req.push_str(&format!("Content-Type: {}\r\n", content_type));
+        }
+        req.push_str(&format!("Content-Length: {}\r\n\r\n", body.len()));
+        let mut data = req.into_bytes();
+        data.extend_from_slice(body);
+        self.send(&data)?;
+
+        loop {
+            let message = self.read_message()?;
+            if message.event {
+                events.push(message);
+            } else {
+                return Ok(message);
+            }
+        }
+    }
+
+    fn send(&mut self, data: &[u8]) -> Result<()> {
+        match self.codec {
+            Some(ref mut codec) => {
+                let mut encoded = BytesMut::new();
+                codec.encode(data.to_vec(), &mut encoded)?;
+                self.stream.write_all(&encoded)?;
+            },
+            None => {
+                self.stream.write_all(data)?;
+            },
+        }
+        self.stream.flush()?;
+        Ok(())
+    }
+
+    /// Reads more data from the stream into the plaintext buffer.
+    ///
+    /// Encrypted frames are only decrypted once they're complete; the Bytes of a partially received
+    /// frame stay in `read_buf` across calls. A read timeout in the middle of a frame therefore
+    /// leaves the ChaCha20-Poly1305 nonces in sync and reading can be resumed later.
+    fn fill(&mut self) -> Result<()> {
+        loop {
+            match self.codec {
+                Some(ref mut codec) => {
+                    if let Some(decrypted) = codec.decode(&mut self.read_buf)? {
+                        self.buf.extend_from_slice(&decrypted);
+                        return Ok(());
+                    }
+                },
+                None if !self.read_buf.is_empty() => {
+                    self.buf.extend_from_slice(&self.read_buf.take());
+                    return Ok(());
+                },
+                None => {},
+            }
+
+            let mut data = [0; 4096];
+            let len = self.stream.read(&mut data)?;
+            if len == 0 {
+                return Err(Error::from_str("connection closed by accessory"));
+            }
+            self.read_buf.extend_from_slice(&data[..len]);
+        }
+    }
+
+    /// Reads the next complete message from the stream.
+    pub fn read_message(&mut self) -> Result<Message> {
+        loop {
+            if let Some((message, len)) = parse_message(&self.buf, &mut self.scanned)? {
+                self.buf.drain(..len);
+                self.scanned = 0;
+                return Ok(message);
+            }
+            self.fill()?;
+        }
+    }
+}
+
+/// Parses a complete message from the beginning of a buffer and returns it along with the number
+/// of Bytes it occupied. Returns `None` if the buffer doesn't contain a complete message yet.
+///
+/// `scanned` is the offset up to which the buffer has already been searched for the end of the
+/// message head, so that partial reads don't rescan the whole buffer.
+fn parse_message(buf: &[u8], scanned: &mut usize) -> Result<Option<(Message, usize)>> {
+    // the accessory is tolerated to separate lines by `\n` only
+    let (head_len, sep_len) = match find_head_end(buf, *scanned) {
+        Some(end) => end,
+        None => {
+            *scanned = buf.len();
+            return Ok(None);
+        },
+    };
+    *scanned = head_len;
+    let head = str::from_utf8(&buf[..head_len])?;
+    let mut lines = head.lines();
+    let status_line = lines.next().ok_or(Error::from_str("missing status line"))?;
+    let mut parts = status_line.split_whitespace();
+    let protocol = parts.next().ok_or(Error::from_str("invalid status line"))?;
+    let status = parts
+        .next()
+        .ok_or(Error::from_str("invalid status line"))?
+        .parse::<u16>()?;
+
+    let mut content_length = 0;
+    for line in lines {
+        let mut header = line.splitn(2, ':');
+        let name = header.next().unwrap_or("").trim();
+        let value = header.next().unwrap_or("").trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse::<usize>()?;
+        }
+    }
+
+    if content_length > MAX_BODY_LEN {
+        return Err(Error::from_str("message body too large"));
+    }
+    let body_start = head_len + sep_len;
+    let end = body_start
+        .checked_add(content_length)
+        .ok_or(Error::from_str("message body too large"))?;
+    if buf.len() < end {
+        return Ok(None);
+    }
+
+    Ok(Some((
+        Message {
+            event: protocol.starts_with("EVENT"),
+            status,
+            body: buf[body_start..end].to_vec(),
+        },
+        end,
+    )))
+}
+
+/// Searches for the end of the message head, starting a separator's length before `from` so that a
+/// separator split across two reads is still found.
+fn find_head_end(buf: &[u8], from: usize) -> Option<(usize, usize)> {
+    for i in from.saturating_sub(3)..buf.len() {
+        if buf[i..].starts_with(b"\r\n\r\n") {
+            return Some((i, 4));
+        }
+        if buf[i..].starts_with(b"\n\n") {
+            return Some((i, 2));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn parse_message_resumes_head_scan() {
+        let message = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}";
+        let mut scanned = 0;
+        for len in 0..message.len() {
+            assert!(parse_message(&message[..len], &mut scanned).unwrap().is_none());
+            assert!(scanned <= len);
+        }
+        let (parsed, len) = parse_message(message, &mut scanned).unwrap().unwrap();
+        assert_eq!(parsed.status, 200);
+        assert_eq!(parsed.body, b"{}");
+        assert_eq!(len, message.len());
+    }
+
+    #[test]
+    fn parse_message_rejects_oversized_content_length() {
+        let overflowing = b"HTTP/1.1 200 OK\r\nContent-Length: 18446744073709551615\r\n\r\n";
+        assert!(parse_message(overflowing, &mut 0).is_err());
+
+        let oversized = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", MAX_BODY_LEN + 1);
+        assert!(parse_message(oversized.as_bytes(), &mut 0).is_err());
+    }
+
+    #[test]
+    fn read_timeout_mid_frame_keeps_partial_frame() {
+        let shared_secret = [7; 32];
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut connection = Connection::connect(&listener.local_addr().unwrap(), Duration::from_secs(1)).unwrap();
+        connection.encrypt(&shared_secret);
+        let (mut accessory, _) = listener.accept().unwrap();
+        let mut codec = FrameCodec::accessory(&shared_secret);
+
+        let mut encoded = BytesMut::new();
+        codec
+            .encode(b"EVENT/1.0 200 OK\r\nContent-Length: 2\r\n\r\n{}".to_vec(), &mut encoded)
+            .unwrap();
+        codec
+            .encode(b"EVENT/1.0 200 OK\r\nContent-Length: 4\r\n\r\n{\"\"}".to_vec(), &mut encoded)
+            .unwrap();
+
+        accessory.write_all(&encoded[..10]).unwrap();
+        connection.set_read_timeout(Some(Duration::from_millis(50))).unwrap();
+        let err = connection.read_message().unwrap_err();
+        match err.kind() {
+            crate::ErrorKind::Io(ref e) => assert!(
+                e.kind() == std::io::ErrorKind::WouldBlock || e.kind() == std::io::ErrorKind::TimedOut
+            ),
+            _ => panic!("unexpected error: {}", err),
+        }
+
+        accessory.write_all(&encoded[10..]).unwrap();
+        connection.set_read_timeout(None).unwrap();
+        let first = connection.read_message().unwrap();
+        assert!(first.event);
+        assert_eq!(first.body, b"{}");
+        let second = connection.read_message().unwrap();
+        assert_eq!(second.body, b"{\"\"}");
+    }
+}
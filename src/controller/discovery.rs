@@ -0,0 +1,246 @@
+use std::{
+    collections::HashMap,
+    io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket},
+    str,
+    time::{Duration, Instant},
+};
+
+use dns_parser::{Builder, Packet, QueryClass, QueryType, RData};
+use log::debug;
+
+use crate::{Error, Result};
+
+const SERVICE_TYPE: &str = "_hap._tcp.local";
+const MDNS_ADDR_V4: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+const MDNS_ADDR_V6: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xfb);
+const MDNS_PORT: u16 = 5353;
+
+/// An accessory advertised on the local network.
+#[derive(Debug, Clone, Default)]
+pub struct DiscoveredAccessory {
+    /// Name of the service instance.
+    pub name: String,
+    /// Addresses the accessory is reachable at.
+    pub addresses: Vec<IpAddr>,
+    /// Port the accessory is listening on.
+    pub port: u16,
+    /// Device ID of the accessory (`id` TXT record).
+    pub id: Option<String>,
+    /// Current configuration number (`c#` TXT record).
+    pub configuration_number: Option<u64>,
+    /// Accessory category identifier (`ci` TXT record).
+    pub category: Option<u8>,
+    /// Status flags (`sf` TXT record). `1` means the accessory isn't paired yet.
+    pub status_flag: Option<u8>,
+    /// All TXT records of the service.
+    pub txt: HashMap<String, String>,
+}
+
+impl DiscoveredAccessory {
+    /// Returns the socket addresses of the accessory.
+    pub fn socket_addrs(&self) -> Vec<SocketAddr> {
+        self.addresses.iter().map(|ip| SocketAddr::new(*ip, self.port)).collect()
+    }
+
+    /// Returns whether the accessory is available for pairing.
+    pub fn is_unpaired(&self) -> bool { self.status_flag.map(|sf| sf & 0x01 == 0x01).unwrap_or(false) }
+}
+
+/// Browses the local network for HAP accessories for the given duration.
+///
+/// The query is sent to the IPv4 and, where the host supports IPv6, the IPv6 mDNS group. Since the
+/// IPv6 group is link-local, its query only reaches the link of the system's default multicast
+/// interface.
+pub fn discover(timeout: Duration) -> Result<Vec<DiscoveredAccessory>> {
+    let mut builder = Builder::new_query(0, false);
+    builder.add_question(SERVICE_TYPE, false, QueryType::PTR, QueryClass::IN);
+    let query = builder.build().map_err(|_| Error::from_str("couldn't build mDNS query"))?;
+
+    let mut sockets = vec![send_query(
+        (Ipv4Addr::UNSPECIFIED, 0).into(),
+        (MDNS_ADDR_V4, MDNS_PORT).into(),
+        &query,
+    )?];
+    match send_query((Ipv6Addr::UNSPECIFIED, 0).into(), (MDNS_ADDR_V6, MDNS_PORT).into(), &query) {
+        Ok(socket) => sockets.push(socket),
+        Err(e) => debug!("couldn't send the IPv6 mDNS query, browsing IPv4 only: {}", e),
+    }
+
+    let mut responses = Responses::default();
+    let start = Instant::now();
+    let mut buf = [0; 9000];
+    while start.elapsed() < timeout {
+        for socket in &sockets {
+            match socket.recv_from(&mut buf) {
+                Ok((len, _)) => responses.add(&buf[..len]),
+                Err(ref e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {},
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    Ok(responses.into_accessories())
+}
+
+fn send_query(local_addr: SocketAddr, group_addr: SocketAddr, query: &[u8]) -> io::Result<UdpSocket> {
+    let socket = UdpSocket::bind(local_addr)?;
+    socket.set_read_timeout(Some(Duration::from_millis(50)))?;
+    socket.send_to(query, group_addr)?;
+    Ok(socket)
+}
+
+/// Collects the records of mDNS responses and assembles the accessories they advertise.
+#[derive(Default)]
+struct Responses {
+    instances: Vec<String>,
+    srv: HashMap<String, (String, u16)>,
+    txt: HashMap<String, HashMap<String, String>>,
+    hosts: HashMap<String, Vec<IpAddr>>,
+}
+
+impl Responses {
+    /// Adds the records of a response. Malformed responses are ignored.
+    fn add(&mut self, response: &[u8]) {
+        let packet = match Packet::parse(response) {
+            Ok(packet) => packet,
+            Err(_) => return,
+        };
+        for record in packet.answers.iter().chain(packet.additional.iter()) {
+            let name = record.name.to_string();
+            match record.data {
+                RData::PTR(ref ptr) if name == SERVICE_TYPE => {
+                    let instance = ptr.0.to_string();
+                    if !self.instances.contains(&instance) {
+                        self.instances.push(instance);
+                    }
+                },
+                RData::SRV(ref s) => {
+                    self.srv.insert(name, (s.target.to_string(), s.port));
+                },
+                RData::TXT(ref t) => {
+                    let entries = self.txt.entry(name).or_default();
+                    for entry in t.iter() {
+                        if let Ok(entry) = str::from_utf8(entry) {
+                            let mut kv = entry.splitn(2, '=');
+                            let key = kv.next().unwrap_or("").to_owned();
+                            let value = kv.next().unwrap_or("").to_owned();
+                            entries.insert(key, value);
+                        }
+                    }
+                },
+                RData::A(ref a) => self.push_address(name, IpAddr::V4(a.0)),
+                RData::AAAA(ref a) => self.push_address(name, IpAddr::V6(a.0)),
+                _ => {},
+            }
+        }
+    }
+
+    fn push_address(&mut self, name: String, ip: IpAddr) {
+        let addresses = self.hosts.entry(name).or_default();
+        if !addresses.contains(&ip) {
+            addresses.push(ip);
+        }
+    }
+
+    /// Returns the accessories whose service instance has been resolved to a port.
+    fn into_accessories(mut self) -> Vec<DiscoveredAccessory> {
+        let mut accessories = Vec::new();
+        for instance in self.instances {
+            let (target, port) = match self.srv.get(&instance) {
+                Some(s) => s.clone(),
+                None => continue,
+            };
+            let txt = self.txt.remove(&instance).unwrap_or_default();
+            let name = instance
+                .trim_end_matches(SERVICE_TYPE)
+                .trim_end_matches('.')
+                .to_owned();
+            accessories.push(DiscoveredAccessory {
+                name,
+                addresses: self.hosts.get(&target).cloned().unwrap_or_default(),
+                port,
+                id: txt.get("id").cloned(),
+                configuration_number: txt.get("c#").and_then(|c| c.parse().ok()),
+                category: txt.get("ci").and_then(|c| c.parse().ok()),
+                status_flag: txt.get("sf").and_then(|s| s.parse().ok()),
+                txt,
+            });
+        }
+        accessories
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A response of an unpaired accessory as sent by Bonjour, using name compression.
+    fn response() -> Vec<u8> {
+        [
+            // header: response, 1 answer, 4 additional records
+            &b"\x00\x00\x84\x00\x00\x00\x00\x01\x00\x00\x00\x04"[..],
+            // offset 12: PTR _hap._tcp.local -> Acme Lamp._hap._tcp.local (offset 39)
+            b"\x04_hap\x04_tcp\x05local\x00\x00\x0c\x00\x01\x00\x00\x11\x94\x00\x0c",
+            b"\x09Acme Lamp\xc0\x0c",
+            // offset 51: SRV Acme Lamp._hap._tcp.local -> Acme-Lamp.local (offset 69):51826
+            b"\xc0\x27\x00\x21\x80\x01\x00\x00\x00\x78\x00\x12\x00\x00\x00\x00\xca\x72",
+            b"\x09Acme-Lamp\xc0\x16",
+            // TXT Acme Lamp._hap._tcp.local
+            b"\xc0\x27\x00\x10\x80\x01\x00\x00\x11\x94\x00\x36",
+            b"\x04c#=2\x04ff=0\x14id=AA:BB:CC:DD:EE:FF\x07md=Lamp\x04s#=1\x04sf=1\x04ci=5",
+            // A Acme-Lamp.local -> 192.168.1.2
+            b"\xc0\x45\x00\x01\x80\x01\x00\x00\x00\x78\x00\x04\xc0\xa8\x01\x02",
+            // AAAA Acme-Lamp.local -> fe80::1
+            b"\xc0\x45\x00\x1c\x80\x01\x00\x00\x00\x78\x00\x10",
+            b"\xfe\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn canned_response_is_parsed() {
+        let mut responses = Responses::default();
+        responses.add(&response());
+        let accessories = responses.into_accessories();
+
+        assert_eq!(accessories.len(), 1);
+        let accessory = &accessories[0];
+        assert_eq!(accessory.name, "Acme Lamp");
+        assert_eq!(accessory.addresses, vec![
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+            IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)),
+        ]);
+        assert_eq!(accessory.port, 51826);
+        assert_eq!(accessory.socket_addrs()[0], "192.168.1.2:51826".parse().unwrap());
+        assert_eq!(accessory.id.as_deref(), Some("AA:BB:CC:DD:EE:FF"));
+        assert_eq!(accessory.configuration_number, Some(2));
+        assert_eq!(accessory.category, Some(5));
+        assert_eq!(accessory.status_flag, Some(1));
+        assert!(accessory.is_unpaired());
+        assert_eq!(accessory.txt.get("md").map(String::as_str), Some("Lamp"));
+    }
+
+    #[test]
+    fn repeated_responses_are_merged() {
+        let mut responses = Responses::default();
+        responses.add(&response());
+        responses.add(&response());
+
+        let accessories = responses.into_accessories();
+        assert_eq!(accessories.len(), 1);
+        assert_eq!(accessories[0].addresses.len(), 2);
+    }
+
+    #[test]
+    fn unresolved_and_malformed_responses_are_ignored() {
+        let mut responses = Responses::default();
+        let response = response();
+        // the PTR answer alone, without the SRV record resolving the instance
+        let mut ptr_only = response[..51].to_vec();
+        ptr_only[11] = 0;
+        responses.add(&ptr_only);
+        responses.add(&response[..60]);
+        assert!(responses.into_accessories().is_empty());
+    }
+}
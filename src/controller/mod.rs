@@ -0,0 +1,214 @@
+//! A client implementation of the HAP controller role.
+//!
+//! A `Controller` pairs with accessories, verifies existing pairings and opens encrypted
+//! `Session`s to them, through which the accessory database can be read, characteristics can be
+//! read and written, events can be subscribed to and pairings can be managed.
+
+use std::{collections::HashMap, net::SocketAddr, time::Duration};
+
+use hyper::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+use crate::{
+    crypto::SecretKey32,
+    pin::Pin,
+    protocol::{
+        tlv::{self, Encodable, Flag, Type},
+        Device,
+    },
+    Error,
+    ErrorKind,
+    Result,
+};
+
+mod connection;
+mod discovery;
+mod pair_setup;
+mod pair_verify;
+mod session;
+
+pub use self::{
+    discovery::{discover, DiscoveredAccessory},
+    session::{CharacteristicEvent, CharacteristicValue, Session, WriteStatus},
+};
+
+use self::connection::{Connection, Message};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An accessory the `Controller` is paired with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairedAccessory {
+    /// Pairing ID of the accessory.
+    pub id: String,
+    /// Long-term public key of the accessory.
+    pub public_key: [u8; 32],
+}
+
+/// Session ID and shared secret of a verified session, which can be used to resume the session
+/// with Pair Resume instead of running a full Pair Verify.
+#[derive(Clone)]
+pub struct ResumableSession {
+    /// ID the accessory cached the session under.
+    pub id: [u8; 8],
+    shared_secret: SecretKey32,
+}
+
+impl ResumableSession {
+    /// Creates a new `ResumableSession` from a session ID and shared secret, e.g. to resume a
+    /// session that was stored across restarts.
+    pub fn new(id: [u8; 8], shared_secret: [u8; 32]) -> ResumableSession {
+        ResumableSession {
+            id,
+            shared_secret: Zeroizing::new(shared_secret),
+        }
+    }
+
+    /// Returns the shared secret of the session.
+    pub fn shared_secret(&self) -> &[u8; 32] { &self.shared_secret }
+}
+
+/// MFi authentication data an accessory sends during Pair Setup with authentication. Verifying
+/// the certificate and the signature of the challenge is up to the caller.
+#[derive(Debug, Clone)]
+pub struct Authentication {
+    /// Challenge derived from the SRP shared secret, which the accessory signed.
+    pub challenge: Vec<u8>,
+    /// Signature of the challenge.
+    pub signature: Vec<u8>,
+    /// Certificate (or software token) of the accessory's authenticator.
+    pub certificate: Vec<u8>,
+}
+
+/// `Controller` holds the pairing identity of a HAP controller.
+pub struct Controller {
+    device: Device,
+}
+
+impl Controller {
+    /// Creates a new `Controller` with a given pairing ID and a random key pair.
+    pub fn new(id: Uuid) -> Controller {
+        Controller {
+            device: Device::new_random(id.to_hyphenated().to_string(), Pin::new()),
+        }
+    }
+
+    /// Creates a new `Controller` from an existing `Device`, e.g. to reuse a stored key pair.
+    pub fn from_device(device: Device) -> Controller { Controller { device } }
+
+    /// Returns the `Device` holding the pairing identity of the `Controller`.
+    pub fn device(&self) -> &Device { &self.device }
+
+    /// Pairs the `Controller` with the accessory at the given address using its setup code.
+    pub fn pair_setup(&self, addr: &SocketAddr, pin: &str) -> Result<PairedAccessory> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        pair_setup::pair_setup(&mut connection, &self.device, pin, 0)
+    }
+
+    /// Pairs the `Controller` with the accessory at the given address, reusing the SRP verifier the
+    /// accessory stored during the last transient split Pair Setup instead of generating a new one.
+    pub fn split_pair_setup(&self, addr: &SocketAddr, pin: &str) -> Result<PairedAccessory> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        pair_setup::pair_setup(&mut connection, &self.device, pin, Flag::Split as u32)
+    }
+
+    /// Runs transient Pair Setup with the accessory at the given address and opens an encrypted
+    /// `Session` to it without pairing. If `split` is set, the accessory stores the SRP verifier
+    /// for a later `split_pair_setup`.
+    pub fn transient_pair_setup(&self, addr: &SocketAddr, pin: &str, split: bool) -> Result<Session> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        let flags = if split { Flag::Split as u32 } else { 0 };
+        let shared_secret = pair_setup::transient_pair_setup(&mut connection, pin, flags)?;
+        connection.encrypt(&shared_secret);
+        Ok(Session::new(connection, None, false))
+    }
+
+    /// Pairs the `Controller` with the accessory at the given address using its setup code and
+    /// requests MFi authentication. Returns the `Authentication` the accessory sent along with the
+    /// pairing.
+    pub fn pair_setup_with_auth(&self, addr: &SocketAddr, pin: &str) -> Result<(PairedAccessory, Authentication)> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        pair_setup::pair_setup_with_auth(&mut connection, &self.device, pin)
+    }
+
+    /// Verifies the pairing with the accessory at the given address and opens an encrypted
+    /// `Session` to it.
+    pub fn connect(&self, addr: &SocketAddr, accessory: &PairedAccessory) -> Result<Session> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        let shared_secret = pair_verify::pair_verify(&mut connection, &self.device, accessory)?;
+        connection.encrypt(&*shared_secret);
+        let resumable = ResumableSession {
+            id: pair_verify::session_id(&*shared_secret),
+            shared_secret,
+        };
+        Ok(Session::new(connection, Some(resumable), false))
+    }
+
+    /// Resumes a previously verified session with the accessory at the given address and opens an
+    /// encrypted `Session` to it. Falls back to a full Pair Verify if the accessory doesn't resume
+    /// the session, e.g. because it has evicted it from its cache.
+    pub fn resume(
+        &self,
+        addr: &SocketAddr,
+        accessory: &PairedAccessory,
+        session: &ResumableSession,
+    ) -> Result<Session> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        let (shared_secret, session_id) =
+            pair_verify::pair_resume(&mut connection, &self.device, accessory, session)?;
+        connection.encrypt(&*shared_secret);
+        let resumable = ResumableSession {
+            id: session_id.unwrap_or_else(|| pair_verify::session_id(&*shared_secret)),
+            shared_secret,
+        };
+        Ok(Session::new(connection, Some(resumable), session_id.is_some()))
+    }
+
+    /// Triggers the identify routine of an unpaired accessory at the given address.
+    pub fn identify(&self, addr: &SocketAddr) -> Result<()> {
+        let mut connection = Connection::connect(addr, CONNECT_TIMEOUT)?;
+        let res = connection.request("POST", "/identify", None, &[], &mut Vec::new())?;
+        if res.status != StatusCode::NO_CONTENT.as_u16() {
+            return Err(http_status(res.status));
+        }
+        Ok(())
+    }
+}
+
+/// Sends a TLV8 request to a pairing endpoint and returns the decoded response. Events received
+/// in the meantime are queued in `events`. A TLV8 error in the response is returned as an `Error`.
+pub(crate) fn tlv_request(
+    connection: &mut Connection,
+    path: &str,
+    container: tlv::Container,
+    events: &mut Vec<Message>,
+) -> Result<HashMap<u8, Vec<u8>>> {
+    let res = connection.request(
+        "POST",
+        path,
+        Some("application/pairing+tlv8"),
+        &container.encode(),
+        events,
+    )?;
+    if res.status != StatusCode::OK.as_u16() {
+        return Err(http_status(res.status));
+    }
+
+    let decoded = tlv::decode(&res.body)?;
+    if let Some(err) = decoded.get(&(Type::Error as u8)) {
+        let code = err.first().cloned().unwrap_or(0x01);
+        return Err(tlv::Error::from_u8(code).into());
+    }
+
+    Ok(decoded)
+}
+
+/// Converts an unexpected HTTP status code into an `Error`.
+pub(crate) fn http_status(status: u16) -> Error {
+    match StatusCode::from_u16(status) {
+        Ok(status) => ErrorKind::HttpStatus(status).into(),
+        Err(_) => Error::from_str("invalid HTTP status code"),
+    }
+}
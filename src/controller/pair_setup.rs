@@ -0,0 +1,242 @@
+use std::{collections::HashMap, ops::BitXor, str};
+
+use log::debug;
+use num::BigUint;
+use rand::{self, distributions::Standard, Rng};
+use sha2::{Digest, Sha512};
+use srp::{
+    client::{srp_private_key, SrpClient},
+    groups::G_3072,
+    types::SrpGroup,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    controller::{connection::Connection, tlv_request, Authentication, PairedAccessory},
+    protocol::{
+        tlv::{self, Encodable, Flag, Method, Type, Value},
+        Device,
+    },
+    crypto::{self, Backend, Crypto, SecretBytes},
+    Error,
+    Result,
+};
+
+/// Runs Pair Setup (M1 - M6) with an accessory using the given flags and returns the exchanged
+/// long-term public key.
+pub(crate) fn pair_setup(
+    connection: &mut Connection,
+    device: &Device,
+    pin: &str,
+    flags: u32,
+) -> Result<PairedAccessory> {
+    let (shared_secret, _) = start_and_verify(connection, pin, Method::PairSetup, flags)?;
+    exchange(connection, device, &shared_secret)
+}
+
+/// Runs transient Pair Setup (M1 - M4) with an accessory using the given flags and returns the
+/// shared secret of the session it establishes.
+pub(crate) fn transient_pair_setup(connection: &mut Connection, pin: &str, flags: u32) -> Result<SecretBytes> {
+    let (shared_secret, _) = start_and_verify(connection, pin, Method::PairSetup, flags | Flag::Transient as u32)?;
+    Ok(shared_secret)
+}
+
+/// Runs Pair Setup with MFi authentication (M1 - M6) with an accessory and returns the exchanged
+/// long-term public key along with the `Authentication` the accessory sent in M4.
+pub(crate) fn pair_setup_with_auth(
+    connection: &mut Connection,
+    device: &Device,
+    pin: &str,
+) -> Result<(PairedAccessory, Authentication)> {
+    let (shared_secret, mut res) = start_and_verify(connection, pin, Method::PairSetupWithAuth, 0)?;
+
+    let data = res
+        .remove(&(Type::EncryptedData as u8))
+        .ok_or(Error::from_str("missing encrypted data"))?;
+    let encryption_key = crypto::derive_key(&shared_secret, b"Pair-Setup-Encrypt-Salt", b"Pair-Setup-Encrypt-Info");
+    let decrypted_data = Backend::open(&encryption_key, &crypto::nonce(b"PS-Msg04"), &[], &data)?;
+
+    let mut sub_tlv = tlv::decode(&decrypted_data)?;
+    let signature = sub_tlv
+        .remove(&(Type::Signature as u8))
+        .ok_or(Error::from_str("missing signature"))?;
+    let certificate = sub_tlv
+        .remove(&(Type::Certificate as u8))
+        .ok_or(Error::from_str("missing certificate"))?;
+    let challenge = crypto::derive_key(&shared_secret, b"MFi-Pair-Setup-Salt", b"MFi-Pair-Setup-Info");
+    let authentication = Authentication {
+        challenge: challenge.to_vec(),
+        signature,
+        certificate,
+    };
+
+    let accessory = exchange(connection, device, &shared_secret)?;
+
+    Ok((accessory, authentication))
+}
+
+/// Runs the SRP part of Pair Setup (M1 - M4) and returns the shared secret along with the decoded
+/// M4 response.
+fn start_and_verify(
+    connection: &mut Connection,
+    pin: &str,
+    method: Method,
+    flags: u32,
+) -> Result<(SecretBytes, HashMap<u8, Vec<u8>>)> {
+    debug!("M1: Sending SRP Start Request");
+
+    let mut req = vec![Value::State(1), Value::Method(method)];
+    if flags != 0 {
+        req.push(Value::Flags(flags));
+    }
+    let mut res = tlv_request(connection, "/pair-setup", req, &mut Vec::new())?;
+
+    debug!("M2: Got SRP Start Response");
+
+    let salt = res.remove(&(Type::Salt as u8)).ok_or(Error::from_str("missing salt"))?;
+    let b_pub = res
+        .remove(&(Type::PublicKey as u8))
+        .ok_or(Error::from_str("missing public key"))?;
+
+    let mut rng = rand::thread_rng();
+    let a = Zeroizing::new(rng.sample_iter::<u8, Standard>(&Standard).take(64).collect::<Vec<u8>>());
+    let srp_client = SrpClient::<Sha512>::new(&a, &G_3072);
+    let a_pub = srp_client.get_a_pub();
+    let private_key = srp_private_key::<Sha512>(b"Pair-Setup", format_pin(pin).as_bytes(), &salt);
+    let verifier = srp_client
+        .process_reply(&private_key, &b_pub)
+        .map_err(|_| Error::from_str("invalid SRP public key"))?;
+    let shared_secret = Zeroizing::new(verifier.get_key().as_slice().to_vec());
+    let a_proof = client_proof::<Sha512>(&b_pub, &a_pub, &salt, &shared_secret, &G_3072);
+
+    debug!("M3: Sending SRP Verify Request");
+
+    let mut res = tlv_request(connection, "/pair-setup", vec![
+        Value::State(3),
+        Value::PublicKey(a_pub.clone()),
+        Value::Proof(a_proof.clone()),
+    ], &mut Vec::new())?;
+
+    debug!("M4: Got SRP Verify Response");
+
+    let b_proof = res.remove(&(Type::Proof as u8)).ok_or(Error::from_str("missing proof"))?;
+    let mut d = Sha512::new();
+    d.input(&a_pub);
+    d.input(&a_proof);
+    d.input(&*shared_secret);
+    if !crypto::ct_eq(d.result().as_slice(), &b_proof) {
+        return Err(tlv::Error::Authentication.into());
+    }
+
+    Ok((shared_secret, res))
+}
+
+/// Exchanges the long-term public keys with the accessory (M5 - M6).
+fn exchange(connection: &mut Connection, device: &Device, shared_secret: &[u8]) -> Result<PairedAccessory> {
+    let encryption_key = crypto::derive_key(shared_secret, b"Pair-Setup-Encrypt-Salt", b"Pair-Setup-Encrypt-Info");
+    let device_x = crypto::derive_key(
+        shared_secret,
+        b"Pair-Setup-Controller-Sign-Salt",
+        b"Pair-Setup-Controller-Sign-Info",
+    );
+
+    let mut device_info: Vec<u8> = Vec::new();
+    device_info.extend(&*device_x);
+    device_info.extend(device.id.as_bytes());
+    device_info.extend(&device.public_key);
+    let device_signature = Backend::ed25519_sign(&device.private_key, &device_info)?;
+
+    let encoded_sub_tlv = vec![
+        Value::Identifier(device.id.clone()),
+        Value::PublicKey(device.public_key.to_vec()),
+        Value::Signature(device_signature.to_vec()),
+    ]
+    .encode();
+
+    let encrypted_data = Backend::seal(&encryption_key, &crypto::nonce(b"PS-Msg05"), &[], &encoded_sub_tlv);
+
+    debug!("M5: Sending SRP Exchange Request");
+
+    let mut res = tlv_request(connection, "/pair-setup", vec![
+        Value::State(5),
+        Value::EncryptedData(encrypted_data),
+    ], &mut Vec::new())?;
+
+    debug!("M6: Got SRP Exchange Response");
+
+    let data = res
+        .remove(&(Type::EncryptedData as u8))
+        .ok_or(Error::from_str("missing encrypted data"))?;
+    let decrypted_data = Backend::open(&encryption_key, &crypto::nonce(b"PS-Msg06"), &[], &data)?;
+
+    let sub_tlv = tlv::decode(&decrypted_data)?;
+    let accessory_pairing_id = sub_tlv
+        .get(&(Type::Identifier as u8))
+        .ok_or(Error::from_str("missing identifier"))?;
+    let accessory_ltpk = sub_tlv
+        .get(&(Type::PublicKey as u8))
+        .ok_or(Error::from_str("missing public key"))?;
+    let accessory_signature = sub_tlv
+        .get(&(Type::Signature as u8))
+        .ok_or(Error::from_str("missing signature"))?;
+    if accessory_ltpk.len() != 32 || accessory_signature.len() != 64 {
+        return Err(Error::from_str("invalid public key or signature"));
+    }
+
+    let accessory_x = crypto::derive_key(
+        shared_secret,
+        b"Pair-Setup-Accessory-Sign-Salt",
+        b"Pair-Setup-Accessory-Sign-Info",
+    );
+
+    let mut accessory_info: Vec<u8> = Vec::new();
+    accessory_info.extend(&*accessory_x);
+    accessory_info.extend(accessory_pairing_id);
+    accessory_info.extend(accessory_ltpk);
+    if !Backend::ed25519_verify(accessory_ltpk, &accessory_info, accessory_signature) {
+        return Err(tlv::Error::Authentication.into());
+    }
+
+    let mut public_key = [0; 32];
+    public_key.copy_from_slice(accessory_ltpk);
+
+    Ok(PairedAccessory {
+        id: str::from_utf8(accessory_pairing_id)?.to_owned(),
+        public_key,
+    })
+}
+
+/// Accepts setup codes both as `"11122333"` and `"111-22-333"`.
+fn format_pin(pin: &str) -> String {
+    if pin.len() == 8 && pin.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}-{}-{}", &pin[..3], &pin[3..5], &pin[5..])
+    } else {
+        pin.to_owned()
+    }
+}
+
+fn client_proof<D: Digest>(b_pub: &[u8], a_pub: &[u8], salt: &[u8], key: &[u8], group: &SrpGroup) -> Vec<u8> {
+    let mut dhn = D::new();
+    dhn.input(group.n.to_bytes_be());
+    let hn = BigUint::from_bytes_be(&dhn.result());
+
+    let mut dhg = D::new();
+    dhg.input(group.g.to_bytes_be());
+    let hg = BigUint::from_bytes_be(&dhg.result());
+
+    let hng = hn.bitxor(hg);
+
+    let mut dhi = D::new();
+    dhi.input(b"Pair-Setup");
+    let hi = dhi.result();
+
+    // M = H(H(N) xor H(g), H(I), s, A, B, K)
+    let mut d = D::new();
+    d.input(hng.to_bytes_be());
+    d.input(&hi);
+    d.input(salt);
+    d.input(a_pub);
+    d.input(b_pub);
+    d.input(key);
+    d.result().as_slice().to_vec()
+}
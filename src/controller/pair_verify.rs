@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use log::debug;
+
+use crate::{
+    controller::{connection::Connection, tlv_request, PairedAccessory, ResumableSession},
+    protocol::{
+        tlv::{self, Encodable, Method, Type, Value},
+        Device,
+    },
+    crypto::{self, Backend, Crypto, SecretKey32},
+    Error,
+    Result,
+};
+
+/// Runs Pair Verify (M1 - M4) with a paired accessory and returns the shared secret of the
+/// session.
+pub(crate) fn pair_verify(
+    connection: &mut Connection,
+    device: &Device,
+    accessory: &PairedAccessory,
+) -> Result<SecretKey32> {
+    debug!("M1: Sending Verify Start Request");
+
+    let (a, a_pub) = Backend::x25519_key_pair();
+
+    let res = tlv_request(connection, "/pair-verify", vec![
+        Value::State(1),
+        Value::PublicKey(a_pub.to_vec()),
+    ], &mut Vec::new())?;
+
+    finish(connection, device, accessory, &a, &a_pub, res)
+}
+
+/// Runs Pair Resume (M1 - M2) to resume a previously verified session with an accessory. If the
+/// accessory doesn't resume the session, e.g. because it doesn't know the session ID anymore, it
+/// falls back to a full Pair Verify. Returns the shared secret of the new session along with its
+/// session ID if it was resumed.
+pub(crate) fn pair_resume(
+    connection: &mut Connection,
+    device: &Device,
+    accessory: &PairedAccessory,
+    session: &ResumableSession,
+) -> Result<(SecretKey32, Option<[u8; 8]>)> {
+    debug!("M1: Sending Resume Request");
+
+    let (a, a_pub) = Backend::x25519_key_pair();
+
+    let mut salt = a_pub.to_vec();
+    salt.extend(&session.id);
+    let request_key = crypto::derive_key(session.shared_secret(), &salt, b"Pair-Resume-Request-Info");
+    let encrypted_data = Backend::seal(&request_key, &crypto::nonce(b"PR-Msg01"), &[], &[]);
+
+    let mut res = tlv_request(connection, "/pair-verify", vec![
+        Value::State(1),
+        Value::Method(Method::PairResume),
+        Value::PublicKey(a_pub.to_vec()),
+        Value::SessionId(session.id.to_vec()),
+        Value::EncryptedData(encrypted_data),
+    ], &mut Vec::new())?;
+
+    if res.get(&(Type::Method as u8)).and_then(|m| m.first()) != Some(&(Method::PairResume as u8)) {
+        debug!("Session wasn't resumed, continuing with full Pair Verify");
+        let shared_secret = finish(connection, device, accessory, &a, &a_pub, res)?;
+        return Ok((shared_secret, None));
+    }
+
+    debug!("M2: Got Resume Response");
+
+    let session_id = res
+        .remove(&(Type::SessionId as u8))
+        .ok_or(Error::from_str("missing session ID"))?;
+    let data = res
+        .remove(&(Type::EncryptedData as u8))
+        .ok_or(Error::from_str("missing encrypted data"))?;
+    if session_id.len() != 8 {
+        return Err(Error::from_str("invalid session ID"));
+    }
+    let mut new_session_id = [0; 8];
+    new_session_id.copy_from_slice(&session_id);
+
+    let mut salt = a_pub.to_vec();
+    salt.extend(&new_session_id);
+    let response_key = crypto::derive_key(session.shared_secret(), &salt, b"Pair-Resume-Response-Info");
+    Backend::open(&response_key, &crypto::nonce(b"PR-Msg02"), &[], &data)?;
+    let shared_secret = crypto::derive_key(session.shared_secret(), &salt, b"Pair-Resume-Shared-Secret-Info");
+
+    Ok((shared_secret, Some(new_session_id)))
+}
+
+/// Derives the ID under which an accessory caches a session established by Pair Verify.
+pub(crate) fn session_id(shared_secret: &[u8]) -> [u8; 8] {
+    let mut session_id = [0; 8];
+    Backend::hkdf_sha512(
+        shared_secret,
+        b"Pair-Verify-ResumeSessionID-Salt",
+        b"Pair-Verify-ResumeSessionID-Info",
+        &mut session_id,
+    );
+    session_id
+}
+
+/// Verifies the Verify Start Response (M2) and finishes Pair Verify (M3 - M4).
+fn finish(
+    connection: &mut Connection,
+    device: &Device,
+    accessory: &PairedAccessory,
+    a: &[u8; 32],
+    a_pub: &[u8; 32],
+    mut res: HashMap<u8, Vec<u8>>,
+) -> Result<SecretKey32> {
+    debug!("M2: Got Verify Start Response");
+
+    let b_pub = res
+        .remove(&(Type::PublicKey as u8))
+        .ok_or(Error::from_str("missing public key"))?;
+    let data = res
+        .remove(&(Type::EncryptedData as u8))
+        .ok_or(Error::from_str("missing encrypted data"))?;
+    let shared_secret =
+        Backend::x25519(a, &b_pub).map_err(|_| Error::from_str("invalid Verify Start Response"))?;
+
+    let session_key = crypto::derive_key(&*shared_secret, b"Pair-Verify-Encrypt-Salt", b"Pair-Verify-Encrypt-Info");
+    let decrypted_data = Backend::open(&session_key, &crypto::nonce(b"PV-Msg02"), &[], &data)?;
+
+    let sub_tlv = tlv::decode(&decrypted_data)?;
+    let accessory_pairing_id = sub_tlv
+        .get(&(Type::Identifier as u8))
+        .ok_or(Error::from_str("missing identifier"))?;
+    let accessory_signature = sub_tlv
+        .get(&(Type::Signature as u8))
+        .ok_or(Error::from_str("missing signature"))?;
+    if !crypto::ct_eq(accessory_pairing_id, accessory.id.as_bytes()) {
+        return Err(Error::from_str("unknown accessory pairing ID"));
+    }
+    if accessory_signature.len() != 64 {
+        return Err(Error::from_str("invalid signature"));
+    }
+
+    let mut accessory_info: Vec<u8> = Vec::new();
+    accessory_info.extend(&b_pub);
+    accessory_info.extend(accessory_pairing_id);
+    accessory_info.extend(a_pub);
+    if !Backend::ed25519_verify(&accessory.public_key, &accessory_info, accessory_signature) {
+        return Err(tlv::Error::Authentication.into());
+    }
+
+    let mut device_info: Vec<u8> = Vec::new();
+    device_info.extend(a_pub);
+    device_info.extend(device.id.as_bytes());
+    device_info.extend(&b_pub);
+    let device_signature = Backend::ed25519_sign(&device.private_key, &device_info)?;
+
+    let encoded_sub_tlv = vec![
+        Value::Identifier(device.id.clone()),
+        Value::Signature(device_signature.to_vec()),
+    ]
+    .encode();
+
+    let encrypted_data = Backend::seal(&session_key, &crypto::nonce(b"PV-Msg03"), &[], &encoded_sub_tlv);
+
+    debug!("M3: Sending Verify Finish Request");
+
+    tlv_request(connection, "/pair-verify", vec![
+        Value::State(3),
+        Value::EncryptedData(encrypted_data),
+    ], &mut Vec::new())?;
+
+    debug!("M4: Got Verify Finish Response");
+
+    Ok(shared_secret)
+}
@@ -0,0 +1,313 @@
+use std::{collections::HashMap, str, time::Duration};
+
+use hyper::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+use serde_json;
+use uuid::Uuid;
+
+use crate::{
+    controller::{
+        connection::{Connection, Message},
+        http_status,
+        tlv_request,
+        ResumableSession,
+    },
+    protocol::{
+        tlv::{self, Encodable, Method, Type, Value},
+        Pairing,
+        Permissions,
+    },
+    Error,
+    ErrorKind,
+    Result,
+};
+
+/// The value of a characteristic as reported by an accessory.
+#[derive(Debug, Deserialize)]
+pub struct CharacteristicValue {
+    pub aid: u64,
+    pub iid: u64,
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    #[serde(default)]
+    pub status: Option<i32>,
+}
+
+/// A characteristic value change pushed by an accessory.
+#[derive(Debug, Deserialize)]
+pub struct CharacteristicEvent {
+    pub aid: u64,
+    pub iid: u64,
+    pub value: serde_json::Value,
+}
+
+/// The status of a single characteristic write as reported by an accessory, along with the value
+/// of the characteristic if a write response was requested.
+#[derive(Debug, Deserialize)]
+pub struct WriteStatus {
+    pub aid: u64,
+    pub iid: u64,
+    pub status: i32,
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct CharacteristicList<T> {
+    characteristics: Vec<T>,
+}
+
+#[derive(Serialize)]
+struct WriteObject {
+    aid: u64,
+    iid: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ev: Option<bool>,
+    #[serde(rename = "r", skip_serializing_if = "Option::is_none")]
+    response: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct WriteBody {
+    characteristics: Vec<WriteObject>,
+}
+
+/// `Session` is an encrypted connection to a paired accessory.
+pub struct Session {
+    connection: Connection,
+    events: Vec<Message>,
+    resumable: Option<ResumableSession>,
+    resumed: bool,
+}
+
+impl Session {
+    pub(crate) fn new(connection: Connection, resumable: Option<ResumableSession>, resumed: bool) -> Session {
+        Session {
+            connection,
+            events: Vec::new(),
+            resumable,
+            resumed,
+        }
+    }
+
+    /// Returns the `ResumableSession` to resume the session with `Controller::resume`, if it can be
+    /// resumed. Sessions established by transient Pair Setup can't.
+    pub fn resumable(&self) -> Option<&ResumableSession> { self.resumable.as_ref() }
+
+    /// Returns whether the session was established by Pair Resume rather than a full Pair Verify.
+    pub fn is_resumed(&self) -> bool { self.resumed }
+
+    /// Fetches the accessory database of the accessory.
+    pub fn get_accessories(&mut self) -> Result<serde_json::Value> {
+        let res = self.request("GET", "/accessories", None, &[])?;
+        check_status(&res, StatusCode::OK)?;
+        Ok(serde_json::from_slice(&res.body)?)
+    }
+
+    /// Reads the values of the characteristics with the given `(aid, iid)` pairs.
+    pub fn read_characteristics(&mut self, ids: &[(u64, u64)]) -> Result<Vec<CharacteristicValue>> {
+        let query = ids
+            .iter()
+            .map(|(aid, iid)| format!("{}.{}", aid, iid))
+            .collect::<Vec<String>>()
+            .join(",");
+        let res = self.request("GET", &format!("/characteristics?id={}", query), None, &[])?;
+        if res.status != StatusCode::OK.as_u16() && res.status != StatusCode::MULTI_STATUS.as_u16() {
+            return Err(http_status(res.status));
+        }
+        let list: CharacteristicList<CharacteristicValue> = serde_json::from_slice(&res.body)?;
+        Ok(list.characteristics)
+    }
+
+    /// Writes the given values to the characteristics with the given `(aid, iid)` pairs. Returns
+    /// the per-characteristic status if the accessory reports a partial failure.
+    pub fn write_characteristics(&mut self, values: Vec<(u64, u64, serde_json::Value)>) -> Result<Vec<WriteStatus>> {
+        let body = WriteBody {
+            characteristics: values
+                .into_iter()
+                .map(|(aid, iid, value)| WriteObject {
+                    aid,
+                    iid,
+                    value: Some(value),
+                    ev: None,
+                    response: None,
+                })
+                .collect(),
+        };
+        self.write(&body)
+    }
+
+    /// Writes the given value to the characteristic with the given `aid` and `iid` and requests
+    /// the value of the characteristic after the write as a write response.
+    pub fn write_characteristic_with_response(
+        &mut self,
+        aid: u64,
+        iid: u64,
+        value: serde_json::Value,
+    ) -> Result<WriteStatus> {
+        let body = WriteBody {
+            characteristics: vec![WriteObject {
+                aid,
+                iid,
+                value: Some(value),
+                ev: None,
+                response: Some(true),
+            }],
+        };
+        self.write(&body)?
+            .pop()
+            .ok_or(Error::from_str("missing write response"))
+    }
+
+    /// Enables or disables event notifications for the characteristic with the given `aid` and
+    /// `iid`.
+    pub fn subscribe(&mut self, aid: u64, iid: u64, enable: bool) -> Result<Vec<WriteStatus>> {
+        let body = WriteBody {
+            characteristics: vec![WriteObject {
+                aid,
+                iid,
+                value: None,
+                ev: Some(enable),
+                response: None,
+            }],
+        };
+        self.write(&body)
+    }
+
+    /// Waits for the next event message of the accessory. Returns `None` if no event arrived
+    /// within the given timeout.
+    pub fn next_event(&mut self, timeout: Duration) -> Result<Option<Vec<CharacteristicEvent>>> {
+        let message = match self.events.is_empty() {
+            false => self.events.remove(0),
+            true => {
+                self.connection.set_read_timeout(Some(timeout))?;
+                let res = self.connection.read_message();
+                self.connection.set_read_timeout(None)?;
+                match res {
+                    Ok(message) => message,
+                    Err(err) => match err.kind() {
+                        ErrorKind::Io(ref e)
+                            if e.kind() == std::io::ErrorKind::WouldBlock
+                                || e.kind() == std::io::ErrorKind::TimedOut =>
+                            return Ok(None),
+                        _ => return Err(err),
+                    },
+                }
+            },
+        };
+        if !message.event {
+            return Err(Error::from_str("unexpected response"));
+        }
+        let list: CharacteristicList<CharacteristicEvent> = serde_json::from_slice(&message.body)?;
+        Ok(Some(list.characteristics))
+    }
+
+    /// Triggers the identify routine of the accessory.
+    pub fn identify(&mut self, aid: u64, iid: u64) -> Result<Vec<WriteStatus>> {
+        self.write_characteristics(vec![(aid, iid, serde_json::Value::Bool(true))])
+    }
+
+    /// Adds a pairing to the accessory. Requires admin permissions.
+    pub fn add_pairing(&mut self, pairing: &Pairing) -> Result<()> {
+        tlv_request(&mut self.connection, "/pairings", vec![
+            Value::State(1),
+            Value::Method(Method::AddPairing),
+            Value::Identifier(pairing.id.to_hyphenated().to_string()),
+            Value::PublicKey(pairing.public_key.to_vec()),
+            Value::Permissions(pairing.permissions.clone()),
+        ], &mut self.events)?;
+        Ok(())
+    }
+
+    /// Removes a pairing from the accessory. Requires admin permissions.
+    pub fn remove_pairing(&mut self, id: Uuid) -> Result<()> {
+        tlv_request(&mut self.connection, "/pairings", vec![
+            Value::State(1),
+            Value::Method(Method::RemovePairing),
+            Value::Identifier(id.to_hyphenated().to_string()),
+        ], &mut self.events)?;
+        Ok(())
+    }
+
+    /// Lists the pairings of the accessory. Requires admin permissions.
+    pub fn list_pairings(&mut self) -> Result<Vec<Pairing>> {
+        let body = self.tlv_body("/pairings", vec![Value::State(1), Value::Method(Method::ListPairings)])?;
+
+        let mut pairings = Vec::new();
+        for mut item in tlv::decode_list(&body)? {
+            if let Some(err) = item.get(&(Type::Error as u8)) {
+                return Err(tlv::Error::from_u8(err.first().cloned().unwrap_or(0x01)).into());
+            }
+            item.remove(&(Type::State as u8));
+            if item.is_empty() {
+                continue;
+            }
+            let id = item
+                .get(&(Type::Identifier as u8))
+                .ok_or(Error::from_str("missing identifier"))?;
+            let public_key = item
+                .get(&(Type::PublicKey as u8))
+                .ok_or(Error::from_str("missing public key"))?;
+            let permissions = item
+                .get(&(Type::Permissions as u8))
+                .and_then(|p| p.first())
+                .ok_or(Error::from_str("missing permissions"))?;
+            if public_key.len() != 32 {
+                return Err(Error::from_str("invalid public key"));
+            }
+            let mut key = [0; 32];
+            key.copy_from_slice(public_key);
+            pairings.push(Pairing::new(
+                Uuid::parse_str(str::from_utf8(id)?).map_err(|_| Error::from_str("invalid pairing ID"))?,
+                Permissions::from_u8(*permissions)?,
+                key,
+            ));
+        }
+
+        Ok(pairings)
+    }
+
+    /// Sends a TLV8 request to a pairing endpoint of the accessory, e.g. `/pair-setup`, and returns
+    /// the decoded response. A TLV8 error in the response is returned as an `Error`.
+    pub fn tlv_request(&mut self, path: &str, container: tlv::Container) -> Result<HashMap<u8, Vec<u8>>> {
+        tlv_request(&mut self.connection, path, container, &mut self.events)
+    }
+
+    fn tlv_body(&mut self, path: &str, container: Vec<Value>) -> Result<Vec<u8>> {
+        let res = self.request(
+            "POST",
+            path,
+            Some("application/pairing+tlv8"),
+            &container.encode(),
+        )?;
+        check_status(&res, StatusCode::OK)?;
+        Ok(res.body)
+    }
+
+    fn write(&mut self, body: &WriteBody) -> Result<Vec<WriteStatus>> {
+        let body = serde_json::to_vec(body)?;
+        let res = self.request("PUT", "/characteristics", Some("application/hap+json"), &body)?;
+        match StatusCode::from_u16(res.status) {
+            Ok(StatusCode::NO_CONTENT) => Ok(Vec::new()),
+            Ok(StatusCode::MULTI_STATUS) => {
+                let list: CharacteristicList<WriteStatus> = serde_json::from_slice(&res.body)?;
+                Ok(list.characteristics)
+            },
+            _ => Err(http_status(res.status)),
+        }
+    }
+
+    fn request(&mut self, method: &str, path: &str, content_type: Option<&str>, body: &[u8]) -> Result<Message> {
+        self.connection
+            .request(method, path, content_type, body, &mut self.events)
+    }
+}
+
+fn check_status(res: &Message, expected: StatusCode) -> Result<()> {
+    if res.status != expected.as_u16() {
+        return Err(http_status(res.status));
+    }
+    Ok(())
+}
@@ -0,0 +1,316 @@
+//! Cryptographic primitives of the HAP protocol.
+//!
+//! Pair Setup, Pair Verify and the encrypted session access all primitives through the `Crypto`
+//! trait, so that the crates implementing them can be swapped in a single place. `Backend` is the
+//! implementation in use, built on the RustCrypto and dalek crates.
+
+use std::convert::TryFrom;
+
+use chacha20poly1305::{
+    aead::{AeadInPlace, NewAead},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+    Tag,
+};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
+use hkdf::Hkdf;
+use rand::{self, Rng};
+use sha2::Sha512;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// A secret of fixed length that is zeroed when it's dropped.
+pub(crate) type SecretKey32 = Zeroizing<[u8; 32]>;
+
+/// A secret of variable length that is zeroed when it's dropped.
+pub(crate) type SecretBytes = Zeroizing<Vec<u8>>;
+
+/// Length of a ChaCha20-Poly1305 authentication tag.
+pub(crate) const TAG_LEN: usize = 16;
+
+/// Error returned when encrypted data or a key exchange fails to authenticate.
+#[derive(Debug)]
+pub struct DecryptionError;
+
+/// Error returned when a private key can't be used for signing.
+#[derive(Debug)]
+pub struct InvalidKeyError;
+
+/// `Crypto` is implemented by the providers of the cryptographic primitives used by HAP.
+pub(crate) trait Crypto {
+    /// Derives `okm.len()` Bytes of key material with HKDF-SHA-512.
+    fn hkdf_sha512(ikm: &[u8], salt: &[u8], info: &[u8], okm: &mut [u8]);
+
+    /// Encrypts `data` with ChaCha20-Poly1305 and returns the ciphertext followed by the
+    /// authentication tag.
+    fn seal(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], data: &[u8]) -> Vec<u8>;
+
+    /// Authenticates and decrypts a ciphertext followed by its ChaCha20-Poly1305 authentication
+    /// tag.
+    fn open(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], data: &[u8]) -> Result<Vec<u8>, DecryptionError>;
+
+    /// Generates a new X25519 key pair and returns the secret and the public key.
+    fn x25519_key_pair() -> (SecretKey32, [u8; 32]);
+
+    /// Computes the X25519 shared secret of a secret and a peer's public key. Public keys of low
+    /// order, which would result in a predictable shared secret, are rejected.
+    fn x25519(secret: &[u8; 32], public_key: &[u8]) -> Result<SecretKey32, DecryptionError>;
+
+    /// Derives the Ed25519 key pair of a 32 Byte seed and returns the 64 Byte private key,
+    /// consisting of the seed followed by the public key, and the public key.
+    fn ed25519_key_pair(seed: &[u8; 32]) -> ([u8; 64], [u8; 32]);
+
+    /// Signs a message with a 64 Byte Ed25519 private key. Private keys whose public half doesn't
+    /// belong to the seed are rejected, since signing with them would leak the seed.
+    fn ed25519_sign(private_key: &[u8; 64], message: &[u8]) -> Result<[u8; 64], InvalidKeyError>;
+
+    /// Verifies the Ed25519 signature of a message. Malformed keys and signatures don't verify.
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// The `Crypto` implementation in use.
+pub(crate) struct Backend;
+
+impl Crypto for Backend {
+    fn hkdf_sha512(ikm: &[u8], salt: &[u8], info: &[u8], okm: &mut [u8]) {
+        Hkdf::<Sha512>::new(Some(salt), ikm)
+            .expand(info, okm)
+            .expect("HKDF output length is always valid for 32 Byte keys");
+    }
+
+    fn seal(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], data: &[u8]) -> Vec<u8> {
+        let cipher = ChaCha20Poly1305::new(&Key::from(*key));
+        let mut buffer = data.to_vec();
+        let tag = cipher
+            .encrypt_in_place_detached(&Nonce::from(*nonce), aad, &mut buffer)
+            .expect("HAP messages are always shorter than the ChaCha20 limit");
+        buffer.extend_from_slice(&tag);
+        buffer
+    }
+
+    fn open(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], data: &[u8]) -> Result<Vec<u8>, DecryptionError> {
+        if data.len() < TAG_LEN {
+            return Err(DecryptionError);
+        }
+        let (ciphertext, tag) = data.split_at(data.len() - TAG_LEN);
+        let tag = <[u8; TAG_LEN]>::try_from(tag).map_err(|_| DecryptionError)?;
+        let cipher = ChaCha20Poly1305::new(&Key::from(*key));
+        let mut buffer = ciphertext.to_vec();
+        cipher
+            .decrypt_in_place_detached(&Nonce::from(*nonce), aad, &mut buffer, &Tag::from(tag))
+            .map_err(|_| DecryptionError)?;
+        Ok(buffer)
+    }
+
+    fn x25519_key_pair() -> (SecretKey32, [u8; 32]) {
+        let secret = x25519_dalek::StaticSecret::from(rand::thread_rng().gen::<[u8; 32]>());
+        let public_key = x25519_dalek::PublicKey::from(&secret);
+        (Zeroizing::new(secret.to_bytes()), *public_key.as_bytes())
+    }
+
+    fn x25519(secret: &[u8; 32], public_key: &[u8]) -> Result<SecretKey32, DecryptionError> {
+        let public_key = <[u8; 32]>::try_from(public_key).map_err(|_| DecryptionError)?;
+        let secret = x25519_dalek::StaticSecret::from(*secret);
+        let shared_secret = secret.diffie_hellman(&x25519_dalek::PublicKey::from(public_key));
+        if !shared_secret.was_contributory() {
+            return Err(DecryptionError);
+        }
+        Ok(Zeroizing::new(*shared_secret.as_bytes()))
+    }
+
+    fn ed25519_key_pair(seed: &[u8; 32]) -> ([u8; 64], [u8; 32]) {
+        let secret = SecretKey::from_bytes(seed).expect("Ed25519 seeds are 32 Bytes long");
+        let public_key = PublicKey::from(&secret);
+        let key_pair = Keypair {
+            secret,
+            public: public_key,
+        };
+        (key_pair.to_bytes(), public_key.to_bytes())
+    }
+
+    fn ed25519_sign(private_key: &[u8; 64], message: &[u8]) -> Result<[u8; 64], InvalidKeyError> {
+        let (seed, public_key) = private_key.split_at(32);
+        let secret = SecretKey::from_bytes(seed).map_err(|_| InvalidKeyError)?;
+        let public = PublicKey::from(&secret);
+        if !ct_eq(public.as_bytes(), public_key) {
+            return Err(InvalidKeyError);
+        }
+        Ok(Keypair { secret, public }.sign(message).to_bytes())
+    }
+
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let public_key = match PublicKey::from_bytes(public_key) {
+            Ok(public_key) => public_key,
+            Err(_) => return false,
+        };
+        let signature = match Signature::try_from(signature) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        public_key.verify(message, &signature).is_ok()
+    }
+}
+
+/// Derives a 32 Byte key with HKDF-SHA-512.
+pub(crate) fn derive_key(ikm: &[u8], salt: &[u8], info: &[u8]) -> SecretKey32 {
+    let mut key = Zeroizing::new([0; 32]);
+    Backend::hkdf_sha512(ikm, salt, info, &mut *key);
+    key
+}
+
+/// Compares two byte strings in constant time, e.g. SRP proofs or keys.
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool { a.ct_eq(b).into() }
+
+/// Builds the 12 Byte nonce of a HAP message from its 8 Byte label or counter.
+pub(crate) fn nonce(suffix: &[u8; 8]) -> [u8; 12] {
+    let mut nonce = [0; 12];
+    nonce[4..].copy_from_slice(suffix);
+    nonce
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn hex32(s: &str) -> [u8; 32] { <[u8; 32]>::try_from(hex(s).as_slice()).unwrap() }
+
+    #[test]
+    fn hkdf_sha512_known_answer() {
+        // RFC 5869 test case 1, with SHA-512 in place of SHA-256
+        let ikm = [0x0b; 22];
+        let salt = hex("000102030405060708090a0b0c");
+        let info = hex("f0f1f2f3f4f5f6f7f8f9");
+        let mut okm = [0; 42];
+        Backend::hkdf_sha512(&ikm, &salt, &info, &mut okm);
+
+        assert_eq!(
+            okm.to_vec(),
+            hex("832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb")
+        );
+        assert_eq!(&*derive_key(&ikm, &salt, &info), &okm[..32]);
+    }
+
+    #[test]
+    fn chacha20_poly1305_known_answer() {
+        // RFC 8439, section 2.8.2
+        let key = hex32("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+        let nonce = <[u8; 12]>::try_from(hex("070000004041424344454647").as_slice()).unwrap();
+        let aad = hex("50515253c0c1c2c3c4c5c6c7");
+        let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the \
+                          future, sunscreen would be it.";
+        let sealed = hex(
+            "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e\
+             060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d2\
+             6586cec64b6116\
+             1ae10b594f09e26a7e902ecbd0600691",
+        );
+
+        assert_eq!(Backend::seal(&key, &nonce, &aad, plaintext), sealed);
+        assert_eq!(Backend::open(&key, &nonce, &aad, &sealed).unwrap(), plaintext.to_vec());
+    }
+
+    #[test]
+    fn open_rejects_tampered_data() {
+        let key = [7; 32];
+        let nonce = nonce(b"PV-Msg02");
+        let sealed = Backend::seal(&key, &nonce, b"", b"message");
+
+        let mut tampered = sealed.clone();
+        tampered[0] ^= 1;
+        assert!(Backend::open(&key, &nonce, b"", &tampered).is_err());
+        assert!(Backend::open(&key, &nonce, b"aad", &sealed).is_err());
+        assert!(Backend::open(&[8; 32], &nonce, b"", &sealed).is_err());
+        assert!(Backend::open(&key, &nonce, b"", &sealed[..TAG_LEN - 1]).is_err());
+        assert_eq!(Backend::open(&key, &nonce, b"", &Backend::seal(&key, &nonce, b"", b"")).unwrap(), b"");
+    }
+
+    #[test]
+    fn x25519_known_answer() {
+        // RFC 7748, section 6.1
+        let alice_secret = hex32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let alice_public = hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a");
+        let bob_secret = hex32("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let bob_public = hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+        let shared_secret = hex32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+
+        assert_eq!(*Backend::x25519(&alice_secret, &bob_public).unwrap(), shared_secret);
+        assert_eq!(*Backend::x25519(&bob_secret, &alice_public).unwrap(), shared_secret);
+    }
+
+    #[test]
+    fn x25519_key_pairs_agree() {
+        let (a, a_pub) = Backend::x25519_key_pair();
+        let (b, b_pub) = Backend::x25519_key_pair();
+
+        assert_eq!(*Backend::x25519(&a, &b_pub).unwrap(), *Backend::x25519(&b, &a_pub).unwrap());
+    }
+
+    #[test]
+    fn x25519_rejects_invalid_public_keys() {
+        let (secret, _) = Backend::x25519_key_pair();
+
+        assert!(Backend::x25519(&secret, &[0; 32]).is_err());
+        assert!(Backend::x25519(&secret, &[0; 31]).is_err());
+        assert!(Backend::x25519(&secret, &[9; 33]).is_err());
+    }
+
+    #[test]
+    fn ed25519_known_answer() {
+        // RFC 8032, section 7.1, test 2
+        let seed = hex32("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb");
+        let public_key = hex32("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c");
+        let message = [0x72];
+        let signature = hex(
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+             085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        );
+
+        let (private_key, derived_public_key) = Backend::ed25519_key_pair(&seed);
+        assert_eq!(derived_public_key, public_key);
+        assert_eq!(&private_key[..32], &seed);
+        assert_eq!(&private_key[32..], &public_key);
+        assert_eq!(Backend::ed25519_sign(&private_key, &message).unwrap().to_vec(), signature);
+        assert!(Backend::ed25519_verify(&public_key, &message, &signature));
+    }
+
+    #[test]
+    fn ed25519_rejects_invalid_keys_and_signatures() {
+        let (private_key, public_key) = Backend::ed25519_key_pair(&[1; 32]);
+        let signature = Backend::ed25519_sign(&private_key, b"message").unwrap();
+
+        assert!(Backend::ed25519_verify(&public_key, b"message", &signature));
+        assert!(!Backend::ed25519_verify(&public_key, b"massage", &signature));
+        assert!(!Backend::ed25519_verify(&public_key, b"message", &signature[..63]));
+        assert!(!Backend::ed25519_verify(&public_key[..31], b"message", &signature));
+
+        let mut tampered = signature;
+        tampered[0] ^= 1;
+        assert!(!Backend::ed25519_verify(&public_key, b"message", &tampered));
+
+        // the public half of a private key must belong to its seed
+        let (_, other_public_key) = Backend::ed25519_key_pair(&[2; 32]);
+        let mut invalid_private_key = private_key;
+        invalid_private_key[32..].copy_from_slice(&other_public_key);
+        assert!(Backend::ed25519_sign(&invalid_private_key, b"message").is_err());
+    }
+
+    #[test]
+    fn ct_eq_compares_contents_and_lengths() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"proof", b"proof"));
+        assert!(!ct_eq(b"proof", b"prooF"));
+        assert!(!ct_eq(b"proof", b"proo"));
+        assert!(!ct_eq(b"proo", b"proof"));
+    }
+
+    #[test]
+    fn nonce_is_left_padded_with_zeros() {
+        assert_eq!(nonce(b"PS-Msg05"), *b"\0\0\0\0PS-Msg05");
+        assert_eq!(nonce(&7u64.to_le_bytes()), [0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::{Arc, Mutex};
 
-use erased_serde::{self, __internal_serialize_trait_object, serialize_trait_object};
+use erased_serde::{self, serialize_trait_object};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use crate::{
@@ -140,6 +140,7 @@ impl AccessoryList {
             aid: write_object.aid,
             iid: write_object.iid,
             status: 0,
+            value: None,
         };
 
         let mut a = self.accessories.lock().expect("couldn't access accessories");
@@ -172,7 +173,15 @@ impl AccessoryList {
                             }
                             if let Some(value) = write_object.value {
                                 if characteristic_perms.contains(&Perm::PairedWrite) {
-                                    characteristic.set_value(value)?;
+                                    if characteristic.set_value(value).is_err() {
+                                        result_object.status = Status::InvalidValueInRequest as i32;
+                                    } else if write_object.response == Some(true)
+                                        && characteristic_perms.contains(&Perm::WriteResponse)
+                                    {
+                                        // the value after the write is returned if the controller
+                                        // requests a write response
+                                        result_object.value = Some(characteristic.get_value()?);
+                                    }
                                 } else {
                                     result_object.status = Status::ReadOnlyCharacteristic as i32;
                                 }
@@ -204,3 +213,266 @@ impl<T: HapAccessory + erased_serde::Serialize> AccessoryListMember for T {}
 serialize_trait_object!(AccessoryListMember);
 
 pub type AccessoryListPtr = Arc<Mutex<Box<dyn AccessoryListMember + Send>>>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        accessory::{
+            bridge,
+            contact_sensor,
+            dynamic,
+            faucet,
+            lightbulb,
+            outlet,
+            programmable_remote,
+            sprinkler,
+            television,
+            Accessory,
+            HapAccessoryService,
+            Information,
+        },
+        characteristic::{self, Format, Perm},
+        event::EventEmitter,
+        service::{self, input_source, HapService, Service},
+        HapType,
+    };
+
+    fn accessory_list() -> AccessoryList { AccessoryList::new(Arc::new(Mutex::new(EventEmitter::new()))) }
+
+    /// Adds the given Accessories to a new `AccessoryList` and returns the list as it's served to
+    /// controllers.
+    fn list(accessories: Vec<Box<dyn AccessoryListMember + Send>>) -> Value {
+        let mut accessory_list = accessory_list();
+        for accessory in accessories {
+            accessory_list.add_accessory(accessory).unwrap();
+        }
+        serde_json::to_value(&accessory_list).unwrap()
+    }
+
+    #[test]
+    fn custom_types_are_listed_with_full_uuids() {
+        let service_uuid = Uuid::parse_str("e863f007-079e-48ff-8f27-9c2605a29f52").unwrap();
+        let characteristic_uuid = Uuid::parse_str("e863f10c-079e-48ff-8f27-9c2605a29f52").unwrap();
+
+        let mut outlet = outlet::new(Information::default()).unwrap();
+        let mut total_consumption =
+            characteristic::custom::new::<f32>(characteristic_uuid, Format::Float, vec![Perm::PairedRead]).unwrap();
+        total_consumption.set_value(1.5).unwrap();
+        outlet
+            .inner
+            .outlet
+            .inner
+            .additional_characteristics
+            .push(Box::new(total_consumption));
+
+        let mut history = service::dynamic::new(HapType::Custom(service_uuid)).unwrap();
+        history.set_hidden(true);
+        history.inner.add_characteristic(
+            characteristic::custom::new::<Vec<u8>>(characteristic_uuid, Format::Data, vec![Perm::PairedRead]).unwrap(),
+        );
+        let mut sensor = dynamic::new(Information::default()).unwrap();
+        sensor.inner.add_service(history);
+
+        let accessories = list(vec![Box::new(outlet), Box::new(sensor)]);
+
+        let outlet = &accessories["accessories"][0]["services"][1];
+        assert_eq!(outlet["type"], json!("47"));
+        let characteristics = outlet["characteristics"].as_array().unwrap();
+        let custom = characteristics.last().unwrap();
+        assert_eq!(custom["type"], json!("E863F10C-079E-48FF-8F27-9C2605A29F52"));
+        assert_eq!(custom["format"], json!("float"));
+        assert_eq!(custom["value"], json!(1.5));
+
+        let history = &accessories["accessories"][1]["services"][1];
+        assert_eq!(history["type"], json!("E863F007-079E-48FF-8F27-9C2605A29F52"));
+        assert_eq!(history["hidden"], json!(true));
+    }
+
+    #[test]
+    fn current_metadata_types_are_listed() {
+        let mut lightbulb = lightbulb::new(Information::default()).unwrap();
+        lightbulb.inner.lightbulb.inner.brightness = Some(characteristic::brightness::new());
+        lightbulb.inner.lightbulb.inner.characteristic_value_transition_control =
+            Some(characteristic::characteristic_value_transition_control::new());
+
+        let accessories = list(vec![Box::new(lightbulb)]);
+
+        let characteristics = accessories["accessories"][0]["services"][1]["characteristics"]
+            .as_array()
+            .unwrap();
+        let brightness = characteristics.iter().find(|c| c["type"] == json!("8")).unwrap();
+        assert_eq!(brightness["format"], json!("int"));
+        let transition_control = characteristics.iter().find(|c| c["type"] == json!("143")).unwrap();
+        assert_eq!(transition_control["format"], json!("tlv8"));
+        assert_eq!(transition_control["perms"], json!(["pr", "pw", "wr"]));
+    }
+
+    #[test]
+    fn additional_services_are_listed() {
+        let mut sensor = contact_sensor::new(Information::default()).unwrap();
+        let battery_service = service::battery_service::new();
+        let battery_level = battery_service.inner.battery_level.clone();
+        sensor.inner.contact_sensor.add_linked_service(&battery_service);
+        sensor.inner.additional_services.push(Box::new(battery_service));
+
+        let accessories = list(vec![Box::new(sensor)]);
+
+        let services = accessories["accessories"][0]["services"].as_array().unwrap();
+        assert_eq!(services.len(), 3);
+        assert_eq!(services[2]["type"], json!("96"));
+        assert_eq!(services[1]["linked"], json!([services[2]["iid"]]));
+
+        let battery_level_iid = battery_level.get_id().unwrap();
+        assert!(battery_level_iid > services[2]["iid"].as_u64().unwrap());
+        let listed_battery_level = services[2]["characteristics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["type"] == json!("68"))
+            .unwrap();
+        assert_eq!(listed_battery_level["iid"], json!(battery_level_iid));
+        assert_eq!(listed_battery_level["value"], json!(0));
+    }
+
+    /// Asserts that the primary Service of an Accessory is linked to all of its Services of the
+    /// linked type, whose Characteristics of the given type carry the given values.
+    fn assert_linked(accessory: &Value, primary_type: &str, linked_type: &str, characteristic_type: &str, values: &[u8]) {
+        let services = accessory["services"].as_array().unwrap();
+        let primary = services.iter().find(|s| s["type"] == json!(primary_type)).unwrap();
+        let linked = services
+            .iter()
+            .filter(|s| s["type"] == json!(linked_type))
+            .collect::<Vec<_>>();
+        let linked_iids = linked.iter().map(|s| s["iid"].clone()).collect::<Vec<_>>();
+        assert_eq!(primary["linked"], json!(linked_iids));
+        let linked_values = linked
+            .iter()
+            .map(|s| {
+                let characteristics = s["characteristics"].as_array().unwrap();
+                let characteristic = characteristics
+                    .iter()
+                    .find(|c| c["type"] == json!(characteristic_type))
+                    .unwrap();
+                characteristic["value"].clone()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(json!(linked_values), json!(values));
+    }
+
+    #[test]
+    fn compositions_link_their_services() {
+        assert!(programmable_remote::new(Information::default(), 0).is_err());
+
+        let accessories = list(vec![
+            Box::new(bridge::new(Information::default()).unwrap()),
+            Box::new(sprinkler::new(Information::default(), 2).unwrap()),
+            Box::new(programmable_remote::new(Information::default(), 3).unwrap()),
+            Box::new(faucet::new(Information::default()).unwrap()),
+        ]);
+
+        // Irrigation System to irrigation Valves, labeled by Service Label Index
+        let sprinkler = &accessories["accessories"][1];
+        assert_linked(sprinkler, "CF", "D0", "D5", &[1, 1]);
+        assert_linked(sprinkler, "CF", "D0", "CB", &[1, 2]);
+        // Service Label to Stateless Programmable Switches, labeled by Service Label Index
+        assert_linked(&accessories["accessories"][2], "CC", "89", "CB", &[1, 2, 3]);
+        // Faucet to a water faucet Valve
+        assert_linked(&accessories["accessories"][3], "D7", "D0", "D5", &[3]);
+    }
+
+    #[test]
+    fn linked_services_are_listed() {
+        // links to Services of other Accessories are rejected
+        let mut television = television::new(Information::default()).unwrap();
+        let other = television::new(Information::default()).unwrap();
+        television.inner.speaker.add_linked_service(&other.inner.speaker);
+        assert!(accessory_list().add_accessory(Box::new(television)).is_err());
+
+        let mut television = television::new(Information::default()).unwrap();
+        television.inner.add_input_source(input_source::new());
+        television.inner.add_input_source(input_source::new());
+
+        let accessories = list(vec![Box::new(television)]);
+
+        let services = accessories["accessories"][0]["services"].as_array().unwrap();
+        let input_source_iids = services
+            .iter()
+            .filter(|s| s["type"] == json!("D9"))
+            .map(|s| s["iid"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(input_source_iids.len(), 2);
+        let television = services.iter().find(|s| s["type"] == json!("D8")).unwrap();
+        assert_eq!(television["linked"], json!(input_source_iids));
+        // Services without links don't carry the field at all
+        assert_eq!(services.iter().filter(|s| s.get("linked").is_some()).count(), 1);
+    }
+
+    /// Service with a custom type, composed with the derive macro.
+    #[derive(Default, HapService)]
+    struct FilterInner {
+        id: u64,
+        hap_type: HapType,
+        hidden: bool,
+        primary: bool,
+        linked_services: Vec<u64>,
+
+        filter_change_indication: characteristic::filter_change_indication::FilterChangeIndication,
+        filter_life_level: Option<characteristic::filter_life_level::FilterLifeLevel>,
+        #[hap(skip)]
+        #[allow(dead_code)]
+        vendor_state: u8,
+    }
+
+    /// Accessory with multiple lists of Services, composed with the derive macro.
+    #[derive(Default, HapAccessory)]
+    struct PowerStripInner {
+        id: u64,
+
+        outlets: Vec<service::outlet::Outlet>,
+        accessory_information: service::accessory_information::AccessoryInformation,
+        filter: Option<Service<FilterInner>>,
+        extras: Vec<Box<dyn HapAccessoryService + Send>>,
+    }
+
+    #[test]
+    fn derived_accessories_are_listed() {
+        let mut power_strip = PowerStripInner {
+            accessory_information: Information::default().to_service().unwrap(),
+            outlets: vec![service::outlet::new(), service::outlet::new()],
+            filter: Some(Service::new(FilterInner {
+                hap_type: HapType::FilterMaintenance,
+                filter_change_indication: characteristic::filter_change_indication::new(),
+                filter_life_level: Some(characteristic::filter_life_level::new()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        power_strip.extras.push(Box::new(service::switch::new()));
+
+        let accessories = list(vec![Box::new(Accessory::new(power_strip))]);
+
+        let services = accessories["accessories"][0]["services"].as_array().unwrap();
+        let types = services.iter().map(|s| s["type"].clone()).collect::<Vec<_>>();
+        // the Accessory Information Service comes first regardless of the field order
+        assert_eq!(types, vec![json!("3E"), json!("47"), json!("47"), json!("BA"), json!("49")]);
+        assert_eq!(services[0]["iid"], json!(1));
+        assert_eq!(services[3]["characteristics"].as_array().unwrap().len(), 2);
+
+        // iids are unique across all Services and Characteristics
+        let mut iids = vec![];
+        for service in services {
+            iids.push(service["iid"].as_u64().unwrap());
+            for characteristic in service["characteristics"].as_array().unwrap() {
+                iids.push(characteristic["iid"].as_u64().unwrap());
+            }
+        }
+        let count = iids.len();
+        iids.sort();
+        iids.dedup();
+        assert_eq!(iids.len(), count);
+    }
+}
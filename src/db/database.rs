@@ -54,6 +54,22 @@ impl Database {
         Ok(())
     }
 
+    /// Returns the SRP salt and verifier stored by the last split Pair Setup.
+    pub fn get_split_setup(&self) -> Result<Vec<u8>> { self.storage.get_bytes("split_setup.srp") }
+
+    /// Stores the SRP salt and verifier of a split Pair Setup.
+    pub fn set_split_setup(&self, record: Vec<u8>) -> Result<()> {
+        self.storage.set_bytes("split_setup.srp", record)
+    }
+
+    /// Deletes the SRP salt and verifier of a split Pair Setup if there are any.
+    pub fn delete_split_setup(&self) -> Result<()> {
+        if self.storage.keys_with_suffix("srp")?.is_empty() {
+            return Ok(());
+        }
+        self.storage.delete("split_setup.srp")
+    }
+
     /// Returns the stored `Pairing` for a given `Uuid`.
     pub fn get_pairing(&self, id: Uuid) -> Result<Pairing> {
         let pairing_bytes = self.get_bytes(&id.to_simple().to_string())?;
@@ -69,7 +85,7 @@ impl Database {
 
     /// Deletes the stored `Pairing` for a given `Uuid`.
     pub fn delete_pairing(&self, id: &Uuid) -> Result<()> {
-        let key = format!("{}.entity", id.to_simple().to_string());
+        let key = format!("{}.entity", id.to_simple());
         self.storage.delete(&key)
     }
 
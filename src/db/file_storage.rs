@@ -41,7 +41,7 @@ impl FileStorage {
     /// Returns a writable `File` for the given file name.
     fn file_for_write(&self, file: &str) -> Result<fs::File> {
         let file_path = self.path_to_file(file);
-        let file = fs::OpenOptions::new().write(true).create(true).open(file_path)?;
+        let file = fs::OpenOptions::new().write(true).create(true).truncate(true).open(file_path)?;
         Ok(file)
     }
 
@@ -136,3 +136,22 @@ impl Storage for FileStorage {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn rewriting_a_value_replaces_it() {
+        let dir = env::temp_dir().join(format!("hap-file-storage-{}", process::id()));
+        let storage = FileStorage::new(dir.to_str().unwrap()).unwrap();
+
+        storage.set_bytes("value", b"a longer value".to_vec()).unwrap();
+        storage.set_bytes("value", b"short".to_vec()).unwrap();
+        assert_eq!(storage.get_bytes("value").unwrap(), b"short");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    collections::HashMap,
+    str,
+    sync::{Arc, Mutex},
+};
+
+use byteorder::{BigEndian, ByteOrder};
+use uuid::Uuid;
+
+use crate::db::storage::Storage;
+
+use crate::{Error, Result};
+
+/// `MemoryStorage` is an implementor of the `Storage` trait that keeps all data in memory. Clones
+/// of a `MemoryStorage` share the same data. Useful for tests and for accessories that don't need
+/// to persist their pairings.
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+    data: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl MemoryStorage {
+    /// Creates a new, empty `MemoryStorage`.
+    pub fn new() -> MemoryStorage { MemoryStorage::default() }
+}
+
+impl Storage for MemoryStorage {
+    fn get_bytes(&self, key: &str) -> Result<Vec<u8>> {
+        self.data
+            .lock()
+            .expect("couldn't access storage")
+            .get(key)
+            .cloned()
+            .ok_or(Error::from_str("key not found"))
+    }
+
+    fn set_bytes(&self, key: &str, value: Vec<u8>) -> Result<()> {
+        self.data
+            .lock()
+            .expect("couldn't access storage")
+            .insert(key.to_owned(), value);
+        Ok(())
+    }
+
+    fn get_u64(&self, key: &str) -> Result<u64> {
+        let value = self.get_bytes(key)?;
+        if value.len() != 8 {
+            return Err(Error::from_str("couldn't read u64"));
+        }
+        Ok(BigEndian::read_u64(&value))
+    }
+
+    fn set_u64(&self, key: &str, value: u64) -> Result<()> {
+        let mut buf = [0; 8];
+        BigEndian::write_u64(&mut buf, value);
+        self.set_bytes(key, buf.to_vec())
+    }
+
+    fn get_uuid(&self, key: &str) -> Result<Uuid> {
+        let value = self.get_bytes(key)?;
+        match str::from_utf8(&value) {
+            Ok(uuid_str) => match Uuid::parse_str(uuid_str) {
+                Ok(value) => Ok(value),
+                _ => Err(Error::from_str("couldn't parse UUID")),
+            },
+            _ => Err(Error::from_str("couldn't read UUID")),
+        }
+    }
+
+    fn set_uuid(&self, key: &str, value: Uuid) -> Result<()> {
+        self.set_bytes(key, value.to_hyphenated().to_string().into_bytes())
+    }
+
+    fn keys_with_suffix(&self, suffix: &str) -> Result<Vec<String>> {
+        let suffix = format!(".{}", suffix);
+        let keys = self
+            .data
+            .lock()
+            .expect("couldn't access storage")
+            .keys()
+            .filter(|key| key.ends_with(&suffix))
+            .map(|key| key[..key.len() - suffix.len()].to_owned())
+            .collect();
+        Ok(keys)
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.data
+            .lock()
+            .expect("couldn't access storage")
+            .remove(key)
+            .ok_or(Error::from_str("key not found"))?;
+        Ok(())
+    }
+}
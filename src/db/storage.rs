@@ -5,15 +5,21 @@ use std::{
 
 use uuid::Uuid;
 
-use crate::Result;
+use crate::{Error, Result};
 
-/// `Storage` is implemented by the data storage methods HAP supports. Currently, that's just
-/// `FileStorage`.
+/// `Storage` is implemented by the data storage methods HAP supports. Currently, that's
+/// `FileStorage` and `MemoryStorage`.
 pub trait Storage {
-    /// Returns a `BufReader` to the `File` stored for the given key.
-    fn get_reader(&self, key: &str) -> Result<BufReader<File>>;
-    /// Returns a `BufWriter` to the `File` stored for the given key.
-    fn get_writer(&self, key: &str) -> Result<BufWriter<File>>;
+    /// Returns a `BufReader` to the `File` stored for the given key. Fails by default for storages
+    /// that aren't backed by files.
+    fn get_reader(&self, _key: &str) -> Result<BufReader<File>> {
+        Err(Error::from_str("storage isn't backed by files"))
+    }
+    /// Returns a `BufWriter` to the `File` stored for the given key. Fails by default for storages
+    /// that aren't backed by files.
+    fn get_writer(&self, _key: &str) -> Result<BufWriter<File>> {
+        Err(Error::from_str("storage isn't backed by files"))
+    }
     /// Returns the stored value for a given key as a `Vec<u8>`.
     fn get_bytes(&self, key: &str) -> Result<Vec<u8>>;
     /// Stores a given `Vec<u8>` as the value for a given key.
@@ -1,10 +1,12 @@
+// `failure_derive` expands `#[derive(Fail)]` to impls nested in an anonymous constant
+#![allow(non_local_definitions)]
+
 use std::{fmt, io, num, str, sync::mpsc};
 
-use chacha20_poly1305_aead;
-use eui48;
 use failure::{self, err_msg, Context, Fail};
 use hyper::{self, http};
-use serde_json;
+
+use crate::crypto;
 
 /// ErrorKind wrapper type.
 #[derive(Debug, Fail)]
@@ -19,8 +21,8 @@ pub enum ErrorKind {
     Http(#[cause] http::Error),
     #[fail(display = "Hyper Error {}", _0)]
     Hyper(#[cause] hyper::error::Error),
-    #[fail(display = "ChaCha20-Poly1305-AEAD Error {}", _0)]
-    ChaCha20Poly1305Aead(#[cause] chacha20_poly1305_aead::DecryptError),
+    #[fail(display = "Decryption Error")]
+    Decryption,
     #[fail(display = "UTF-8 Error {}", _0)]
     Utf8(#[cause] str::Utf8Error),
     #[fail(display = "MAC Address Parse Error {}", _0)]
@@ -47,9 +49,10 @@ impl Error {
     }
 
     /// Returns a reference to the `ErrorKind` of the `Error`.
-    pub fn kind(&self) -> &ErrorKind { &self.kind.get_context() }
+    pub fn kind(&self) -> &ErrorKind { self.kind.get_context() }
 
     /// Creates a new `Error` from a `&'static str`.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(cause: &'static str) -> Error { ErrorKind::Other(err_msg(cause)).into() }
 }
 
@@ -100,8 +103,12 @@ impl From<hyper::error::Error> for Error {
     fn from(err: hyper::error::Error) -> Error { ErrorKind::Hyper(err).into() }
 }
 
-impl From<chacha20_poly1305_aead::DecryptError> for Error {
-    fn from(err: chacha20_poly1305_aead::DecryptError) -> Error { ErrorKind::ChaCha20Poly1305Aead(err).into() }
+impl From<crypto::DecryptionError> for Error {
+    fn from(_: crypto::DecryptionError) -> Error { ErrorKind::Decryption.into() }
+}
+
+impl From<crypto::InvalidKeyError> for Error {
+    fn from(_: crypto::InvalidKeyError) -> Error { Error::from_str("invalid Ed25519 private key") }
 }
 
 impl From<str::Utf8Error> for Error {
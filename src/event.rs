@@ -2,31 +2,35 @@ use std::sync::{Arc, Mutex};
 
 use serde_json::Value;
 
+/// Events emitted within the transport.
 pub enum Event {
     DevicePaired,
     DeviceUnpaired,
     CharacteristicValueChanged { aid: u64, iid: u64, value: Value },
 }
 
+type Listener = Box<dyn Fn(&Event) + Send>;
+
+/// Emits `Event`s to its listeners.
 #[derive(Default)]
 pub struct EventEmitter {
-    listeners: Vec<Box<dyn Fn(&Event) + Send>>,
+    listeners: Vec<Listener>,
 }
 
 impl EventEmitter {
+    /// Creates a new `EventEmitter`.
     pub fn new() -> EventEmitter { EventEmitter { listeners: vec![] } }
 
+    /// Adds a listener that is called with every emitted `Event`.
     pub fn add_listener(&mut self, listener: Box<dyn Fn(&Event) + Send>) { self.listeners.push(listener); }
 
+    /// Emits an `Event` to all listeners.
     pub fn emit(&self, event: &Event) {
         for listener in &self.listeners {
-            listener(&event);
+            listener(event);
         }
     }
 }
 
 /// Pointer to an `EventEmitter`.
 pub type EventEmitterPtr = Arc<Mutex<EventEmitter>>;
-
-/// Pointer to a list of event subscriptions.
-pub type EventSubscriptions = Arc<Mutex<Vec<(u64, u64)>>>;
@@ -0,0 +1,68 @@
+//! Entry points for fuzzing and property testing the parsers that process untrusted input.
+//!
+//! This module is only compiled with the `fuzzing` feature. It isn't part of the public API and may
+//! change at any time.
+
+use std::sync::{Arc, Mutex};
+
+use bytes::BytesMut;
+use tokio::codec::{Decoder, Encoder};
+use zeroize::Zeroizing;
+
+use crate::{
+    accessory::{lightbulb, Information},
+    db::AccessoryList,
+    event::EventEmitter,
+    transport::{http::handler::characteristics, tcp::FrameCodec},
+    Result,
+};
+
+pub use crate::transport::http::handler::characteristics::ReadQuery;
+
+/// Splits `data` into frames of the encrypted HAP session and encrypts them with the given key.
+pub fn encode_frames(key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>> {
+    let mut encoded = BytesMut::new();
+    FrameCodec::new(Zeroizing::new(*key), Zeroizing::new(*key)).encode(data.to_vec(), &mut encoded)?;
+    Ok(encoded.to_vec())
+}
+
+/// Decodes and decrypts all complete frames of the encrypted HAP session in `data` with the given
+/// key, the way an `EncryptedStream` does.
+pub fn decode_frames(key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>> {
+    let mut codec = FrameCodec::new(Zeroizing::new(*key), Zeroizing::new(*key));
+    let mut buf = BytesMut::from(data);
+    let mut decrypted = Vec::new();
+    while let Some(frame) = codec.decode(&mut buf)? {
+        decrypted.extend(frame);
+    }
+    Ok(decrypted)
+}
+
+/// Decodes and decrypts the frames of the encrypted HAP session in `data` like `decode_frames`,
+/// but feeds the decoder with fragments of `fragment_len` Bytes as if they were read one at a time.
+pub fn decode_fragmented_frames(key: &[u8; 32], data: &[u8], fragment_len: usize) -> Result<Vec<u8>> {
+    let mut codec = FrameCodec::new(Zeroizing::new(*key), Zeroizing::new(*key));
+    let mut buf = BytesMut::new();
+    let mut decrypted = Vec::new();
+    for fragment in data.chunks(fragment_len.max(1)) {
+        buf.extend_from_slice(fragment);
+        while let Some(frame) = codec.decode(&mut buf)? {
+            decrypted.extend(frame);
+        }
+    }
+    Ok(decrypted)
+}
+
+/// Parses the query string of a `GET /characteristics` request.
+pub fn parse_read_query(query: &str) -> Result<ReadQuery> { ReadQuery::parse(query) }
+
+/// Applies the body of a `PUT /characteristics` request to a lightbulb Accessory and returns the
+/// HTTP status code of the response.
+pub fn write_characteristics(body: &[u8]) -> Result<u16> {
+    let mut accessories = AccessoryList::new(Arc::new(Mutex::new(EventEmitter::new())));
+    accessories.add_accessory(Box::new(lightbulb::new(Information::default())?))?;
+    let event_subscriptions = Arc::new(Mutex::new(Vec::new()));
+
+    let res = characteristics::write_characteristics(body, &event_subscriptions, &accessories)?;
+    Ok(res.status().as_u16())
+}
@@ -1,11 +1,15 @@
 // THIS FILE IS AUTO-GENERATED
 
 use serde::ser::{Serialize, Serializer};
+use uuid::Uuid;
 
 /// HAP Service and Characteristic type.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum HapType {
+    #[default]
     Unknown,
+    /// Custom type of a vendor-specific Service or Characteristic, identified by its full UUID.
+    Custom(Uuid),
 	AccessoryFlags,
 	Active,
 	ActiveIdentifier,
@@ -148,6 +152,36 @@ pub enum HapType {
 	VolumeControlType,
 	VolumeSelector,
 	WaterLevel,
+	CharacteristicValueTransitionControl,
+	SupportedCharacteristicValueTransitionConfiguration,
+	CharacteristicValueActiveTransitionCount,
+	TargetControlSupportedConfiguration,
+	TargetControlList,
+	ButtonEvent,
+	SelectedAudioStreamConfiguration,
+	SupportedDataStreamTransportConfiguration,
+	SetupDataStreamTransport,
+	SiriInputType,
+	CurrentTransport,
+	WiFiCapabilities,
+	WiFiConfigurationControl,
+	AccessCodeSupportedConfiguration,
+	AccessCodeControlPoint,
+	ConfigurationState,
+	NFCAccessControlPoint,
+	NFCAccessSupportedConfiguration,
+	HomeKitCameraActive,
+	ThirdPartyCameraActive,
+	CameraOperatingModeIndicator,
+	EventSnapshotsActive,
+	DiagonalFieldOfView,
+	PeriodicSnapshotsActive,
+	RecordingAudioActive,
+	ManuallyDisabled,
+	SupportedCameraRecordingConfiguration,
+	SupportedVideoRecordingConfiguration,
+	SupportedAudioRecordingConfiguration,
+	SelectedCameraRecordingConfiguration,
 	AccessoryInformation,
 	AirPurifier,
 	AirQualitySensor,
@@ -190,13 +224,26 @@ pub enum HapType {
 	WindowCovering,
 	Television,
 	InputSource,
+	TargetControlManagement,
+	TargetControl,
+	AudioStreamManagement,
+	DataStreamTransportManagement,
+	Siri,
+	CameraRecordingManagement,
+	CameraOperatingMode,
+	WiFiTransport,
+	AccessCode,
+	NFCAccess,
 }
 
 impl HapType {
-    /// Converts a `HapType` to its corresponding shortened UUID string.
+    /// Converts a `HapType` to its corresponding UUID string. Apple-defined types are converted to
+    /// their shortened form, custom types to their full upper-case form.
+    #[allow(clippy::inherent_to_string)]
     pub fn to_string(self) -> String {
         match self {
             HapType::Unknown => "unknown".into(),
+            HapType::Custom(uuid) => uuid.to_hyphenated().to_string().to_uppercase(),
 			HapType::AccessoryFlags => "A6".into(),
 			HapType::Active => "B0".into(),
 			HapType::ActiveIdentifier => "E7".into(),
@@ -339,6 +386,36 @@ impl HapType {
 			HapType::VolumeControlType => "E9".into(),
 			HapType::VolumeSelector => "EA".into(),
 			HapType::WaterLevel => "B5".into(),
+			HapType::CharacteristicValueTransitionControl => "143".into(),
+			HapType::SupportedCharacteristicValueTransitionConfiguration => "144".into(),
+			HapType::CharacteristicValueActiveTransitionCount => "24B".into(),
+			HapType::TargetControlSupportedConfiguration => "123".into(),
+			HapType::TargetControlList => "124".into(),
+			HapType::ButtonEvent => "126".into(),
+			HapType::SelectedAudioStreamConfiguration => "128".into(),
+			HapType::SupportedDataStreamTransportConfiguration => "130".into(),
+			HapType::SetupDataStreamTransport => "131".into(),
+			HapType::SiriInputType => "132".into(),
+			HapType::CurrentTransport => "22B".into(),
+			HapType::WiFiCapabilities => "22C".into(),
+			HapType::WiFiConfigurationControl => "22D".into(),
+			HapType::AccessCodeSupportedConfiguration => "261".into(),
+			HapType::AccessCodeControlPoint => "262".into(),
+			HapType::ConfigurationState => "263".into(),
+			HapType::NFCAccessControlPoint => "264".into(),
+			HapType::NFCAccessSupportedConfiguration => "265".into(),
+			HapType::HomeKitCameraActive => "21B".into(),
+			HapType::ThirdPartyCameraActive => "21C".into(),
+			HapType::CameraOperatingModeIndicator => "21D".into(),
+			HapType::EventSnapshotsActive => "223".into(),
+			HapType::DiagonalFieldOfView => "224".into(),
+			HapType::PeriodicSnapshotsActive => "225".into(),
+			HapType::RecordingAudioActive => "226".into(),
+			HapType::ManuallyDisabled => "227".into(),
+			HapType::SupportedCameraRecordingConfiguration => "205".into(),
+			HapType::SupportedVideoRecordingConfiguration => "206".into(),
+			HapType::SupportedAudioRecordingConfiguration => "207".into(),
+			HapType::SelectedCameraRecordingConfiguration => "209".into(),
 			HapType::AccessoryInformation => "3E".into(),
 			HapType::AirPurifier => "BB".into(),
 			HapType::AirQualitySensor => "8D".into(),
@@ -381,14 +458,20 @@ impl HapType {
 			HapType::WindowCovering => "8C".into(),
 			HapType::Television => "D8".into(),
 			HapType::InputSource => "D9".into(),
+			HapType::TargetControlManagement => "122".into(),
+			HapType::TargetControl => "125".into(),
+			HapType::AudioStreamManagement => "127".into(),
+			HapType::DataStreamTransportManagement => "129".into(),
+			HapType::Siri => "133".into(),
+			HapType::CameraRecordingManagement => "204".into(),
+			HapType::CameraOperatingMode => "21A".into(),
+			HapType::WiFiTransport => "22A".into(),
+			HapType::AccessCode => "260".into(),
+			HapType::NFCAccess => "266".into(),
 		}
     }
 }
 
-impl Default for HapType {
-    fn default() -> HapType { HapType::Unknown }
-}
-
 impl Serialize for HapType {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(&self.to_string())
@@ -1,21 +1,31 @@
+// lets the derive macros refer to the crate as `::hap` from within the crate as well
+extern crate self as hap;
+
 pub mod accessory;
 pub mod characteristic;
+pub mod controller;
 pub mod service;
 
 pub mod db;
+pub mod event;
 pub mod protocol;
 pub mod transport;
 
 mod config;
+mod crypto;
 mod error;
-mod event;
 mod hap_type;
 mod pin;
 
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+
 pub use crate::{
     config::Config,
     error::{Error, ErrorKind},
     hap_type::HapType,
 };
+pub use uuid::Uuid;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -2,7 +2,7 @@ use crate::{Error, Result};
 
 pub type Pin = String;
 
-const INVALID_PINS: [&'static str; 12] = [
+const INVALID_PINS: [&str; 12] = [
     "12345678", "87654321", "00000000", "11111111", "22222222", "33333333", "44444444", "55555555", "66666666",
     "77777777", "88888888", "99999999",
 ];
@@ -15,7 +15,7 @@ pub fn new(input: &str) -> Result<Pin> {
         return Err(Error::from_str("pin must be 8 characters long"));
     }
     for digit in input.chars() {
-        if digit < '0' || digit > '9' {
+        if !digit.is_ascii_digit() {
             return Err(Error::from_str("pin must only contain numbers"));
         }
     }
@@ -0,0 +1,57 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    crypto::{Backend, Crypto},
+    Result,
+};
+
+/// `Authenticator` is implemented by the MFi authentication methods an Accessory can use during
+/// Pair Setup, e.g. an Apple Authentication Coprocessor or a software token.
+///
+/// If a controller requests Pair Setup with authentication, the Accessory derives a challenge
+/// from the SRP shared secret, has it signed by the `Authenticator` and sends the signature along
+/// with the `Authenticator`'s certificate back to the controller.
+pub trait Authenticator {
+    /// Returns the certificate (or software token) of the `Authenticator`.
+    fn get_certificate(&mut self) -> Result<Vec<u8>>;
+    /// Signs the given challenge and returns the signature.
+    fn sign(&mut self, challenge: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Pointer to an `Authenticator`.
+pub type AuthenticatorPtr = Arc<Mutex<Box<dyn Authenticator + Send>>>;
+
+/// `MockAuthenticator` is an implementor of the `Authenticator` trait that signs challenges with
+/// an Ed25519 key pair and returns a fixed certificate. It's meant to be used for testing Pair
+/// Setup with authentication without real MFi hardware.
+pub struct MockAuthenticator {
+    pub certificate: Vec<u8>,
+    pub private_key: [u8; 64],
+    pub public_key: [u8; 32],
+}
+
+impl MockAuthenticator {
+    /// Creates a new `MockAuthenticator` with a given certificate and a key pair derived from a
+    /// given seed.
+    pub fn new(certificate: Vec<u8>, seed: [u8; 32]) -> MockAuthenticator {
+        let (private_key, public_key) = Backend::ed25519_key_pair(&seed);
+        MockAuthenticator {
+            certificate,
+            private_key,
+            public_key,
+        }
+    }
+
+    /// Verifies a signature created by the `MockAuthenticator`.
+    pub fn verify(&self, challenge: &[u8], signature: &[u8]) -> bool {
+        Backend::ed25519_verify(&self.public_key, challenge, signature)
+    }
+}
+
+impl Authenticator for MockAuthenticator {
+    fn get_certificate(&mut self) -> Result<Vec<u8>> { Ok(self.certificate.clone()) }
+
+    fn sign(&mut self, challenge: &[u8]) -> Result<Vec<u8>> {
+        Ok(Backend::ed25519_sign(&self.private_key, challenge)?.to_vec())
+    }
+}
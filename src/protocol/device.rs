@@ -1,6 +1,5 @@
 use std::{fmt, marker::PhantomData};
 
-use crypto::ed25519;
 use rand::{self, Rng};
 use serde::{
     de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -10,6 +9,7 @@ use serde_derive::{Deserialize, Serialize};
 use serde_json;
 
 use crate::{
+    crypto::{Backend, Crypto},
     db::{Database, DatabasePtr},
     pin::Pin,
     Result,
@@ -87,7 +87,7 @@ impl Device {
 fn generate_key_pair() -> ([u8; 64], [u8; 32]) {
     let mut rng = rand::thread_rng();
     let seed = rng.gen::<[u8; 32]>();
-    ed25519::keypair(&seed)
+    Backend::ed25519_key_pair(&seed)
 }
 
 // see https://github.com/serde-rs/serde/issues/631
@@ -1,9 +1,11 @@
-pub(crate) mod tlv;
+pub mod tlv;
 
+mod authenticator;
 mod device;
 mod pairing;
 
 pub use self::{
+    authenticator::{Authenticator, AuthenticatorPtr, MockAuthenticator},
     device::Device,
     pairing::{Pairing, Permissions},
 };
@@ -43,7 +43,7 @@ impl Pairing {
 
     /// Deserializes a `Pairing` from a `&[u8]`.
     pub fn from_bytes(bytes: &[u8]) -> Result<Pairing> {
-        let value = serde_json::from_slice(&bytes)?;
+        let value = serde_json::from_slice(bytes)?;
         Ok(value)
     }
 }
@@ -1,78 +1,167 @@
+// `failure_derive` expands `#[derive(Fail)]` to impls nested in an anonymous constant
+#![allow(non_local_definitions)]
+
 use std::{cell, collections::HashMap, io, str};
 
-use byteorder::{LittleEndian, WriteBytesExt};
-use chacha20_poly1305_aead;
+use byteorder::{ByteOrder, LittleEndian, WriteBytesExt};
 use failure::Fail;
 use srp::types::SrpAuthError;
 use uuid;
 
-use crate::{error, protocol::pairing::Permissions};
+use crate::{crypto, error, protocol::pairing::Permissions};
 
-/// Encodes a `HashMap<u8, Vec<u8>>` in the format `<Type, Value>` to a `Vec<u8>` of concatenated
-/// TLVs.
-pub fn encode(hm: HashMap<u8, Vec<u8>>) -> Vec<u8> {
-    let mut vec: Vec<u8> = Vec::new();
-    for (k, v) in &hm {
-        let length = v.len();
-        if length <= 255 {
-            vec.push(*k);
-            vec.push(length as u8);
-            for byte in v {
-                vec.push(*byte);
-            }
-        } else {
-            let mut l = length;
-            let mut p = 0;
-            while l > 255 {
-                vec.push(*k);
-                vec.push(255);
-                for byte in &v[p..(p + 255)] {
-                    vec.push(*byte);
-                }
-                l -= 255;
-                p += 255;
-            }
-            if l > 0 {
-                vec.push(k.clone());
-                vec.push(l as u8);
-                for byte in &v[p..(p + l)] {
-                    vec.push(*byte);
-                }
+/// Writes TLV8 items in order to a `Vec<u8>`, splitting values longer than 255 Bytes into
+/// fragments.
+#[derive(Debug, Default)]
+pub struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    /// Creates a new, empty `Writer`.
+    pub fn new() -> Writer { Writer::default() }
+
+    /// Appends an item of a given type. Values longer than 255 Bytes are written as consecutive
+    /// fragments of the same type. A value ending in a full fragment is terminated by an empty
+    /// fragment, so that a following item of the same type isn't read as its continuation.
+    pub fn push(&mut self, t: u8, value: &[u8]) -> &mut Writer {
+        for chunk in value.chunks(255) {
+            self.buf.push(t);
+            self.buf.push(chunk.len() as u8);
+            self.buf.extend_from_slice(chunk);
+        }
+        if value.len().is_multiple_of(255) {
+            self.buf.push(t);
+            self.buf.push(0);
+        }
+        self
+    }
+
+    /// Appends a `Value`.
+    pub fn push_value(&mut self, value: Value) -> &mut Writer {
+        let (t, v) = value.as_tlv();
+        self.push(t, &v)
+    }
+
+    /// Appends an item whose value is a nested TLV8 structure.
+    pub fn push_nested(&mut self, t: u8, nested: Writer) -> &mut Writer { self.push(t, &nested.buf) }
+
+    /// Appends a zero-length separator item, used to delimit the elements of a list.
+    pub fn push_separator(&mut self) -> &mut Writer { self.push(Type::Separator as u8, &[]) }
+
+    /// Returns the encoded TLV8 data.
+    pub fn into_bytes(self) -> Vec<u8> { self.buf }
+}
+
+/// Error returned when decoding malformed TLV8 data.
+#[derive(Copy, Clone, Debug, PartialEq, Fail)]
+pub enum DecodeError {
+    #[fail(display = "TLV8 item at offset {} is missing its length", offset)]
+    MissingLength { offset: usize },
+    #[fail(display = "TLV8 item at offset {} is truncated", offset)]
+    Truncated { offset: usize },
+}
+
+/// Reads TLV8 items in order from a byte slice. Fragments of the same type are merged into a
+/// single item; separators are returned as items of `Type::Separator` with an empty value.
+///
+/// The `Reader` yields an error and stops on malformed input instead of panicking.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    failed: bool,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a new `Reader` over the given data.
+    pub fn new(data: &'a [u8]) -> Reader<'a> {
+        Reader {
+            data,
+            pos: 0,
+            failed: false,
+        }
+    }
+
+    fn read_fragment(&mut self) -> Result<(u8, &'a [u8]), DecodeError> {
+        let offset = self.pos;
+        let t = self.data[offset];
+        let len = *self
+            .data
+            .get(offset + 1)
+            .ok_or(DecodeError::MissingLength { offset })? as usize;
+        let value = self
+            .data
+            .get(offset + 2..offset + 2 + len)
+            .ok_or(DecodeError::Truncated { offset })?;
+        self.pos = offset + 2 + len;
+        Ok((t, value))
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Result<(u8, Vec<u8>), DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.pos >= self.data.len() {
+            return None;
+        }
+        let (t, first) = match self.read_fragment() {
+            Ok(fragment) => fragment,
+            Err(err) => {
+                self.failed = true;
+                return Some(Err(err));
+            },
+        };
+        let mut value = first.to_vec();
+        let mut last_len = first.len();
+        // a fragment of full length followed by an item of the same type continues the value
+        while last_len == 255 && self.data.get(self.pos) == Some(&t) {
+            match self.read_fragment() {
+                Ok((_, fragment)) => {
+                    value.extend_from_slice(fragment);
+                    last_len = fragment.len();
+                },
+                Err(err) => {
+                    self.failed = true;
+                    return Some(Err(err));
+                },
             }
         }
+        Some(Ok((t, value)))
+    }
+}
+
+/// Decodes TLV8 data to a `HashMap<u8, Vec<u8>>` in the format `<Type, Value>`. If a type occurs
+/// more than once, the last value wins; use `decode_list` or a `Reader` for lists.
+pub fn decode(tlv: &[u8]) -> Result<HashMap<u8, Vec<u8>>, DecodeError> {
+    let mut hm = HashMap::new();
+    for item in Reader::new(tlv) {
+        let (t, v) = item?;
+        hm.insert(t, v);
     }
-    vec
+    Ok(hm)
 }
 
-/// Decodes a `Vec<u8>` of concatenated TLVs to a `HashMap<u8, Vec<u8>>` in the format
-/// `<Type, Value>`.
-pub fn decode(tlv: Vec<u8>) -> HashMap<u8, Vec<u8>> {
+/// Decodes TLV8 data containing a list of elements delimited by separators to a
+/// `Vec<HashMap<u8, Vec<u8>>>`, one map per element. Empty elements are skipped.
+pub fn decode_list(tlv: &[u8]) -> Result<Vec<HashMap<u8, Vec<u8>>>, DecodeError> {
+    let mut list = Vec::new();
     let mut hm = HashMap::new();
-    let mut buf: Vec<u8> = Vec::new();
-    let mut p = 0;
-    let mut pt = 0;
-    while p < tlv.len() {
-        let t = tlv[p];
-        let l = tlv[p + 1];
-        if l < 255 {
-            if t != pt && !buf.is_empty() {
-                hm.insert(t, buf.clone());
-                buf.clear();
+    for item in Reader::new(tlv) {
+        let (t, v) = item?;
+        if t == Type::Separator as u8 {
+            if !hm.is_empty() {
+                list.push(hm);
+                hm = HashMap::new();
             }
-            buf.extend_from_slice(&tlv[p + 2..p + 2 + l as usize]);
-            hm.insert(t, buf.clone());
-            buf.clear();
         } else {
-            buf.extend_from_slice(&tlv[p + 2..p + 2 + l as usize]);
+            hm.insert(t, v);
         }
-        pt = t;
-        p = p + 2 + l as usize;
     }
-    if !buf.is_empty() {
-        hm.insert(pt, buf.clone());
-        buf.clear();
+    if !hm.is_empty() {
+        list.push(hm);
     }
-    hm
+    Ok(list)
 }
 
 /// `Encodable` is implemented by types that can be encoded to a to a `Vec<u8>` of concatenated
@@ -98,6 +187,8 @@ pub enum Type {
     Permissions = 0x0B,
     FragmentData = 0x0C,
     FragmentLast = 0x0D,
+    SessionId = 0x0E,
+    Flags = 0x13,
     Separator = 0xFF,
 }
 
@@ -118,11 +209,14 @@ pub enum Value {
     Permissions(Permissions),
     FragmentData(Vec<u8>),
     FragmentLast(Vec<u8>),
+    SessionId(Vec<u8>),
+    Flags(u32),
     Separator,
 }
 
 impl Value {
     /// Converts a variant of `Value` to a `(u8, Vec<u8>)` tuple in the format `(Type, Value)`.
+    #[allow(clippy::wrong_self_convention)]
     pub fn as_tlv(self) -> (u8, Vec<u8>) {
         match self {
             Value::Method(method_kind) => (Type::Method as u8, vec![method_kind as u8]),
@@ -144,26 +238,70 @@ impl Value {
             Value::Permissions(permissions) => (Type::Permissions as u8, vec![permissions.as_u8()]),
             Value::FragmentData(fragment_data) => (Type::FragmentData as u8, fragment_data),
             Value::FragmentLast(fragment_last) => (Type::FragmentLast as u8, fragment_last),
-            Value::Separator => (Type::Separator as u8, vec![0x00]),
+            Value::SessionId(session_id) => (Type::SessionId as u8, session_id),
+            Value::Flags(flags) => {
+                let mut vec: Vec<u8> = Vec::new();
+                vec.write_u32::<LittleEndian>(flags).unwrap();
+                while vec.len() > 1 && vec[vec.len() - 1] == 0 {
+                    vec.pop();
+                }
+                (Type::Flags as u8, vec)
+            },
+            Value::Separator => (Type::Separator as u8, Vec::new()),
         }
     }
-
-    /// Converts a variant of `Value` to a `(u8, Vec<u8>)` tuple in the format `(Type, Value)` and
-    /// inserts it into a `HashMap<u8, Vec<u8>>`.
-    pub fn into_map(self, map: &mut HashMap<u8, Vec<u8>>) {
-        let (t, v) = self.as_tlv();
-        map.insert(t, v);
-    }
 }
 
 #[allow(dead_code)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Method {
-    PairSetup = 1,
+    PairSetup = 0,
+    PairSetupWithAuth = 1,
     PairVerify = 2,
     AddPairing = 3,
     RemovePairing = 4,
     ListPairings = 5,
+    PairResume = 6,
+}
+
+impl Method {
+    /// Converts a Byte value to the corresponding `Method` variant.
+    pub fn from_u8(u: u8) -> Option<Method> {
+        match u {
+            0 => Some(Method::PairSetup),
+            1 => Some(Method::PairSetupWithAuth),
+            2 => Some(Method::PairVerify),
+            3 => Some(Method::AddPairing),
+            4 => Some(Method::RemovePairing),
+            5 => Some(Method::ListPairings),
+            6 => Some(Method::PairResume),
+            _ => None,
+        }
+    }
+}
+
+/// Pairing flags that can be sent along with a Pair Setup request.
+#[derive(Copy, Clone)]
+pub enum Flag {
+    /// Pair Setup M1 - M4 without exchanging public keys.
+    Transient = 0x0000_0010,
+    /// Pair Setup M1 - M4 without generating a new SRP verifier.
+    Split = 0x0100_0000,
+}
+
+impl Flag {
+    /// Returns whether the flag is set in a given flags value.
+    pub fn is_set(self, flags: u32) -> bool { flags & self as u32 != 0 }
+}
+
+/// Decodes a little-endian integer of up to 4 Bytes, e.g. the value of a `Type::Flags` TLV.
+pub fn decode_u32(value: &[u8]) -> Option<u32> {
+    if value.is_empty() || value.len() > 4 {
+        return None;
+    }
+    let mut buf = [0; 4];
+    buf[..value.len()].copy_from_slice(value);
+    Some(LittleEndian::read_u32(&buf))
 }
 
 #[allow(dead_code)]
@@ -185,10 +323,37 @@ pub enum Error {
     Busy = 0x07,
 }
 
+impl Error {
+    /// Converts a Byte value to the corresponding `Error` variant.
+    pub fn from_u8(u: u8) -> Error {
+        match u {
+            0x02 => Error::Authentication,
+            0x03 => Error::Backoff,
+            0x04 => Error::MaxPeers,
+            0x05 => Error::MaxTries,
+            0x06 => Error::Unavailable,
+            0x07 => Error::Busy,
+            _ => Error::Unknown,
+        }
+    }
+}
+
+impl From<Error> for error::Error {
+    fn from(err: Error) -> error::Error { error::ErrorKind::Other(err.into()).into() }
+}
+
+impl From<DecodeError> for error::Error {
+    fn from(err: DecodeError) -> error::Error { error::ErrorKind::Other(err.into()).into() }
+}
+
 impl From<error::Error> for Error {
     fn from(_: error::Error) -> Self { Error::Unknown }
 }
 
+impl From<DecodeError> for Error {
+    fn from(_: DecodeError) -> Self { Error::Unknown }
+}
+
 impl From<io::Error> for Error {
     fn from(_: io::Error) -> Self { Error::Unknown }
 }
@@ -213,19 +378,23 @@ impl From<SrpAuthError> for Error {
     fn from(_: SrpAuthError) -> Self { Error::Authentication }
 }
 
-impl From<chacha20_poly1305_aead::DecryptError> for Error {
-    fn from(_: chacha20_poly1305_aead::DecryptError) -> Self { Error::Authentication }
+impl From<crypto::DecryptionError> for Error {
+    fn from(_: crypto::DecryptionError) -> Self { Error::Authentication }
+}
+
+impl From<crypto::InvalidKeyError> for Error {
+    fn from(_: crypto::InvalidKeyError) -> Self { Error::Unknown }
 }
 
 pub type Container = Vec<Value>;
 
 impl Encodable for Container {
     fn encode(self) -> Vec<u8> {
-        let mut map = HashMap::new();
+        let mut writer = Writer::new();
         for value in self {
-            value.into_map(&mut map);
+            writer.push_value(value);
         }
-        encode(map)
+        writer.into_bytes()
     }
 }
 
@@ -240,9 +409,102 @@ impl ErrorContainer {
 
 impl Encodable for ErrorContainer {
     fn encode(self) -> Vec<u8> {
-        let mut map = HashMap::new();
-        Value::State(self.step).into_map(&mut map);
-        Value::Error(self.error).into_map(&mut map);
-        encode(map)
+        let mut writer = Writer::new();
+        writer.push_value(Value::State(self.step));
+        writer.push_value(Value::Error(self.error));
+        writer.into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(data: &[u8]) -> Vec<(u8, Vec<u8>)> { Reader::new(data).collect::<Result<Vec<_>, _>>().unwrap() }
+
+    #[test]
+    fn decode_list_splits_pairings_on_separators() {
+        let mut writer = Writer::new();
+        writer
+            .push(Type::Identifier as u8, b"first")
+            .push(Type::Permissions as u8, &[1])
+            .push_separator()
+            .push(Type::Identifier as u8, b"second")
+            .push(Type::Permissions as u8, &[0])
+            .push_separator();
+        let list = decode_list(&writer.into_bytes()).unwrap();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0][&(Type::Identifier as u8)], b"first".to_vec());
+        assert_eq!(list[0][&(Type::Permissions as u8)], vec![1]);
+        assert_eq!(list[1][&(Type::Identifier as u8)], b"second".to_vec());
+        assert_eq!(list[1][&(Type::Permissions as u8)], vec![0]);
+        assert!(decode_list(&[0xFF, 0x00, 0xFF, 0x00]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn consecutive_items_of_the_same_type_are_merged_as_fragments() {
+        let value = (0..600).map(|i| i as u8).collect::<Vec<_>>();
+        let mut writer = Writer::new();
+        writer.push(0x01, &value).push(0x02, &[7]);
+        let encoded = writer.into_bytes();
+        assert_eq!(&encoded[..2], &[0x01, 255]);
+        assert_eq!(&encoded[257..259], &[0x01, 255]);
+        assert_eq!(&encoded[514..516], &[0x01, 90]);
+
+        assert_eq!(items(&encoded), vec![(0x01, value.clone()), (0x02, vec![7])]);
+        assert_eq!(decode(&encoded).unwrap()[&0x01], value);
+
+        // items shorter than 255 Bytes aren't continued by the next item of the same type
+        assert_eq!(items(&[0x01, 0x01, 1, 0x01, 0x01, 2]), vec![(0x01, vec![1]), (0x01, vec![2])]);
+    }
+
+    #[test]
+    fn item_of_255_bytes_is_terminated_by_an_empty_fragment() {
+        let mut data = vec![0x01, 255];
+        data.extend_from_slice(&[9; 255]);
+        data.extend_from_slice(&[0x01, 0x00, 0x02, 0x01, 7]);
+
+        assert_eq!(items(&data), vec![(0x01, vec![9; 255]), (0x02, vec![7])]);
+    }
+
+    #[test]
+    fn consecutive_items_of_full_fragments_round_trip() {
+        for len in &[255, 510] {
+            let mut writer = Writer::new();
+            writer.push(0x01, &vec![1; *len]).push(0x01, &[2]).push(0x01, &vec![3; *len]);
+            let encoded = writer.into_bytes();
+
+            assert_eq!(items(&encoded), vec![
+                (0x01, vec![1; *len]),
+                (0x01, vec![2]),
+                (0x01, vec![3; *len]),
+            ]);
+            assert_eq!(&encoded[encoded.len() - 2..], &[0x01, 0x00]);
+        }
+    }
+
+    #[test]
+    fn missing_length_is_an_error() {
+        assert_eq!(decode(&[0x01]), Err(DecodeError::MissingLength { offset: 0 }));
+        assert_eq!(
+            decode(&[0x06, 0x01, 0x01, 0x02]),
+            Err(DecodeError::MissingLength { offset: 3 })
+        );
+        assert_eq!(decode_list(&[0xFF, 0x00, 0x01]), Err(DecodeError::MissingLength { offset: 2 }));
+    }
+
+    #[test]
+    fn truncated_value_is_an_error() {
+        assert_eq!(decode(&[0x01, 0x03, 1, 2]), Err(DecodeError::Truncated { offset: 0 }));
+        // the continuation of a full-length fragment is truncated
+        let mut data = vec![0x01, 255];
+        data.extend_from_slice(&[9; 255]);
+        data.extend_from_slice(&[0x01, 0x02, 1]);
+        assert_eq!(decode(&data), Err(DecodeError::Truncated { offset: 257 }));
+
+        let mut reader = Reader::new(&[0x01, 0x03, 1, 2]);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
     }
 }
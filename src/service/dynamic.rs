@@ -0,0 +1,80 @@
+use crate::{
+    characteristic::{self, HapCharacteristic},
+    service::{self, HapService, Service},
+    Error,
+    HapType,
+    Result,
+};
+
+/// Dynamic Service, composed of arbitrary Characteristics at runtime.
+pub type DynamicService = Service<DynamicServiceInner>;
+
+/// Inner type of the Dynamic Service.
+#[derive(Default, HapService)]
+pub struct DynamicServiceInner {
+    /// ID of the Dynamic Service.
+    id: u64,
+    /// `HapType` of the Dynamic Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+    /// Characteristics of the Dynamic Service.
+    pub characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl DynamicServiceInner {
+    /// Adds a Characteristic to the Service.
+    pub fn add_characteristic<C: 'static + HapCharacteristic + Send>(&mut self, characteristic: C) {
+        self.characteristics.push(Box::new(characteristic));
+    }
+
+    /// Adds a new Characteristic of the given `HapType` to the Service.
+    pub fn add_characteristic_by_type(&mut self, hap_type: HapType) -> Result<()> {
+        let characteristic = characteristic::new_by_type(hap_type)
+            .ok_or_else(|| Error::from_str("HapType isn't a Characteristic type"))?;
+        self.characteristics.push(characteristic);
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the first Characteristic of the given `HapType`, if any.
+    pub fn get_mut_characteristic(&mut self, hap_type: HapType) -> Option<&mut (dyn HapCharacteristic + Send)> {
+        for characteristic in &mut self.characteristics {
+            if characteristic.get_type().ok() == Some(hap_type) {
+                return Some(characteristic.as_mut());
+            }
+        }
+        None
+    }
+}
+
+/// Creates a new Dynamic Service of the given `HapType` along with its required Characteristics.
+/// Dynamic Services of a `HapType::Custom` are created without any Characteristics.
+///
+/// # Examples
+///
+/// ```
+/// use hap::{service::dynamic, HapType};
+///
+/// let mut lightbulb = dynamic::new(HapType::Lightbulb).unwrap();
+/// lightbulb.inner.add_characteristic_by_type(HapType::Brightness).unwrap();
+/// ```
+pub fn new(hap_type: HapType) -> Result<DynamicService> {
+    let required_characteristics = match hap_type {
+        HapType::Custom(_) => Vec::new(),
+        _ => service::required_characteristics(hap_type)
+            .ok_or_else(|| Error::from_str("HapType isn't a Service type"))?,
+    };
+    let mut inner = DynamicServiceInner {
+        hap_type,
+        ..Default::default()
+    };
+    for characteristic_type in required_characteristics {
+        inner.add_characteristic_by_type(characteristic_type)?;
+    }
+    Ok(DynamicService::new(inner))
+}
@@ -0,0 +1,118 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		access_code_control_point,
+		access_code_supported_configuration,
+		configuration_state,
+	},
+    HapType,
+};
+
+/// Access Code Service.
+pub type AccessCode = Service<AccessCodeInner>;
+
+impl Default for AccessCode {
+    fn default() -> AccessCode { new() }
+}
+
+/// Inner type of the Access Code Service.
+#[derive(Default)]
+pub struct AccessCodeInner {
+    /// ID of the Access Code Service.
+    id: u64,
+    /// `HapType` of the Access Code Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Access Code Control Point Characteristic.
+	pub access_code_control_point: access_code_control_point::AccessCodeControlPoint,
+	/// Access Code Supported Configuration Characteristic.
+	pub access_code_supported_configuration: access_code_supported_configuration::AccessCodeSupportedConfiguration,
+	/// Configuration State Characteristic.
+	pub configuration_state: configuration_state::ConfigurationState,
+
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for AccessCodeInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.access_code_control_point,
+			&self.access_code_supported_configuration,
+			&self.configuration_state,
+		];
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.access_code_control_point,
+			&mut self.access_code_supported_configuration,
+			&mut self.configuration_state,
+		];
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Access Code Service.
+pub fn new() -> AccessCode {
+    AccessCode::new(AccessCodeInner {
+        hap_type: HapType::AccessCode,
+		access_code_control_point: access_code_control_point::new(),
+		access_code_supported_configuration: access_code_supported_configuration::new(),
+		configuration_state: configuration_state::new(),
+		..Default::default()
+    })
+}
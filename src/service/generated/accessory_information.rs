@@ -34,6 +34,8 @@ pub struct AccessoryInformationInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Identify Characteristic.
 	pub identify: identify::Identify,
@@ -52,6 +54,9 @@ pub struct AccessoryInformationInner {
 	pub hardware_revision: Option<hardware_revision::HardwareRevision>,
 	/// Accessory Flags Characteristic.
 	pub accessory_flags: Option<accessory_flags::AccessoryFlags>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for AccessoryInformationInner {
@@ -83,6 +88,14 @@ impl HapService for AccessoryInformationInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.identify,
@@ -93,10 +106,13 @@ impl HapService for AccessoryInformationInner {
 			&self.firmware_revision,
 		];
 		if let Some(c) = &self.hardware_revision {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.accessory_flags {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -111,10 +127,13 @@ impl HapService for AccessoryInformationInner {
 			&mut self.firmware_revision,
 		];
 		if let Some(c) = &mut self.hardware_revision {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.accessory_flags {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -33,6 +33,8 @@ pub struct AirPurifierInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Active Characteristic.
 	pub active: active::Active,
@@ -49,6 +51,9 @@ pub struct AirPurifierInner {
 	pub swing_mode: Option<swing_mode::SwingMode>,
 	/// Rotation Speed Characteristic.
 	pub rotation_speed: Option<rotation_speed::RotationSpeed>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for AirPurifierInner {
@@ -80,6 +85,14 @@ impl HapService for AirPurifierInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.active,
@@ -87,16 +100,19 @@ impl HapService for AirPurifierInner {
 			&self.target_air_purifier_state,
 		];
 		if let Some(c) = &self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -108,16 +124,19 @@ impl HapService for AirPurifierInner {
 			&mut self.target_air_purifier_state,
 		];
 		if let Some(c) = &mut self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -40,6 +40,8 @@ pub struct AirQualitySensorInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Air Quality Characteristic.
 	pub air_quality: air_quality::AirQuality,
@@ -70,6 +72,9 @@ pub struct AirQualitySensorInner {
 	pub carbon_monoxide_level: Option<carbon_monoxide_level::CarbonMonoxideLevel>,
 	/// Carbon Dioxide Level Characteristic.
 	pub carbon_dioxide_level: Option<carbon_dioxide_level::CarbonDioxideLevel>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for AirQualitySensorInner {
@@ -101,48 +106,59 @@ impl HapService for AirQualitySensorInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.air_quality,
 		];
 		if let Some(c) = &self.status_active {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_fault {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_tampered {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_low_battery {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.ozone_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.nitrogen_dioxide_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.sulphur_dioxide_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.pm2_5_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.pm10_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.voc_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.carbon_monoxide_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.carbon_dioxide_level {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -152,43 +168,46 @@ impl HapService for AirQualitySensorInner {
 			&mut self.air_quality,
 		];
 		if let Some(c) = &mut self.status_active {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_fault {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_tampered {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_low_battery {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.ozone_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.nitrogen_dioxide_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.sulphur_dioxide_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.pm2_5_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.pm10_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.voc_density {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.carbon_monoxide_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.carbon_dioxide_level {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -0,0 +1,112 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		supported_audio_stream_configuration,
+		selected_audio_stream_configuration,
+	},
+    HapType,
+};
+
+/// Audio Stream Management Service.
+pub type AudioStreamManagement = Service<AudioStreamManagementInner>;
+
+impl Default for AudioStreamManagement {
+    fn default() -> AudioStreamManagement { new() }
+}
+
+/// Inner type of the Audio Stream Management Service.
+#[derive(Default)]
+pub struct AudioStreamManagementInner {
+    /// ID of the Audio Stream Management Service.
+    id: u64,
+    /// `HapType` of the Audio Stream Management Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Supported Audio Stream Configuration Characteristic.
+	pub supported_audio_stream_configuration: supported_audio_stream_configuration::SupportedAudioStreamConfiguration,
+	/// Selected Audio Stream Configuration Characteristic.
+	pub selected_audio_stream_configuration: selected_audio_stream_configuration::SelectedAudioStreamConfiguration,
+
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for AudioStreamManagementInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.supported_audio_stream_configuration,
+			&self.selected_audio_stream_configuration,
+		];
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.supported_audio_stream_configuration,
+			&mut self.selected_audio_stream_configuration,
+		];
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Audio Stream Management Service.
+pub fn new() -> AudioStreamManagement {
+    AudioStreamManagement::new(AudioStreamManagementInner {
+        hap_type: HapType::AudioStreamManagement,
+		supported_audio_stream_configuration: supported_audio_stream_configuration::new(),
+		selected_audio_stream_configuration: selected_audio_stream_configuration::new(),
+		..Default::default()
+    })
+}
@@ -30,6 +30,8 @@ pub struct BatteryServiceInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Battery Level Characteristic.
 	pub battery_level: battery_level::BatteryLevel,
@@ -40,6 +42,9 @@ pub struct BatteryServiceInner {
 
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for BatteryServiceInner {
@@ -71,6 +76,14 @@ impl HapService for BatteryServiceInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.battery_level,
@@ -78,7 +91,10 @@ impl HapService for BatteryServiceInner {
 			&self.status_low_battery,
 		];
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -90,7 +106,10 @@ impl HapService for BatteryServiceInner {
 			&mut self.status_low_battery,
 		];
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -0,0 +1,175 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		event_snapshots_active,
+		homekit_camera_active,
+		camera_operating_mode_indicator,
+		manually_disabled,
+		night_vision,
+		periodic_snapshots_active,
+		third_party_camera_active,
+		diagonal_field_of_view,
+		image_rotation,
+	},
+    HapType,
+};
+
+/// Camera Operating Mode Service.
+pub type CameraOperatingMode = Service<CameraOperatingModeInner>;
+
+impl Default for CameraOperatingMode {
+    fn default() -> CameraOperatingMode { new() }
+}
+
+/// Inner type of the Camera Operating Mode Service.
+#[derive(Default)]
+pub struct CameraOperatingModeInner {
+    /// ID of the Camera Operating Mode Service.
+    id: u64,
+    /// `HapType` of the Camera Operating Mode Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Event Snapshots Active Characteristic.
+	pub event_snapshots_active: event_snapshots_active::EventSnapshotsActive,
+	/// HomeKit Camera Active Characteristic.
+	pub homekit_camera_active: homekit_camera_active::HomeKitCameraActive,
+
+	/// Camera Operating Mode Indicator Characteristic.
+	pub camera_operating_mode_indicator: Option<camera_operating_mode_indicator::CameraOperatingModeIndicator>,
+	/// Manually Disabled Characteristic.
+	pub manually_disabled: Option<manually_disabled::ManuallyDisabled>,
+	/// Night Vision Characteristic.
+	pub night_vision: Option<night_vision::NightVision>,
+	/// Periodic Snapshots Active Characteristic.
+	pub periodic_snapshots_active: Option<periodic_snapshots_active::PeriodicSnapshotsActive>,
+	/// Third Party Camera Active Characteristic.
+	pub third_party_camera_active: Option<third_party_camera_active::ThirdPartyCameraActive>,
+	/// Diagonal Field Of View Characteristic.
+	pub diagonal_field_of_view: Option<diagonal_field_of_view::DiagonalFieldOfView>,
+	/// Image Rotation Characteristic.
+	pub image_rotation: Option<image_rotation::ImageRotation>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for CameraOperatingModeInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.event_snapshots_active,
+			&self.homekit_camera_active,
+		];
+		if let Some(c) = &self.camera_operating_mode_indicator {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.manually_disabled {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.night_vision {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.periodic_snapshots_active {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.third_party_camera_active {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.diagonal_field_of_view {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.image_rotation {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.event_snapshots_active,
+			&mut self.homekit_camera_active,
+		];
+		if let Some(c) = &mut self.camera_operating_mode_indicator {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.manually_disabled {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.night_vision {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.periodic_snapshots_active {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.third_party_camera_active {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.diagonal_field_of_view {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.image_rotation {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Camera Operating Mode Service.
+pub fn new() -> CameraOperatingMode {
+    CameraOperatingMode::new(CameraOperatingModeInner {
+        hap_type: HapType::CameraOperatingMode,
+		event_snapshots_active: event_snapshots_active::new(),
+		homekit_camera_active: homekit_camera_active::new(),
+		..Default::default()
+    })
+}
@@ -0,0 +1,139 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		active,
+		supported_camera_recording_configuration,
+		supported_video_recording_configuration,
+		supported_audio_recording_configuration,
+		selected_camera_recording_configuration,
+		recording_audio_active,
+	},
+    HapType,
+};
+
+/// Camera Recording Management Service.
+pub type CameraRecordingManagement = Service<CameraRecordingManagementInner>;
+
+impl Default for CameraRecordingManagement {
+    fn default() -> CameraRecordingManagement { new() }
+}
+
+/// Inner type of the Camera Recording Management Service.
+#[derive(Default)]
+pub struct CameraRecordingManagementInner {
+    /// ID of the Camera Recording Management Service.
+    id: u64,
+    /// `HapType` of the Camera Recording Management Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Active Characteristic.
+	pub active: active::Active,
+	/// Supported Camera Recording Configuration Characteristic.
+	pub supported_camera_recording_configuration: supported_camera_recording_configuration::SupportedCameraRecordingConfiguration,
+	/// Supported Video Recording Configuration Characteristic.
+	pub supported_video_recording_configuration: supported_video_recording_configuration::SupportedVideoRecordingConfiguration,
+	/// Supported Audio Recording Configuration Characteristic.
+	pub supported_audio_recording_configuration: supported_audio_recording_configuration::SupportedAudioRecordingConfiguration,
+	/// Selected Camera Recording Configuration Characteristic.
+	pub selected_camera_recording_configuration: selected_camera_recording_configuration::SelectedCameraRecordingConfiguration,
+
+	/// Recording Audio Active Characteristic.
+	pub recording_audio_active: Option<recording_audio_active::RecordingAudioActive>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for CameraRecordingManagementInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.active,
+			&self.supported_camera_recording_configuration,
+			&self.supported_video_recording_configuration,
+			&self.supported_audio_recording_configuration,
+			&self.selected_camera_recording_configuration,
+		];
+		if let Some(c) = &self.recording_audio_active {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.active,
+			&mut self.supported_camera_recording_configuration,
+			&mut self.supported_video_recording_configuration,
+			&mut self.supported_audio_recording_configuration,
+			&mut self.selected_camera_recording_configuration,
+		];
+		if let Some(c) = &mut self.recording_audio_active {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Camera Recording Management Service.
+pub fn new() -> CameraRecordingManagement {
+    CameraRecordingManagement::new(CameraRecordingManagementInner {
+        hap_type: HapType::CameraRecordingManagement,
+		active: active::new(),
+		supported_camera_recording_configuration: supported_camera_recording_configuration::new(),
+		supported_video_recording_configuration: supported_video_recording_configuration::new(),
+		supported_audio_recording_configuration: supported_audio_recording_configuration::new(),
+		selected_camera_recording_configuration: selected_camera_recording_configuration::new(),
+		..Default::default()
+    })
+}
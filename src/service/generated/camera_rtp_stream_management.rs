@@ -11,6 +11,7 @@ use crate::{
 		streaming_status,
 		setup_endpoints,
 		name,
+		active,
 	},
     HapType,
 };
@@ -33,6 +34,8 @@ pub struct CameraRTPStreamManagementInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Supported Video Stream Configuration Characteristic.
 	pub supported_video_stream_configuration: supported_video_stream_configuration::SupportedVideoStreamConfiguration,
@@ -49,6 +52,11 @@ pub struct CameraRTPStreamManagementInner {
 
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+	/// Active Characteristic.
+	pub active: Option<active::Active>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for CameraRTPStreamManagementInner {
@@ -80,6 +88,14 @@ impl HapService for CameraRTPStreamManagementInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.supported_video_stream_configuration,
@@ -90,7 +106,13 @@ impl HapService for CameraRTPStreamManagementInner {
 			&self.setup_endpoints,
 		];
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.active {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -105,7 +127,13 @@ impl HapService for CameraRTPStreamManagementInner {
 			&mut self.setup_endpoints,
 		];
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.active {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -34,6 +34,8 @@ pub struct CarbonDioxideSensorInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Carbon Dioxide Detected Characteristic.
 	pub carbon_dioxide_detected: carbon_dioxide_detected::CarbonDioxideDetected,
@@ -52,6 +54,9 @@ pub struct CarbonDioxideSensorInner {
 	pub carbon_dioxide_peak_level: Option<carbon_dioxide_peak_level::CarbonDioxidePeakLevel>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for CarbonDioxideSensorInner {
@@ -83,30 +88,41 @@ impl HapService for CarbonDioxideSensorInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.carbon_dioxide_detected,
 		];
 		if let Some(c) = &self.status_active {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_fault {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_low_battery {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_tampered {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.carbon_dioxide_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.carbon_dioxide_peak_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -116,25 +132,28 @@ impl HapService for CarbonDioxideSensorInner {
 			&mut self.carbon_dioxide_detected,
 		];
 		if let Some(c) = &mut self.status_active {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_fault {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_low_battery {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_tampered {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.carbon_dioxide_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.carbon_dioxide_peak_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
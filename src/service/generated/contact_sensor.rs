@@ -32,6 +32,8 @@ pub struct ContactSensorInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Contact Sensor State Characteristic.
 	pub contact_sensor_state: contact_sensor_state::ContactSensorState,
@@ -46,6 +48,9 @@ pub struct ContactSensorInner {
 	pub status_low_battery: Option<status_low_battery::StatusLowBattery>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for ContactSensorInner {
@@ -77,24 +82,35 @@ impl HapService for ContactSensorInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.contact_sensor_state,
 		];
 		if let Some(c) = &self.status_active {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_fault {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_tampered {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.status_low_battery {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -104,19 +120,22 @@ impl HapService for ContactSensorInner {
 			&mut self.contact_sensor_state,
 		];
 		if let Some(c) = &mut self.status_active {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_fault {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_tampered {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.status_low_battery {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -0,0 +1,118 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		supported_data_stream_transport_configuration,
+		setup_data_stream_transport,
+		version,
+	},
+    HapType,
+};
+
+/// Data Stream Transport Management Service.
+pub type DataStreamTransportManagement = Service<DataStreamTransportManagementInner>;
+
+impl Default for DataStreamTransportManagement {
+    fn default() -> DataStreamTransportManagement { new() }
+}
+
+/// Inner type of the Data Stream Transport Management Service.
+#[derive(Default)]
+pub struct DataStreamTransportManagementInner {
+    /// ID of the Data Stream Transport Management Service.
+    id: u64,
+    /// `HapType` of the Data Stream Transport Management Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Supported Data Stream Transport Configuration Characteristic.
+	pub supported_data_stream_transport_configuration: supported_data_stream_transport_configuration::SupportedDataStreamTransportConfiguration,
+	/// Setup Data Stream Transport Characteristic.
+	pub setup_data_stream_transport: setup_data_stream_transport::SetupDataStreamTransport,
+	/// Version Characteristic.
+	pub version: version::Version,
+
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for DataStreamTransportManagementInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.supported_data_stream_transport_configuration,
+			&self.setup_data_stream_transport,
+			&self.version,
+		];
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.supported_data_stream_transport_configuration,
+			&mut self.setup_data_stream_transport,
+			&mut self.version,
+		];
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Data Stream Transport Management Service.
+pub fn new() -> DataStreamTransportManagement {
+    DataStreamTransportManagement::new(DataStreamTransportManagementInner {
+        hap_type: HapType::DataStreamTransportManagement,
+		supported_data_stream_transport_configuration: supported_data_stream_transport_configuration::new(),
+		setup_data_stream_transport: setup_data_stream_transport::new(),
+		version: version::new(),
+		..Default::default()
+    })
+}
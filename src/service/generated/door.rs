@@ -32,6 +32,8 @@ pub struct DoorInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Current Position Characteristic.
 	pub current_position: current_position::CurrentPosition,
@@ -46,6 +48,9 @@ pub struct DoorInner {
 	pub obstruction_detected: Option<obstruction_detected::ObstructionDetected>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for DoorInner {
@@ -77,6 +82,14 @@ impl HapService for DoorInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.current_position,
@@ -84,13 +97,16 @@ impl HapService for DoorInner {
 			&self.target_position,
 		];
 		if let Some(c) = &self.hold_position {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.obstruction_detected {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -102,13 +118,16 @@ impl HapService for DoorInner {
 			&mut self.target_position,
 		];
 		if let Some(c) = &mut self.hold_position {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.obstruction_detected {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
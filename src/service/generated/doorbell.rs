@@ -30,6 +30,8 @@ pub struct DoorbellInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Programmable Switch Event Characteristic.
 	pub programmable_switch_event: programmable_switch_event::ProgrammableSwitchEvent,
@@ -40,6 +42,9 @@ pub struct DoorbellInner {
 	pub volume: Option<volume::Volume>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for DoorbellInner {
@@ -71,18 +76,29 @@ impl HapService for DoorbellInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.programmable_switch_event,
 		];
 		if let Some(c) = &self.brightness {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.volume {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -92,13 +108,16 @@ impl HapService for DoorbellInner {
 			&mut self.programmable_switch_event,
 		];
 		if let Some(c) = &mut self.brightness {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.volume {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
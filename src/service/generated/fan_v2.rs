@@ -34,6 +34,8 @@ pub struct Fanv2Inner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Active Characteristic.
 	pub active: active::Active,
@@ -52,6 +54,9 @@ pub struct Fanv2Inner {
 	pub rotation_speed: Option<rotation_speed::RotationSpeed>,
 	/// Swing Mode Characteristic.
 	pub swing_mode: Option<swing_mode::SwingMode>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for Fanv2Inner {
@@ -83,30 +88,41 @@ impl HapService for Fanv2Inner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.active,
 		];
 		if let Some(c) = &self.current_fan_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_fan_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.rotation_direction {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -116,25 +132,28 @@ impl HapService for Fanv2Inner {
 			&mut self.active,
 		];
 		if let Some(c) = &mut self.current_fan_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_fan_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.rotation_direction {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
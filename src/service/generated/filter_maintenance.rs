@@ -30,6 +30,8 @@ pub struct FilterMaintenanceInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Filter Change Indication Characteristic.
 	pub filter_change_indication: filter_change_indication::FilterChangeIndication,
@@ -40,6 +42,9 @@ pub struct FilterMaintenanceInner {
 	pub reset_filter_indication: Option<reset_filter_indication::ResetFilterIndication>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for FilterMaintenanceInner {
@@ -71,18 +76,29 @@ impl HapService for FilterMaintenanceInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.filter_change_indication,
 		];
 		if let Some(c) = &self.filter_life_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.reset_filter_indication {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -92,13 +108,16 @@ impl HapService for FilterMaintenanceInner {
 			&mut self.filter_change_indication,
 		];
 		if let Some(c) = &mut self.filter_life_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.reset_filter_indication {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -32,6 +32,8 @@ pub struct GarageDoorOpenerInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Current Door State Characteristic.
 	pub current_door_state: current_door_state::CurrentDoorState,
@@ -46,6 +48,9 @@ pub struct GarageDoorOpenerInner {
 	pub lock_target_state: Option<lock_target_state::LockTargetState>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for GarageDoorOpenerInner {
@@ -77,6 +82,14 @@ impl HapService for GarageDoorOpenerInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.current_door_state,
@@ -84,13 +97,16 @@ impl HapService for GarageDoorOpenerInner {
 			&self.obstruction_detected,
 		];
 		if let Some(c) = &self.lock_current_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.lock_target_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -102,13 +118,16 @@ impl HapService for GarageDoorOpenerInner {
 			&mut self.obstruction_detected,
 		];
 		if let Some(c) = &mut self.lock_current_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.lock_target_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
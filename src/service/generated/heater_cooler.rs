@@ -37,6 +37,8 @@ pub struct HeaterCoolerInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Active Characteristic.
 	pub active: active::Active,
@@ -61,6 +63,9 @@ pub struct HeaterCoolerInner {
 	pub temperature_display_units: Option<temperature_display_units::TemperatureDisplayUnits>,
 	/// Rotation Speed Characteristic.
 	pub rotation_speed: Option<rotation_speed::RotationSpeed>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for HeaterCoolerInner {
@@ -92,6 +97,14 @@ impl HapService for HeaterCoolerInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.active,
@@ -100,25 +113,28 @@ impl HapService for HeaterCoolerInner {
 			&self.current_temperature,
 		];
 		if let Some(c) = &self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.cooling_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.heating_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.temperature_display_units {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -131,25 +147,28 @@ impl HapService for HeaterCoolerInner {
 			&mut self.current_temperature,
 		];
 		if let Some(c) = &mut self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.cooling_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.heating_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.temperature_display_units {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
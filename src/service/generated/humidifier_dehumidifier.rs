@@ -37,6 +37,8 @@ pub struct HumidifierDehumidifierInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Current Relative Humidity Characteristic.
 	pub current_relative_humidity: current_relative_humidity::CurrentRelativeHumidity,
@@ -61,6 +63,9 @@ pub struct HumidifierDehumidifierInner {
 	pub relative_humidity_humidifier_threshold: Option<relative_humidity_humidifier_threshold::RelativeHumidityHumidifierThreshold>,
 	/// Rotation Speed Characteristic.
 	pub rotation_speed: Option<rotation_speed::RotationSpeed>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for HumidifierDehumidifierInner {
@@ -92,6 +97,14 @@ impl HapService for HumidifierDehumidifierInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.current_relative_humidity,
@@ -100,25 +113,28 @@ impl HapService for HumidifierDehumidifierInner {
 			&self.active,
 		];
 		if let Some(c) = &self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.water_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.relative_humidity_dehumidifier_threshold {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.relative_humidity_humidifier_threshold {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -131,25 +147,28 @@ impl HapService for HumidifierDehumidifierInner {
 			&mut self.active,
 		];
 		if let Some(c) = &mut self.lock_physical_controls {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.water_level {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.relative_humidity_dehumidifier_threshold {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.relative_humidity_humidifier_threshold {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.rotation_speed {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -34,6 +34,8 @@ pub struct InputSourceInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Configured Name Characteristic.
 	pub configured_name: configured_name::ConfiguredName,
@@ -52,6 +54,9 @@ pub struct InputSourceInner {
 	pub target_visibility_state: Option<target_visibility_state::TargetVisibilityState>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for InputSourceInner {
@@ -83,6 +88,14 @@ impl HapService for InputSourceInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.configured_name,
@@ -91,16 +104,19 @@ impl HapService for InputSourceInner {
 			&self.current_visibility_state,
 		];
 		if let Some(c) = &self.identifier {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.input_device_type {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_visibility_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -113,16 +129,19 @@ impl HapService for InputSourceInner {
 			&mut self.current_visibility_state,
 		];
 		if let Some(c) = &mut self.identifier {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.input_device_type {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_visibility_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
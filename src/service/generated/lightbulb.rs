@@ -9,6 +9,10 @@ use crate::{
 		hue,
 		saturation,
 		name,
+		color_temperature,
+		characteristic_value_transition_control,
+		supported_characteristic_value_transition_configuration,
+		characteristic_value_active_transition_count,
 	},
     HapType,
 };
@@ -31,6 +35,8 @@ pub struct LightbulbInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// On Characteristic.
 	pub on: on::On,
@@ -43,6 +49,17 @@ pub struct LightbulbInner {
 	pub saturation: Option<saturation::Saturation>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+	/// Color Temperature Characteristic.
+	pub color_temperature: Option<color_temperature::ColorTemperature>,
+	/// Characteristic Value Transition Control Characteristic.
+	pub characteristic_value_transition_control: Option<characteristic_value_transition_control::CharacteristicValueTransitionControl>,
+	/// Supported Characteristic Value Transition Configuration Characteristic.
+	pub supported_characteristic_value_transition_configuration: Option<supported_characteristic_value_transition_configuration::SupportedCharacteristicValueTransitionConfiguration>,
+	/// Characteristic Value Active Transition Count Characteristic.
+	pub characteristic_value_active_transition_count: Option<characteristic_value_active_transition_count::CharacteristicValueActiveTransitionCount>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for LightbulbInner {
@@ -74,21 +91,44 @@ impl HapService for LightbulbInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.on,
 		];
 		if let Some(c) = &self.brightness {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.hue {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.saturation {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.color_temperature {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.characteristic_value_transition_control {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.supported_characteristic_value_transition_configuration {
+			characteristics.push(c);
+		}
+		if let Some(c) = &self.characteristic_value_active_transition_count {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -98,16 +138,31 @@ impl HapService for LightbulbInner {
 			&mut self.on,
 		];
 		if let Some(c) = &mut self.brightness {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.hue {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.saturation {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.color_temperature {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.characteristic_value_transition_control {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.supported_characteristic_value_transition_configuration {
+			characteristics.push(c);
+		}
+		if let Some(c) = &mut self.characteristic_value_active_transition_count {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
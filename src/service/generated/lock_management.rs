@@ -36,6 +36,8 @@ pub struct LockManagementInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Lock Control Point Characteristic.
 	pub lock_control_point: lock_control_point::LockControlPoint,
@@ -58,6 +60,9 @@ pub struct LockManagementInner {
 	pub motion_detected: Option<motion_detected::MotionDetected>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for LockManagementInner {
@@ -89,34 +94,45 @@ impl HapService for LockManagementInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.lock_control_point,
 			&self.version,
 		];
 		if let Some(c) = &self.logs {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.audio_feedback {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.lock_management_auto_security_timeout {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.administrator_only_access {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.lock_last_known_action {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.current_door_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.motion_detected {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -127,28 +143,31 @@ impl HapService for LockManagementInner {
 			&mut self.version,
 		];
 		if let Some(c) = &mut self.logs {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.audio_feedback {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.lock_management_auto_security_timeout {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.administrator_only_access {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.lock_last_known_action {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.current_door_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.motion_detected {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
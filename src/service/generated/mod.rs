@@ -1,5 +1,7 @@
 // THIS FILE IS AUTO-GENERATED
 
+use crate::HapType;
+
 pub mod accessory_information;
 pub mod air_purifier;
 pub mod air_quality_sensor;
@@ -42,3 +44,73 @@ pub mod window;
 pub mod window_covering;
 pub mod television;
 pub mod input_source;
+pub mod target_control_management;
+pub mod target_control;
+pub mod audio_stream_management;
+pub mod data_stream_transport_management;
+pub mod siri;
+pub mod camera_recording_management;
+pub mod camera_operating_mode;
+pub mod wifi_transport;
+pub mod access_code;
+pub mod nfc_access;
+
+/// Returns the `HapType`s of the required Characteristics of a Service of the given `HapType`.
+/// Returns `None` if the `HapType` isn't a Service type.
+pub fn required_characteristics(hap_type: HapType) -> Option<Vec<HapType>> {
+    match hap_type {
+		HapType::AccessoryInformation => Some(vec![HapType::Identify, HapType::Manufacturer, HapType::Model, HapType::Name, HapType::SerialNumber, HapType::FirmwareRevision]),
+		HapType::AirPurifier => Some(vec![HapType::Active, HapType::CurrentAirPurifierState, HapType::TargetAirPurifierState]),
+		HapType::AirQualitySensor => Some(vec![HapType::AirQuality]),
+		HapType::BatteryService => Some(vec![HapType::BatteryLevel, HapType::ChargingState, HapType::StatusLowBattery]),
+		HapType::CameraRTPStreamManagement => Some(vec![HapType::SupportedVideoStreamConfiguration, HapType::SupportedAudioStreamConfiguration, HapType::SupportedRTPConfiguration, HapType::SelectedRTPStreamConfiguration, HapType::StreamingStatus, HapType::SetupEndpoints]),
+		HapType::CarbonDioxideSensor => Some(vec![HapType::CarbonDioxideDetected]),
+		HapType::CarbonMonoxideSensor => Some(vec![HapType::CarbonMonoxideDetected]),
+		HapType::ContactSensor => Some(vec![HapType::ContactSensorState]),
+		HapType::Door => Some(vec![HapType::CurrentPosition, HapType::PositionState, HapType::TargetPosition]),
+		HapType::Doorbell => Some(vec![HapType::ProgrammableSwitchEvent]),
+		HapType::Fan => Some(vec![HapType::On]),
+		HapType::Fanv2 => Some(vec![HapType::Active]),
+		HapType::FilterMaintenance => Some(vec![HapType::FilterChangeIndication]),
+		HapType::Faucet => Some(vec![HapType::Active]),
+		HapType::GarageDoorOpener => Some(vec![HapType::CurrentDoorState, HapType::TargetDoorState, HapType::ObstructionDetected]),
+		HapType::HeaterCooler => Some(vec![HapType::Active, HapType::CurrentHeaterCoolerState, HapType::TargetHeaterCoolerState, HapType::CurrentTemperature]),
+		HapType::HumidifierDehumidifier => Some(vec![HapType::CurrentRelativeHumidity, HapType::CurrentHumidifierDehumidifierState, HapType::TargetHumidifierDehumidifierState, HapType::Active]),
+		HapType::HumiditySensor => Some(vec![HapType::CurrentRelativeHumidity]),
+		HapType::IrrigationSystem => Some(vec![HapType::Active, HapType::ProgramMode, HapType::InUse]),
+		HapType::LeakSensor => Some(vec![HapType::LeakDetected]),
+		HapType::LightSensor => Some(vec![HapType::CurrentAmbientLightLevel]),
+		HapType::Lightbulb => Some(vec![HapType::On]),
+		HapType::LockManagement => Some(vec![HapType::LockControlPoint, HapType::Version]),
+		HapType::LockMechanism => Some(vec![HapType::LockCurrentState, HapType::LockTargetState]),
+		HapType::Microphone => Some(vec![HapType::Volume, HapType::Mute]),
+		HapType::MotionSensor => Some(vec![HapType::MotionDetected]),
+		HapType::OccupancySensor => Some(vec![HapType::OccupancyDetected]),
+		HapType::Outlet => Some(vec![HapType::On, HapType::OutletInUse]),
+		HapType::SecuritySystem => Some(vec![HapType::SecuritySystemCurrentState, HapType::SecuritySystemTargetState]),
+		HapType::ServiceLabel => Some(vec![HapType::ServiceLabelNamespace]),
+		HapType::Slat => Some(vec![HapType::SlatType, HapType::CurrentSlatState]),
+		HapType::SmokeSensor => Some(vec![HapType::SmokeDetected]),
+		HapType::Speaker => Some(vec![HapType::Mute]),
+		HapType::StatelessProgrammableSwitch => Some(vec![HapType::ProgrammableSwitchEvent]),
+		HapType::Switch => Some(vec![HapType::On]),
+		HapType::TemperatureSensor => Some(vec![HapType::CurrentTemperature]),
+		HapType::Thermostat => Some(vec![HapType::CurrentHeatingCoolingState, HapType::TargetHeatingCoolingState, HapType::CurrentTemperature, HapType::TargetTemperature, HapType::TemperatureDisplayUnits]),
+		HapType::Valve => Some(vec![HapType::Active, HapType::InUse, HapType::ValveType]),
+		HapType::Window => Some(vec![HapType::CurrentPosition, HapType::TargetPosition, HapType::PositionState]),
+		HapType::WindowCovering => Some(vec![HapType::CurrentPosition, HapType::TargetPosition, HapType::PositionState]),
+		HapType::Television => Some(vec![HapType::Active, HapType::ActiveIdentifier, HapType::ConfiguredName, HapType::SleepDiscoveryMode]),
+		HapType::InputSource => Some(vec![HapType::ConfiguredName, HapType::InputSourceType, HapType::IsConfigured, HapType::CurrentVisibilityState]),
+		HapType::TargetControlManagement => Some(vec![HapType::TargetControlSupportedConfiguration, HapType::TargetControlList]),
+		HapType::TargetControl => Some(vec![HapType::ActiveIdentifier, HapType::Active, HapType::ButtonEvent]),
+		HapType::AudioStreamManagement => Some(vec![HapType::SupportedAudioStreamConfiguration, HapType::SelectedAudioStreamConfiguration]),
+		HapType::DataStreamTransportManagement => Some(vec![HapType::SupportedDataStreamTransportConfiguration, HapType::SetupDataStreamTransport, HapType::Version]),
+		HapType::Siri => Some(vec![HapType::SiriInputType]),
+		HapType::CameraRecordingManagement => Some(vec![HapType::Active, HapType::SupportedCameraRecordingConfiguration, HapType::SupportedVideoRecordingConfiguration, HapType::SupportedAudioRecordingConfiguration, HapType::SelectedCameraRecordingConfiguration]),
+		HapType::CameraOperatingMode => Some(vec![HapType::EventSnapshotsActive, HapType::HomeKitCameraActive]),
+		HapType::WiFiTransport => Some(vec![HapType::CurrentTransport, HapType::WiFiCapabilities]),
+		HapType::AccessCode => Some(vec![HapType::AccessCodeControlPoint, HapType::AccessCodeSupportedConfiguration, HapType::ConfigurationState]),
+		HapType::NFCAccess => Some(vec![HapType::ConfigurationState, HapType::NFCAccessControlPoint, HapType::NFCAccessSupportedConfiguration]),
+		_ => None,
+    }
+}
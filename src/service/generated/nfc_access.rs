@@ -0,0 +1,118 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		configuration_state,
+		nfc_access_control_point,
+		nfc_access_supported_configuration,
+	},
+    HapType,
+};
+
+/// NFC Access Service.
+pub type NFCAccess = Service<NFCAccessInner>;
+
+impl Default for NFCAccess {
+    fn default() -> NFCAccess { new() }
+}
+
+/// Inner type of the NFC Access Service.
+#[derive(Default)]
+pub struct NFCAccessInner {
+    /// ID of the NFC Access Service.
+    id: u64,
+    /// `HapType` of the NFC Access Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Configuration State Characteristic.
+	pub configuration_state: configuration_state::ConfigurationState,
+	/// NFC Access Control Point Characteristic.
+	pub nfc_access_control_point: nfc_access_control_point::NFCAccessControlPoint,
+	/// NFC Access Supported Configuration Characteristic.
+	pub nfc_access_supported_configuration: nfc_access_supported_configuration::NFCAccessSupportedConfiguration,
+
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for NFCAccessInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.configuration_state,
+			&self.nfc_access_control_point,
+			&self.nfc_access_supported_configuration,
+		];
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.configuration_state,
+			&mut self.nfc_access_control_point,
+			&mut self.nfc_access_supported_configuration,
+		];
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new NFC Access Service.
+pub fn new() -> NFCAccess {
+    NFCAccess::new(NFCAccessInner {
+        hap_type: HapType::NFCAccess,
+		configuration_state: configuration_state::new(),
+		nfc_access_control_point: nfc_access_control_point::new(),
+		nfc_access_supported_configuration: nfc_access_supported_configuration::new(),
+		..Default::default()
+    })
+}
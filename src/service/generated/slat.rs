@@ -32,6 +32,8 @@ pub struct SlatInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Slat Type Characteristic.
 	pub slat_type: slat_type::SlatType,
@@ -46,6 +48,9 @@ pub struct SlatInner {
 	pub target_tilt_angle: Option<target_tilt_angle::TargetTiltAngle>,
 	/// Swing Mode Characteristic.
 	pub swing_mode: Option<swing_mode::SwingMode>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for SlatInner {
@@ -77,22 +82,33 @@ impl HapService for SlatInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.slat_type,
 			&self.current_slat_state,
 		];
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.current_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -103,16 +119,19 @@ impl HapService for SlatInner {
 			&mut self.current_slat_state,
 		];
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.current_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.swing_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
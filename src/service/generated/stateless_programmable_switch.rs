@@ -29,6 +29,8 @@ pub struct StatelessProgrammableSwitchInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Programmable Switch Event Characteristic.
 	pub programmable_switch_event: programmable_switch_event::ProgrammableSwitchEvent,
@@ -37,6 +39,9 @@ pub struct StatelessProgrammableSwitchInner {
 	pub name: Option<name::Name>,
 	/// Service Label Index Characteristic.
 	pub service_label_index: Option<service_label_index::ServiceLabelIndex>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for StatelessProgrammableSwitchInner {
@@ -68,15 +73,26 @@ impl HapService for StatelessProgrammableSwitchInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.programmable_switch_event,
 		];
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.service_label_index {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -86,10 +102,13 @@ impl HapService for StatelessProgrammableSwitchInner {
 			&mut self.programmable_switch_event,
 		];
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.service_label_index {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -0,0 +1,127 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		active_identifier,
+		active,
+		button_event,
+		name,
+	},
+    HapType,
+};
+
+/// Target Control Service.
+pub type TargetControl = Service<TargetControlInner>;
+
+impl Default for TargetControl {
+    fn default() -> TargetControl { new() }
+}
+
+/// Inner type of the Target Control Service.
+#[derive(Default)]
+pub struct TargetControlInner {
+    /// ID of the Target Control Service.
+    id: u64,
+    /// `HapType` of the Target Control Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Active Identifier Characteristic.
+	pub active_identifier: active_identifier::ActiveIdentifier,
+	/// Active Characteristic.
+	pub active: active::Active,
+	/// Button Event Characteristic.
+	pub button_event: button_event::ButtonEvent,
+
+	/// Name Characteristic.
+	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for TargetControlInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.active_identifier,
+			&self.active,
+			&self.button_event,
+		];
+		if let Some(c) = &self.name {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.active_identifier,
+			&mut self.active,
+			&mut self.button_event,
+		];
+		if let Some(c) = &mut self.name {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Target Control Service.
+pub fn new() -> TargetControl {
+    TargetControl::new(TargetControlInner {
+        hap_type: HapType::TargetControl,
+		active_identifier: active_identifier::new(),
+		active: active::new(),
+		button_event: button_event::new(),
+		..Default::default()
+    })
+}
@@ -0,0 +1,112 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		target_control_supported_configuration,
+		target_control_list,
+	},
+    HapType,
+};
+
+/// Target Control Management Service.
+pub type TargetControlManagement = Service<TargetControlManagementInner>;
+
+impl Default for TargetControlManagement {
+    fn default() -> TargetControlManagement { new() }
+}
+
+/// Inner type of the Target Control Management Service.
+#[derive(Default)]
+pub struct TargetControlManagementInner {
+    /// ID of the Target Control Management Service.
+    id: u64,
+    /// `HapType` of the Target Control Management Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Target Control Supported Configuration Characteristic.
+	pub target_control_supported_configuration: target_control_supported_configuration::TargetControlSupportedConfiguration,
+	/// Target Control List Characteristic.
+	pub target_control_list: target_control_list::TargetControlList,
+
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for TargetControlManagementInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.target_control_supported_configuration,
+			&self.target_control_list,
+		];
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.target_control_supported_configuration,
+			&mut self.target_control_list,
+		];
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Target Control Management Service.
+pub fn new() -> TargetControlManagement {
+    TargetControlManagement::new(TargetControlManagementInner {
+        hap_type: HapType::TargetControlManagement,
+		target_control_supported_configuration: target_control_supported_configuration::new(),
+		target_control_list: target_control_list::new(),
+		..Default::default()
+    })
+}
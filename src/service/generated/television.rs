@@ -38,6 +38,8 @@ pub struct TelevisionInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Active Characteristic.
 	pub active: active::Active,
@@ -64,6 +66,9 @@ pub struct TelevisionInner {
 	pub power_mode_selection: Option<power_mode_selection::PowerModeSelection>,
 	/// Remote Key Characteristic.
 	pub remote_key: Option<remote_key::RemoteKey>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for TelevisionInner {
@@ -95,6 +100,14 @@ impl HapService for TelevisionInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.active,
@@ -103,28 +116,31 @@ impl HapService for TelevisionInner {
 			&self.sleep_discovery_mode,
 		];
 		if let Some(c) = &self.brightness {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.closed_captions {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.display_order {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.current_media_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_media_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.picture_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.power_mode_selection {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.remote_key {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -137,28 +153,31 @@ impl HapService for TelevisionInner {
 			&mut self.sleep_discovery_mode,
 		];
 		if let Some(c) = &mut self.brightness {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.closed_captions {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.display_order {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.current_media_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_media_state {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.picture_mode {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.power_mode_selection {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.remote_key {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
@@ -36,6 +36,8 @@ pub struct ThermostatInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Current Heating Cooling State Characteristic.
 	pub current_heating_cooling_state: current_heating_cooling_state::CurrentHeatingCoolingState,
@@ -58,6 +60,9 @@ pub struct ThermostatInner {
 	pub heating_threshold_temperature: Option<heating_threshold_temperature::HeatingThresholdTemperature>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for ThermostatInner {
@@ -89,6 +94,14 @@ impl HapService for ThermostatInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.current_heating_cooling_state,
@@ -98,19 +111,22 @@ impl HapService for ThermostatInner {
 			&self.temperature_display_units,
 		];
 		if let Some(c) = &self.current_relative_humidity {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_relative_humidity {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.cooling_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.heating_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -124,19 +140,22 @@ impl HapService for ThermostatInner {
 			&mut self.temperature_display_units,
 		];
 		if let Some(c) = &mut self.current_relative_humidity {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_relative_humidity {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.cooling_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.heating_threshold_temperature {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
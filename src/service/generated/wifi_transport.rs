@@ -0,0 +1,121 @@
+// THIS FILE IS AUTO-GENERATED
+
+use crate::{
+    service::{HapService, Service},
+    characteristic::{
+        HapCharacteristic,
+		current_transport,
+		wifi_capabilities,
+		wifi_configuration_control,
+	},
+    HapType,
+};
+
+/// Wi-Fi Transport Service.
+pub type WiFiTransport = Service<WiFiTransportInner>;
+
+impl Default for WiFiTransport {
+    fn default() -> WiFiTransport { new() }
+}
+
+/// Inner type of the Wi-Fi Transport Service.
+#[derive(Default)]
+pub struct WiFiTransportInner {
+    /// ID of the Wi-Fi Transport Service.
+    id: u64,
+    /// `HapType` of the Wi-Fi Transport Service.
+    hap_type: HapType,
+    /// Specifies if the Service is hidden.
+    hidden: bool,
+    /// Specifies if the Service is the primary Service of the Accessory.
+    primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
+
+	/// Current Transport Characteristic.
+	pub current_transport: current_transport::CurrentTransport,
+	/// Wi-Fi Capabilities Characteristic.
+	pub wifi_capabilities: wifi_capabilities::WiFiCapabilities,
+
+	/// Wi-Fi Configuration Control Characteristic.
+	pub wifi_configuration_control: Option<wifi_configuration_control::WiFiConfigurationControl>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
+}
+
+impl HapService for WiFiTransportInner {
+    fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    fn set_id(&mut self, id: u64) {
+        self.id = id;
+    }
+
+    fn get_type(&self) -> HapType {
+        self.hap_type
+    }
+
+    fn get_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn get_primary(&self) -> bool {
+        self.primary
+    }
+
+    fn set_primary(&mut self, primary: bool) {
+        self.primary = primary;
+    }
+
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
+    fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
+        let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
+			&self.current_transport,
+			&self.wifi_capabilities,
+		];
+		if let Some(c) = &self.wifi_configuration_control {
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
+		}
+		characteristics
+    }
+
+    fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> {
+        let mut characteristics: Vec<&mut dyn HapCharacteristic> = vec![
+			&mut self.current_transport,
+			&mut self.wifi_capabilities,
+		];
+		if let Some(c) = &mut self.wifi_configuration_control {
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
+		}
+		characteristics
+    }
+}
+
+/// Creates a new Wi-Fi Transport Service.
+pub fn new() -> WiFiTransport {
+    WiFiTransport::new(WiFiTransportInner {
+        hap_type: HapType::WiFiTransport,
+		current_transport: current_transport::new(),
+		wifi_capabilities: wifi_capabilities::new(),
+		..Default::default()
+    })
+}
@@ -36,6 +36,8 @@ pub struct WindowCoveringInner {
     hidden: bool,
     /// Specifies if the Service is the primary Service of the Accessory.
     primary: bool,
+    /// IDs of the Services linked to the Service.
+    linked_services: Vec<u64>,
 
 	/// Current Position Characteristic.
 	pub current_position: current_position::CurrentPosition,
@@ -58,6 +60,9 @@ pub struct WindowCoveringInner {
 	pub obstruction_detected: Option<obstruction_detected::ObstructionDetected>,
 	/// Name Characteristic.
 	pub name: Option<name::Name>,
+
+	/// Additional Characteristics of the Service, e.g. vendor-specific ones.
+	pub additional_characteristics: Vec<Box<dyn HapCharacteristic + Send>>,
 }
 
 impl HapService for WindowCoveringInner {
@@ -89,6 +94,14 @@ impl HapService for WindowCoveringInner {
         self.primary = primary;
     }
 
+    fn get_linked_services(&self) -> Vec<u64> {
+        self.linked_services.clone()
+    }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) {
+        self.linked_services = linked_services;
+    }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> {
         let mut characteristics: Vec<&dyn HapCharacteristic> = vec![
 			&self.current_position,
@@ -96,25 +109,28 @@ impl HapService for WindowCoveringInner {
 			&self.position_state,
 		];
 		if let Some(c) = &self.hold_position {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_horizontal_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.target_vertical_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.current_horizontal_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.current_vertical_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.obstruction_detected {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &self.additional_characteristics {
+			characteristics.push(c.as_ref());
 		}
 		characteristics
     }
@@ -126,25 +142,28 @@ impl HapService for WindowCoveringInner {
 			&mut self.position_state,
 		];
 		if let Some(c) = &mut self.hold_position {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_horizontal_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.target_vertical_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.current_horizontal_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.current_vertical_tilt_angle {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.obstruction_detected {
-		    characteristics.push(c);
+			characteristics.push(c);
 		}
 		if let Some(c) = &mut self.name {
-		    characteristics.push(c);
+			characteristics.push(c);
+		}
+		for c in &mut self.additional_characteristics {
+			characteristics.push(c.as_mut());
 		}
 		characteristics
     }
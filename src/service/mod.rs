@@ -1,10 +1,14 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use crate::{characteristic::HapCharacteristic, HapType};
 
+pub mod dynamic;
 mod generated;
 
 pub use crate::service::generated::*;
+pub use hap_derive::HapService;
 
 /// `HapService` is implemented by the inner type of every `Service`.
 pub trait HapService {
@@ -22,32 +26,75 @@ pub trait HapService {
     fn get_primary(&self) -> bool;
     /// Sets the primary value of a Service.
     fn set_primary(&mut self, primary: bool);
+    /// Returns the IDs of the Services linked to a Service.
+    fn get_linked_services(&self) -> Vec<u64>;
+    /// Sets the IDs of the Services linked to a Service.
+    fn set_linked_services(&mut self, linked_services: Vec<u64>);
     /// Returns references to the Characteristics of a Service.
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic>;
     /// Returns mutable references to the Characteristics of a Service.
     fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic>;
+    /// Returns the reference other Services of the Accessory are linked to a Service by, if any.
+    fn get_ref(&self) -> Option<ServiceRef> { None }
+    /// Returns the references of the Services linked to a Service, which are resolved to their IDs
+    /// when the IDs of the Accessory are initialized.
+    fn get_linked_refs(&self) -> Vec<ServiceRef> { Vec::new() }
+}
+
+/// Reference to a `Service`, used to link Services before their IDs are assigned. Unique for every
+/// `Service` created in a process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRef(u64);
+
+impl ServiceRef {
+    fn new() -> ServiceRef {
+        static NEXT_REF: AtomicU64 = AtomicU64::new(0);
+        ServiceRef(NEXT_REF.fetch_add(1, Ordering::Relaxed))
+    }
 }
 
 /// A Service. Services group functionality in order to provide context. They are comprised of
 /// characteristics.
 pub struct Service<T: HapService> {
     pub inner: T,
+    reference: ServiceRef,
+    linked_refs: Vec<ServiceRef>,
 }
 
 impl<T: HapService> Service<T> {
     /// Creates a new `Service`.
-    fn new(inner: T) -> Service<T> { Service { inner } }
+    pub fn new(inner: T) -> Service<T> {
+        Service {
+            inner,
+            reference: ServiceRef::new(),
+            linked_refs: Vec::new(),
+        }
+    }
+
+    /// Links another Service of the same Accessory to the Service, e.g. an Input Source to a
+    /// Television or a Valve to a Faucet. The link is resolved to the ID of the linked Service once
+    /// the Accessory is added to a transport.
+    pub fn add_linked_service<U: HapService>(&mut self, service: &Service<U>) {
+        if !self.linked_refs.contains(&service.reference) {
+            self.linked_refs.push(service.reference);
+        }
+    }
 }
 
 impl<T: HapService> Serialize for Service<T> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut state = serializer.serialize_struct("HapService", 5)?;
+        let linked_services = self.get_linked_services();
+        let mut state = serializer.serialize_struct("HapService", 6)?;
         state.serialize_field("iid", &self.get_id())?;
         state.serialize_field("type", &self.get_type())?;
         state.serialize_field("hidden", &self.get_hidden())?;
         state.serialize_field("primary", &self.get_primary())?;
         state.serialize_field("characteristics", &self.get_characteristics())?;
-        // linked services left out for now
+        if linked_services.is_empty() {
+            state.skip_field("linked")?;
+        } else {
+            state.serialize_field("linked", &linked_services)?;
+        }
         state.end()
     }
 }
@@ -67,7 +114,15 @@ impl<T: HapService> HapService for Service<T> {
 
     fn set_primary(&mut self, primary: bool) { self.inner.set_primary(primary) }
 
+    fn get_linked_services(&self) -> Vec<u64> { self.inner.get_linked_services() }
+
+    fn set_linked_services(&mut self, linked_services: Vec<u64>) { self.inner.set_linked_services(linked_services) }
+
     fn get_characteristics(&self) -> Vec<&dyn HapCharacteristic> { self.inner.get_characteristics() }
 
     fn get_mut_characteristics(&mut self) -> Vec<&mut dyn HapCharacteristic> { self.inner.get_mut_characteristics() }
+
+    fn get_ref(&self) -> Option<ServiceRef> { Some(self.reference) }
+
+    fn get_linked_refs(&self) -> Vec<ServiceRef> { self.linked_refs.clone() }
 }
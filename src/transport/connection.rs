@@ -0,0 +1,204 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use futures::{
+    sync::oneshot,
+    Async::{NotReady, Ready},
+    Future,
+    Poll,
+};
+use log::debug;
+use tokio::timer::Delay;
+use uuid::Uuid;
+
+struct Entry {
+    id: u64,
+    verified: bool,
+    controller_id: Option<Uuid>,
+    close_sender: oneshot::Sender<()>,
+}
+
+/// `ConnectionList` keeps track of the open TCP connections of the accessory server and enforces
+/// the connection limits of the `Config`.
+///
+/// Connections are kept in the order they were accepted. If the maximum number of connections is
+/// reached, the oldest connection that hasn't completed Pair Verify yet is closed to make room for
+/// a new one. If all connections are verified, new connections are rejected. If a per-controller
+/// limit is set and a controller exceeds it, its oldest connections are closed.
+pub(crate) struct ConnectionList {
+    max_connections: usize,
+    max_controller_connections: Option<usize>,
+    handshake_timeout: Duration,
+    idle_timeout: Option<Duration>,
+    next_id: u64,
+    entries: Vec<Entry>,
+}
+
+/// Pointer to a `ConnectionList`.
+pub(crate) type ConnectionListPtr = Arc<Mutex<ConnectionList>>;
+
+impl ConnectionList {
+    /// Creates a new, empty `ConnectionList`.
+    pub fn new(
+        max_connections: usize,
+        max_controller_connections: Option<usize>,
+        handshake_timeout: Duration,
+        idle_timeout: Option<Duration>,
+    ) -> ConnectionList {
+        ConnectionList {
+            max_connections,
+            max_controller_connections,
+            handshake_timeout,
+            idle_timeout,
+            next_id: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers a newly accepted connection and returns its `Connection`, or `None` if the
+    /// connection has to be rejected.
+    pub fn accept(connections: &ConnectionListPtr) -> Option<Connection> {
+        let mut list = connections.lock().expect("couldn't access connection list");
+
+        if list.entries.len() >= list.max_connections {
+            match list.entries.iter().position(|e| !e.verified) {
+                Some(pos) => {
+                    let entry = list.entries.remove(pos);
+                    debug!("Closing unverified connection {} to make room for a new one", entry.id);
+                    let _ = entry.close_sender.send(());
+                },
+                None => return None,
+            }
+        }
+
+        let id = list.next_id;
+        list.next_id += 1;
+        let (close_sender, close_receiver) = oneshot::channel();
+        list.entries.push(Entry {
+            id,
+            verified: false,
+            controller_id: None,
+            close_sender,
+        });
+
+        Some(Connection {
+            id,
+            connections: connections.clone(),
+            close_receiver,
+            verified: false,
+            handshake_timer: Delay::new(Instant::now() + list.handshake_timeout),
+            idle_timeout: list.idle_timeout,
+            idle_timer: None,
+        })
+    }
+
+    /// Marks a connection as verified by a controller and closes the oldest connections of the
+    /// controller exceeding its limit, if any. Transient sessions don't belong to a controller and
+    /// aren't subject to the limit.
+    fn verify(&mut self, id: u64, controller_id: Option<Uuid>) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
+            entry.verified = true;
+            entry.controller_id = controller_id;
+        }
+
+        if let (Some(controller_id), Some(max_controller_connections)) =
+            (controller_id, self.max_controller_connections)
+        {
+            let count = self
+                .entries
+                .iter()
+                .filter(|e| e.controller_id == Some(controller_id))
+                .count();
+            let mut excess = count.saturating_sub(max_controller_connections);
+            let mut i = 0;
+            while excess > 0 && i < self.entries.len() {
+                if self.entries[i].controller_id == Some(controller_id) && self.entries[i].id != id {
+                    let entry = self.entries.remove(i);
+                    debug!("Closing connection {} of controller {}", entry.id, controller_id);
+                    let _ = entry.close_sender.send(());
+                    excess -= 1;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    fn remove(&mut self, id: u64) { self.entries.retain(|e| e.id != id); }
+}
+
+/// `Connection` tracks the lifetime of a TCP connection in a `ConnectionList`. It's closed when
+/// Pair Verify isn't completed within the handshake timeout, when a verified connection sees no
+/// traffic within the idle timeout or when the `ConnectionList` evicts it.
+pub(crate) struct Connection {
+    id: u64,
+    connections: ConnectionListPtr,
+    close_receiver: oneshot::Receiver<()>,
+    verified: bool,
+    handshake_timer: Delay,
+    idle_timeout: Option<Duration>,
+    idle_timer: Option<Delay>,
+}
+
+impl Connection {
+    /// Marks the connection as verified. The handshake timeout doesn't apply anymore and the idle
+    /// timeout starts.
+    pub fn verify(&mut self, controller_id: Option<Uuid>) {
+        self.verified = true;
+        self.idle_timer = self.idle_timeout.map(|timeout| Delay::new(Instant::now() + timeout));
+        self.connections
+            .lock()
+            .expect("couldn't access connection list")
+            .verify(self.id, controller_id);
+    }
+
+    /// Records traffic on the connection and restarts the idle timeout.
+    pub fn touch(&mut self) {
+        if let (Some(timer), Some(timeout)) = (self.idle_timer.as_mut(), self.idle_timeout) {
+            timer.reset(Instant::now() + timeout);
+        }
+    }
+
+    /// Resolves when the connection has to be closed.
+    pub fn poll_close(&mut self) -> Poll<(), io::Error> {
+        match self.close_receiver.poll() {
+            Ok(Ready(())) => {
+                debug!("Connection {} was evicted", self.id);
+                return Ok(Ready(()));
+            },
+            // the `ConnectionList` doesn't drop the sender without closing the connection
+            Err(_) => return Ok(Ready(())),
+            Ok(NotReady) => {},
+        }
+
+        let timer = match self.idle_timer {
+            Some(ref mut timer) => timer,
+            None if self.verified => return Ok(NotReady),
+            None => &mut self.handshake_timer,
+        };
+        match timer.poll() {
+            Ok(Ready(())) => {
+                if self.verified {
+                    debug!("Connection {} timed out", self.id);
+                } else {
+                    debug!("Connection {} didn't complete Pair Verify in time", self.id);
+                }
+                Ok(Ready(()))
+            },
+            Ok(NotReady) => Ok(NotReady),
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.connections
+            .lock()
+            .expect("couldn't access connection list")
+            .remove(self.id);
+    }
+}
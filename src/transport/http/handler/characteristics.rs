@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use hyper::{Body, Response, StatusCode, Uri};
 use serde_json;
 use url::form_urlencoded;
@@ -44,30 +42,21 @@ impl JsonHandler for GetCharacteristics {
         _: &EventEmitterPtr,
     ) -> Result<Response<Body>> {
         if let Some(query) = uri.query() {
+            let query = ReadQuery::parse(query)?;
             let mut resp_body = CharacteristicResponseBody::<ReadResponseObject> {
                 characteristics: Vec::new(),
             };
             let mut some_err = false;
 
-            // TODO - using a String seems ugly
-            let mut queries: HashMap<String, String> = HashMap::new();
-            for (key, val) in form_urlencoded::parse(query.as_bytes()) {
-                queries.insert(key.into(), val.into());
-            }
-            let (f_meta, f_perms, f_type, f_ev) = check_flags(&queries);
-            let q_id = queries
-                .get("id")
-                .ok_or(Error::new(ErrorKind::HttpStatus(StatusCode::BAD_REQUEST)))?;
-            let ids = q_id.split(',').collect::<Vec<&str>>();
-            for id in ids {
-                let id_pair = id.split('.').collect::<Vec<&str>>();
-                if id_pair.len() != 2 {
-                    return Err(ErrorKind::HttpStatus(StatusCode::BAD_REQUEST).into());
-                }
-                let aid = id_pair[0].parse::<u64>()?;
-                let iid = id_pair[1].parse::<u64>()?;
-
-                let res_object = match accessories.read_characteristic(aid, iid, f_meta, f_perms, f_type, f_ev) {
+            for (aid, iid) in query.ids {
+                let res_object = match accessories.read_characteristic(
+                    aid,
+                    iid,
+                    query.meta,
+                    query.perms,
+                    query.hap_type,
+                    query.ev,
+                ) {
                     Ok(mut res_object) => {
                         if res_object.status != Some(0) {
                             some_err = true;
@@ -105,16 +94,52 @@ impl JsonHandler for GetCharacteristics {
     }
 }
 
-fn check_flags(flags: &HashMap<String, String>) -> (bool, bool, bool, bool) {
-    let true_val = "1".to_string();
-    (
-        flags.get("meta") == Some(&true_val),
-        flags.get("perms") == Some(&true_val),
-        flags.get("type") == Some(&true_val),
-        flags.get("ev") == Some(&true_val),
-    )
+/// Parsed query of a `GET /characteristics` request.
+#[derive(Debug, Default, PartialEq)]
+pub struct ReadQuery {
+    pub ids: Vec<(u64, u64)>,
+    pub meta: bool,
+    pub perms: bool,
+    pub hap_type: bool,
+    pub ev: bool,
 }
 
+impl ReadQuery {
+    /// Parses a query string like `id=1.9,2.10&meta=1`. Malformed queries result in a
+    /// `400 Bad Request` error.
+    pub fn parse(query: &str) -> Result<ReadQuery> {
+        let mut read_query = ReadQuery::default();
+        let mut ids = None;
+        for (key, val) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "id" => ids = Some(val.into_owned()),
+                "meta" => read_query.meta = val == "1",
+                "perms" => read_query.perms = val == "1",
+                "type" => read_query.hap_type = val == "1",
+                "ev" => read_query.ev = val == "1",
+                _ => {},
+            }
+        }
+
+        let ids = ids.ok_or(bad_request())?;
+        for id in ids.split(',') {
+            let mut id_pair = id.split('.');
+            match (id_pair.next(), id_pair.next(), id_pair.next()) {
+                (Some(aid), Some(iid), None) => {
+                    let aid = aid.parse::<u64>().map_err(|_| bad_request())?;
+                    let iid = iid.parse::<u64>().map_err(|_| bad_request())?;
+                    read_query.ids.push((aid, iid));
+                },
+                _ => return Err(bad_request()),
+            }
+        }
+
+        Ok(read_query)
+    }
+}
+
+fn bad_request() -> Error { ErrorKind::HttpStatus(StatusCode::BAD_REQUEST).into() }
+
 pub struct UpdateCharacteristics {}
 
 impl UpdateCharacteristics {
@@ -133,46 +158,62 @@ impl JsonHandler for UpdateCharacteristics {
         accessories: &AccessoryList,
         _: &EventEmitterPtr,
     ) -> Result<Response<Body>> {
-        let write_body: CharacteristicResponseBody<WriteObject> = serde_json::from_slice(&body)?;
-        let mut resp_body = CharacteristicResponseBody::<WriteResponseObject> {
-            characteristics: Vec::new(),
-        };
-        let mut some_err = false;
-        let mut all_err = true;
-
-        for c in write_body.characteristics {
-            let iid = c.iid;
-            let aid = c.aid;
-            let res_object = match accessories.write_characteristic(c, event_subscriptions) {
-                Ok(res_object) => {
-                    if res_object.status != 0 {
-                        some_err = true;
-                    } else {
-                        all_err = false;
-                    }
-                    res_object
-                },
-                Err(_) => {
+        write_characteristics(&body, event_subscriptions, accessories)
+    }
+}
+
+/// Applies the body of a `PUT /characteristics` request to the Accessories. A body that isn't a
+/// valid list of write objects results in a `400 Bad Request` error. Values requested as write
+/// responses are returned with a `207 Multi-Status`.
+pub fn write_characteristics(
+    body: &[u8],
+    event_subscriptions: &EventSubscriptions,
+    accessories: &AccessoryList,
+) -> Result<Response<Body>> {
+    let write_body: CharacteristicResponseBody<WriteObject> = serde_json::from_slice(body).map_err(|_| bad_request())?;
+    let mut resp_body = CharacteristicResponseBody::<WriteResponseObject> {
+        characteristics: Vec::new(),
+    };
+    let mut some_err = false;
+    let mut all_err = true;
+    let mut some_value = false;
+
+    for c in write_body.characteristics {
+        let iid = c.iid;
+        let aid = c.aid;
+        let res_object = match accessories.write_characteristic(c, event_subscriptions) {
+            Ok(res_object) => {
+                if res_object.status != 0 {
                     some_err = true;
-                    WriteResponseObject {
-                        iid,
-                        aid,
-                        status: Status::ServiceCommunicationFailure as i32,
-                    }
-                },
-            };
+                } else {
+                    all_err = false;
+                }
+                if res_object.value.is_some() {
+                    some_value = true;
+                }
+                res_object
+            },
+            Err(_) => {
+                some_err = true;
+                WriteResponseObject {
+                    iid,
+                    aid,
+                    status: Status::ServiceCommunicationFailure as i32,
+                    value: None,
+                }
+            },
+        };
 
-            resp_body.characteristics.push(res_object);
-        }
+        resp_body.characteristics.push(res_object);
+    }
 
-        if all_err {
-            let res = serde_json::to_vec(&resp_body)?;
-            json_response(res, StatusCode::BAD_REQUEST)
-        } else if some_err {
-            let res = serde_json::to_vec(&resp_body)?;
-            json_response(res, StatusCode::MULTI_STATUS)
-        } else {
-            status_response(StatusCode::NO_CONTENT)
-        }
+    if all_err {
+        let res = serde_json::to_vec(&resp_body)?;
+        json_response(res, StatusCode::BAD_REQUEST)
+    } else if some_err || some_value {
+        let res = serde_json::to_vec(&resp_body)?;
+        json_response(res, StatusCode::MULTI_STATUS)
+    } else {
+        status_response(StatusCode::NO_CONTENT)
     }
 }
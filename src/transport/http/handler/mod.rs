@@ -23,6 +23,7 @@ pub mod pair_verify;
 pub mod pairings;
 
 pub trait Handler {
+    #[allow(clippy::too_many_arguments)]
     fn handle(
         &mut self,
         uri: Uri,
@@ -76,12 +77,13 @@ impl<T: TlvHandler> Handler for TlvHandlerType<T> {
             },
         };
         Box::new(future::result(
-            tlv_response(response, StatusCode::OK).map_err(Error::from),
+            tlv_response(response, StatusCode::OK),
         ))
     }
 }
 
 pub trait JsonHandler {
+    #[allow(clippy::too_many_arguments)]
     fn handle(
         &mut self,
         uri: Uri,
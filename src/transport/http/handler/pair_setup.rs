@@ -1,11 +1,13 @@
-use std::{collections::HashMap, ops::BitXor, str};
+use std::{
+    convert::TryInto,
+    ops::BitXor,
+    str,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use chacha20_poly1305_aead;
-use crypto::ed25519;
 use log::debug;
 use num::BigUint;
 use rand::{self, distributions::Standard, Rng};
-use ring::{digest, hkdf, hmac};
 use sha2::{Digest, Sha512};
 use srp::{
     client::{srp_private_key, SrpClient},
@@ -14,39 +16,49 @@ use srp::{
     types::SrpGroup,
 };
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
 use crate::{
     config::ConfigPtr,
+    crypto::{self, Backend, Crypto, SecretBytes},
     db::DatabasePtr,
     event::{EventEmitterPtr, Event},
     protocol::{
-        tlv::{self, Type, Value},
+        tlv::{self, Encodable, Flag, Method, Type, Value},
+        AuthenticatorPtr,
         Device,
         IdPtr,
         Pairing,
         Permissions,
     },
-    transport::http::handler::TlvHandler,
+    transport::{http::handler::TlvHandler, tcp},
 };
 
+/// Time after which the SRP verifier stored by a transient split Pair Setup expires.
+const SPLIT_SETUP_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
 struct Session {
+    method: Method,
+    flags: u32,
     salt: Vec<u8>,
     verifier: Vec<u8>,
-    b: Vec<u8>,
+    b: SecretBytes,
     b_pub: Vec<u8>,
-    shared_secret: Option<Vec<u8>>,
+    shared_secret: Option<SecretBytes>,
 }
 
 pub struct PairSetup {
     session: Option<Session>,
     unsuccessful_tries: u8,
+    session_sender: tcp::SessionSenderPtr,
 }
 
 impl PairSetup {
-    pub fn new() -> PairSetup {
+    pub fn new(session_sender: tcp::SessionSenderPtr) -> PairSetup {
         PairSetup {
             session: None,
             unsuccessful_tries: 0,
+            session_sender,
         }
     }
 }
@@ -62,7 +74,7 @@ enum StepNumber {
 }
 
 pub enum Step {
-    Start,
+    Start { method: Method, flags: u32 },
     Verify { a_pub: Vec<u8>, a_proof: Vec<u8> },
     Exchange { data: Vec<u8> },
 }
@@ -72,10 +84,30 @@ impl TlvHandler for PairSetup {
     type Result = tlv::Container;
 
     fn parse(&self, body: Vec<u8>) -> Result<Step, tlv::ErrorContainer> {
-        let mut decoded = tlv::decode(body);
-        match decoded.get(&(Type::State as u8)) {
-            Some(method) => match method[0] {
-                x if x == StepNumber::StartReq as u8 => Ok(Step::Start),
+        let mut decoded = tlv::decode(&body)
+            .map_err(|_| tlv::ErrorContainer::new(StepNumber::Unknown as u8, tlv::Error::Unknown))?;
+        match decoded.get(&(Type::State as u8)).and_then(|state| state.first()) {
+            Some(state) => match *state {
+                x if x == StepNumber::StartReq as u8 => {
+                    let method = match decoded.get(&(Type::Method as u8)) {
+                        Some(method) => method
+                            .first()
+                            .and_then(|m| Method::from_u8(*m))
+                            .ok_or(tlv::ErrorContainer::new(
+                                StepNumber::StartRes as u8,
+                                tlv::Error::Unknown,
+                            ))?,
+                        None => Method::PairSetup,
+                    };
+                    let flags = match decoded.get(&(Type::Flags as u8)) {
+                        Some(flags) => tlv::decode_u32(flags).ok_or(tlv::ErrorContainer::new(
+                            StepNumber::StartRes as u8,
+                            tlv::Error::Unknown,
+                        ))?,
+                        None => 0,
+                    };
+                    Ok(Step::Start { method, flags })
+                },
                 x if x == StepNumber::VerifyReq as u8 => {
                     let a_pub = decoded
                         .remove(&(Type::PublicKey as u8))
@@ -113,23 +145,17 @@ impl TlvHandler for PairSetup {
         event_emitter: &EventEmitterPtr,
     ) -> Result<tlv::Container, tlv::ErrorContainer> {
         match step {
-            Step::Start => match handle_start(self, database) {
-                Ok(res) => {
-                    self.unsuccessful_tries = 0;
-                    Ok(res)
-                },
-                Err(err) => {
-                    self.unsuccessful_tries += 1;
-                    Err(tlv::ErrorContainer::new(StepNumber::StartRes as u8, err))
-                },
-            },
-            Step::Verify { a_pub, a_proof } => match handle_verify(self, &a_pub, &a_proof) {
+            // starting a Pair Setup says nothing about the setup code, so only failed proofs and
+            // exchanges count towards the try limit, and rejecting a start isn't a try
+            Step::Start { method, flags } => handle_start(self, config, database, method, flags)
+                .map_err(|err| tlv::ErrorContainer::new(StepNumber::StartRes as u8, err)),
+            Step::Verify { a_pub, a_proof } => match handle_verify(self, config, &a_pub, &a_proof) {
                 Ok(res) => {
                     self.unsuccessful_tries = 0;
                     Ok(res)
                 },
                 Err(err) => {
-                    self.unsuccessful_tries += 1;
+                    self.unsuccessful_tries = self.unsuccessful_tries.saturating_add(1);
                     Err(tlv::ErrorContainer::new(StepNumber::VerifyRes as u8, err))
                 },
             },
@@ -139,7 +165,7 @@ impl TlvHandler for PairSetup {
                     Ok(res)
                 },
                 Err(err) => {
-                    self.unsuccessful_tries += 1;
+                    self.unsuccessful_tries = self.unsuccessful_tries.saturating_add(1);
                     Err(tlv::ErrorContainer::new(StepNumber::ExchangeRes as u8, err))
                 },
             },
@@ -147,22 +173,66 @@ impl TlvHandler for PairSetup {
     }
 }
 
-fn handle_start(handler: &mut PairSetup, database: &DatabasePtr) -> Result<tlv::Container, tlv::Error> {
+fn handle_start(
+    handler: &mut PairSetup,
+    config: &ConfigPtr,
+    database: &DatabasePtr,
+    method: Method,
+    flags: u32,
+) -> Result<tlv::Container, tlv::Error> {
     debug!("M1: Got SRP Start Request");
 
+    // a paired accessory only takes new controllers through Add Pairing. This includes transient
+    // Pair Setup, since its session would give anyone knowing the setup code access to the
+    // accessory without being paired
+    if database.lock().expect("couldn't access database").count_pairings()? > 0 {
+        return Err(tlv::Error::Unavailable);
+    }
+
     if handler.unsuccessful_tries > 99 {
         return Err(tlv::Error::MaxTries);
     }
 
-    let accessory = Device::load_from(database)?;
+    match method {
+        Method::PairSetup => {},
+        Method::PairSetupWithAuth => {
+            if config.lock().expect("couldn't access config").authenticator.is_none() {
+                return Err(tlv::Error::Unavailable);
+            }
+        },
+        _ => {
+            return Err(tlv::Error::Unavailable);
+        },
+    }
 
     let mut rng = rand::thread_rng();
-    let salt = rng.sample_iter::<u8, Standard>(&Standard).take(16).collect::<Vec<u8>>(); // s
-    let b = rng.sample_iter::<u8, Standard>(&Standard).take(64).collect::<Vec<u8>>();
+    let b = Zeroizing::new(rng.sample_iter::<u8, Standard>(&Standard).take(64).collect::<Vec<u8>>());
+
+    let (salt, verifier) = if Flag::Split.is_set(flags) && !Flag::Transient.is_set(flags) {
+        // split Pair Setup reuses the SRP verifier of the last transient split Pair Setup. The
+        // verifier can only be used once
+        let d = database.lock().expect("couldn't access database");
+        let record = d.get_split_setup().map_err(|_| tlv::Error::Authentication)?;
+        d.delete_split_setup()?;
+        drop(d);
+        decode_split_setup(&record, SystemTime::now()).ok_or(tlv::Error::Authentication)?
+    } else {
+        let accessory = Device::load_from(database)?;
+
+        let salt = rng.sample_iter::<u8, Standard>(&Standard).take(16).collect::<Vec<u8>>(); // s
+        let private_key = srp_private_key::<Sha512>(b"Pair-Setup", accessory.pin.as_bytes(), &salt); // x = H(s | H(I | ":" | P))
+        let srp_client = SrpClient::<Sha512>::new(&private_key, &G_3072);
+        let verifier = srp_client.get_password_verifier(&private_key); // v = g^x
 
-    let private_key = srp_private_key::<Sha512>(b"Pair-Setup", accessory.pin.as_bytes(), &salt); // x = H(s | H(I | ":" | P))
-    let srp_client = SrpClient::<Sha512>::new(&private_key, &G_3072);
-    let verifier = srp_client.get_password_verifier(&private_key); // v = g^x
+        if Flag::Split.is_set(flags) {
+            database
+                .lock()
+                .expect("couldn't access database")
+                .set_split_setup(encode_split_setup(SystemTime::now(), &salt, &verifier))?;
+        }
+
+        (salt, verifier)
+    };
 
     let user = UserRecord {
         username: b"Pair-Setup",
@@ -173,23 +243,35 @@ fn handle_start(handler: &mut PairSetup, database: &DatabasePtr) -> Result<tlv::
     let b_pub = srp_server.get_b_pub();
 
     handler.session = Some(Session {
+        method,
+        flags,
         salt: salt.clone(),
         verifier: verifier.clone(),
-        b: b.clone(),
+        b,
         b_pub: b_pub.clone(),
         shared_secret: None,
     });
 
     debug!("M2: Sending SRP Start Response");
 
-    Ok(vec![
+    let mut res = vec![
         Value::State(StepNumber::StartRes as u8),
         Value::PublicKey(b_pub),
         Value::Salt(salt.clone()),
-    ])
+    ];
+    if flags != 0 {
+        res.push(Value::Flags(flags));
+    }
+
+    Ok(res)
 }
 
-fn handle_verify(handler: &mut PairSetup, a_pub: &[u8], a_proof: &[u8]) -> Result<tlv::Container, tlv::Error> {
+fn handle_verify(
+    handler: &mut PairSetup,
+    config: &ConfigPtr,
+    a_pub: &[u8],
+    a_proof: &[u8],
+) -> Result<tlv::Container, tlv::Error> {
     debug!("M3: Got SRP Verify Request");
 
     if let Some(ref mut session) = handler.session {
@@ -198,21 +280,49 @@ fn handle_verify(handler: &mut PairSetup, a_pub: &[u8], a_proof: &[u8]) -> Resul
             salt: &session.salt,
             verifier: &session.verifier,
         };
-        let srp_server = SrpServer::<Sha512>::new(&user, &a_pub, &session.b, &G_3072)?;
-        let shared_secret = srp_server.get_key();
-        session.shared_secret = Some(shared_secret.as_slice().to_vec());
+        let srp_server = SrpServer::<Sha512>::new(&user, a_pub, &session.b, &G_3072)?;
+        let shared_secret = Zeroizing::new(srp_server.get_key().as_slice().to_vec());
         let b_proof = verify_client_proof::<Sha512>(
             &session.b_pub,
             a_pub,
             a_proof,
             &session.salt,
-            &shared_secret.as_slice().to_vec(),
+            &shared_secret,
             &G_3072,
         )?;
 
+        let mut res = vec![Value::State(StepNumber::VerifyRes as u8), Value::Proof(b_proof)];
+
+        if session.method == Method::PairSetupWithAuth {
+            let authenticator = config
+                .lock()
+                .expect("couldn't access config")
+                .authenticator
+                .clone()
+                .ok_or(tlv::Error::Unavailable)?;
+            let encrypted_data = authenticate(&authenticator, &shared_secret)?;
+            res.push(Value::EncryptedData(encrypted_data));
+        }
+
+        // transient Pair Setup establishes a session right away without exchanging and persisting
+        // long-term public keys
+        if Flag::Transient.is_set(session.flags) {
+            let sender = handler
+                .session_sender
+                .lock()
+                .expect("couldn't access session_sender")
+                .take()
+                .ok_or(tlv::Error::Unknown)?;
+            let _session = sender.send(tcp::Session {
+                controller_id: None,
+                shared_secret: shared_secret.clone(),
+            });
+        }
+        session.shared_secret = Some(shared_secret);
+
         debug!("M4: Sending SRP Verify Response");
 
-        Ok(vec![Value::State(StepNumber::VerifyRes as u8), Value::Proof(b_proof)])
+        Ok(res)
     } else {
         Err(tlv::Error::Unknown)
     }
@@ -228,40 +338,33 @@ fn handle_exchange(
     debug!("M5: Got SRP Exchange Request");
 
     if let Some(ref mut session) = handler.session {
-        if let Some(ref mut shared_secret) = session.shared_secret {
-            let encrypted_data = Vec::from(&data[..data.len() - 16]);
-            let auth_tag = Vec::from(&data[data.len() - 16..]);
-
-            let mut encryption_key = [0; 32];
-            let salt = hmac::SigningKey::new(&digest::SHA512, b"Pair-Setup-Encrypt-Salt");
-            hkdf::extract_and_expand(&salt, &shared_secret, b"Pair-Setup-Encrypt-Info", &mut encryption_key);
-
-            let mut decrypted_data = Vec::new();
-            let mut nonce = vec![0; 4];
-            nonce.extend(b"PS-Msg05");
-            chacha20_poly1305_aead::decrypt(
-                &encryption_key,
-                &nonce,
-                &[],
-                &encrypted_data,
-                &auth_tag,
-                &mut decrypted_data,
-            )?;
-
-            let sub_tlv = tlv::decode(decrypted_data);
+        if Flag::Transient.is_set(session.flags) {
+            return Err(tlv::Error::Unknown);
+        }
+        if let Some(ref shared_secret) = session.shared_secret {
+            let encryption_key =
+                crypto::derive_key(shared_secret, b"Pair-Setup-Encrypt-Salt", b"Pair-Setup-Encrypt-Info");
+            let decrypted_data = Zeroizing::new(Backend::open(&encryption_key, &crypto::nonce(b"PS-Msg05"), &[], data)?);
+
+            let sub_tlv = tlv::decode(&decrypted_data)?;
             let device_pairing_id = sub_tlv.get(&(Type::Identifier as u8)).ok_or(tlv::Error::Unknown)?;
             let device_ltpk = sub_tlv.get(&(Type::PublicKey as u8)).ok_or(tlv::Error::Unknown)?;
             let device_signature = sub_tlv.get(&(Type::Signature as u8)).ok_or(tlv::Error::Unknown)?;
+            if device_ltpk.len() != 32 || device_signature.len() != 64 {
+                return Err(tlv::Error::Authentication);
+            }
 
-            let mut device_x = [0; 32];
-            let salt = hmac::SigningKey::new(&digest::SHA512, b"Pair-Setup-Controller-Sign-Salt");
-            hkdf::extract_and_expand(&salt, &shared_secret, b"Pair-Setup-Controller-Sign-Info", &mut device_x);
+            let device_x = crypto::derive_key(
+                shared_secret,
+                b"Pair-Setup-Controller-Sign-Salt",
+                b"Pair-Setup-Controller-Sign-Info",
+            );
 
             let mut device_info: Vec<u8> = Vec::new();
-            device_info.extend(&device_x);
+            device_info.extend(&*device_x);
             device_info.extend(device_pairing_id);
             device_info.extend(device_ltpk);
-            if !ed25519::verify(&device_info, &device_ltpk, &device_signature) {
+            if !Backend::ed25519_verify(device_ltpk, &device_info, device_signature) {
                 return Err(tlv::Error::Authentication);
             }
 
@@ -278,35 +381,33 @@ fn handle_exchange(
 
             let pairing = Pairing::new(pairing_uuid, Permissions::Admin, pairing_ltpk);
             pairing.save_to(database)?;
+            // a verifier left by a transient split Pair Setup is of no use once paired
+            database
+                .lock()
+                .expect("couldn't access database")
+                .delete_split_setup()?;
 
-            let mut accessory_x = [0; 32];
-            let salt = hmac::SigningKey::new(&digest::SHA512, b"Pair-Setup-Accessory-Sign-Salt");
-            hkdf::extract_and_expand(
-                &salt,
-                &shared_secret,
+            let accessory_x = crypto::derive_key(
+                shared_secret,
+                b"Pair-Setup-Accessory-Sign-Salt",
                 b"Pair-Setup-Accessory-Sign-Info",
-                &mut accessory_x,
             );
 
             let accessory = Device::load_from(database)?;
             let mut accessory_info: Vec<u8> = Vec::new();
-            accessory_info.extend(&accessory_x);
+            accessory_info.extend(&*accessory_x);
             accessory_info.extend(accessory.id.as_bytes());
             accessory_info.extend(&accessory.public_key);
-            let accessory_signature = ed25519::signature(&accessory_info, &accessory.private_key);
+            let accessory_signature = Backend::ed25519_sign(&accessory.private_key, &accessory_info)?;
 
-            let mut sub_tlv: HashMap<u8, Vec<u8>> = HashMap::new();
-            Value::Identifier(accessory.id).into_map(&mut sub_tlv);
-            Value::PublicKey(accessory.public_key.to_vec()).into_map(&mut sub_tlv);
-            Value::Signature(accessory_signature.to_vec()).into_map(&mut sub_tlv);
-            let encoded_sub_tlv = tlv::encode(sub_tlv);
+            let encoded_sub_tlv = vec![
+                Value::Identifier(accessory.id),
+                Value::PublicKey(accessory.public_key.to_vec()),
+                Value::Signature(accessory_signature.to_vec()),
+            ]
+            .encode();
 
-            let mut encrypted_data = Vec::new();
-            let mut nonce = vec![0; 4];
-            nonce.extend(b"PS-Msg06");
-            let auth_tag =
-                chacha20_poly1305_aead::encrypt(&encryption_key, &nonce, &[], &encoded_sub_tlv, &mut encrypted_data)?;
-            encrypted_data.extend(&auth_tag);
+            let encrypted_data = Backend::seal(&encryption_key, &crypto::nonce(b"PS-Msg06"), &[], &encoded_sub_tlv);
 
             event_emitter
                 .lock()
@@ -327,6 +428,44 @@ fn handle_exchange(
     }
 }
 
+fn authenticate(authenticator: &AuthenticatorPtr, shared_secret: &[u8]) -> Result<Vec<u8>, tlv::Error> {
+    let challenge = crypto::derive_key(shared_secret, b"MFi-Pair-Setup-Salt", b"MFi-Pair-Setup-Info");
+
+    let (certificate, signature) = {
+        let mut a = authenticator.lock().expect("couldn't access authenticator");
+        (a.get_certificate()?, a.sign(&*challenge)?)
+    };
+
+    let encoded_sub_tlv = vec![Value::Signature(signature), Value::Certificate(certificate)].encode();
+
+    let encryption_key = crypto::derive_key(shared_secret, b"Pair-Setup-Encrypt-Salt", b"Pair-Setup-Encrypt-Info");
+
+    Ok(Backend::seal(&encryption_key, &crypto::nonce(b"PS-Msg04"), &[], &encoded_sub_tlv))
+}
+
+/// Encodes the SRP salt and verifier of a transient split Pair Setup along with the time they were
+/// created as seconds since the Unix epoch.
+fn encode_split_setup(created: SystemTime, salt: &[u8], verifier: &[u8]) -> Vec<u8> {
+    let created = created.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let mut record = created.to_be_bytes().to_vec();
+    record.extend(salt);
+    record.extend(verifier);
+    record
+}
+
+/// Decodes the SRP salt and verifier of a transient split Pair Setup. Returns `None` if the record
+/// is malformed or has expired at the given time.
+fn decode_split_setup(record: &[u8], now: SystemTime) -> Option<(Vec<u8>, Vec<u8>)> {
+    if record.len() <= 8 + 16 {
+        return None;
+    }
+    let created = UNIX_EPOCH.checked_add(Duration::from_secs(u64::from_be_bytes(record[..8].try_into().ok()?)))?;
+    if now.duration_since(created).unwrap_or_default() > SPLIT_SETUP_TIMEOUT {
+        return None;
+    }
+    Some((record[8..24].to_vec(), record[24..].to_vec()))
+}
+
 fn verify_client_proof<D: Digest>(
     b_pub: &[u8],
     a_pub: &[u8],
@@ -336,11 +475,11 @@ fn verify_client_proof<D: Digest>(
     group: &SrpGroup,
 ) -> Result<Vec<u8>, tlv::Error> {
     let mut dhn = D::new();
-    dhn.input(&group.n.to_bytes_be());
+    dhn.input(group.n.to_bytes_be());
     let hn = BigUint::from_bytes_be(&dhn.result());
 
     let mut dhg = D::new();
-    dhg.input(&group.g.to_bytes_be());
+    dhg.input(group.g.to_bytes_be());
     let hg = BigUint::from_bytes_be(&dhg.result());
 
     let hng = hn.bitxor(hg);
@@ -351,14 +490,14 @@ fn verify_client_proof<D: Digest>(
 
     let mut d = D::new();
     // M = H(H(N) xor H(g), H(I), s, A, B, K)
-    d.input(&hng.to_bytes_be());
+    d.input(hng.to_bytes_be());
     d.input(&hi);
     d.input(salt);
     d.input(a_pub);
     d.input(b_pub);
     d.input(key);
 
-    if a_proof == d.result().as_slice() {
+    if crypto::ct_eq(a_proof, d.result().as_slice()) {
         // H(A, M, K)
         let mut d = D::new();
         d.input(a_pub);
@@ -369,3 +508,144 @@ fn verify_client_proof<D: Digest>(
         Err(tlv::Error::Authentication)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{
+        db::{Database, MemoryStorage},
+        event::EventEmitter,
+        Config,
+    };
+
+    struct Accessory {
+        handler: PairSetup,
+        config: ConfigPtr,
+        database: DatabasePtr,
+        event_emitter: EventEmitterPtr,
+    }
+
+    impl Accessory {
+        fn new() -> Accessory {
+            Accessory {
+                handler: PairSetup::new(Arc::new(Mutex::new(None))),
+                config: Arc::new(Mutex::new(Config::default())),
+                database: Arc::new(Mutex::new(Database::new(Box::new(MemoryStorage::new())))),
+                event_emitter: Arc::new(Mutex::new(EventEmitter::new())),
+            }
+        }
+
+        fn pair(&self) {
+            let pairing = Pairing::new(Uuid::new_v4(), Permissions::Admin, [1; 32]);
+            self.database.lock().unwrap().set_pairing(&pairing).unwrap();
+        }
+
+        fn start(&mut self, method: Method) -> Result<tlv::Container, tlv::ErrorContainer> {
+            self.handler.handle(
+                Step::Start { method, flags: 0 },
+                &Arc::new(Mutex::new(None)),
+                &self.config,
+                &self.database,
+                &self.event_emitter,
+            )
+        }
+    }
+
+    #[test]
+    fn split_setup_round_trip() {
+        let created = UNIX_EPOCH + Duration::from_secs(1_546_300_800);
+        let record = encode_split_setup(created, &[1; 16], &[2; 384]);
+
+        assert_eq!(decode_split_setup(&record, created), Some((vec![1; 16], vec![2; 384])));
+        assert!(decode_split_setup(&record, created + SPLIT_SETUP_TIMEOUT).is_some());
+    }
+
+    #[test]
+    fn split_setup_expires() {
+        let created = UNIX_EPOCH + Duration::from_secs(1_546_300_800);
+        let record = encode_split_setup(created, &[1; 16], &[2; 384]);
+
+        assert_eq!(
+            decode_split_setup(&record, created + SPLIT_SETUP_TIMEOUT + Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn split_setup_rejects_malformed_records() {
+        assert_eq!(decode_split_setup(&[], UNIX_EPOCH), None);
+        assert_eq!(decode_split_setup(&[0; 24], UNIX_EPOCH), None);
+    }
+
+    #[test]
+    fn rejected_starts_of_a_paired_accessory_are_no_tries() {
+        let mut accessory = Accessory::new();
+        accessory.pair();
+
+        for _ in 0..300 {
+            assert!(accessory.start(Method::PairSetup).is_err());
+        }
+        assert_eq!(accessory.handler.unsuccessful_tries, 0);
+    }
+
+    #[test]
+    fn paired_accessories_are_unavailable_before_the_try_limit() {
+        let mut accessory = Accessory::new();
+        accessory.pair();
+        accessory.handler.unsuccessful_tries = 100;
+
+        let err = handle_start(
+            &mut accessory.handler,
+            &accessory.config,
+            &accessory.database,
+            Method::PairSetup,
+            0,
+        );
+        assert!(matches!(err, Err(tlv::Error::Unavailable)));
+    }
+
+    #[test]
+    fn unpaired_accessories_enforce_the_try_limit() {
+        let mut accessory = Accessory::new();
+        accessory.handler.unsuccessful_tries = 100;
+
+        let err = handle_start(
+            &mut accessory.handler,
+            &accessory.config,
+            &accessory.database,
+            Method::PairSetup,
+            0,
+        );
+        assert!(matches!(err, Err(tlv::Error::MaxTries)));
+    }
+
+    #[test]
+    fn failed_proofs_count_as_tries_without_overflowing() {
+        let mut accessory = Accessory::new();
+
+        for tries in 1..=300 {
+            let res = accessory.handler.handle(
+                Step::Verify {
+                    a_pub: vec![1; 384],
+                    a_proof: vec![2; 64],
+                },
+                &Arc::new(Mutex::new(None)),
+                &accessory.config,
+                &accessory.database,
+                &accessory.event_emitter,
+            );
+            assert!(res.is_err());
+            assert_eq!(usize::from(accessory.handler.unsuccessful_tries), tries.min(255));
+        }
+        let err = handle_start(
+            &mut accessory.handler,
+            &accessory.config,
+            &accessory.database,
+            Method::PairSetup,
+            0,
+        );
+        assert!(matches!(err, Err(tlv::Error::MaxTries)));
+    }
+}
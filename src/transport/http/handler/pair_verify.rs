@@ -1,19 +1,21 @@
-use std::{collections::HashMap, str};
+use std::{
+    collections::{HashMap, VecDeque},
+    str,
+    sync::{Arc, Mutex},
+};
 
-use chacha20_poly1305_aead;
-use crypto::{curve25519, ed25519};
-use futures::sync::oneshot;
 use log::debug;
 use rand::{self, Rng};
-use ring::{digest, hkdf, hmac};
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
 use crate::{
     config::ConfigPtr,
+    crypto::{self, Backend, Crypto, SecretBytes, SecretKey32},
     db::DatabasePtr,
     event::EventEmitterPtr,
     protocol::{
-        tlv::{self, Type, Value},
+        tlv::{self, Encodable, Method, Type, Value},
         Device,
         IdPtr,
         Pairing,
@@ -24,24 +26,87 @@ use crate::{
 struct Session {
     b_pub: [u8; 32],
     a_pub: Vec<u8>,
-    shared_secret: [u8; 32],
-    session_key: [u8; 32],
+    shared_secret: SecretKey32,
+    session_key: SecretKey32,
 }
 
 pub struct PairVerify {
     session: Option<Session>,
-    session_sender: Option<oneshot::Sender<tcp::Session>>,
+    session_sender: tcp::SessionSenderPtr,
+    session_cache: SessionCachePtr,
 }
 
 impl PairVerify {
-    pub fn new(session_sender: oneshot::Sender<tcp::Session>) -> PairVerify {
+    pub fn new(session_sender: tcp::SessionSenderPtr, session_cache: SessionCachePtr) -> PairVerify {
         PairVerify {
             session: None,
-            session_sender: Some(session_sender),
+            session_sender,
+            session_cache,
+        }
+    }
+}
+
+/// Maximum number of sessions kept for Pair Resume.
+const MAX_CACHED_SESSIONS: usize = 32;
+
+#[derive(Clone)]
+struct CachedSession {
+    controller_id: Uuid,
+    shared_secret: SecretBytes,
+}
+
+/// Bounded cache of verified sessions that can be resumed by their session ID. If the cache is
+/// full, the oldest session is evicted.
+pub struct SessionCache {
+    capacity: usize,
+    sessions: HashMap<[u8; 8], CachedSession>,
+    order: VecDeque<[u8; 8]>,
+}
+
+impl SessionCache {
+    /// Creates a new `SessionCache` holding up to `MAX_CACHED_SESSIONS` sessions.
+    pub fn new() -> SessionCache { SessionCache::with_capacity(MAX_CACHED_SESSIONS) }
+
+    /// Creates a new `SessionCache` holding up to `capacity` sessions.
+    pub fn with_capacity(capacity: usize) -> SessionCache {
+        SessionCache {
+            capacity,
+            sessions: HashMap::new(),
+            order: VecDeque::new(),
         }
     }
+
+    fn insert(&mut self, session_id: [u8; 8], controller_id: Uuid, shared_secret: SecretBytes) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.sessions.contains_key(&session_id) {
+            self.order.retain(|id| id != &session_id);
+        }
+        while self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.sessions.remove(&oldest);
+            }
+        }
+        self.sessions.insert(session_id, CachedSession {
+            controller_id,
+            shared_secret,
+        });
+        self.order.push_back(session_id);
+    }
+
+    fn get(&self, session_id: &[u8; 8]) -> Option<CachedSession> { self.sessions.get(session_id).cloned() }
+
+    fn take(&mut self, session_id: &[u8; 8]) -> Option<CachedSession> {
+        let session = self.sessions.remove(session_id)?;
+        self.order.retain(|id| id != session_id);
+        Some(session)
+    }
 }
 
+/// Pointer to a `SessionCache`.
+pub type SessionCachePtr = Arc<Mutex<SessionCache>>;
+
 enum StepNumber {
     Unknown = 0,
     StartReq = 1,
@@ -52,6 +117,11 @@ enum StepNumber {
 
 pub enum Step {
     Start { a_pub: Vec<u8> },
+    Resume {
+        a_pub: Vec<u8>,
+        session_id: Vec<u8>,
+        data: Vec<u8>,
+    },
     Finish { data: Vec<u8> },
 }
 
@@ -60,14 +130,39 @@ impl TlvHandler for PairVerify {
     type Result = tlv::Container;
 
     fn parse(&self, body: Vec<u8>) -> Result<Step, tlv::ErrorContainer> {
-        let decoded = tlv::decode(body);
-        match decoded.get(&(Type::State as u8)) {
-            Some(method) => match method[0] {
+        let decoded = tlv::decode(&body)
+            .map_err(|_| tlv::ErrorContainer::new(StepNumber::Unknown as u8, tlv::Error::Unknown))?;
+        match decoded.get(&(Type::State as u8)).and_then(|state| state.first()) {
+            Some(state) => match *state {
                 x if x == StepNumber::StartReq as u8 => {
                     let a_pub = decoded.get(&(Type::PublicKey as u8)).ok_or(tlv::ErrorContainer::new(
                         StepNumber::StartRes as u8,
                         tlv::Error::Unknown,
                     ))?;
+                    if a_pub.len() != 32 {
+                        return Err(tlv::ErrorContainer::new(
+                            StepNumber::StartRes as u8,
+                            tlv::Error::Unknown,
+                        ));
+                    }
+                    let method = decoded.get(&(Type::Method as u8)).and_then(|m| m.first().cloned());
+                    if method == Some(Method::PairResume as u8) {
+                        let session_id = decoded.get(&(Type::SessionId as u8)).ok_or(tlv::ErrorContainer::new(
+                            StepNumber::StartRes as u8,
+                            tlv::Error::Unknown,
+                        ))?;
+                        let data = decoded
+                            .get(&(Type::EncryptedData as u8))
+                            .ok_or(tlv::ErrorContainer::new(
+                                StepNumber::StartRes as u8,
+                                tlv::Error::Unknown,
+                            ))?;
+                        return Ok(Step::Resume {
+                            a_pub: a_pub.clone(),
+                            session_id: session_id.clone(),
+                            data: data.clone(),
+                        });
+                    }
                     Ok(Step::Start { a_pub: a_pub.clone() })
                 },
                 x if x == StepNumber::FinishReq as u8 => {
@@ -98,6 +193,14 @@ impl TlvHandler for PairVerify {
                 Ok(res) => Ok(res),
                 Err(err) => Err(tlv::ErrorContainer::new(StepNumber::StartRes as u8, err)),
             },
+            Step::Resume {
+                a_pub,
+                session_id,
+                data,
+            } => match handle_resume(self, database, a_pub, &session_id, &data) {
+                Ok(res) => Ok(res),
+                Err(err) => Err(tlv::ErrorContainer::new(StepNumber::StartRes as u8, err)),
+            },
             Step::Finish { data } => match handle_finish(self, database, &data) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(tlv::ErrorContainer::new(StepNumber::FinishRes as u8, err)),
@@ -113,28 +216,24 @@ fn handle_start(
 ) -> Result<tlv::Container, tlv::Error> {
     debug!("M1: Got Verify Start Request");
 
-    let mut rng = rand::thread_rng();
-    let b = rng.gen::<[u8; 32]>();
-    let b_pub = curve25519::curve25519_base(&b);
-    let shared_secret = curve25519::curve25519(&b, &a_pub);
+    let (b, b_pub) = Backend::x25519_key_pair();
+    let shared_secret = Backend::x25519(&b, &a_pub)?;
 
     let accessory = Device::load_from(database)?;
     let mut accessory_info: Vec<u8> = Vec::new();
     accessory_info.extend(&b_pub);
     accessory_info.extend(accessory.id.as_bytes());
     accessory_info.extend(&a_pub);
-    let accessory_signature = ed25519::signature(&accessory_info, &accessory.private_key);
+    let accessory_signature = Backend::ed25519_sign(&accessory.private_key, &accessory_info)?;
 
-    let mut sub_tlv: HashMap<u8, Vec<u8>> = HashMap::new();
-    let (t, v) = Value::Identifier(accessory.id).as_tlv();
-    sub_tlv.insert(t, v);
-    let (t, v) = Value::Signature(accessory_signature.to_vec()).as_tlv();
-    sub_tlv.insert(t, v);
-    let encoded_sub_tlv = tlv::encode(sub_tlv);
+    let encoded_sub_tlv = vec![
+        Value::Identifier(accessory.id),
+        Value::Signature(accessory_signature.to_vec()),
+    ]
+    .encode();
 
-    let mut session_key = [0; 32];
-    let salt = hmac::SigningKey::new(&digest::SHA512, b"Pair-Verify-Encrypt-Salt");
-    hkdf::extract_and_expand(&salt, &shared_secret, b"Pair-Verify-Encrypt-Info", &mut session_key);
+    let session_key = crypto::derive_key(&*shared_secret, b"Pair-Verify-Encrypt-Salt", b"Pair-Verify-Encrypt-Info");
+    let encrypted_data = Backend::seal(&session_key, &crypto::nonce(b"PV-Msg02"), &[], &encoded_sub_tlv);
 
     handler.session = Some(Session {
         b_pub,
@@ -143,12 +242,6 @@ fn handle_start(
         session_key,
     });
 
-    let mut encrypted_data = Vec::new();
-    let mut nonce = vec![0; 4];
-    nonce.extend(b"PV-Msg02");
-    let auth_tag = chacha20_poly1305_aead::encrypt(&session_key, &nonce, &[], &encoded_sub_tlv, &mut encrypted_data)?;
-    encrypted_data.extend(&auth_tag);
-
     debug!("M2: Sending Verify Start Response");
 
     Ok(vec![
@@ -162,24 +255,14 @@ fn handle_finish(handler: &mut PairVerify, database: &DatabasePtr, data: &[u8])
     debug!("M3: Got Verify Finish Request");
 
     if let Some(ref mut session) = handler.session {
-        let encrypted_data = Vec::from(&data[..data.len() - 16]);
-        let auth_tag = Vec::from(&data[data.len() - 16..]);
-
-        let mut decrypted_data = Vec::new();
-        let mut nonce = vec![0; 4];
-        nonce.extend(b"PV-Msg03");
-        chacha20_poly1305_aead::decrypt(
-            &session.session_key,
-            &nonce,
-            &[],
-            &encrypted_data,
-            &auth_tag,
-            &mut decrypted_data,
-        )?;
-
-        let sub_tlv = tlv::decode(decrypted_data);
+        let decrypted_data = Backend::open(&session.session_key, &crypto::nonce(b"PV-Msg03"), &[], data)?;
+
+        let sub_tlv = tlv::decode(&decrypted_data)?;
         let device_pairing_id = sub_tlv.get(&(Type::Identifier as u8)).ok_or(tlv::Error::Unknown)?;
         let device_signature = sub_tlv.get(&(Type::Signature as u8)).ok_or(tlv::Error::Unknown)?;
+        if device_signature.len() != 64 {
+            return Err(tlv::Error::Authentication);
+        }
 
         let uuid_str = str::from_utf8(device_pairing_id)?;
         let pairing_uuid = Uuid::parse_str(uuid_str)?;
@@ -189,16 +272,34 @@ fn handle_finish(handler: &mut PairVerify, database: &DatabasePtr, data: &[u8])
         device_info.extend(&session.a_pub);
         device_info.extend(device_pairing_id);
         device_info.extend(&session.b_pub);
-        if !ed25519::verify(&device_info, &pairing.public_key, &device_signature) {
+        if !Backend::ed25519_verify(&pairing.public_key, &device_info, device_signature) {
             return Err(tlv::Error::Authentication);
         }
 
-        if let Some(sender) = handler.session_sender.take() {
+        if let Some(sender) = handler
+            .session_sender
+            .lock()
+            .expect("couldn't access session_sender")
+            .take()
+        {
             let encrypted_session = tcp::Session {
-                controller_id: pairing_uuid,
-                shared_secret: session.shared_secret,
+                controller_id: Some(pairing_uuid),
+                shared_secret: Zeroizing::new(session.shared_secret.to_vec()),
             };
             let _session = sender.send(encrypted_session);
+
+            let mut session_id = [0; 8];
+            Backend::hkdf_sha512(
+                &*session.shared_secret,
+                b"Pair-Verify-ResumeSessionID-Salt",
+                b"Pair-Verify-ResumeSessionID-Info",
+                &mut session_id,
+            );
+            handler
+                .session_cache
+                .lock()
+                .expect("couldn't access session_cache")
+                .insert(session_id, pairing_uuid, Zeroizing::new(session.shared_secret.to_vec()));
         } else {
             return Err(tlv::Error::Unknown);
         }
@@ -210,3 +311,195 @@ fn handle_finish(handler: &mut PairVerify, database: &DatabasePtr, data: &[u8])
         Err(tlv::Error::Unknown)
     }
 }
+
+fn handle_resume(
+    handler: &mut PairVerify,
+    database: &DatabasePtr,
+    a_pub: Vec<u8>,
+    session_id: &[u8],
+    data: &[u8],
+) -> Result<tlv::Container, tlv::Error> {
+    debug!("M1: Got Resume Request");
+
+    // the session ID is sent in the clear, so the cached session is only removed once the request
+    // has been authenticated
+    let id = if session_id.len() == 8 {
+        let mut id = [0; 8];
+        id.copy_from_slice(session_id);
+        Some(id)
+    } else {
+        None
+    };
+    let cached_session = id.and_then(|id| {
+        handler
+            .session_cache
+            .lock()
+            .expect("couldn't access session_cache")
+            .get(&id)
+    });
+
+    let (id, cached_session) = match (id, cached_session) {
+        (Some(id), Some(cached_session)) => (id, cached_session),
+        _ => {
+            debug!("Unknown session ID, falling back to full Pair Verify");
+            return handle_start(handler, database, a_pub);
+        },
+    };
+
+    // the pairing may have been removed since the session was cached
+    if Pairing::load_from(cached_session.controller_id, database).is_err() {
+        return handle_start(handler, database, a_pub);
+    }
+
+    let mut salt = a_pub.clone();
+    salt.extend(session_id);
+    let request_key = crypto::derive_key(&cached_session.shared_secret, &salt, b"Pair-Resume-Request-Info");
+
+    if Backend::open(&request_key, &crypto::nonce(b"PR-Msg01"), &[], data).is_err() {
+        debug!("Couldn't authenticate resume request, falling back to full Pair Verify");
+        return handle_start(handler, database, a_pub);
+    }
+
+    handler
+        .session_cache
+        .lock()
+        .expect("couldn't access session_cache")
+        .take(&id);
+
+    let mut rng = rand::thread_rng();
+    let new_session_id = rng.gen::<[u8; 8]>();
+
+    let mut salt = a_pub.clone();
+    salt.extend(&new_session_id);
+    let response_key = crypto::derive_key(&cached_session.shared_secret, &salt, b"Pair-Resume-Response-Info");
+    let shared_secret = crypto::derive_key(&cached_session.shared_secret, &salt, b"Pair-Resume-Shared-Secret-Info");
+
+    let encrypted_data = Backend::seal(&response_key, &crypto::nonce(b"PR-Msg02"), &[], &[]);
+
+    if let Some(sender) = handler
+        .session_sender
+        .lock()
+        .expect("couldn't access session_sender")
+        .take()
+    {
+        let _session = sender.send(tcp::Session {
+            controller_id: Some(cached_session.controller_id),
+            shared_secret: Zeroizing::new(shared_secret.to_vec()),
+        });
+    } else {
+        return Err(tlv::Error::Unknown);
+    }
+
+    handler
+        .session_cache
+        .lock()
+        .expect("couldn't access session_cache")
+        .insert(
+            new_session_id,
+            cached_session.controller_id,
+            Zeroizing::new(shared_secret.to_vec()),
+        );
+
+    debug!("M2: Sending Resume Response");
+
+    Ok(vec![
+        Value::State(StepNumber::StartRes as u8),
+        Value::Method(Method::PairResume),
+        Value::SessionId(new_session_id.to_vec()),
+        Value::EncryptedData(encrypted_data),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{Database, MemoryStorage},
+        pin,
+        protocol::Permissions,
+    };
+
+    use super::*;
+
+    fn secret(byte: u8) -> SecretBytes { Zeroizing::new(vec![byte; 32]) }
+
+    fn id(n: usize) -> [u8; 8] { (n as u64).to_be_bytes() }
+
+    #[test]
+    fn cached_sessions_are_taken_once() {
+        let mut cache = SessionCache::new();
+        let controller_id = Uuid::new_v4();
+        cache.insert(id(1), controller_id, secret(1));
+
+        assert!(cache.take(&id(2)).is_none());
+        let session = cache.take(&id(1)).unwrap();
+        assert_eq!(session.controller_id, controller_id);
+        assert_eq!(*session.shared_secret, vec![1; 32]);
+        assert!(cache.take(&id(1)).is_none());
+    }
+
+    #[test]
+    fn oldest_sessions_are_evicted_at_capacity() {
+        let mut cache = SessionCache::new();
+        for n in 0..MAX_CACHED_SESSIONS {
+            cache.insert(id(n), Uuid::new_v4(), secret(n as u8));
+        }
+        assert_eq!(cache.sessions.len(), 32);
+
+        cache.insert(id(32), Uuid::new_v4(), secret(32));
+        cache.insert(id(33), Uuid::new_v4(), secret(33));
+        assert_eq!(cache.sessions.len(), 32);
+        assert_eq!(cache.order.len(), 32);
+        assert!(cache.take(&id(0)).is_none());
+        assert!(cache.take(&id(1)).is_none());
+        for n in 2..34 {
+            assert!(cache.take(&id(n)).is_some());
+        }
+    }
+
+    #[test]
+    fn reinserted_sessions_move_to_the_back() {
+        let mut cache = SessionCache::with_capacity(2);
+        cache.insert(id(0), Uuid::new_v4(), secret(0));
+        cache.insert(id(1), Uuid::new_v4(), secret(1));
+        cache.insert(id(0), Uuid::new_v4(), secret(2));
+        cache.insert(id(2), Uuid::new_v4(), secret(3));
+
+        assert!(cache.take(&id(1)).is_none());
+        assert_eq!(*cache.take(&id(0)).unwrap().shared_secret, vec![2; 32]);
+        assert!(cache.take(&id(2)).is_some());
+    }
+
+    #[test]
+    fn nothing_is_cached_without_capacity() {
+        let mut cache = SessionCache::with_capacity(0);
+        cache.insert(id(0), Uuid::new_v4(), secret(0));
+
+        assert!(cache.take(&id(0)).is_none());
+    }
+
+    #[test]
+    fn forged_resume_keeps_the_cached_session() {
+        let database = Arc::new(Mutex::new(Database::new(Box::new(MemoryStorage::new()))));
+        Device::new_random("AA:BB:CC:DD:EE:FF".into(), pin::new("03145154").unwrap())
+            .save_to(&database)
+            .unwrap();
+        let controller_id = Uuid::new_v4();
+        Pairing::new(controller_id, Permissions::Admin, [1; 32])
+            .save_to(&database)
+            .unwrap();
+
+        let session_cache = Arc::new(Mutex::new(SessionCache::new()));
+        session_cache
+            .lock()
+            .unwrap()
+            .insert(id(1), controller_id, secret(1));
+        let mut handler = PairVerify::new(Arc::new(Mutex::new(None)), session_cache.clone());
+
+        let (_, a_pub) = Backend::x25519_key_pair();
+        let res = handle_resume(&mut handler, &database, a_pub.to_vec(), &id(1), &[0; 16]).unwrap();
+
+        assert!(!res.iter().any(|value| matches!(value, Value::Method(Method::PairResume))));
+        assert!(handler.session.is_some());
+        assert!(session_cache.lock().unwrap().take(&id(1)).is_some());
+    }
+}
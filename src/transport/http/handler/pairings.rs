@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use crate::{
     config::ConfigPtr,
+    crypto,
     db::DatabasePtr,
     event::{EventEmitterPtr, Event},
     protocol::{
@@ -50,12 +51,13 @@ impl TlvHandler for Pairings {
     type Result = tlv::Container;
 
     fn parse(&self, body: Vec<u8>) -> Result<HandlerType, tlv::ErrorContainer> {
-        let decoded = tlv::decode(body);
+        let decoded = tlv::decode(&body)
+            .map_err(|_| tlv::ErrorContainer::new(StepNumber::Unknown as u8, tlv::Error::Unknown))?;
         if decoded.get(&(Type::State as u8)) != Some(&vec![1]) {
             return Err(tlv::ErrorContainer::new(0, tlv::Error::Unknown));
         }
         match decoded.get(&(Type::Method as u8)) {
-            Some(handler) => match handler[0] {
+            Some(handler) => match handler.first().cloned().unwrap_or(0) {
                 x if x == HandlerNumber::Add as u8 => {
                     let pairing_id = decoded
                         .get(&(Type::Identifier as u8))
@@ -63,10 +65,13 @@ impl TlvHandler for Pairings {
                     let ltpk = decoded
                         .get(&(Type::PublicKey as u8))
                         .ok_or(tlv::ErrorContainer::new(StepNumber::Res as u8, tlv::Error::Unknown))?;
+                    if ltpk.len() != 32 {
+                        return Err(tlv::ErrorContainer::new(StepNumber::Res as u8, tlv::Error::Unknown));
+                    }
                     let perms = decoded
                         .get(&(Type::Permissions as u8))
                         .ok_or(tlv::ErrorContainer::new(StepNumber::Res as u8, tlv::Error::Unknown))?;
-                    let permissions = Permissions::from_u8(perms[0])
+                    let permissions = Permissions::from_u8(perms.first().cloned().unwrap_or(0xFF))
                         .map_err(|_| tlv::ErrorContainer::new(StepNumber::Res as u8, tlv::Error::Unknown))?;
                     Ok(HandlerType::Add {
                         pairing_id: pairing_id.clone(),
@@ -140,13 +145,13 @@ fn handle_add(
 
     check_admin(database, controller_id)?;
 
-    let uuid_str = str::from_utf8(&pairing_id)?;
+    let uuid_str = str::from_utf8(pairing_id)?;
     let pairing_uuid = Uuid::parse_str(uuid_str)?;
 
     let d = database.lock().expect("couldn't access database");
     match d.get_pairing(pairing_uuid) {
         Ok(mut pairing) => {
-            if pairing.public_key != ltpk {
+            if !crypto::ct_eq(&pairing.public_key, ltpk) {
                 return Err(tlv::Error::Unknown);
             }
             pairing.permissions = permissions;
@@ -166,7 +171,7 @@ fn handle_add(
             }
 
             let mut public_key = [0; 32];
-            public_key.clone_from_slice(&ltpk);
+            public_key.clone_from_slice(ltpk);
             let pairing = Pairing {
                 id: pairing_uuid,
                 permissions,
@@ -197,10 +202,11 @@ fn handle_remove(
 
     check_admin(database, controller_id)?;
 
-    let uuid_str = str::from_utf8(&pairing_id)?;
+    let uuid_str = str::from_utf8(pairing_id)?;
     let pairing_uuid = Uuid::parse_str(uuid_str)?;
     let d = database.lock().expect("couldn't access database");
     d.delete_pairing(&d.get_pairing(pairing_uuid)?.id)?;
+    d.delete_split_setup()?;
     drop(d);
 
     event_emitter
@@ -224,7 +230,7 @@ fn handle_list(database: &DatabasePtr, controller_id: &IdPtr) -> Result<tlv::Con
         list.push(Value::Identifier(pairing.id.to_hyphenated().to_string()));
         list.push(Value::PublicKey(pairing.public_key.to_vec()));
         list.push(Value::Permissions(pairing.permissions.clone()));
-        if i < pairings.len() {
+        if i + 1 < pairings.len() {
             list.push(Value::Separator);
         }
     }
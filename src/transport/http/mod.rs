@@ -38,6 +38,7 @@ enum ContentType {
 }
 
 impl ContentType {
+    #[allow(clippy::inherent_to_string, clippy::wrong_self_convention)]
     pub fn to_string(self) -> String {
         match self {
             ContentType::PairingTLV8 => "application/pairing+tlv8".into(),
@@ -85,9 +86,13 @@ pub struct WriteObject {
     pub aid: u64,
     pub ev: Option<bool>,
     pub value: Option<serde_json::Value>,
+    #[allow(dead_code)]
     #[serde(rename = "authData")]
     pub auth_data: Option<String>,
+    #[allow(dead_code)]
     pub remote: Option<bool>,
+    #[serde(rename = "r")]
+    pub response: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -95,6 +100,8 @@ pub struct WriteResponseObject {
     pub iid: u64,
     pub aid: u64,
     pub status: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1,13 +1,19 @@
 use std::{
-    net::SocketAddr,
+    collections::HashMap,
+    io,
+    net::{self, IpAddr, Ipv4Addr, SocketAddr},
     sync::{Arc, Mutex},
 };
 
 use futures::{future, stream::Stream, sync::oneshot, Future};
 use hyper::{self, server::conn::Http, service::Service, Body, Method, Request, Response, StatusCode};
-use log::error;
+use log::{debug, error};
+use net2::TcpBuilder;
 use route_recognizer::Router;
-use tokio::net::TcpListener;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    reactor::Handle,
+};
 
 use crate::{
     config::ConfigPtr,
@@ -21,7 +27,11 @@ use crate::{
             status_response,
             EventObject,
         },
-        tcp::{EncryptedStream, Session, StreamWrapper},
+        connection::{ConnectionList, ConnectionListPtr},
+        interfaces::{self, InterfaceAddress, Interfaces},
+        mdns::{AnnouncerPtr, ServiceId},
+        monitor::NetworkMonitor,
+        tcp::{EncryptedStream, SessionSenderPtr, StreamWrapper},
     },
     Error,
     Result,
@@ -47,6 +57,7 @@ struct Api {
 }
 
 impl Api {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         controller_id: IdPtr,
         event_subscriptions: EventSubscriptions,
@@ -54,19 +65,20 @@ impl Api {
         database: DatabasePtr,
         accessories: AccessoryList,
         event_emitter: EventEmitterPtr,
-        session_sender: oneshot::Sender<Session>,
+        session_sender: SessionSenderPtr,
+        session_cache: pair_verify::SessionCachePtr,
     ) -> Api {
         let mut router = Router::new();
         router.add(
             "/pair-setup",
             Route::Post(Box::new(Mutex::new(handler::TlvHandlerType::from(
-                pair_setup::PairSetup::new(),
+                pair_setup::PairSetup::new(session_sender.clone()),
             )))),
         );
         router.add(
             "/pair-verify",
             Route::Post(Box::new(Mutex::new(handler::TlvHandlerType::from(
-                pair_verify::PairVerify::new(session_sender),
+                pair_verify::PairVerify::new(session_sender, session_cache),
             )))),
         );
         router.add(
@@ -133,9 +145,9 @@ impl Service for Api {
             .and_then(move |body| {
                 if let Ok(route_match) = router.recognize(parts.uri.path()) {
                     match (route_match.handler, parts.method) {
-                        (&Route::Get(ref handler), Method::GET) => handler.lock().unwrap().handle(
+                        (Route::Get(handler), Method::GET) => handler.lock().unwrap().handle(
                             parts.uri,
-                            body.into(),
+                            body,
                             &controller_id,
                             &event_subscriptions,
                             &config,
@@ -143,9 +155,9 @@ impl Service for Api {
                             &accessories,
                             &event_emitter,
                         ),
-                        (&Route::Post(ref handler), Method::POST) => handler.lock().unwrap().handle(
+                        (Route::Post(handler), Method::POST) => handler.lock().unwrap().handle(
                             parts.uri,
-                            body.into(),
+                            body,
                             &controller_id,
                             &event_subscriptions,
                             &config,
@@ -153,9 +165,9 @@ impl Service for Api {
                             &accessories,
                             &event_emitter,
                         ),
-                        (&Route::GetPut { ref _get, ref _put }, Method::GET) => _get.lock().unwrap().handle(
+                        (Route::GetPut { _get, _put }, Method::GET) => _get.lock().unwrap().handle(
                             parts.uri,
-                            body.into(),
+                            body,
                             &controller_id,
                             &event_subscriptions,
                             &config,
@@ -163,9 +175,9 @@ impl Service for Api {
                             &accessories,
                             &event_emitter,
                         ),
-                        (&Route::GetPut { ref _get, ref _put }, Method::PUT) => _put.lock().unwrap().handle(
+                        (Route::GetPut { _get, _put }, Method::PUT) => _put.lock().unwrap().handle(
                             parts.uri,
-                            body.into(),
+                            body,
                             &controller_id,
                             &event_subscriptions,
                             &config,
@@ -185,78 +197,392 @@ impl Service for Api {
 
 pub type EventSubscriptions = Arc<Mutex<Vec<(u64, u64)>>>;
 
+/// Binds the listeners to serve on for the configured IP address and interfaces.
+///
+/// An unspecified IP address on all interfaces is served by a single listener, which is dual-stack
+/// for `::`. Otherwise, a listener is bound to every selected address. A port of `0` is resolved by
+/// the first listener and reused by all others.
+pub fn bind(ip: IpAddr, port: u16, interfaces: &Interfaces) -> Result<Vec<TcpListener>> {
+    let listeners = if serves_all_addresses(ip, interfaces) {
+        let listener = match ip {
+            IpAddr::V6(_) => listen(&SocketAddr::new(ip, port), false).or_else(|e| {
+                debug!("couldn't bind dual-stack listener, falling back to IPv4: {}", e);
+                listen(&SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), port), true)
+            })?,
+            IpAddr::V4(_) => listen(&SocketAddr::new(ip, port), true)?,
+        };
+        vec![listener]
+    } else {
+        let mut port = port;
+        let mut listeners = Vec::new();
+        for address in interfaces::selected_addresses(ip, interfaces) {
+            match listen(&address.socket_addr(port), true) {
+                Ok(listener) => {
+                    port = listener.local_addr()?.port();
+                    listeners.push(listener);
+                },
+                Err(e) => error!("couldn't bind to {} on {}: {}", address.ip, address.name, e),
+            }
+        }
+        listeners
+    };
+
+    if listeners.is_empty() {
+        return Err(Error::from_str("couldn't bind to any address"));
+    }
+
+    listeners
+        .into_iter()
+        .map(|l| Ok(TcpListener::from_std(l, &Handle::default())?))
+        .collect()
+}
+
+fn listen(socket_addr: &SocketAddr, only_v6: bool) -> io::Result<net::TcpListener> {
+    let builder = match socket_addr {
+        SocketAddr::V4(_) => TcpBuilder::new_v4()?,
+        SocketAddr::V6(_) => {
+            let builder = TcpBuilder::new_v6()?;
+            builder.only_v6(only_v6)?;
+            builder
+        },
+    };
+    #[cfg(unix)]
+    builder.reuse_address(true)?;
+    builder.bind(socket_addr)?;
+    builder.listen(128)
+}
+
 pub fn serve(
-    socket_addr: &SocketAddr,
+    listeners: Vec<TcpListener>,
     config: &ConfigPtr,
     database: &DatabasePtr,
     accessories: &AccessoryList,
     event_emitter: &EventEmitterPtr,
+    mdns_announcer: &AnnouncerPtr,
+    mdns_service: ServiceId,
 ) -> Result<()> {
-    let listener = TcpListener::bind(socket_addr)?;
-
-    let config = config.clone();
-    let database = database.clone();
-    let accessories = accessories.clone();
-    let event_emitter = event_emitter.clone();
-
-    let server = listener
-        .incoming()
-        .for_each(move |stream| {
-            let (encrypted_stream, stream_incoming, stream_outgoing, session_sender) = EncryptedStream::new(stream);
-            let stream_wrapper = StreamWrapper::new(stream_incoming, stream_outgoing.clone());
-            let event_subscriptions = Arc::new(Mutex::new(vec![]));
-            let api = Api::new(
-                encrypted_stream.controller_id.clone(),
-                event_subscriptions.clone(),
-                config.clone(),
-                database.clone(),
-                accessories.clone(),
-                event_emitter.clone(),
-                session_sender,
-            );
-            let http = Http::new();
-
-            event_emitter
-                .lock()
-                .expect("couldn't add listener for characteristic value change events")
-                .add_listener(Box::new(move |event| match *event {
-                    Event::CharacteristicValueChanged { aid, iid, ref value } => {
-                        let mut dropped_subscriptions = vec![];
-                        for (i, &(s_aid, s_iid)) in event_subscriptions
+    let (ip, interfaces, network_monitor_interval, connections) = {
+        let c = config.lock().expect("couldn't access config");
+        let connections = Arc::new(Mutex::new(ConnectionList::new(
+            c.max_connections,
+            c.max_controller_connections,
+            c.handshake_timeout,
+            c.idle_timeout,
+        )));
+        (c.ip, c.interfaces.clone(), c.network_monitor_interval, connections)
+    };
+    let server = Server {
+        config: config.clone(),
+        database: database.clone(),
+        accessories: accessories.clone(),
+        event_emitter: event_emitter.clone(),
+        session_cache: Arc::new(Mutex::new(pair_verify::SessionCache::new())),
+        connections,
+    };
+    let mdns_announcer = mdns_announcer.clone();
+
+    tokio::run(future::lazy(move || {
+        let mut listeners = listeners
+            .into_iter()
+            .map(|listener| Ok((listener.local_addr()?, server.spawn_listener(listener))))
+            .collect::<io::Result<HashMap<_, _>>>()
+            .map_err(|e| error!("{}", e))?;
+
+        if let Some(interval) = network_monitor_interval {
+            let addresses = interfaces::selected_addresses(ip, &interfaces);
+            let rebind = !serves_all_addresses(ip, &interfaces);
+            let monitor = NetworkMonitor::new(ip, interfaces, addresses, interval);
+            tokio::spawn(
+                monitor
+                    .for_each(move |addresses| {
+                        if rebind {
+                            server.rebind(&mut listeners, &addresses);
+                        }
+                        // a failed update mustn't end the monitoring of later changes
+                        if let Err(e) = mdns_announcer
                             .lock()
-                            .expect("couldn't read event subscriptions")
-                            .iter()
-                            .enumerate()
+                            .expect("couldn't access mDNS announcer")
+                            .update_addresses(mdns_service)
                         {
-                            if s_aid == aid && s_iid == iid {
-                                let event = EventObject {
-                                    aid,
-                                    iid,
-                                    value: value.clone(),
-                                };
-                                let event_res = event_response(vec![event]).expect("couldn't create event response");
-                                if stream_outgoing.unbounded_send(event_res).is_err() {
-                                    dropped_subscriptions.push(i);
-                                }
-                            }
+                            error!("couldn't update the addresses of the mDNS service: {}", e);
                         }
-                        let mut ev = event_subscriptions.lock().expect("couldn't modify event subscriptions");
-                        for s in dropped_subscriptions {
-                            ev.remove(s);
+                        Ok(())
+                    })
+                    .map_err(|e| error!("{}", e)),
+            );
+        }
+
+        Ok(())
+    }));
+
+    Ok(())
+}
+
+/// Returns whether a single listener on the unspecified address serves all selected addresses, so
+/// that no listeners have to be bound to individual addresses.
+fn serves_all_addresses(ip: IpAddr, interfaces: &Interfaces) -> bool {
+    ip.is_unspecified() && *interfaces == Interfaces::All
+}
+
+/// State shared by the listeners of the server.
+#[derive(Clone)]
+struct Server {
+    config: ConfigPtr,
+    database: DatabasePtr,
+    accessories: AccessoryList,
+    event_emitter: EventEmitterPtr,
+    session_cache: pair_verify::SessionCachePtr,
+    connections: ConnectionListPtr,
+}
+
+impl Server {
+    /// Spawns a task accepting connections on a listener. The listener is closed once a value is
+    /// sent on the returned sender. Dropping the sender keeps the listener open.
+    fn spawn_listener(&self, listener: TcpListener) -> oneshot::Sender<()> {
+        let (close_sender, close_receiver) = oneshot::channel();
+        let server = self.clone();
+        tokio::spawn(
+            listener
+                .incoming()
+                .for_each(move |stream| {
+                    server.accept(stream);
+                    Ok(())
+                })
+                .map_err(|e| error!("{}", e))
+                .select(close_receiver.or_else(|_| future::empty()))
+                .then(|_| Ok(())),
+        );
+        close_sender
+    }
+
+    /// Binds listeners to new addresses and closes the listeners of vanished addresses.
+    fn rebind(&self, listeners: &mut HashMap<SocketAddr, oneshot::Sender<()>>, addresses: &[InterfaceAddress]) {
+        let port = self.config.lock().expect("couldn't access config").port;
+        let socket_addrs = addresses.iter().map(|a| a.socket_addr(port)).collect::<Vec<_>>();
+
+        let vanished = listeners
+            .keys()
+            .filter(|socket_addr| !socket_addrs.contains(socket_addr))
+            .cloned()
+            .collect::<Vec<_>>();
+        for socket_addr in vanished {
+            debug!("Closing listener on vanished address {}", socket_addr);
+            if let Some(close_sender) = listeners.remove(&socket_addr) {
+                let _ = close_sender.send(());
+            }
+        }
+
+        for socket_addr in socket_addrs {
+            if listeners.contains_key(&socket_addr) {
+                continue;
+            }
+            let listener = listen(&socket_addr, true).and_then(|l| TcpListener::from_std(l, &Handle::default()));
+            match listener {
+                Ok(listener) => {
+                    debug!("Listening on new address {}", socket_addr);
+                    listeners.insert(socket_addr, self.spawn_listener(listener));
+                },
+                Err(e) => error!("couldn't bind to {}: {}", socket_addr, e),
+            }
+        }
+    }
+
+    fn accept(&self, stream: TcpStream) {
+        let connection = match ConnectionList::accept(&self.connections) {
+            Some(connection) => connection,
+            None => {
+                debug!("Rejecting connection, maximum number of connections reached");
+                return;
+            },
+        };
+        let (encrypted_stream, stream_incoming, stream_outgoing, session_sender) =
+            EncryptedStream::new(stream, connection);
+        let session_sender = Arc::new(Mutex::new(Some(session_sender)));
+        let stream_wrapper = StreamWrapper::new(stream_incoming, stream_outgoing.clone());
+        let stream_outgoing = Mutex::new(stream_outgoing);
+        let event_subscriptions = Arc::new(Mutex::new(vec![]));
+        let api = Api::new(
+            encrypted_stream.controller_id.clone(),
+            event_subscriptions.clone(),
+            self.config.clone(),
+            self.database.clone(),
+            self.accessories.clone(),
+            self.event_emitter.clone(),
+            session_sender,
+            self.session_cache.clone(),
+        );
+        let http = Http::new();
+
+        self.event_emitter
+            .lock()
+            .expect("couldn't add listener for characteristic value change events")
+            .add_listener(Box::new(move |event| {
+                if let Event::CharacteristicValueChanged { aid, iid, ref value } = *event {
+                    let mut dropped_subscriptions = vec![];
+                    for (i, &(s_aid, s_iid)) in event_subscriptions
+                        .lock()
+                        .expect("couldn't read event subscriptions")
+                        .iter()
+                        .enumerate()
+                    {
+                        if s_aid == aid && s_iid == iid {
+                            let event = EventObject {
+                                aid,
+                                iid,
+                                value: value.clone(),
+                            };
+                            let event_res = event_response(vec![event]).expect("couldn't create event response");
+                            let sent = stream_outgoing
+                                .lock()
+                                .expect("couldn't access outgoing stream")
+                                .try_send(event_res);
+                            match sent {
+                                Err(ref e) if e.is_disconnected() => dropped_subscriptions.push(i),
+                                // a controller that doesn't keep up with its connection misses events
+                                Err(_) => debug!("Dropping event for a congested connection"),
+                                Ok(()) => {},
+                            }
                         }
-                    },
-                    _ => {},
-                }));
+                    }
+                    let mut ev = event_subscriptions.lock().expect("couldn't modify event subscriptions");
+                    for s in dropped_subscriptions {
+                        ev.remove(s);
+                    }
+                }
+            }));
 
+        // once the `EncryptedStream` resolves because the connection was closed, evicted or timed
+        // out, the HTTP connection sees the end of its stream and resolves as well
+        tokio::spawn(
             encrypted_stream
                 .map_err(|e| error!("{}", e))
                 .join(http.serve_connection(stream_wrapper, api).map_err(|e| error!("{}", e)))
                 .map(|_| ())
-                .then(|_| Ok(()))
-        })
-        .map_err(|e| error!("{}", e));
+                .then(|_| Ok(())),
+        );
+    }
+}
 
-    tokio::run(server);
+#[cfg(test)]
+mod tests {
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
 
-    Ok(())
+    use tokio::runtime::Runtime;
+
+    use super::*;
+    use crate::{
+        db::{Database, MemoryStorage},
+        event::EventEmitter,
+        Config,
+    };
+
+    fn server(port: u16) -> Server {
+        let event_emitter = Arc::new(Mutex::new(EventEmitter::new()));
+        Server {
+            config: Arc::new(Mutex::new(Config {
+                port,
+                ..Default::default()
+            })),
+            database: Arc::new(Mutex::new(Database::new(Box::new(MemoryStorage::new())))),
+            accessories: AccessoryList::new(event_emitter.clone()),
+            event_emitter,
+            session_cache: Arc::new(Mutex::new(pair_verify::SessionCache::new())),
+            connections: Arc::new(Mutex::new(ConnectionList::new(16, None, Duration::from_secs(60), None))),
+        }
+    }
+
+    fn address(ip: &str) -> InterfaceAddress {
+        InterfaceAddress {
+            name: "lo".into(),
+            index: 1,
+            ip: ip.parse().unwrap(),
+            prefix: 8,
+        }
+    }
+
+    fn rebind(
+        runtime: &mut Runtime,
+        server: &Server,
+        listeners: HashMap<SocketAddr, oneshot::Sender<()>>,
+        addresses: Vec<InterfaceAddress>,
+    ) -> HashMap<SocketAddr, oneshot::Sender<()>> {
+        let server = server.clone();
+        runtime
+            .block_on(future::lazy(move || {
+                let mut listeners = listeners;
+                server.rebind(&mut listeners, &addresses);
+                Ok::<_, ()>(listeners)
+            }))
+            .unwrap()
+    }
+
+    fn is_listening(socket_addr: &SocketAddr) -> bool {
+        net::TcpStream::connect_timeout(socket_addr, Duration::from_secs(1)).is_ok()
+    }
+
+    /// Waits for a closed listener to stop accepting connections, since it's closed asynchronously.
+    fn is_closed(socket_addr: &SocketAddr) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if !is_listening(socket_addr) {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        false
+    }
+
+    fn socket_addr(ip: &str, port: u16) -> SocketAddr { SocketAddr::new(ip.parse().unwrap(), port) }
+
+    #[test]
+    fn single_listener_only_for_all_interfaces_on_the_unspecified_address() {
+        let unspecified_v4 = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+        let unspecified_v6 = "::".parse().unwrap();
+        let named = Interfaces::Named(vec!["eth0".into()]);
+
+        assert!(serves_all_addresses(unspecified_v4, &Interfaces::All));
+        assert!(serves_all_addresses(unspecified_v6, &Interfaces::All));
+        assert!(!serves_all_addresses(unspecified_v4, &named));
+        assert!(!serves_all_addresses(unspecified_v6, &named));
+        assert!(!serves_all_addresses("192.168.1.10".parse().unwrap(), &Interfaces::All));
+        assert!(!serves_all_addresses("::1".parse().unwrap(), &named));
+    }
+
+    #[test]
+    fn rebind_follows_changing_addresses() {
+        let port = net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let server = server(port);
+        let mut runtime = Runtime::new().unwrap();
+        let first = socket_addr("127.0.0.1", port);
+        let second = socket_addr("127.0.0.2", port);
+        let third = socket_addr("127.0.0.3", port);
+
+        let listeners = rebind(&mut runtime, &server, HashMap::new(), vec![address("127.0.0.1")]);
+        assert_eq!(listeners.keys().collect::<Vec<_>>(), vec![&first]);
+        assert!(is_listening(&first));
+
+        // the listener of the vanished address is closed, the new address is bound
+        let listeners = rebind(&mut runtime, &server, listeners, vec![address("127.0.0.2")]);
+        assert_eq!(listeners.keys().collect::<Vec<_>>(), vec![&second]);
+        assert!(is_closed(&first));
+        assert!(is_listening(&second));
+
+        // the listeners of remaining addresses are kept
+        let listeners = rebind(&mut runtime, &server, listeners, vec![
+            address("127.0.0.2"),
+            address("127.0.0.3"),
+        ]);
+        assert_eq!(listeners.len(), 2);
+        assert!(listeners.contains_key(&second) && listeners.contains_key(&third));
+        assert!(is_listening(&second));
+        assert!(is_listening(&third));
+
+        let listeners = rebind(&mut runtime, &server, listeners, Vec::new());
+        assert!(listeners.is_empty());
+        assert!(is_closed(&second));
+        assert!(is_closed(&third));
+    }
 }
@@ -0,0 +1,266 @@
+use std::net::{IpAddr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+use pnet::datalink;
+
+/// Network interfaces the IP transport is served and announced on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Interfaces {
+    /// All interfaces that are up, except loopback interfaces.
+    #[default]
+    All,
+    /// The interfaces with the given names, e.g. `"eth0"` or `"wlan0"`.
+    Named(Vec<String>),
+}
+
+impl Interfaces {
+    fn includes(&self, interface: &datalink::NetworkInterface) -> bool {
+        match *self {
+            Interfaces::All => interface.is_up() && !interface.is_loopback(),
+            Interfaces::Named(ref names) => names.iter().any(|name| name == &interface.name),
+        }
+    }
+}
+
+/// An IP address of a network interface.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct InterfaceAddress {
+    /// Name of the interface.
+    pub name: String,
+    /// Index of the interface. Used as the scope ID of IPv6 link-local addresses.
+    pub index: u32,
+    /// The address.
+    pub ip: IpAddr,
+    /// Prefix length of the network of the address.
+    pub prefix: u8,
+}
+
+impl InterfaceAddress {
+    /// Returns the socket address of the address and a given port. IPv6 link-local addresses are
+    /// scoped to their interface.
+    pub fn socket_addr(&self, port: u16) -> SocketAddr {
+        match self.ip {
+            IpAddr::V6(ip) if is_link_local(&ip) => SocketAddr::V6(SocketAddrV6::new(ip, port, 0, self.index)),
+            ip => SocketAddr::new(ip, port),
+        }
+    }
+
+    /// Returns whether an IP address is on the same network as the address.
+    pub fn is_on_link(&self, ip: IpAddr) -> bool {
+        match (self.ip, ip) {
+            (IpAddr::V4(own), IpAddr::V4(other)) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(self.prefix.min(32))).unwrap_or(0);
+                u32::from(own) & mask == u32::from(other) & mask
+            },
+            (IpAddr::V6(own), IpAddr::V6(other)) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(self.prefix.min(128))).unwrap_or(0);
+                u128::from(own) & mask == u128::from(other) & mask
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Returns the addresses the IP transport is served and announced on for the configured IP address
+/// and interfaces.
+///
+/// An unspecified IPv6 address (`::`) selects all IPv4 and IPv6 addresses of the selected
+/// interfaces, an unspecified IPv4 address (`0.0.0.0`) only their IPv4 addresses. A specific IP
+/// address selects only itself, regardless of the interfaces.
+pub(crate) fn selected_addresses(ip: IpAddr, interfaces: &Interfaces) -> Vec<InterfaceAddress> {
+    select_addresses(&datalink::interfaces(), ip, interfaces)
+}
+
+/// Selects the addresses like `selected_addresses` from a given list of interfaces.
+fn select_addresses(
+    all_interfaces: &[datalink::NetworkInterface],
+    ip: IpAddr,
+    interfaces: &Interfaces,
+) -> Vec<InterfaceAddress> {
+    if !ip.is_unspecified() {
+        for interface in all_interfaces {
+            if let Some(network) = interface.ips.iter().find(|network| network.ip() == ip) {
+                return vec![InterfaceAddress {
+                    name: interface.name.clone(),
+                    index: interface.index,
+                    ip,
+                    prefix: network.prefix(),
+                }];
+            }
+        }
+        return vec![InterfaceAddress {
+            name: String::new(),
+            index: 0,
+            ip,
+            prefix: if ip.is_ipv4() { 32 } else { 128 },
+        }];
+    }
+
+    let mut addresses = Vec::new();
+    for interface in all_interfaces.iter().filter(|interface| interfaces.includes(interface)) {
+        for network in &interface.ips {
+            if ip.is_ipv4() && !network.is_ipv4() {
+                continue;
+            }
+            addresses.push(InterfaceAddress {
+                name: interface.name.clone(),
+                index: interface.index,
+                ip: network.ip(),
+                prefix: network.prefix(),
+            });
+        }
+    }
+    addresses
+}
+
+/// Returns whether an IPv6 address is a link-local unicast address (`fe80::/10`).
+pub(crate) fn is_link_local(ip: &Ipv6Addr) -> bool { (ip.segments()[0] & 0xffc0) == 0xfe80 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddrV4};
+
+    use pnet::ipnetwork::IpNetwork;
+
+    use super::*;
+
+    const IFF_UP: u32 = 0x1;
+    const IFF_LOOPBACK: u32 = 0x8;
+
+    fn address(ip: &str, prefix: u8, index: u32) -> InterfaceAddress {
+        InterfaceAddress {
+            name: String::new(),
+            index,
+            ip: ip.parse().unwrap(),
+            prefix,
+        }
+    }
+
+    fn interface(name: &str, index: u32, flags: u32, ips: &[&str]) -> datalink::NetworkInterface {
+        datalink::NetworkInterface {
+            name: name.into(),
+            description: String::new(),
+            index,
+            mac: None,
+            ips: ips.iter().map(|ip| ip.parse::<IpNetwork>().unwrap()).collect(),
+            flags,
+        }
+    }
+
+    fn test_interfaces() -> Vec<datalink::NetworkInterface> {
+        vec![
+            interface("lo", 1, IFF_UP | IFF_LOOPBACK, &["127.0.0.1/8", "::1/128"]),
+            interface("eth0", 2, IFF_UP, &["192.168.1.10/24", "fe80::1/64", "2001:db8::10/64"]),
+            interface("wlan0", 3, IFF_UP, &["10.0.0.5/8", "fe80::2/64"]),
+            interface("eth1", 4, 0, &["172.16.0.1/16"]),
+        ]
+    }
+
+    fn ips(addresses: &[InterfaceAddress]) -> Vec<(String, String)> {
+        addresses.iter().map(|a| (a.name.clone(), a.ip.to_string())).collect()
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(name, ip)| (name.to_string(), ip.to_string())).collect()
+    }
+
+    #[test]
+    fn ipv4_addresses_match_their_prefix() {
+        let own = address("192.168.1.10", 24, 2);
+        assert!(own.is_on_link("192.168.1.200".parse().unwrap()));
+        assert!(!own.is_on_link("192.168.2.10".parse().unwrap()));
+        assert!(!own.is_on_link("::ffff:192.168.1.200".parse().unwrap()));
+
+        assert!(address("10.1.2.3", 0, 2).is_on_link("172.16.0.1".parse().unwrap()));
+        assert!(address("10.1.2.3", 32, 2).is_on_link("10.1.2.3".parse().unwrap()));
+        assert!(!address("10.1.2.3", 32, 2).is_on_link("10.1.2.4".parse().unwrap()));
+
+        assert_eq!(
+            own.socket_addr(51826),
+            SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(192, 168, 1, 10), 51826))
+        );
+    }
+
+    #[test]
+    fn link_local_addresses_are_scoped_to_their_interface() {
+        let own = address("fe80::1", 64, 3);
+        assert!(own.is_on_link("fe80::abcd".parse().unwrap()));
+        assert!(!own.is_on_link("fe80:0:0:1::1".parse().unwrap()));
+        assert!(!own.is_on_link("192.168.1.1".parse().unwrap()));
+        assert_eq!(
+            own.socket_addr(51826),
+            SocketAddr::V6(SocketAddrV6::new("fe80::1".parse().unwrap(), 51826, 0, 3))
+        );
+
+        let global = address("2001:db8::10", 64, 3);
+        assert_eq!(
+            global.socket_addr(51826),
+            SocketAddr::V6(SocketAddrV6::new("2001:db8::10".parse().unwrap(), 51826, 0, 0))
+        );
+
+        assert!(is_link_local(&"fe80::1".parse().unwrap()));
+        assert!(is_link_local(&"febf::1".parse().unwrap()));
+        assert!(!is_link_local(&"fec0::1".parse().unwrap()));
+        assert!(!is_link_local(&"2001:db8::1".parse().unwrap()));
+        assert!(!is_link_local(&"::1".parse().unwrap()));
+    }
+
+    #[test]
+    fn all_interfaces_skip_loopback_and_down_interfaces() {
+        let addresses = select_addresses(&test_interfaces(), "::".parse().unwrap(), &Interfaces::All);
+        assert_eq!(
+            ips(&addresses),
+            pairs(&[
+                ("eth0", "192.168.1.10"),
+                ("eth0", "fe80::1"),
+                ("eth0", "2001:db8::10"),
+                ("wlan0", "10.0.0.5"),
+                ("wlan0", "fe80::2"),
+            ])
+        );
+        assert_eq!(addresses[1].index, 2);
+        assert_eq!(addresses[1].prefix, 64);
+    }
+
+    #[test]
+    fn named_interfaces_are_filtered() {
+        let interfaces = Interfaces::Named(vec!["wlan0".into(), "lo".into()]);
+        let addresses = select_addresses(&test_interfaces(), "::".parse().unwrap(), &interfaces);
+        assert_eq!(
+            ips(&addresses),
+            pairs(&[
+                ("lo", "127.0.0.1"),
+                ("lo", "::1"),
+                ("wlan0", "10.0.0.5"),
+                ("wlan0", "fe80::2"),
+            ])
+        );
+
+        let unknown = Interfaces::Named(vec!["eth9".into()]);
+        assert!(select_addresses(&test_interfaces(), "::".parse().unwrap(), &unknown).is_empty());
+    }
+
+    #[test]
+    fn unspecified_ipv4_selects_only_ipv4_addresses() {
+        let interfaces = Interfaces::Named(vec!["eth0".into()]);
+        let addresses = select_addresses(&test_interfaces(), "0.0.0.0".parse().unwrap(), &interfaces);
+        assert_eq!(ips(&addresses), pairs(&[("eth0", "192.168.1.10")]));
+    }
+
+    #[test]
+    fn specific_ip_selects_only_itself() {
+        // regardless of the selected interfaces
+        let interfaces = Interfaces::Named(vec!["wlan0".into()]);
+        let addresses = select_addresses(&test_interfaces(), "2001:db8::10".parse().unwrap(), &interfaces);
+        assert_eq!(addresses, vec![InterfaceAddress {
+            name: "eth0".into(),
+            index: 2,
+            ip: "2001:db8::10".parse().unwrap(),
+            prefix: 64,
+        }]);
+
+        let addresses = select_addresses(&test_interfaces(), "192.0.2.1".parse().unwrap(), &Interfaces::All);
+        assert_eq!(addresses, vec![address("192.0.2.1", 32, 0)]);
+        let addresses = select_addresses(&test_interfaces(), "2001:db8:1::1".parse().unwrap(), &Interfaces::All);
+        assert_eq!(addresses, vec![address("2001:db8:1::1", 128, 0)]);
+    }
+}
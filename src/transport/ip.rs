@@ -3,16 +3,27 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use log::error;
+
 use crate::{
     config::{Config, ConfigPtr},
-    db::{AccessoryList, AccessoryListMember, AccessoryListPtr, Database, DatabasePtr, FileStorage, Storage},
+    db::{
+        AccessoryList,
+        AccessoryListMember,
+        AccessoryListPtr,
+        Database,
+        DatabasePtr,
+        FileStorage,
+        MemoryStorage,
+        Storage,
+    },
     event::{EventEmitter, EventEmitterPtr, Event},
     pin,
     protocol::Device,
     transport::{
         bonjour::StatusFlag,
         http,
-        mdns::{Responder, ResponderPtr},
+        mdns::{AnnouncerPtr, Service, ServiceId},
         Transport,
     },
     Result,
@@ -22,11 +33,14 @@ use crate::{
 #[derive(Clone)]
 pub struct IpTransport<S: Storage> {
     config: ConfigPtr,
+    #[allow(dead_code)]
     storage: S,
     database: DatabasePtr,
     accessories: AccessoryList,
     event_emitter: EventEmitterPtr,
-    mdns_responder: ResponderPtr,
+    mdns_announcer: AnnouncerPtr,
+    mdns_service: Arc<Mutex<Option<ServiceId>>>,
+    local_addr: Arc<Mutex<Option<SocketAddr>>>,
 }
 
 impl IpTransport<FileStorage> {
@@ -72,10 +86,27 @@ impl IpTransport<FileStorage> {
     ///
     /// //ip_transport.start().unwrap();
     /// ```
-    pub fn new(mut config: Config) -> Result<IpTransport<FileStorage>> {
+    pub fn new(config: Config) -> Result<IpTransport<FileStorage>> {
         let storage = FileStorage::new(&config.storage_path)?;
         let database = Database::new_with_file_storage(&config.storage_path)?;
+        IpTransport::new_with_storage(config, storage, database)
+    }
+}
+
+impl IpTransport<MemoryStorage> {
+    /// Creates a new `IpTransport` that keeps its configuration and pairings in memory only.
+    ///
+    /// Combined with a `port` of `0` in the `Config`, this is useful to run an accessory
+    /// in-process, e.g. in tests.
+    pub fn new_in_memory(config: Config) -> Result<IpTransport<MemoryStorage>> {
+        let storage = MemoryStorage::new();
+        let database = Database::new(Box::new(storage.clone()));
+        IpTransport::new_with_storage(config, storage, database)
+    }
+}
 
+impl<S: Storage> IpTransport<S> {
+    fn new_with_storage(mut config: Config, storage: S, database: Database) -> Result<IpTransport<S>> {
         config.load_from(&storage)?;
         config.update_hash();
         config.save_to(&storage)?;
@@ -83,11 +114,7 @@ impl IpTransport<FileStorage> {
         let pin = pin::new(&config.pin)?;
         let device = Device::load_or_new(config.device_id.to_hex_string(), pin, &database)?;
         let event_emitter = Arc::new(Mutex::new(EventEmitter::new()));
-        let mdns_responder = Arc::new(Mutex::new(Responder::new(
-            &config.name,
-            config.port,
-            config.txt_records(),
-        )));
+        let mdns_announcer = config.mdns_backend.announcer()?;
 
         let ip_transport = IpTransport {
             config: Arc::new(Mutex::new(config)),
@@ -95,29 +122,56 @@ impl IpTransport<FileStorage> {
             database: Arc::new(Mutex::new(database)),
             accessories: AccessoryList::new(event_emitter.clone()),
             event_emitter,
-            mdns_responder,
+            mdns_announcer,
+            mdns_service: Arc::new(Mutex::new(None)),
+            local_addr: Arc::new(Mutex::new(None)),
         };
         device.save_to(&ip_transport.database)?;
 
         Ok(ip_transport)
     }
+
+    /// Returns the address the transport is listening on once it has been started. Useful to get
+    /// the actual port if the `Config` specifies port `0`.
+    pub fn local_addr(&self) -> Option<SocketAddr> { *self.local_addr.lock().expect("couldn't access local_addr") }
+
+    /// Returns a pointer to the `Database` holding the pairings of the accessory.
+    pub fn database(&self) -> &DatabasePtr { &self.database }
+
+    /// Returns the current HAP TXT records of the accessory. Useful to announce the accessory via
+    /// another announcer if the `Config` specifies `MdnsBackend::External`.
+    pub fn txt_records(&self) -> [String; 8] { self.config.lock().expect("couldn't access config").txt_records() }
 }
 
-impl Transport for IpTransport<FileStorage> {
+impl<S: Storage> Transport for IpTransport<S> {
     fn start(&mut self) -> Result<()> {
-        self.mdns_responder
-            .lock()
-            .expect("couldn't access event_emitter")
-            .start();
+        let (listeners, mdns_service) = {
+            let mut c = self.config.lock().expect("couldn't access config");
+            let listeners = http::server::bind(c.ip, c.port, &c.interfaces)?;
+            let local_addr = listeners[0].local_addr()?;
+            *self.local_addr.lock().expect("couldn't access local_addr") = Some(local_addr);
+            c.port = local_addr.port();
+
+            let mdns_service = self
+                .mdns_announcer
+                .lock()
+                .expect("couldn't access mDNS announcer")
+                .register(Service {
+                    name: c.name.clone(),
+                    host_name: format!("{}.local", c.device_id.to_hex_string().replace(':', "-")),
+                    port: c.port,
+                    txt_records: c.txt_records(),
+                    ip: c.ip,
+                    interfaces: c.interfaces.clone(),
+                })?;
+            *self.mdns_service.lock().expect("couldn't access mDNS service") = Some(mdns_service);
 
-        let (ip, port) = {
-            let c = self.config.lock().expect("couldn't access config");
-            (c.ip, c.port)
+            (listeners, mdns_service)
         };
 
         let config = self.config.clone();
         let database = self.database.clone();
-        let mdns_responder = self.mdns_responder.clone();
+        let mdns_announcer = self.mdns_announcer.clone();
         self.event_emitter
             .lock()
             .expect("couldn't access event_emitter")
@@ -127,11 +181,13 @@ impl Transport for IpTransport<FileStorage> {
                         if count > 0 {
                             let mut c = config.lock().expect("couldn't access config");
                             c.status_flag = StatusFlag::Zero;
-                            mdns_responder
+                            if let Err(e) = mdns_announcer
                                 .lock()
-                                .expect("couldn't access mDNS responder")
-                                .update_txt_records(c.txt_records())
-                                .expect("couldn't update mDNS TXT records");
+                                .expect("couldn't access mDNS announcer")
+                                .update_txt_records(mdns_service, c.txt_records())
+                            {
+                                error!("couldn't update mDNS TXT records: {}", e);
+                            }
                         }
                     }
                 },
@@ -140,11 +196,13 @@ impl Transport for IpTransport<FileStorage> {
                         if count == 0 {
                             let mut c = config.lock().expect("couldn't access config");
                             c.status_flag = StatusFlag::NotPaired;
-                            mdns_responder
+                            if let Err(e) = mdns_announcer
                                 .lock()
-                                .expect("couldn't access mDNS responder")
-                                .update_txt_records(c.txt_records())
-                                .expect("couldn't update mDNS TXT records");
+                                .expect("couldn't access mDNS announcer")
+                                .update_txt_records(mdns_service, c.txt_records())
+                            {
+                                error!("couldn't update mDNS TXT records: {}", e);
+                            }
                         }
                     }
                 },
@@ -152,20 +210,24 @@ impl Transport for IpTransport<FileStorage> {
             }));
 
         http::server::serve(
-            &SocketAddr::new(ip, port),
+            listeners,
             &self.config,
             &self.database,
             &self.accessories,
             &self.event_emitter,
+            &self.mdns_announcer,
+            mdns_service,
         )?;
         Ok(())
     }
 
     fn stop(&self) -> Result<()> {
-        self.mdns_responder
-            .lock()
-            .expect("couldn't access mDNS responder")
-            .stop()?;
+        if let Some(mdns_service) = self.mdns_service.lock().expect("couldn't access mDNS service").take() {
+            self.mdns_announcer
+                .lock()
+                .expect("couldn't access mDNS announcer")
+                .unregister(mdns_service)?;
+        }
         Ok(())
     }
 
@@ -0,0 +1,345 @@
+use std::{
+    net::IpAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use dbus::{
+    blocking::SyncConnection,
+    message::{MatchRule, Message},
+    Path,
+};
+use failure::err_msg;
+use log::{error, warn};
+
+use crate::{
+    transport::interfaces::{self, Interfaces},
+    Error,
+    Result,
+};
+
+use super::{Announcer, Service, ServiceId};
+
+const AVAHI: &str = "org.freedesktop.Avahi";
+const SERVER: &str = "org.freedesktop.Avahi.Server";
+const ENTRY_GROUP: &str = "org.freedesktop.Avahi.EntryGroup";
+const COLLISION_ERROR: &str = "org.freedesktop.Avahi.CollisionError";
+const SERVICE_TYPE: &str = "_hap._tcp";
+const IF_UNSPEC: i32 = -1;
+const PROTO_UNSPEC: i32 = -1;
+const PROTO_INET: i32 = 0;
+const PROTO_INET6: i32 = 1;
+const STATE_COLLISION: i32 = 3;
+const STATE_FAILURE: i32 = 4;
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An `Announcer` registering services with the Avahi daemon over D-Bus.
+///
+/// Avahi announces the services under its own host name along with the host's addresses and keeps
+/// track of address changes on its own. Services are registered on the interfaces selected by
+/// their `ip` and `interfaces`.
+///
+/// If the name of a service collides with another service on the network, the service is renamed
+/// to the alternative name Avahi suggests, e.g. `"Acme Lamp #2"`, and registered again.
+pub struct Avahi {
+    connection: Arc<SyncConnection>,
+    groups: Arc<Mutex<Vec<EntryGroup>>>,
+    running: Arc<AtomicBool>,
+    next_id: u64,
+}
+
+/// An Avahi entry group holding a registered service.
+struct EntryGroup {
+    id: ServiceId,
+    path: Path<'static>,
+    name: String,
+    port: u16,
+    txt_records: [String; 8],
+    interfaces: Vec<i32>,
+    protocol: i32,
+}
+
+impl Avahi {
+    /// Connects to the Avahi daemon on the system bus and starts watching the states of the
+    /// registered services for name collisions.
+    pub fn new() -> Result<Avahi> {
+        let connection = Arc::new(SyncConnection::new_system().map_err(dbus_error)?);
+        let groups: Arc<Mutex<Vec<EntryGroup>>> = Arc::new(Mutex::new(Vec::new()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let watched_groups = groups.clone();
+        connection
+            .add_match(
+                MatchRule::new_signal(ENTRY_GROUP, "StateChanged"),
+                move |(state, error): (i32, String), connection: &SyncConnection, msg: &Message| {
+                    on_state_changed(connection, &watched_groups, msg, state, &error);
+                    true
+                },
+            )
+            .map_err(dbus_error)?;
+
+        let watcher_connection = connection.clone();
+        let watcher_running = running.clone();
+        thread::Builder::new()
+            .name("avahi-watcher".into())
+            .spawn(move || {
+                while watcher_running.load(Ordering::Relaxed) {
+                    if let Err(e) = watcher_connection.process(Duration::from_secs(1)) {
+                        error!("couldn't receive Avahi D-Bus messages: {}", e);
+                        return;
+                    }
+                }
+            })?;
+
+        Ok(Avahi {
+            connection,
+            groups,
+            running,
+            next_id: 0,
+        })
+    }
+}
+
+impl Announcer for Avahi {
+    fn register(&mut self, service: Service) -> Result<ServiceId> {
+        let (path,): (Path<'static>,) = self
+            .connection
+            .with_proxy(AVAHI, "/", TIMEOUT)
+            .method_call(SERVER, "EntryGroupNew", ())
+            .map_err(dbus_error)?;
+        let mut group = EntryGroup {
+            id: ServiceId(self.next_id),
+            path,
+            name: service.name.clone(),
+            port: service.port,
+            txt_records: service.txt_records.clone(),
+            interfaces: interface_indices(service.ip, &service.interfaces),
+            protocol: protocol(service.ip),
+        };
+        self.next_id += 1;
+
+        // keeps collisions reported right after the commit from being handled before the group is stored
+        let mut groups = self.groups.lock().expect("couldn't access Avahi entry groups");
+        publish(&self.connection, &mut group)?;
+
+        let id = group.id;
+        groups.push(group);
+        Ok(id)
+    }
+
+    fn unregister(&mut self, id: ServiceId) -> Result<()> {
+        let mut groups = self.groups.lock().expect("couldn't access Avahi entry groups");
+        if let Some(position) = groups.iter().position(|g| g.id == id) {
+            let group = groups.remove(position);
+            self.connection
+                .with_proxy(AVAHI, group.path, TIMEOUT)
+                .method_call::<(), _, _, _>(ENTRY_GROUP, "Free", ())
+                .map_err(dbus_error)?;
+        }
+        Ok(())
+    }
+
+    fn update_txt_records(&mut self, id: ServiceId, txt_records: [String; 8]) -> Result<()> {
+        let mut groups = self.groups.lock().expect("couldn't access Avahi entry groups");
+        if let Some(group) = groups.iter_mut().find(|g| g.id == id) {
+            group.txt_records = txt_records;
+            let proxy = self.connection.with_proxy(AVAHI, group.path.clone(), TIMEOUT);
+            for interface in &group.interfaces {
+                proxy
+                    .method_call::<(), _, _, _>(ENTRY_GROUP, "UpdateServiceTxt", (
+                        *interface,
+                        group.protocol,
+                        0u32,
+                        group.name.as_str(),
+                        SERVICE_TYPE,
+                        "",
+                        txt(&group.txt_records),
+                    ))
+                    .map_err(dbus_error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Avahi {
+    fn drop(&mut self) { self.running.store(false, Ordering::Relaxed); }
+}
+
+/// Renames and re-registers the service of an entry group whose name collided with another
+/// service on the network.
+fn on_state_changed(
+    connection: &SyncConnection,
+    groups: &Mutex<Vec<EntryGroup>>,
+    msg: &Message,
+    state: i32,
+    error: &str,
+) {
+    let path = match msg.path() {
+        Some(path) => path,
+        None => return,
+    };
+    let mut groups = groups.lock().expect("couldn't access Avahi entry groups");
+    let group = match groups.iter_mut().find(|g| g.path == path) {
+        Some(group) => group,
+        None => return,
+    };
+    match state {
+        STATE_COLLISION => {
+            if let Err(e) = reset(connection, group).and_then(|_| publish(connection, group)) {
+                error!("couldn't re-register mDNS service after a name collision: {}", e);
+            }
+        },
+        STATE_FAILURE => error!("Avahi couldn't register mDNS service {}: {}", group.name, error),
+        _ => {},
+    }
+}
+
+/// Adds the service of an entry group on all of its interfaces and commits the group. If the name
+/// is already taken by another local service, the service is renamed first.
+fn publish(connection: &SyncConnection, group: &mut EntryGroup) -> Result<()> {
+    let proxy = connection.with_proxy(AVAHI, group.path.clone(), TIMEOUT);
+    'publish: loop {
+        for interface in &group.interfaces {
+            let res = proxy.method_call::<(), _, _, _>(ENTRY_GROUP, "AddService", (
+                *interface,
+                group.protocol,
+                0u32,
+                group.name.as_str(),
+                SERVICE_TYPE,
+                "",
+                "",
+                group.port,
+                txt(&group.txt_records),
+            ));
+            match res {
+                Err(ref e) if e.name() == Some(COLLISION_ERROR) => {
+                    reset(connection, group)?;
+                    continue 'publish;
+                },
+                res => res.map_err(dbus_error)?,
+            }
+        }
+        return proxy
+            .method_call::<(), _, _, _>(ENTRY_GROUP, "Commit", ())
+            .map_err(dbus_error);
+    }
+}
+
+/// Resets an entry group and renames its service to the alternative name Avahi suggests.
+fn reset(connection: &SyncConnection, group: &mut EntryGroup) -> Result<()> {
+    connection
+        .with_proxy(AVAHI, group.path.clone(), TIMEOUT)
+        .method_call::<(), _, _, _>(ENTRY_GROUP, "Reset", ())
+        .map_err(dbus_error)?;
+    let (name,): (String,) = connection
+        .with_proxy(AVAHI, "/", TIMEOUT)
+        .method_call(SERVER, "GetAlternativeServiceName", (group.name.as_str(),))
+        .map_err(dbus_error)?;
+    warn!("mDNS service name {} is taken, renaming it to {}", group.name, name);
+    group.name = name;
+    Ok(())
+}
+
+/// Returns the Avahi interface indices to register a service on.
+fn interface_indices(ip: IpAddr, interfaces: &Interfaces) -> Vec<i32> {
+    if ip.is_unspecified() && *interfaces == Interfaces::All {
+        return vec![IF_UNSPEC];
+    }
+    let mut indices = interfaces::selected_addresses(ip, interfaces)
+        .into_iter()
+        .filter(|a| a.index != 0)
+        .map(|a| a.index as i32)
+        .collect::<Vec<_>>();
+    indices.sort();
+    indices.dedup();
+    if indices.is_empty() {
+        indices.push(IF_UNSPEC);
+    }
+    indices
+}
+
+/// Returns the Avahi protocol to register a service for.
+fn protocol(ip: IpAddr) -> i32 {
+    match ip {
+        IpAddr::V6(ip) if ip.is_unspecified() => PROTO_UNSPEC,
+        IpAddr::V6(_) => PROTO_INET6,
+        IpAddr::V4(_) => PROTO_INET,
+    }
+}
+
+fn txt(txt_records: &[String; 8]) -> Vec<Vec<u8>> { txt_records.iter().map(|r| r.as_bytes().to_vec()).collect() }
+
+fn dbus_error(e: dbus::Error) -> Error { err_msg(format!("Avahi D-Bus error: {}", e)).into() }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn txt_records_are_converted_to_byte_strings() {
+        let txt_records = [
+            "c#=1".to_string(),
+            "ff=0".into(),
+            "id=AA:BB:CC:DD:EE:FF".into(),
+            "md=Acme Lamp".into(),
+            "pv=1.1".into(),
+            "s#=1".into(),
+            "sf=1".into(),
+            "ci=5".into(),
+        ];
+
+        let txt = txt(&txt_records);
+        assert_eq!(txt.len(), 8);
+        assert_eq!(txt[0], b"c#=1".to_vec());
+        assert_eq!(txt[3], b"md=Acme Lamp".to_vec());
+        assert_eq!(txt[7], b"ci=5".to_vec());
+    }
+
+    #[test]
+    fn protocol_follows_the_ip_address() {
+        assert_eq!(protocol(IpAddr::V6(Ipv6Addr::UNSPECIFIED)), PROTO_UNSPEC);
+        assert_eq!(protocol(IpAddr::V6(Ipv6Addr::LOCALHOST)), PROTO_INET6);
+        assert_eq!(protocol(IpAddr::V4(Ipv4Addr::UNSPECIFIED)), PROTO_INET);
+        assert_eq!(protocol(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2))), PROTO_INET);
+    }
+
+    #[test]
+    fn all_interfaces_are_registered_unspecified() {
+        assert_eq!(
+            interface_indices(IpAddr::V6(Ipv6Addr::UNSPECIFIED), &Interfaces::All),
+            vec![IF_UNSPEC]
+        );
+    }
+
+    #[test]
+    fn specific_addresses_are_registered_on_their_interface() {
+        let loopback = interfaces::selected_addresses(IpAddr::V4(Ipv4Addr::LOCALHOST), &Interfaces::All);
+        let indices = interface_indices(IpAddr::V4(Ipv4Addr::LOCALHOST), &Interfaces::All);
+        assert_eq!(indices, vec![loopback[0].index as i32]);
+        assert_ne!(indices, vec![IF_UNSPEC]);
+
+        let named = Interfaces::Named(vec![loopback[0].name.clone()]);
+        assert_eq!(interface_indices(IpAddr::V6(Ipv6Addr::UNSPECIFIED), &named), indices);
+    }
+
+    #[test]
+    fn unknown_interfaces_fall_back_to_unspecified() {
+        let interfaces = Interfaces::Named(vec!["does-not-exist0".into()]);
+        assert_eq!(
+            interface_indices(IpAddr::V6(Ipv6Addr::UNSPECIFIED), &interfaces),
+            vec![IF_UNSPEC]
+        );
+        assert_eq!(
+            interface_indices(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), &Interfaces::All),
+            vec![IF_UNSPEC]
+        );
+    }
+}
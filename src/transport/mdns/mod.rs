@@ -0,0 +1,145 @@
+use std::{
+    net::IpAddr,
+    sync::{Arc, Mutex},
+};
+
+use crate::{transport::interfaces::Interfaces, Result};
+
+#[cfg(feature = "avahi")]
+pub use self::avahi::Avahi;
+pub use self::responder::{Responder, ResponderPtr};
+
+#[cfg(feature = "avahi")]
+mod avahi;
+mod record;
+mod registration;
+mod responder;
+mod sockets;
+
+const SERVICE_TYPE: &str = "_hap._tcp.local";
+const SERVICE_TYPE_ENUMERATION: &str = "_services._dns-sd._udp.local";
+
+/// A HAP service announced via mDNS.
+#[derive(Clone, Debug)]
+pub struct Service {
+    /// Name of the service instance, i.e. the name of the Accessory.
+    pub name: String,
+    /// Host name the service is reachable at, e.g. `"Acme-Outlet.local"`.
+    pub host_name: String,
+    /// Port the service is served on.
+    pub port: u16,
+    /// HAP TXT records of the service.
+    pub txt_records: [String; 8],
+    /// IP address the service is served on. Selects the announced addresses along with
+    /// `interfaces`, like the `ip` of the `Config`.
+    pub ip: IpAddr,
+    /// Interfaces the service is announced on.
+    pub interfaces: Interfaces,
+}
+
+/// Identifies a `Service` registered on an `Announcer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceId(pub u64);
+
+/// `Announcer` is implemented by the mDNS backends announcing the Accessory to potential
+/// controllers.
+pub trait Announcer: Send {
+    /// Registers a service and starts announcing it.
+    fn register(&mut self, service: Service) -> Result<ServiceId>;
+    /// Stops announcing a registered service.
+    fn unregister(&mut self, id: ServiceId) -> Result<()>;
+    /// Updates the TXT records of a registered service, e.g. after the status flag changed.
+    fn update_txt_records(&mut self, id: ServiceId, txt_records: [String; 8]) -> Result<()>;
+    /// Called when the network addresses of a registered service changed. Backends that keep track
+    /// of the addresses on their own don't need to do anything.
+    fn update_addresses(&mut self, _id: ServiceId) -> Result<()> { Ok(()) }
+}
+
+/// Pointer to an `Announcer`.
+pub type AnnouncerPtr = Arc<Mutex<dyn Announcer>>;
+
+/// The mDNS backend used to announce the Accessory.
+#[derive(Clone, Default)]
+pub enum MdnsBackend {
+    /// The built-in `Responder`. Every transport starts its own.
+    #[default]
+    BuiltIn,
+    /// The Avahi daemon, accessed over D-Bus. Use this if Avahi already owns the mDNS port.
+    #[cfg(feature = "avahi")]
+    Avahi,
+    /// No announcement at all. The TXT records are exposed by the transport, so that another
+    /// announcer, e.g. a system-wide responder configured by other means, can announce the
+    /// Accessory.
+    External,
+    /// A given `Announcer`, e.g. a `Responder` or `External` shared by multiple transports in one
+    /// process.
+    Custom(AnnouncerPtr),
+}
+
+impl MdnsBackend {
+    /// Creates the `Announcer` of the backend.
+    pub(crate) fn announcer(&self) -> Result<AnnouncerPtr> {
+        Ok(match *self {
+            MdnsBackend::BuiltIn => Arc::new(Mutex::new(Responder::new())),
+            #[cfg(feature = "avahi")]
+            MdnsBackend::Avahi => Arc::new(Mutex::new(Avahi::new()?)),
+            MdnsBackend::External => Arc::new(Mutex::new(External::new())),
+            MdnsBackend::Custom(ref announcer) => announcer.clone(),
+        })
+    }
+}
+
+/// Listener of an `External` announcer, called with all registered services.
+pub type ExternalListener = Box<dyn FnMut(&[Service]) + Send>;
+
+/// An `Announcer` that doesn't announce anything itself, but keeps the registered services along
+/// with their current TXT records for another announcer.
+#[derive(Default)]
+pub struct External {
+    services: Vec<(ServiceId, Service)>,
+    next_id: u64,
+    listener: Option<ExternalListener>,
+}
+
+impl External {
+    /// Creates a new `External` announcer.
+    pub fn new() -> External { External::default() }
+
+    /// Sets a listener that is called with all registered services whenever a service is
+    /// registered, unregistered or its TXT records change.
+    pub fn set_listener(&mut self, listener: ExternalListener) { self.listener = Some(listener); }
+
+    /// Returns the registered services.
+    pub fn services(&self) -> Vec<Service> { self.services.iter().map(|(_, s)| s.clone()).collect() }
+
+    fn notify(&mut self) {
+        let services = self.services();
+        if let Some(ref mut listener) = self.listener {
+            listener(&services);
+        }
+    }
+}
+
+impl Announcer for External {
+    fn register(&mut self, service: Service) -> Result<ServiceId> {
+        let id = ServiceId(self.next_id);
+        self.next_id += 1;
+        self.services.push((id, service));
+        self.notify();
+        Ok(id)
+    }
+
+    fn unregister(&mut self, id: ServiceId) -> Result<()> {
+        self.services.retain(|(s_id, _)| *s_id != id);
+        self.notify();
+        Ok(())
+    }
+
+    fn update_txt_records(&mut self, id: ServiceId, txt_records: [String; 8]) -> Result<()> {
+        if let Some((_, service)) = self.services.iter_mut().find(|(s_id, _)| *s_id == id) {
+            service.txt_records = txt_records;
+        }
+        self.notify();
+        Ok(())
+    }
+}
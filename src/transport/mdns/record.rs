@@ -0,0 +1,326 @@
+use std::net::IpAddr;
+
+use dns_parser::QueryType;
+
+/// TTL of records referring to the host, e.g. SRV, A and AAAA records.
+pub(crate) const HOST_TTL: u32 = 120;
+/// TTL of all other records.
+pub(crate) const OTHER_TTL: u32 = 4500;
+
+const TYPE_A: u16 = 1;
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const TYPE_AAAA: u16 = 28;
+const TYPE_SRV: u16 = 33;
+const CLASS_IN: u16 = 1;
+/// Marks a record as unique, so that caches replace previously received records of the same name
+/// and type.
+const CACHE_FLUSH: u16 = 0x8000;
+
+/// Resource record data announced by the responder.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum RecordData {
+    Ptr(String),
+    Srv { port: u16, target: String },
+    Txt(Vec<String>),
+    Address(IpAddr),
+}
+
+/// A resource record announced by the responder.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Record {
+    pub name: String,
+    pub ttl: u32,
+    pub data: RecordData,
+}
+
+impl Record {
+    /// Creates a new `Record`.
+    pub fn new(name: &str, ttl: u32, data: RecordData) -> Record {
+        Record {
+            name: name.to_owned(),
+            ttl,
+            data,
+        }
+    }
+
+    /// Returns a copy of the record with a TTL of `0`, which removes it from caches.
+    pub fn goodbye(&self) -> Record {
+        Record {
+            ttl: 0,
+            ..self.clone()
+        }
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_name(&self.name, buf);
+        let (rr_type, unique) = match self.data {
+            RecordData::Ptr(_) => (TYPE_PTR, false),
+            RecordData::Srv { .. } => (TYPE_SRV, true),
+            RecordData::Txt(_) => (TYPE_TXT, true),
+            RecordData::Address(IpAddr::V4(_)) => (TYPE_A, true),
+            RecordData::Address(IpAddr::V6(_)) => (TYPE_AAAA, true),
+        };
+        let class = if unique { CLASS_IN | CACHE_FLUSH } else { CLASS_IN };
+        buf.extend_from_slice(&rr_type.to_be_bytes());
+        buf.extend_from_slice(&class.to_be_bytes());
+        buf.extend_from_slice(&self.ttl.to_be_bytes());
+
+        let mut rdata = Vec::new();
+        match self.data {
+            RecordData::Ptr(ref target) => encode_name(target, &mut rdata),
+            RecordData::Srv { port, ref target } => {
+                // priority and weight
+                rdata.extend_from_slice(&[0, 0, 0, 0]);
+                rdata.extend_from_slice(&port.to_be_bytes());
+                encode_name(target, &mut rdata);
+            },
+            RecordData::Txt(ref entries) => {
+                for entry in entries {
+                    let entry = &entry.as_bytes()[..entry.len().min(255)];
+                    rdata.push(entry.len() as u8);
+                    rdata.extend_from_slice(entry);
+                }
+                if entries.is_empty() {
+                    rdata.push(0);
+                }
+            },
+            RecordData::Address(IpAddr::V4(ip)) => rdata.extend_from_slice(&ip.octets()),
+            RecordData::Address(IpAddr::V6(ip)) => rdata.extend_from_slice(&ip.octets()),
+        }
+        buf.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        buf.extend_from_slice(&rdata);
+    }
+
+    /// Returns whether the record answers a question for the given name and type. Names are
+    /// compared label by label, so that unescaped names as reported by `dns_parser` match.
+    pub fn answers(&self, name: &str, qtype: QueryType) -> bool {
+        if !split_labels(&self.name).join(".").eq_ignore_ascii_case(name.trim_end_matches('.')) {
+            return false;
+        }
+        matches!(
+            (qtype, &self.data),
+            (QueryType::All, _)
+                | (QueryType::PTR, RecordData::Ptr(_))
+                | (QueryType::SRV, RecordData::Srv { .. })
+                | (QueryType::TXT, RecordData::Txt(_))
+                | (QueryType::A, RecordData::Address(IpAddr::V4(_)))
+                | (QueryType::AAAA, RecordData::Address(IpAddr::V6(_)))
+        )
+    }
+}
+
+/// Encodes an mDNS response message with the given answers and additional records.
+pub(crate) fn encode_response(id: u16, answers: &[Record], additional: &[Record]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(512);
+    buf.extend_from_slice(&id.to_be_bytes());
+    // response, authoritative answer
+    buf.extend_from_slice(&0x8400u16.to_be_bytes());
+    // questions
+    buf.extend_from_slice(&0u16.to_be_bytes());
+    buf.extend_from_slice(&(answers.len() as u16).to_be_bytes());
+    // authority records
+    buf.extend_from_slice(&0u16.to_be_bytes());
+    buf.extend_from_slice(&(additional.len() as u16).to_be_bytes());
+    for record in answers.iter().chain(additional) {
+        record.encode(&mut buf);
+    }
+    buf
+}
+
+/// Encodes a domain name without compression. Dots separate labels, labels are cut to 63 Bytes.
+fn encode_name(name: &str, buf: &mut Vec<u8>) {
+    for label in split_labels(name) {
+        let label = &label.as_bytes()[..label.len().min(63)];
+        buf.push(label.len() as u8);
+        buf.extend_from_slice(label);
+    }
+    buf.push(0);
+}
+
+/// Splits a domain name into its labels. The first label of a service instance name may contain
+/// dots, which are escaped as `\.`.
+fn split_labels(name: &str) -> Vec<String> {
+    let mut labels = Vec::new();
+    let mut label = String::new();
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    label.push(escaped);
+                }
+            },
+            '.' => {
+                if !label.is_empty() {
+                    labels.push(label);
+                }
+                label = String::new();
+            },
+            c => label.push(c),
+        }
+    }
+    if !label.is_empty() {
+        labels.push(label);
+    }
+    labels
+}
+
+/// Escapes a service instance name for use as the first label of a domain name.
+pub(crate) fn escape_label(label: &str) -> String { label.replace('\\', "\\\\").replace('.', "\\.") }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use dns_parser::{Class, Packet, RData};
+
+    use super::*;
+
+    const INSTANCE: &str = "Acme\\.Lamp._hap._tcp.local";
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new("_hap._tcp.local", OTHER_TTL, RecordData::Ptr(INSTANCE.into())),
+            Record::new(INSTANCE, HOST_TTL, RecordData::Srv {
+                port: 51826,
+                target: "Acme-Lamp.local".into(),
+            }),
+            Record::new(INSTANCE, OTHER_TTL, RecordData::Txt(vec!["c#=1".into(), "sf=1".into()])),
+            Record::new("Acme-Lamp.local", HOST_TTL, RecordData::Address(Ipv4Addr::new(192, 168, 1, 2).into())),
+            Record::new("Acme-Lamp.local", HOST_TTL, RecordData::Address(Ipv6Addr::LOCALHOST.into())),
+        ]
+    }
+
+    #[test]
+    fn labels_are_split_at_unescaped_dots() {
+        assert_eq!(split_labels("_hap._tcp.local"), vec!["_hap", "_tcp", "local"]);
+        assert_eq!(split_labels("_hap._tcp.local."), vec!["_hap", "_tcp", "local"]);
+        assert_eq!(split_labels(INSTANCE), vec!["Acme.Lamp", "_hap", "_tcp", "local"]);
+        assert_eq!(split_labels("Back\\\\slash.local"), vec!["Back\\slash", "local"]);
+        assert!(split_labels("").is_empty());
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        assert_eq!(escape_label("Acme Lamp"), "Acme Lamp");
+        assert_eq!(escape_label("Acme.Lamp"), "Acme\\.Lamp");
+        assert_eq!(escape_label("Back\\slash"), "Back\\\\slash");
+        for label in &["Acme.Lamp", "Back\\slash", "a.b\\.c"] {
+            assert_eq!(split_labels(&format!("{}.local", escape_label(label)))[0], *label);
+        }
+    }
+
+    #[test]
+    fn names_are_encoded_as_labels() {
+        let mut buf = Vec::new();
+        encode_name(INSTANCE, &mut buf);
+        assert_eq!(buf, b"\x09Acme.Lamp\x04_hap\x04_tcp\x05local\x00".to_vec());
+
+        let mut buf = Vec::new();
+        encode_name(&format!("{}.local", "a".repeat(70)), &mut buf);
+        assert_eq!(buf[0], 63);
+        assert_eq!(&buf[64..], b"\x05local\x00");
+    }
+
+    #[test]
+    fn records_are_encoded() {
+        let mut buf = Vec::new();
+        Record::new("a.local", HOST_TTL, RecordData::Address(Ipv4Addr::new(10, 0, 0, 1).into())).encode(&mut buf);
+        assert_eq!(buf, vec![
+            1, b'a', 5, b'l', b'o', b'c', b'a', b'l', 0, // name
+            0, 1, // A
+            0x80, 1, // cache flush, IN
+            0, 0, 0, 120, // TTL
+            0, 4, // length
+            10, 0, 0, 1,
+        ]);
+
+        let mut buf = Vec::new();
+        Record::new("a.local", OTHER_TTL, RecordData::Ptr("b.local".into())).encode(&mut buf);
+        assert_eq!(&buf[9..13], &[0, 12, 0, 1]);
+
+        let mut buf = Vec::new();
+        Record::new("a.local", OTHER_TTL, RecordData::Txt(vec![])).encode(&mut buf);
+        assert_eq!(&buf[buf.len() - 3..], &[0, 1, 0]);
+    }
+
+    #[test]
+    fn responses_are_parsed_by_dns_parser() {
+        let records = records();
+        let response = encode_response(0x1234, &records[..1], &records[1..]);
+        let packet = Packet::parse(&response).unwrap();
+
+        assert_eq!(packet.header.id, 0x1234);
+        assert!(!packet.header.query);
+        assert!(packet.header.authoritative);
+        assert!(packet.questions.is_empty());
+        assert_eq!(packet.answers.len(), 1);
+        assert_eq!(packet.additional.len(), 4);
+
+        let ptr = &packet.answers[0];
+        assert_eq!(ptr.name.to_string(), "_hap._tcp.local");
+        assert!(!ptr.multicast_unique);
+        assert_eq!(ptr.cls, Class::IN);
+        assert_eq!(ptr.ttl, OTHER_TTL);
+        match ptr.data {
+            RData::PTR(ref ptr) => assert_eq!(ptr.0.to_string(), "Acme.Lamp._hap._tcp.local"),
+            ref data => panic!("unexpected record data: {:?}", data),
+        }
+
+        for record in &packet.additional {
+            assert!(record.multicast_unique);
+        }
+        match packet.additional[0].data {
+            RData::SRV(ref srv) => {
+                assert_eq!(srv.port, 51826);
+                assert_eq!(srv.target.to_string(), "Acme-Lamp.local");
+            },
+            ref data => panic!("unexpected record data: {:?}", data),
+        }
+        match packet.additional[1].data {
+            RData::TXT(ref txt) => assert_eq!(txt.iter().collect::<Vec<_>>(), vec![&b"c#=1"[..], &b"sf=1"[..]]),
+            ref data => panic!("unexpected record data: {:?}", data),
+        }
+        match packet.additional[2].data {
+            RData::A(ref a) => assert_eq!(a.0, Ipv4Addr::new(192, 168, 1, 2)),
+            ref data => panic!("unexpected record data: {:?}", data),
+        }
+        match packet.additional[3].data {
+            RData::AAAA(ref aaaa) => assert_eq!(aaaa.0, Ipv6Addr::LOCALHOST),
+            ref data => panic!("unexpected record data: {:?}", data),
+        }
+    }
+
+    #[test]
+    fn goodbyes_have_a_ttl_of_zero() {
+        let packet_bytes = encode_response(0, &records().iter().map(Record::goodbye).collect::<Vec<_>>(), &[]);
+        let packet = Packet::parse(&packet_bytes).unwrap();
+        assert_eq!(packet.answers.len(), 5);
+        assert!(packet.answers.iter().all(|r| r.ttl == 0));
+    }
+
+    #[test]
+    fn records_answer_questions_of_their_name_and_type() {
+        let records = records();
+        let (ptr, srv, txt, a, aaaa) = (&records[0], &records[1], &records[2], &records[3], &records[4]);
+
+        assert!(ptr.answers("_hap._tcp.local", QueryType::PTR));
+        assert!(ptr.answers("_HAP._tcp.local.", QueryType::PTR));
+        assert!(ptr.answers("_hap._tcp.local", QueryType::All));
+        assert!(!ptr.answers("_hap._tcp.local", QueryType::SRV));
+        assert!(!ptr.answers("_airplay._tcp.local", QueryType::PTR));
+
+        // `dns_parser` reports the escaped instance name with a plain dot
+        assert!(srv.answers("Acme.Lamp._hap._tcp.local", QueryType::SRV));
+        assert!(txt.answers("acme.lamp._hap._tcp.local", QueryType::TXT));
+        assert!(!srv.answers("Acme.Lamp._hap._tcp.local", QueryType::TXT));
+        assert!(!txt.answers("Lamp._hap._tcp.local", QueryType::TXT));
+
+        assert!(a.answers("Acme-Lamp.local", QueryType::A));
+        assert!(!a.answers("Acme-Lamp.local", QueryType::AAAA));
+        assert!(aaaa.answers("Acme-Lamp.local", QueryType::AAAA));
+        assert!(!aaaa.answers("Acme-Lamp.local", QueryType::A));
+        assert!(aaaa.answers("Acme-Lamp.local", QueryType::All));
+    }
+}
@@ -0,0 +1,351 @@
+use std::net::{IpAddr, SocketAddr};
+
+use dns_parser::{QueryClass, Question};
+
+use crate::transport::interfaces::{self, InterfaceAddress, Interfaces};
+
+use super::{
+    record::{self, Record, RecordData, HOST_TTL, OTHER_TTL},
+    Service,
+    ServiceId,
+    SERVICE_TYPE,
+    SERVICE_TYPE_ENUMERATION,
+};
+
+/// Number of unsolicited announcements sent after a service is registered or changed, one second
+/// apart.
+const ANNOUNCEMENTS: usize = 2;
+
+/// A service registered on a running `Responder` along with its announcement state.
+pub(crate) struct Registration {
+    pub id: ServiceId,
+    instance: String,
+    host_name: String,
+    port: u16,
+    txt_records: Vec<String>,
+    ip: IpAddr,
+    interfaces: Interfaces,
+    addresses: Vec<InterfaceAddress>,
+    /// Number of pending unsolicited announcements.
+    announcements: usize,
+    /// Whether the pending announcements only carry the TXT record.
+    txt_only: bool,
+}
+
+/// Records answering a query.
+#[derive(Default)]
+pub(crate) struct Answer {
+    pub answers: Vec<Record>,
+    pub additional: Vec<Record>,
+    pub prefer_unicast: bool,
+}
+
+impl Registration {
+    /// Creates a new `Registration` of a service on its currently selected addresses.
+    pub fn new(id: ServiceId, service: Service) -> Registration {
+        let addresses = interfaces::selected_addresses(service.ip, &service.interfaces);
+        Registration {
+            id,
+            instance: format!("{}.{}", record::escape_label(&service.name), SERVICE_TYPE),
+            host_name: service.host_name,
+            port: service.port,
+            txt_records: service.txt_records.to_vec(),
+            ip: service.ip,
+            interfaces: service.interfaces,
+            addresses,
+            announcements: ANNOUNCEMENTS,
+            txt_only: false,
+        }
+    }
+
+    pub fn addresses(&self) -> &[InterfaceAddress] { &self.addresses }
+
+    /// Returns whether unsolicited announcements are pending.
+    pub fn is_announcing(&self) -> bool { self.announcements > 0 }
+
+    /// Updates the TXT records in place and schedules their announcement.
+    pub fn set_txt_records(&mut self, txt_records: Vec<String>) {
+        self.txt_records = txt_records;
+        if self.announcements == 0 {
+            self.txt_only = true;
+        }
+        self.announcements = ANNOUNCEMENTS;
+    }
+
+    /// Updates the addresses to the currently selected ones, schedules a full announcement and
+    /// returns the goodbye messages for the vanished addresses.
+    pub fn update_addresses(&mut self) -> Vec<(InterfaceAddress, Vec<Record>)> {
+        let addresses = interfaces::selected_addresses(self.ip, &self.interfaces);
+        let vanished = self
+            .addresses
+            .iter()
+            .filter(|a| !addresses.contains(a))
+            .cloned()
+            .collect::<Vec<_>>();
+        self.addresses = addresses;
+        self.announcements = ANNOUNCEMENTS;
+        self.txt_only = false;
+
+        per_interface(&vanished)
+            .into_iter()
+            .map(|address| {
+                let records = self
+                    .address_records(vanished.iter().filter(|a| a.index == address.index))
+                    .iter()
+                    .map(Record::goodbye)
+                    .collect();
+                (address.clone(), records)
+            })
+            .collect()
+    }
+
+    /// Returns the next pending unsolicited announcement, to be multicast on every interface.
+    pub fn next_announcement(&mut self) -> Vec<(InterfaceAddress, Vec<Record>)> {
+        if self.announcements == 0 {
+            return vec![];
+        }
+        self.announcements -= 1;
+        let announcement = if self.txt_only {
+            let txt = self.txt_record();
+            per_interface(&self.addresses)
+                .into_iter()
+                .map(|address| (address.clone(), vec![txt.clone()]))
+                .collect()
+        } else {
+            self.records_per_interface(false)
+        };
+        if self.announcements == 0 {
+            self.txt_only = false;
+        }
+        announcement
+    }
+
+    /// Returns the messages removing all records of the service from caches.
+    pub fn goodbye(&self) -> Vec<(InterfaceAddress, Vec<Record>)> { self.records_per_interface(true) }
+
+    /// Answers the questions of a query received from the given source address.
+    pub fn answer(&self, questions: &[Question], src: &SocketAddr) -> Answer {
+        let mut records = self.service_records();
+        records.push(Record::new(
+            SERVICE_TYPE_ENUMERATION,
+            OTHER_TTL,
+            RecordData::Ptr(SERVICE_TYPE.into()),
+        ));
+        records.extend(self.address_records(addresses_for(&self.addresses, src)));
+
+        let mut answer = Answer::default();
+        for question in questions {
+            if question.qclass != QueryClass::IN && question.qclass != QueryClass::Any {
+                continue;
+            }
+            let qname = question.qname.to_string();
+            for record in &records {
+                if record.answers(&qname, question.qtype) && !answer.answers.contains(record) {
+                    answer.answers.push(record.clone());
+                    answer.prefer_unicast |= question.prefer_unicast;
+                }
+            }
+        }
+
+        // PTR answers imply the SRV, TXT and address records, SRV answers the address records
+        for record in &records {
+            let implied = answer.answers.iter().any(|a| match a.data {
+                RecordData::Ptr(ref target) => {
+                    target == &self.instance && (record.name == self.instance || record.name == self.host_name)
+                },
+                RecordData::Srv { .. } => record.name == self.host_name,
+                _ => false,
+            });
+            if implied && !answer.answers.contains(record) && !answer.additional.contains(record) {
+                answer.additional.push(record.clone());
+            }
+        }
+
+        answer
+    }
+
+    fn txt_record(&self) -> Record {
+        Record::new(&self.instance, OTHER_TTL, RecordData::Txt(self.txt_records.clone()))
+    }
+
+    /// Returns the service records, i.e. the PTR, SRV and TXT records.
+    fn service_records(&self) -> Vec<Record> {
+        vec![
+            Record::new(SERVICE_TYPE, OTHER_TTL, RecordData::Ptr(self.instance.clone())),
+            Record::new(&self.instance, HOST_TTL, RecordData::Srv {
+                port: self.port,
+                target: self.host_name.clone(),
+            }),
+            self.txt_record(),
+        ]
+    }
+
+    /// Returns the A and AAAA records of the given addresses.
+    fn address_records<'a, I: IntoIterator<Item = &'a InterfaceAddress>>(&self, addresses: I) -> Vec<Record> {
+        addresses
+            .into_iter()
+            .map(|a| Record::new(&self.host_name, HOST_TTL, RecordData::Address(a.ip)))
+            .collect()
+    }
+
+    /// Returns all records for every interface, carrying only the addresses of that interface.
+    fn records_per_interface(&self, goodbye: bool) -> Vec<(InterfaceAddress, Vec<Record>)> {
+        per_interface(&self.addresses)
+            .into_iter()
+            .map(|address| {
+                let mut records = self.service_records();
+                records.extend(self.address_records(self.addresses.iter().filter(|a| a.index == address.index)));
+                if goodbye {
+                    records = records.iter().map(Record::goodbye).collect();
+                }
+                (address.clone(), records)
+            })
+            .collect()
+    }
+}
+
+/// Returns one address per interface and address family, i.e. the addresses to multicast a
+/// message for all of the given addresses on.
+fn per_interface(addresses: &[InterfaceAddress]) -> Vec<&InterfaceAddress> {
+    let mut selected: Vec<&InterfaceAddress> = Vec::new();
+    for address in addresses {
+        if !selected
+            .iter()
+            .any(|a| a.index == address.index && a.ip.is_ipv4() == address.ip.is_ipv4())
+        {
+            selected.push(address);
+        }
+    }
+    selected
+}
+
+/// Returns the addresses on the interfaces the given source address is reachable on. Falls back to
+/// all addresses if the source isn't on the link of any address.
+pub(crate) fn addresses_for<'a>(addresses: &'a [InterfaceAddress], src: &SocketAddr) -> Vec<&'a InterfaceAddress> {
+    let indices = addresses
+        .iter()
+        .filter(|a| match *src {
+            SocketAddr::V6(ref src) if interfaces::is_link_local(src.ip()) => a.index == src.scope_id(),
+            _ => a.is_on_link(src.ip()),
+        })
+        .map(|a| a.index)
+        .collect::<Vec<_>>();
+    if indices.is_empty() {
+        return addresses.iter().collect();
+    }
+    addresses.iter().filter(|a| indices.contains(&a.index)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use dns_parser::{Builder, Packet, QueryType};
+
+    use super::*;
+
+    fn registration() -> Registration {
+        let address = |index, ip: IpAddr, prefix| InterfaceAddress {
+            name: format!("eth{}", index),
+            index,
+            ip,
+            prefix,
+        };
+        Registration {
+            id: ServiceId(0),
+            instance: format!("{}.{}", record::escape_label("Acme.Lamp"), SERVICE_TYPE),
+            host_name: "Acme-Lamp.local".into(),
+            port: 51826,
+            txt_records: vec!["c#=1".into(), "sf=1".into()],
+            ip: Ipv4Addr::UNSPECIFIED.into(),
+            interfaces: Interfaces::All,
+            addresses: vec![
+                address(1, Ipv4Addr::new(192, 168, 1, 2).into(), 24),
+                address(1, "fe80::1".parse::<Ipv6Addr>().unwrap().into(), 64),
+                address(2, Ipv4Addr::new(10, 0, 0, 2).into(), 8),
+            ],
+            announcements: ANNOUNCEMENTS,
+            txt_only: false,
+        }
+    }
+
+    fn answer_query(questions: &[(&str, QueryType)], src: &str) -> Answer {
+        let mut builder = Builder::new_query(0, false);
+        for &(qname, qtype) in questions {
+            builder.add_question(qname, false, qtype, QueryClass::IN);
+        }
+        let query = builder.build().unwrap();
+        let packet = Packet::parse(&query).unwrap();
+        registration().answer(&packet.questions, &src.parse().unwrap())
+    }
+
+    #[test]
+    fn ptr_questions_imply_the_service_and_address_records() {
+        let answer = answer_query(&[("_hap._tcp.local", QueryType::PTR)], "192.168.1.10:5353");
+
+        assert_eq!(answer.answers, vec![Record::new(
+            "_hap._tcp.local",
+            OTHER_TTL,
+            RecordData::Ptr("Acme\\.Lamp._hap._tcp.local".into())
+        )]);
+        let additional = answer.additional.iter().map(|r| r.data.clone()).collect::<Vec<_>>();
+        assert_eq!(additional, vec![
+            RecordData::Srv {
+                port: 51826,
+                target: "Acme-Lamp.local".into(),
+            },
+            RecordData::Txt(vec!["c#=1".into(), "sf=1".into()]),
+            RecordData::Address(Ipv4Addr::new(192, 168, 1, 2).into()),
+            RecordData::Address("fe80::1".parse::<Ipv6Addr>().unwrap().into()),
+        ]);
+    }
+
+    #[test]
+    fn escaped_instance_names_are_answered() {
+        let answer = answer_query(
+            &[
+                ("Acme.Lamp._hap._tcp.local", QueryType::SRV),
+                ("Acme.Lamp._hap._tcp.local", QueryType::TXT),
+            ],
+            "10.1.2.3:5353",
+        );
+
+        assert_eq!(answer.answers.len(), 2);
+        assert!(answer.answers.iter().all(|r| r.name == "Acme\\.Lamp._hap._tcp.local"));
+        // the SRV answer implies the addresses on the interface of the source only
+        let additional = answer.additional.iter().map(|r| r.data.clone()).collect::<Vec<_>>();
+        assert_eq!(additional, vec![RecordData::Address(Ipv4Addr::new(10, 0, 0, 2).into())]);
+    }
+
+    #[test]
+    fn address_questions_are_answered_by_family() {
+        let answer = answer_query(&[("acme-lamp.local", QueryType::A)], "192.168.1.10:5353");
+        assert_eq!(answer.answers.len(), 1);
+        assert_eq!(answer.answers[0].data, RecordData::Address(Ipv4Addr::new(192, 168, 1, 2).into()));
+        assert!(answer.additional.is_empty());
+
+        let answer = answer_query(&[("Acme-Lamp.local", QueryType::AAAA)], "192.168.1.10:5353");
+        assert_eq!(answer.answers.len(), 1);
+        assert_eq!(
+            answer.answers[0].data,
+            RecordData::Address("fe80::1".parse::<Ipv6Addr>().unwrap().into())
+        );
+    }
+
+    #[test]
+    fn unrelated_questions_are_ignored() {
+        let answer = answer_query(
+            &[("_airplay._tcp.local", QueryType::PTR), ("Other.local", QueryType::A)],
+            "192.168.1.10:5353",
+        );
+        assert!(answer.answers.is_empty());
+        assert!(answer.additional.is_empty());
+    }
+
+    #[test]
+    fn service_types_are_enumerated() {
+        let answer = answer_query(&[("_services._dns-sd._udp.local", QueryType::PTR)], "192.168.1.10:5353");
+        assert_eq!(answer.answers.len(), 1);
+        assert_eq!(answer.answers[0].data, RecordData::Ptr(SERVICE_TYPE.into()));
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use dns_parser::Packet;
+use futures::{sync::mpsc, Async, Future, Poll, Stream};
+use log::{debug, error};
+use tokio::{runtime::current_thread, timer::Delay};
+
+use crate::{transport::interfaces::InterfaceAddress, Error, Result};
+
+use super::{
+    record::{self, Record},
+    registration::{self, Registration},
+    sockets::{Sockets, MDNS_PORT},
+    Announcer,
+    Service,
+    ServiceId,
+};
+
+/// The built-in mDNS Responder. Used to announce the Accessory's name and HAP TXT records to
+/// potential controllers.
+///
+/// The Responder runs on a separate thread once the first service is registered and announces any
+/// number of services, e.g. those of multiple transports in one process. Along with a service, the
+/// Responder announces A and AAAA records of the addresses the service is served on. Announcements
+/// and responses are sent on every selected interface and only carry the addresses of that
+/// interface.
+///
+/// Updated TXT records are announced on the existing registration. When the addresses of a service
+/// change, the vanished addresses are removed from caches and the service is re-announced.
+///
+/// The Responder doesn't probe for the uniqueness of its names as described in RFC 6762, section
+/// 8.1, but starts announcing right away. Conflicts with the instance or host name of another
+/// device on the network are neither detected nor resolved, so these names need to be unique,
+/// e.g. by deriving the host name from the device ID as `IpTransport` does. Use the Avahi backend
+/// if conflicts need to be handled.
+pub struct Responder {
+    commands: Option<mpsc::UnboundedSender<Command>>,
+    thread: Option<thread::JoinHandle<()>>,
+    next_id: u64,
+}
+
+/// Commands sent to the thread of a running `Responder`.
+enum Command {
+    Register(Registration),
+    Unregister(ServiceId),
+    UpdateTxtRecords(ServiceId, Vec<String>),
+    UpdateAddresses(ServiceId),
+    Stop,
+}
+
+impl Responder {
+    /// Creates a new mDNS Responder.
+    pub fn new() -> Responder {
+        Responder {
+            commands: None,
+            thread: None,
+            next_id: 0,
+        }
+    }
+
+    /// Removes the records of all registered services from caches and stops the Responder. Returns
+    /// once the Responder's thread has finished.
+    pub fn stop(&mut self) -> Result<()> {
+        if let Some(commands) = self.commands.take() {
+            // the thread might have stopped on its own already
+            let _ = commands.unbounded_send(Command::Stop);
+        }
+        if let Some(thread) = self.thread.take() {
+            thread
+                .join()
+                .map_err(|_| Error::from_str("mDNS responder thread panicked"))?;
+        }
+        Ok(())
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let (sender, receiver) = mpsc::unbounded();
+        let thread = thread::Builder::new()
+            .name("mdns-responder".into())
+            .spawn(move || match current_thread::Runtime::new() {
+                Ok(mut runtime) => {
+                    let _ = runtime.block_on(Task::new(receiver));
+                },
+                Err(e) => error!("couldn't start mDNS responder: {}", e),
+            })?;
+        self.commands = Some(sender);
+        self.thread = Some(thread);
+        Ok(())
+    }
+
+    fn send(&self, command: Command) -> Result<()> {
+        self.commands
+            .as_ref()
+            .ok_or_else(|| Error::from_str("mDNS responder isn't running"))?
+            .unbounded_send(command)
+            .map_err(|_| Error::from_str("mDNS responder stopped"))
+    }
+}
+
+impl Announcer for Responder {
+    /// Registers a service and starts announcing it. Starts the Responder if it isn't running.
+    fn register(&mut self, service: Service) -> Result<ServiceId> {
+        if self.commands.is_none() {
+            self.start()?;
+        }
+        let id = ServiceId(self.next_id);
+        self.next_id += 1;
+        self.send(Command::Register(Registration::new(id, service)))?;
+        Ok(id)
+    }
+
+    fn unregister(&mut self, id: ServiceId) -> Result<()> { self.send(Command::Unregister(id)) }
+
+    /// Updates the TXT records of a registered service in place and announces them.
+    fn update_txt_records(&mut self, id: ServiceId, txt_records: [String; 8]) -> Result<()> {
+        self.send(Command::UpdateTxtRecords(id, txt_records.to_vec()))
+    }
+
+    /// Removes the vanished addresses of a registered service from caches and re-announces it on its
+    /// current addresses.
+    fn update_addresses(&mut self, id: ServiceId) -> Result<()> { self.send(Command::UpdateAddresses(id)) }
+}
+
+impl Default for Responder {
+    fn default() -> Responder { Responder::new() }
+}
+
+impl Drop for Responder {
+    fn drop(&mut self) {
+        if let Err(e) = self.stop() {
+            error!("{}", e);
+        }
+    }
+}
+
+/// Pointer to a `Responder`.
+pub type ResponderPtr = Arc<Mutex<Responder>>;
+
+/// The future running on the thread of a `Responder`. Waits for commands, queries and pending
+/// announcements and resolves once the Responder is stopped.
+struct Task {
+    commands: mpsc::UnboundedReceiver<Command>,
+    registrations: Vec<Registration>,
+    /// The addresses of all registrations, which the sockets are bound for.
+    addresses: Vec<InterfaceAddress>,
+    sockets: Option<Sockets>,
+    announcement_timer: Option<Delay>,
+    buf: Vec<u8>,
+}
+
+impl Task {
+    fn new(commands: mpsc::UnboundedReceiver<Command>) -> Task {
+        Task {
+            commands,
+            registrations: Vec::new(),
+            addresses: Vec::new(),
+            sockets: None,
+            announcement_timer: None,
+            buf: vec![0; 9000],
+        }
+    }
+
+    /// Handles a command and returns whether the Responder keeps running.
+    fn handle(&mut self, command: Command) -> bool {
+        match command {
+            Command::Register(registration) => {
+                self.registrations.push(registration);
+                self.rebind();
+            },
+            Command::Unregister(id) => {
+                if let Some(position) = self.registrations.iter().position(|r| r.id == id) {
+                    let registration = self.registrations.remove(position);
+                    self.multicast(registration.goodbye());
+                    self.rebind();
+                }
+            },
+            Command::UpdateTxtRecords(id, txt_records) => {
+                if let Some(registration) = self.registrations.iter_mut().find(|r| r.id == id) {
+                    registration.set_txt_records(txt_records);
+                }
+            },
+            Command::UpdateAddresses(id) => {
+                let goodbye = match self.registrations.iter_mut().find(|r| r.id == id) {
+                    Some(registration) => registration.update_addresses(),
+                    None => return true,
+                };
+                debug!("Network addresses changed, re-announcing mDNS service");
+                self.multicast(goodbye);
+                self.rebind();
+            },
+            Command::Stop => {
+                self.shutdown();
+                return false;
+            },
+        }
+        if self.announcement_timer.is_none() && self.registrations.iter().any(Registration::is_announcing) {
+            self.announcement_timer = Some(Delay::new(Instant::now()));
+        }
+        true
+    }
+
+    fn shutdown(&mut self) {
+        let goodbye = self
+            .registrations
+            .iter()
+            .flat_map(Registration::goodbye)
+            .collect::<Vec<_>>();
+        self.multicast(goodbye);
+        self.registrations.clear();
+    }
+
+    /// Binds the sockets for the addresses of all registrations if they changed.
+    fn rebind(&mut self) {
+        let mut addresses: Vec<InterfaceAddress> = Vec::new();
+        for address in self.registrations.iter().flat_map(Registration::addresses) {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        if addresses.len() == self.addresses.len() && addresses.iter().all(|a| self.addresses.contains(a)) {
+            return;
+        }
+
+        // drop the old sockets first, so that the new ones can bind to the mDNS port
+        self.sockets = None;
+        if !addresses.is_empty() {
+            match Sockets::bind(&addresses) {
+                Ok(sockets) => self.sockets = Some(sockets),
+                Err(e) => error!("couldn't bind mDNS sockets: {}", e),
+            }
+        }
+        self.addresses = addresses;
+    }
+
+    fn multicast(&self, messages: Vec<(InterfaceAddress, Vec<Record>)>) {
+        if let Some(ref sockets) = self.sockets {
+            for (address, records) in messages {
+                sockets.multicast(&record::encode_response(0, &records, &[]), &address);
+            }
+        }
+    }
+
+    /// Sends pending announcements once the announcement timer fires.
+    fn poll_announcements(&mut self) {
+        loop {
+            match self.announcement_timer.as_mut().map(Delay::poll) {
+                None | Some(Ok(Async::NotReady)) => return,
+                Some(Ok(Async::Ready(()))) => {},
+                Some(Err(e)) => debug!("mDNS announcement timer failed: {}", e),
+            }
+
+            let announcements = self
+                .registrations
+                .iter_mut()
+                .flat_map(Registration::next_announcement)
+                .collect::<Vec<_>>();
+            self.multicast(announcements);
+
+            self.announcement_timer = if self.registrations.iter().any(Registration::is_announcing) {
+                Some(Delay::new(Instant::now() + Duration::from_secs(1)))
+            } else {
+                None
+            };
+        }
+    }
+
+    /// Answers received queries until no more messages are available.
+    fn poll_queries(&mut self) {
+        loop {
+            let received = match self.sockets {
+                Some(ref mut sockets) => sockets.poll_recv(&mut self.buf),
+                None => return,
+            };
+            match received {
+                Ok(Async::Ready((len, src))) => self.respond(len, src),
+                Ok(Async::NotReady) => return,
+                Err(e) => debug!("couldn't receive mDNS message: {}", e),
+            }
+        }
+    }
+
+    fn respond(&self, len: usize, src: SocketAddr) {
+        let sockets = match self.sockets {
+            Some(ref sockets) => sockets,
+            None => return,
+        };
+        let packet = match Packet::parse(&self.buf[..len]) {
+            Ok(packet) => packet,
+            Err(_) => return,
+        };
+        if !packet.header.query {
+            return;
+        }
+
+        let mut answers: Vec<Record> = Vec::new();
+        let mut additional: Vec<Record> = Vec::new();
+        let mut unicast = src.port() != MDNS_PORT;
+        for registration in &self.registrations {
+            let answer = registration.answer(&packet.questions, &src);
+            unicast |= answer.prefer_unicast;
+            for record in answer.answers {
+                if !answers.contains(&record) {
+                    answers.push(record);
+                }
+            }
+            for record in answer.additional {
+                if !answers.contains(&record) && !additional.contains(&record) {
+                    additional.push(record);
+                }
+            }
+        }
+        if answers.is_empty() {
+            return;
+        }
+
+        // legacy unicast queries expect their ID to be echoed
+        let id = if src.port() != MDNS_PORT { packet.header.id } else { 0 };
+        let response = record::encode_response(id, &answers, &additional);
+        if unicast {
+            sockets.unicast(&response, &src);
+        } else if let Some(address) = registration::addresses_for(&self.addresses, &src)
+            .into_iter()
+            .find(|a| a.ip.is_ipv4() == src.is_ipv4())
+        {
+            sockets.multicast(&response, address);
+        }
+    }
+}
+
+impl Future for Task {
+    type Error = ();
+    type Item = ();
+
+    fn poll(&mut self) -> Poll<(), ()> {
+        loop {
+            match self.commands.poll() {
+                Ok(Async::Ready(Some(command))) => {
+                    if !self.handle(command) {
+                        return Ok(Async::Ready(()));
+                    }
+                },
+                // all `Responder` handles are gone
+                Ok(Async::Ready(None)) | Err(()) => {
+                    self.shutdown();
+                    return Ok(Async::Ready(()));
+                },
+                Ok(Async::NotReady) => break,
+            }
+        }
+        self.poll_announcements();
+        self.poll_queries();
+        Ok(Async::NotReady)
+    }
+}
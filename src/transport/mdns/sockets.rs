@@ -0,0 +1,161 @@
+use std::{
+    io,
+    net::{self, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6},
+};
+
+use futures::{Async, Poll};
+#[cfg(unix)]
+use net2::unix::UnixUdpBuilderExt;
+use log::{debug, error};
+use net2::{UdpBuilder, UdpSocketExt};
+use tokio::{net::UdpSocket, reactor::Handle};
+
+use crate::transport::interfaces::InterfaceAddress;
+
+pub(crate) const MDNS_ADDR_V4: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+pub(crate) const MDNS_ADDR_V6: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 0xfb);
+pub(crate) const MDNS_PORT: u16 = 5353;
+
+/// A multicast socket. Messages are received through the reactor, but sent through a clone of the
+/// underlying socket, since the outgoing interface is set per message.
+struct Socket {
+    incoming: UdpSocket,
+    outgoing: net::UdpSocket,
+}
+
+/// The multicast sockets of a running `Responder`. Either of them may be missing if the host
+/// doesn't support the address family or no address of the family is selected.
+pub(crate) struct Sockets {
+    v4: Option<Socket>,
+    v6: Option<Socket>,
+}
+
+impl Sockets {
+    /// Binds the sockets and joins the mDNS multicast groups on the interfaces of the given
+    /// addresses. Has to be called on the thread of the reactor the sockets are registered with.
+    pub fn bind(addresses: &[InterfaceAddress]) -> io::Result<Sockets> {
+        let v4_addresses = addresses
+            .iter()
+            .filter_map(|a| match a.ip {
+                IpAddr::V4(ip) => Some(ip),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut v6_indices = addresses
+            .iter()
+            .filter(|a| a.ip.is_ipv6())
+            .map(|a| a.index)
+            .collect::<Vec<_>>();
+        v6_indices.sort();
+        v6_indices.dedup();
+
+        let v4 = if v4_addresses.is_empty() {
+            None
+        } else {
+            match bind_v4(&v4_addresses).and_then(Socket::new) {
+                Ok(socket) => Some(socket),
+                Err(e) => {
+                    error!("couldn't bind IPv4 mDNS socket: {}", e);
+                    None
+                },
+            }
+        };
+        let v6 = if v6_indices.is_empty() {
+            None
+        } else {
+            match bind_v6(&v6_indices).and_then(Socket::new) {
+                Ok(socket) => Some(socket),
+                Err(e) => {
+                    error!("couldn't bind IPv6 mDNS socket: {}", e);
+                    None
+                },
+            }
+        };
+
+        if v4.is_none() && v6.is_none() {
+            return Err(io::Error::new(io::ErrorKind::AddrNotAvailable, "no address to announce"));
+        }
+
+        Ok(Sockets { v4, v6 })
+    }
+
+    /// Multicasts a message on the interface of the given address.
+    pub fn multicast(&self, message: &[u8], address: &InterfaceAddress) {
+        let sent = match (address.ip, &self.v4, &self.v6) {
+            (IpAddr::V4(ip), Some(socket), _) => socket
+                .outgoing
+                .set_multicast_if_v4(&ip)
+                .and_then(|_| socket.outgoing.send_to(message, (MDNS_ADDR_V4, MDNS_PORT))),
+            (IpAddr::V6(_), _, Some(socket)) => socket.outgoing.send_to(
+                message,
+                SocketAddr::V6(SocketAddrV6::new(MDNS_ADDR_V6, MDNS_PORT, 0, address.index)),
+            ),
+            _ => return,
+        };
+        if let Err(e) = sent {
+            debug!("couldn't send mDNS message on {}: {}", address.name, e);
+        }
+    }
+
+    /// Sends a message to a single destination.
+    pub fn unicast(&self, message: &[u8], dst: &SocketAddr) {
+        let socket = match *dst {
+            SocketAddr::V4(_) => self.v4.as_ref(),
+            SocketAddr::V6(_) => self.v6.as_ref(),
+        };
+        if let Some(socket) = socket {
+            if let Err(e) = socket.outgoing.send_to(message, dst) {
+                debug!("couldn't send mDNS response to {}: {}", dst, e);
+            }
+        }
+    }
+
+    /// Receives a message from either socket.
+    pub fn poll_recv(&mut self, buf: &mut [u8]) -> Poll<(usize, SocketAddr), io::Error> {
+        for socket in self.v4.iter_mut().chain(self.v6.iter_mut()) {
+            if let Async::Ready(received) = socket.incoming.poll_recv_from(buf)? {
+                return Ok(Async::Ready(received));
+            }
+        }
+        Ok(Async::NotReady)
+    }
+}
+
+impl Socket {
+    fn new(socket: net::UdpSocket) -> io::Result<Socket> {
+        let outgoing = socket.try_clone()?;
+        let incoming = UdpSocket::from_std(socket, &Handle::default())?;
+        Ok(Socket { incoming, outgoing })
+    }
+}
+
+fn bind_v4(addresses: &[Ipv4Addr]) -> io::Result<net::UdpSocket> {
+    let builder = UdpBuilder::new_v4()?;
+    builder.reuse_address(true)?;
+    #[cfg(unix)]
+    builder.reuse_port(true)?;
+    let socket = builder.bind((Ipv4Addr::UNSPECIFIED, MDNS_PORT))?;
+    socket.set_multicast_ttl_v4(255)?;
+    for ip in addresses {
+        if let Err(e) = socket.join_multicast_v4(&MDNS_ADDR_V4, ip) {
+            debug!("couldn't join mDNS multicast group on {}: {}", ip, e);
+        }
+    }
+    Ok(socket)
+}
+
+fn bind_v6(indices: &[u32]) -> io::Result<net::UdpSocket> {
+    let builder = UdpBuilder::new_v6()?;
+    builder.only_v6(true)?;
+    builder.reuse_address(true)?;
+    #[cfg(unix)]
+    builder.reuse_port(true)?;
+    let socket = builder.bind((Ipv6Addr::UNSPECIFIED, MDNS_PORT))?;
+    socket.set_multicast_hops_v6(255)?;
+    for index in indices {
+        if let Err(e) = socket.join_multicast_v6(&MDNS_ADDR_V6, *index) {
+            debug!("couldn't join mDNS multicast group on interface {}: {}", index, e);
+        }
+    }
+    Ok(socket)
+}
@@ -4,9 +4,12 @@ use crate::{
 };
 
 pub mod bonjour;
+pub mod interfaces;
 pub mod mdns;
 
+pub(crate) mod connection;
 pub(crate) mod http;
+pub(crate) mod monitor;
 pub(crate) mod tcp;
 
 mod ip;
@@ -0,0 +1,105 @@
+use std::{
+    io,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use futures::{try_ready, Async, Poll, Stream};
+use tokio::timer::Interval;
+
+use crate::{
+    transport::interfaces::{self, InterfaceAddress, Interfaces},
+    Error,
+};
+
+/// Watches the addresses selected by the configured IP address and interfaces and yields the new
+/// set of addresses whenever an interface goes up or down or an address is added or removed, e.g.
+/// after moving to another network or a DHCP renewal.
+///
+/// Network interfaces are polled, since there's no portable way to be notified of changes.
+pub(crate) struct NetworkMonitor {
+    ip: IpAddr,
+    interfaces: Interfaces,
+    addresses: Vec<InterfaceAddress>,
+    interval: Interval,
+}
+
+impl NetworkMonitor {
+    /// Creates a new `NetworkMonitor` checking for changes every `interval`, starting from the
+    /// given addresses.
+    pub fn new(ip: IpAddr, interfaces: Interfaces, addresses: Vec<InterfaceAddress>, interval: Duration) -> Self {
+        NetworkMonitor {
+            ip,
+            interfaces,
+            addresses,
+            interval: Interval::new(Instant::now() + interval, interval),
+        }
+    }
+}
+
+impl Stream for NetworkMonitor {
+    type Error = Error;
+    type Item = Vec<InterfaceAddress>;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        loop {
+            let tick = self.interval.poll().map_err(io::Error::other);
+            if try_ready!(tick).is_none() {
+                return Ok(Async::Ready(None));
+            }
+            let addresses = interfaces::selected_addresses(self.ip, &self.interfaces);
+            if !same_addresses(&addresses, &self.addresses) {
+                self.addresses = addresses.clone();
+                return Ok(Async::Ready(Some(addresses)));
+            }
+        }
+    }
+}
+
+fn same_addresses(a: &[InterfaceAddress], b: &[InterfaceAddress]) -> bool {
+    a.len() == b.len() && a.iter().all(|address| b.contains(address))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use futures::Future;
+    use tokio::runtime::Runtime;
+
+    use super::*;
+
+    fn address(name: &str, ip: &str, prefix: u8) -> InterfaceAddress {
+        InterfaceAddress {
+            name: name.into(),
+            index: 1,
+            ip: ip.parse().unwrap(),
+            prefix,
+        }
+    }
+
+    #[test]
+    fn same_addresses_ignores_order() {
+        let a = vec![address("eth0", "192.168.1.10", 24), address("eth0", "fe80::1", 64)];
+        let b = vec![address("eth0", "fe80::1", 64), address("eth0", "192.168.1.10", 24)];
+
+        assert!(same_addresses(&a, &b));
+        assert!(same_addresses(&[], &[]));
+        assert!(!same_addresses(&a, &a[..1]));
+        assert!(!same_addresses(&a[..1], &a));
+        assert!(!same_addresses(&a[..1], &[address("eth0", "192.168.1.11", 24)]));
+        assert!(!same_addresses(&a[..1], &[address("eth0", "192.168.1.10", 16)]));
+        assert!(!same_addresses(&a[..1], &[address("wlan0", "192.168.1.10", 24)]));
+    }
+
+    #[test]
+    fn changed_addresses_are_yielded() {
+        let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let current = interfaces::selected_addresses(ip, &Interfaces::All);
+        let monitor = NetworkMonitor::new(ip, Interfaces::All, Vec::new(), Duration::from_millis(10));
+
+        let mut runtime = Runtime::new().unwrap();
+        let (addresses, _) = runtime.block_on(monitor.into_future().map_err(|(e, _)| e)).unwrap();
+        assert_eq!(addresses, Some(current));
+    }
+}
@@ -5,64 +5,73 @@ use std::{
 };
 
 use byteorder::{ByteOrder, LittleEndian};
-use bytes::{buf::FromBuf, BytesMut};
-use chacha20_poly1305_aead;
+use bytes::BytesMut;
 use futures::{
     sync::{
-        mpsc::{self, UnboundedReceiver, UnboundedSender},
+        mpsc::{self, Receiver, Sender},
         oneshot,
     },
-    try_ready,
-    Async::{self, NotReady, Ready},
+    Async::{NotReady, Ready},
+    AsyncSink,
     Future,
     Poll,
     Sink,
     Stream,
 };
-use ring::{digest, hkdf, hmac};
 use tokio::{
+    codec::{Decoder, Encoder},
     io::{AsyncRead, AsyncWrite},
     net::TcpStream,
 };
 use uuid::Uuid;
 
-use crate::{protocol::IdPtr, Result};
+use crate::{
+    crypto::{self, Backend, Crypto, SecretBytes, SecretKey32},
+    protocol::IdPtr,
+    transport::connection::Connection,
+    Result,
+};
+
+/// Maximum length of the plaintext of a frame of the encrypted HAP session.
+pub(crate) const MAX_FRAME_LEN: usize = 1024;
+
+/// Number of messages buffered in either direction between an `EncryptedStream` and the HTTP
+/// connection it serves before the sending side is put on hold.
+const CHANNEL_BUFFER: usize = 16;
+
+/// Amount of pending outgoing data above which an `EncryptedStream` stops taking messages from the
+/// HTTP connection until the socket has caught up.
+const WRITE_HIGH_WATER_MARK: usize = 64 * 1024;
 
+/// `StreamWrapper` is the I/O object the HTTP connection is served on. It exchanges plaintext
+/// with the `EncryptedStream` of the underlying TCP connection through bounded channels.
 pub struct StreamWrapper {
-    incoming_receiver: UnboundedReceiver<Vec<u8>>,
-    outgoing_sender: UnboundedSender<Vec<u8>>,
+    incoming_receiver: Receiver<Vec<u8>>,
+    outgoing_sender: Sender<Vec<u8>>,
     incoming_buf: BytesMut,
 }
 
 impl StreamWrapper {
-    pub fn new(
-        incoming_receiver: UnboundedReceiver<Vec<u8>>,
-        outgoing_sender: UnboundedSender<Vec<u8>>,
-    ) -> StreamWrapper {
+    pub fn new(incoming_receiver: Receiver<Vec<u8>>, outgoing_sender: Sender<Vec<u8>>) -> StreamWrapper {
         StreamWrapper {
             incoming_receiver,
             outgoing_sender,
             incoming_buf: BytesMut::new(),
         }
     }
-
-    fn poll_receiver(&mut self) -> std::result::Result<usize, io::Error> {
-        match self.incoming_receiver.poll() {
-            Ok(NotReady) => Err(ErrorKind::WouldBlock.into()),
-            Ok(Ready(Some(incoming))) => {
-                self.incoming_buf.extend_from_slice(&incoming);
-                Ok(incoming.len())
-            },
-            Ok(Ready(None)) => Ok(0),
-            // Err(_) => Err(Error::from_str("couldn't poll receiver").into()),
-            Err(_) => Err(io::Error::new(io::ErrorKind::Other, "couldn't poll receiver")),
-        }
-    }
 }
 
 impl Read for StreamWrapper {
     fn read(&mut self, buf: &mut [u8]) -> std::result::Result<usize, io::Error> {
-        self.poll_receiver()?;
+        while self.incoming_buf.is_empty() {
+            match self.incoming_receiver.poll() {
+                Ok(NotReady) => return Err(ErrorKind::WouldBlock.into()),
+                Ok(Ready(Some(incoming))) => self.incoming_buf.extend_from_slice(&incoming),
+                Ok(Ready(None)) => return Ok(0),
+                Err(_) => return Err(io::Error::other("couldn't poll receiver")),
+            }
+        }
+
         let r_len = min(buf.len(), self.incoming_buf.len());
         buf[..r_len].copy_from_slice(&self.incoming_buf[..r_len]);
         self.incoming_buf.advance(r_len);
@@ -72,17 +81,20 @@ impl Read for StreamWrapper {
 
 impl Write for StreamWrapper {
     fn write(&mut self, buf: &[u8]) -> std::result::Result<usize, io::Error> {
-        self.outgoing_sender.unbounded_send(buf.to_vec())
-            // .map_err(|_| Error::from_str("couldn't write").into())?;
-            .map_err(|_| io::Error::new(io::ErrorKind::Other, "couldn't write"))?;
-        Ok(buf.len())
+        // a full channel puts the HTTP connection on hold until the `EncryptedStream` caught up
+        match self.outgoing_sender.start_send(buf.to_vec()) {
+            Ok(AsyncSink::Ready) => Ok(buf.len()),
+            Ok(AsyncSink::NotReady(_)) => Err(ErrorKind::WouldBlock.into()),
+            Err(_) => Err(io::Error::new(ErrorKind::BrokenPipe, "couldn't write")),
+        }
     }
 
     fn flush(&mut self) -> std::result::Result<(), io::Error> {
-        self.outgoing_sender.poll_complete()
-            .map(|_| ())
-            // .map_err(|_| Error::from_str("couldn't flush").into())
-            .map_err(|_| io::Error::new(io::ErrorKind::Other, "couldn't flush"))
+        match self.outgoing_sender.poll_complete() {
+            Ok(Ready(())) => Ok(()),
+            Ok(NotReady) => Err(ErrorKind::WouldBlock.into()),
+            Err(_) => Err(io::Error::new(ErrorKind::BrokenPipe, "couldn't flush")),
+        }
     }
 }
 
@@ -95,60 +107,138 @@ impl AsyncWrite for StreamWrapper {
     }
 }
 
+/// Session established by a successful Pair Verify or transient Pair Setup.
 pub struct Session {
-    pub controller_id: Uuid,
-    pub shared_secret: [u8; 32],
+    /// ID of the paired controller. Transient sessions don't belong to a persisted pairing and
+    /// don't carry a controller ID.
+    pub controller_id: Option<Uuid>,
+    pub shared_secret: SecretBytes,
+}
+
+/// Pointer to the sender half of the channel a `Session` is handed to the `EncryptedStream` on.
+pub type SessionSenderPtr = Arc<Mutex<Option<oneshot::Sender<Session>>>>;
+
+/// `FrameCodec` encodes and decodes the frames of an encrypted HAP session. Every frame consists
+/// of the little-endian length of its plaintext, which is used as additional authenticated data,
+/// the ChaCha20-Poly1305 encrypted plaintext of at most `MAX_FRAME_LEN` Bytes and the 16 Byte
+/// authentication tag.
+pub(crate) struct FrameCodec {
+    read_key: SecretKey32,
+    write_key: SecretKey32,
+    read_count: u64,
+    write_count: u64,
+}
+
+impl FrameCodec {
+    /// Creates a new `FrameCodec` with the given keys.
+    pub fn new(read_key: SecretKey32, write_key: SecretKey32) -> FrameCodec {
+        FrameCodec {
+            read_key,
+            write_key,
+            read_count: 0,
+            write_count: 0,
+        }
+    }
+
+    /// Creates a new `FrameCodec` for the accessory side of a session.
+    pub fn accessory(shared_secret: &[u8]) -> FrameCodec {
+        FrameCodec::new(compute_read_key(shared_secret), compute_write_key(shared_secret))
+    }
+
+    /// Creates a new `FrameCodec` for the controller side of a session. The controller reads what
+    /// the accessory writes and vice versa.
+    pub fn controller(shared_secret: &[u8]) -> FrameCodec {
+        FrameCodec::new(compute_write_key(shared_secret), compute_read_key(shared_secret))
+    }
 }
 
+impl Decoder for FrameCodec {
+    type Error = io::Error;
+    type Item = Vec<u8>;
+
+    fn decode(&mut self, src: &mut BytesMut) -> std::result::Result<Option<Vec<u8>>, io::Error> {
+        if src.len() < 2 {
+            return Ok(None);
+        }
+        let len = LittleEndian::read_u16(&src[..2]) as usize;
+        if len > MAX_FRAME_LEN {
+            return Err(io::Error::new(ErrorKind::InvalidData, "encrypted frame too long"));
+        }
+        if src.len() < 2 + len + 16 {
+            src.reserve(2 + len + 16 - src.len());
+            return Ok(None);
+        }
+
+        let frame = src.split_to(2 + len + 16);
+        let decrypted = decrypt_chunk(
+            &self.read_key,
+            &frame[..2],
+            &frame[2..],
+            &mut self.read_count,
+        )
+        .map_err(|_| io::Error::new(ErrorKind::InvalidData, "authentication of encrypted frame failed"))?;
+
+        Ok(Some(decrypted))
+    }
+}
+
+impl Encoder for FrameCodec {
+    type Error = io::Error;
+    type Item = Vec<u8>;
+
+    fn encode(&mut self, data: Vec<u8>, dst: &mut BytesMut) -> std::result::Result<(), io::Error> {
+        for chunk in data.chunks(MAX_FRAME_LEN) {
+            let (aad, encrypted) = encrypt_chunk(&self.write_key, chunk, &mut self.write_count);
+            dst.reserve(aad.len() + encrypted.len());
+            dst.extend_from_slice(&aad);
+            dst.extend_from_slice(&encrypted);
+        }
+        Ok(())
+    }
+}
+
+/// `EncryptedStream` drives the TCP connection of an HTTP connection. Data is passed through as
+/// plaintext until a `Session` is established and encoded with a `FrameCodec` afterwards.
+///
+/// Outgoing data is buffered until the socket accepts it. Once more than `WRITE_HIGH_WATER_MARK`
+/// Bytes are pending, no more data is taken from the HTTP connection, and no more data is read from
+/// the socket while the HTTP connection hasn't consumed the previously read data. A frame that
+/// fails authentication terminates the stream with an error. The stream also resolves when its
+/// `Connection` is closed.
 pub struct EncryptedStream {
     stream: TcpStream,
-    incoming_sender: UnboundedSender<Vec<u8>>,
-    outgoing_receiver: UnboundedReceiver<Vec<u8>>,
+    connection: Connection,
+    incoming_sender: Sender<Vec<u8>>,
+    outgoing_receiver: Receiver<Vec<u8>>,
     session_receiver: oneshot::Receiver<Session>,
     pub controller_id: IdPtr,
-    shared_secret: Option<[u8; 32]>,
-    decrypt_count: u64,
-    encrypt_count: u64,
-    encrypted_buf: BytesMut,
-    decrypted_buf: BytesMut,
-    packet_len: usize,
-    already_copied: usize,
-    already_read: usize,
-    decrypted_ready: bool,
-    missing_data_for_decrypted_buf: bool,
-    missing_data_for_encrypted_buf: bool,
+    codec: Option<FrameCodec>,
+    read_buf: BytesMut,
+    write_buf: BytesMut,
+    pending_incoming: Option<Vec<u8>>,
 }
 
 impl EncryptedStream {
+    #[allow(clippy::type_complexity)]
     pub fn new(
         stream: TcpStream,
-    ) -> (
-        EncryptedStream,
-        UnboundedReceiver<Vec<u8>>,
-        UnboundedSender<Vec<u8>>,
-        oneshot::Sender<Session>,
-    ) {
+        connection: Connection,
+    ) -> (EncryptedStream, Receiver<Vec<u8>>, Sender<Vec<u8>>, oneshot::Sender<Session>) {
         let (sender, receiver) = oneshot::channel();
-        let (incoming_sender, incoming_receiver) = mpsc::unbounded();
-        let (outgoing_sender, outgoing_receiver) = mpsc::unbounded();
+        let (incoming_sender, incoming_receiver) = mpsc::channel(CHANNEL_BUFFER);
+        let (outgoing_sender, outgoing_receiver) = mpsc::channel(CHANNEL_BUFFER);
         (
             EncryptedStream {
                 stream,
+                connection,
                 incoming_sender,
                 outgoing_receiver,
                 session_receiver: receiver,
                 controller_id: Arc::new(Mutex::new(None)),
-                shared_secret: None,
-                decrypt_count: 0,
-                encrypt_count: 0,
-                encrypted_buf: BytesMut::from_buf(vec![0; 1042]),
-                decrypted_buf: BytesMut::from_buf(vec![0; 1024]),
-                packet_len: 0,
-                already_copied: 0,
-                already_read: 0,
-                decrypted_ready: false,
-                missing_data_for_decrypted_buf: false,
-                missing_data_for_encrypted_buf: false,
+                codec: None,
+                read_buf: BytesMut::new(),
+                write_buf: BytesMut::new(),
+                pending_incoming: None,
             },
             incoming_receiver,
             outgoing_sender,
@@ -156,110 +246,85 @@ impl EncryptedStream {
         )
     }
 
-    fn read_decrypted(&mut self, buf: &mut [u8]) -> std::result::Result<usize, io::Error> {
-        if self.decrypted_ready {
-            let len = min(buf.len(), self.packet_len - 16);
-            buf[..len].copy_from_slice(&self.decrypted_buf[..len]);
-            self.already_copied = len;
-            if self.already_copied == (self.packet_len - 16) {
-                self.already_copied = 0;
-                self.decrypted_ready = false;
+    /// Takes outgoing data from the HTTP connection and writes it to the socket. Returns whether
+    /// all outgoing data has been taken from the HTTP connection.
+    fn poll_outgoing(&mut self) -> std::result::Result<bool, io::Error> {
+        let mut drained = false;
+        while self.write_buf.len() < WRITE_HIGH_WATER_MARK {
+            match self.outgoing_receiver.poll() {
+                Ok(Ready(Some(data))) => match self.codec {
+                    Some(ref mut codec) => codec.encode(data, &mut self.write_buf)?,
+                    None => self.write_buf.extend_from_slice(&data),
+                },
+                Ok(Ready(None)) | Ok(NotReady) => {
+                    drained = true;
+                    break;
+                },
+                Err(_) => return Err(io::Error::other("couldn't receive outgoing data")),
             }
-
-            return Ok(len);
         }
 
-        Err(ErrorKind::WouldBlock.into())
-    }
-
-    fn read_encrypted(&mut self, buf: &mut [u8]) -> std::result::Result<usize, io::Error> {
-        if self.missing_data_for_decrypted_buf {
-            let decrypted = decrypt_chunk(
-                &self.shared_secret.expect("missing shared secret"),
-                &self.encrypted_buf[..2],
-                &self.encrypted_buf[2..(self.packet_len - 14)],
-                &self.encrypted_buf[(self.packet_len - 14)..(self.packet_len + 2)],
-                &mut self.decrypt_count,
-            )
-            .map_err(|_| io::Error::new(io::ErrorKind::Other, "decryption failed"))?;
-            self.decrypted_buf[..decrypted.len()].copy_from_slice(&decrypted);
-            self.missing_data_for_decrypted_buf = false;
-            self.decrypted_ready = true;
-
-            return self.read_decrypted(buf);
+        while !self.write_buf.is_empty() {
+            match self.stream.poll_write(&self.write_buf)? {
+                Ready(0) => return Err(ErrorKind::WriteZero.into()),
+                Ready(w_len) => {
+                    self.write_buf.advance(w_len);
+                    self.connection.touch();
+                },
+                NotReady => break,
+            }
         }
 
-        Err(ErrorKind::WouldBlock.into())
+        Ok(drained)
     }
 
-    fn read_stream(&mut self, buf: &mut [u8]) -> std::result::Result<usize, io::Error> {
-        if self.missing_data_for_encrypted_buf {
-            let r_len = self.stream.read(&mut self.encrypted_buf[self.already_read..])?;
-
-            if self.already_read + r_len == self.packet_len {
-                self.already_read = 0;
-                self.missing_data_for_encrypted_buf = false;
-                self.missing_data_for_decrypted_buf = true;
-                return self.read_encrypted(buf);
+    /// Reads incoming data from the socket and hands it to the HTTP connection. Resolves when the
+    /// socket is closed.
+    fn poll_incoming(&mut self, outgoing_drained: bool) -> Poll<(), io::Error> {
+        loop {
+            if let Some(data) = self.pending_incoming.take() {
+                match self.incoming_sender.start_send(data) {
+                    Ok(AsyncSink::Ready) => {},
+                    Ok(AsyncSink::NotReady(data)) => {
+                        self.pending_incoming = Some(data);
+                        return Ok(NotReady);
+                    },
+                    Err(_) => return Err(io::Error::other("couldn't send incoming data")),
+                }
             }
-            Err(ErrorKind::WouldBlock.into())
-        } else {
-            let r_len = self.stream.read(&mut self.encrypted_buf[self.already_read..2])?;
-            self.already_read += r_len;
-
-            if self.already_read == 2 {
-                self.packet_len = LittleEndian::read_u16(&self.encrypted_buf) as usize + 16;
-
-                self.missing_data_for_encrypted_buf = true;
-                let r_len = self.stream.read(&mut self.encrypted_buf[self.already_read..])?;
-                if r_len == self.packet_len {
-                    self.already_read = 0;
-                    self.missing_data_for_encrypted_buf = false;
-                    self.missing_data_for_decrypted_buf = true;
-                    self.read_encrypted(buf)
-                } else {
-                    self.already_read += r_len;
-                    Err(ErrorKind::WouldBlock.into())
+
+            // the response establishing the session is still sent in plaintext, so the session
+            // only takes effect once it has been taken from the HTTP connection
+            if self.codec.is_none() && outgoing_drained {
+                if let Ok(Ready(session)) = self.session_receiver.poll() {
+                    *self.controller_id.lock().expect("couldn't access controller_id") = session.controller_id;
+                    self.codec = Some(FrameCodec::accessory(&session.shared_secret));
+                    self.connection.verify(session.controller_id);
                 }
-            } else {
-                Err(ErrorKind::WouldBlock.into())
             }
-        }
-    }
 
-    fn poll_incoming(&mut self) -> Poll<(), io::Error> {
-        let mut data = [0; 1536];
-        loop {
-            match self.read(&mut data) {
-                Err(e) => match e.kind() {
-                    ErrorKind::WouldBlock => {
-                        return Ok(NotReady);
-                    },
-                    _ => {
-                        return Err(e);
-                    },
-                },
-                Ok(r_len) => {
-                    if r_len == 0 {
-                        return Ok(Ready(()));
-                    }
-                    self.incoming_sender.unbounded_send(data[..r_len].to_vec())
-                        // .map_err(|_| Error::from_str("couldn't send incoming data").into())?;
-                        .map_err(|_| io::Error::new(io::ErrorKind::Other, "couldn't send incoming data"))?;
+            let decoded = match self.codec {
+                Some(ref mut codec) => codec.decode(&mut self.read_buf)?,
+                None if !self.read_buf.is_empty() => Some(self.read_buf.take().to_vec()),
+                None => None,
+            };
+            match decoded {
+                Some(ref data) if data.is_empty() => continue,
+                Some(data) => {
+                    self.pending_incoming = Some(data);
+                    continue;
                 },
+                None => {},
             }
-        }
-    }
 
-    fn poll_outgoing(&mut self) -> Poll<(), ()> {
-        loop {
-            match try_ready!(self.outgoing_receiver.poll()) {
-                None => {
-                    return Ok(Ready(()));
-                },
-                Some(data) => {
-                    self.write(&data).map_err(|_| ())?;
+            let mut data = [0; 4096];
+            match self.stream.poll_read(&mut data)? {
+                Ready(0) => return Ok(Ready(())),
+                Ready(r_len) => {
+                    self.read_buf.extend_from_slice(&data[..r_len]);
+                    self.connection.touch();
                 },
+                NotReady => return Ok(NotReady),
             }
         }
     }
@@ -270,122 +335,46 @@ impl Future for EncryptedStream {
     type Item = ();
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
-        self.poll_outgoing()
-            // .map_err(|_| Error::from_str("couldn't receive outgoing data").into())?;
-            .map_err(|_| io::Error::new(io::ErrorKind::Other, "couldn't receive incoming data"))?;
-
-        self.poll_incoming()
-    }
-}
-
-impl Read for EncryptedStream {
-    fn read(&mut self, buf: &mut [u8]) -> std::result::Result<usize, io::Error> {
-        if self.shared_secret.is_none() {
-            match self.session_receiver.poll() {
-                Ok(Async::Ready(session)) => {
-                    *self.controller_id.lock().expect("couldn't access controller_id") = Some(session.controller_id);
-                    self.shared_secret = Some(session.shared_secret);
-                },
-                _ => {
-                    return self.stream.read(buf);
-                },
-            }
-        }
-
-        match self.read_decrypted(buf) {
-            Ok(size) => Ok(size),
-            Err(_) => match self.read_encrypted(buf) {
-                Ok(size) => Ok(size),
-                Err(_) => self.read_stream(buf),
-            },
+        let outgoing_drained = self.poll_outgoing()?;
+        if let Ready(()) = self.poll_incoming(outgoing_drained)? {
+            return Ok(Ready(()));
         }
+        self.connection.poll_close()
     }
 }
 
-impl Write for EncryptedStream {
-    fn write(&mut self, buf: &[u8]) -> std::result::Result<usize, io::Error> {
-        if let Some(shared_secret) = self.shared_secret {
-            let mut write_buf = BytesMut::from_buf(buf);
-
-            while write_buf.len() > 1024 {
-                let (aad, chunk, auth_tag) = encrypt_chunk(&shared_secret, &write_buf[..1024], &mut self.encrypt_count)
-                    .map_err(|_| io::Error::new(io::ErrorKind::Other, "encryption failed"))?;
-                self.stream.write_all(&aad)?;
-                self.stream.write_all(&chunk)?;
-                self.stream.write_all(&auth_tag)?;
-                write_buf.advance(1024);
-            }
-
-            let (aad, chunk, auth_tag) = encrypt_chunk(&shared_secret, &write_buf, &mut self.encrypt_count)
-                .map_err(|_| io::Error::new(io::ErrorKind::Other, "encryption failed"))?; // TODO - make this better
-            self.stream.write_all(&aad)?;
-            self.stream.write_all(&chunk)?;
-            self.stream.write_all(&auth_tag)?;
-            Ok(buf.len())
-        } else {
-            self.stream.write(buf)
-        }
-    }
-
-    fn flush(&mut self) -> std::result::Result<(), io::Error> { self.stream.flush() }
+/// Decrypts a frame of the encrypted HAP session, consisting of the ciphertext followed by the
+/// authentication tag, with the given key.
+pub(crate) fn decrypt_chunk(read_key: &[u8; 32], aad: &[u8], data: &[u8], count: &mut u64) -> Result<Vec<u8>> {
+    let nonce = count_nonce(count);
+    Ok(Backend::open(read_key, &nonce, aad, data)?)
 }
 
-impl AsyncRead for EncryptedStream {}
-
-impl AsyncWrite for EncryptedStream {
-    fn shutdown(&mut self) -> Poll<(), io::Error> { AsyncWrite::shutdown(&mut self.stream) }
-}
-
-fn decrypt_chunk(
-    shared_secret: &[u8; 32],
-    aad: &[u8],
-    data: &[u8],
-    auth_tag: &[u8],
-    count: &mut u64,
-) -> Result<Vec<u8>> {
-    let mut decrypted_data = Vec::new();
-    let read_key = compute_read_key(shared_secret);
-
-    let mut nonce = vec![0; 4];
-    let mut suffix = vec![0; 8];
-    LittleEndian::write_u64(&mut suffix, *count);
-    nonce.extend(suffix);
-    *count += 1;
-
-    chacha20_poly1305_aead::decrypt(&read_key, &nonce, aad, &data, auth_tag, &mut decrypted_data)?;
-
-    Ok(decrypted_data)
-}
-
-fn encrypt_chunk(shared_secret: &[u8; 32], data: &[u8], count: &mut u64) -> Result<([u8; 2], Vec<u8>, [u8; 16])> {
-    let mut encrypted_data = Vec::new();
-    let write_key = compute_write_key(shared_secret);
-
-    let mut nonce = vec![0; 4];
-    let mut suffix = vec![0; 8];
-    LittleEndian::write_u64(&mut suffix, *count);
-    nonce.extend(suffix);
-    *count += 1;
+/// Encrypts a frame of the encrypted HAP session with the given key and returns its length header
+/// and the ciphertext followed by the authentication tag.
+pub(crate) fn encrypt_chunk(write_key: &[u8; 32], data: &[u8], count: &mut u64) -> ([u8; 2], Vec<u8>) {
+    let nonce = count_nonce(count);
 
     let mut aad = [0; 2];
     LittleEndian::write_u16(&mut aad, data.len() as u16);
 
-    let auth_tag = chacha20_poly1305_aead::encrypt(&write_key, &nonce, &aad, &data, &mut encrypted_data)?;
-
-    Ok((aad, encrypted_data, auth_tag))
+    (aad, Backend::seal(write_key, &nonce, &aad, data))
 }
 
-fn compute_read_key(shared_secret: &[u8; 32]) -> [u8; 32] {
-    compute_key(shared_secret, b"Control-Write-Encryption-Key")
+/// Returns the nonce of the next frame and increments the frame counter.
+fn count_nonce(count: &mut u64) -> [u8; 12] {
+    let mut suffix = [0; 8];
+    LittleEndian::write_u64(&mut suffix, *count);
+    *count += 1;
+    crypto::nonce(&suffix)
 }
 
-fn compute_write_key(shared_secret: &[u8; 32]) -> [u8; 32] {
-    compute_key(shared_secret, b"Control-Read-Encryption-Key")
+/// Computes the key the accessory decrypts incoming frames with.
+pub(crate) fn compute_read_key(shared_secret: &[u8]) -> SecretKey32 {
+    crypto::derive_key(shared_secret, b"Control-Salt", b"Control-Write-Encryption-Key")
 }
 
-fn compute_key(shared_secret: &[u8; 32], info: &[u8]) -> [u8; 32] {
-    let mut key = [0; 32];
-    let salt = hmac::SigningKey::new(&digest::SHA512, b"Control-Salt");
-    hkdf::extract_and_expand(&salt, shared_secret, &info, &mut key);
-    key
+/// Computes the key the accessory encrypts outgoing frames with.
+pub(crate) fn compute_write_key(shared_secret: &[u8]) -> SecretKey32 {
+    crypto::derive_key(shared_secret, b"Control-Salt", b"Control-Read-Encryption-Key")
 }
@@ -0,0 +1,66 @@
+//! Compiles the code `hap-codegen` generates for user-supplied metadata against the `hap` crate and
+//! uses the generated Characteristics, Services and Accessories.
+
+use std::convert::TryFrom;
+
+use serde_json::json;
+
+use hap::{
+    accessory::{HapAccessory, Information},
+    characteristic::{HapCharacteristic, Perm, ValidValue},
+    service::HapService,
+    HapType,
+    Uuid,
+};
+
+mod generated {
+    include!("../hap-codegen/tests/fixtures/hap_generated.rs");
+}
+
+use generated::{
+    accessory::power_meter,
+    characteristic::{firmware_channel, metering_mode, total_consumption},
+};
+
+#[test]
+fn generated_characteristics_have_custom_types() {
+    let total_consumption = total_consumption::new();
+    assert_eq!(
+        HapCharacteristic::get_type(&total_consumption).unwrap(),
+        HapType::Custom(Uuid::parse_str("E863F10C-079E-48FF-8F27-9C2605A29F52").unwrap())
+    );
+    assert_eq!(total_consumption.get_max_value().unwrap(), Some(1_000_000.0));
+    assert_eq!(total_consumption.get_step_value().unwrap(), Some(0.1));
+
+    let firmware_channel = firmware_channel::new();
+    assert_eq!(HapCharacteristic::get_perms(&firmware_channel).unwrap(), vec![Perm::PairedRead]);
+    assert_eq!(firmware_channel.get_max_len().unwrap(), None);
+
+    let mut metering_mode = metering_mode::new();
+    assert_eq!(serde_json::to_value(&metering_mode).unwrap()["valid-values"], json!([0, 1, 2]));
+    assert_eq!(metering_mode::Value::hap_type(), HapCharacteristic::get_type(&metering_mode).unwrap());
+    metering_mode.set_value(metering_mode::Value::Eco.into()).unwrap();
+    assert_eq!(
+        metering_mode::Value::try_from(metering_mode.get_value().unwrap()).unwrap(),
+        metering_mode::Value::Eco
+    );
+}
+
+#[test]
+fn generated_accessories_contain_their_service() {
+    let mut power_meter = power_meter::new(Information {
+        name: "Power Meter".into(),
+        ..Default::default()
+    })
+    .unwrap();
+    power_meter.inner.power_meter.inner.metering_mode = Some(metering_mode::new());
+
+    let service = &power_meter.inner.power_meter;
+    assert!(service.get_primary());
+    assert_eq!(
+        service.get_type(),
+        HapType::Custom(Uuid::parse_str("B3E0E8A2-1D4B-4C1E-9E1A-5A8F3C2D7E20").unwrap())
+    );
+    assert_eq!(service.get_characteristics().len(), 2);
+    assert_eq!(power_meter.get_services().len(), 2);
+}
@@ -0,0 +1,95 @@
+//! Property tests for the internal parsers that process untrusted input, run through the entry
+//! points of the `fuzzing` feature. Arbitrary input must never make them panic, and valid input must
+//! round-trip.
+
+use proptest::{collection::vec, prelude::*};
+use serde_json::json;
+
+use hap::fuzzing;
+
+const KEY: [u8; 32] = [7; 32];
+
+proptest! {
+    #[test]
+    fn encrypted_frames_dont_panic(data in vec(any::<u8>(), 0..2048)) {
+        let _ = fuzzing::decode_frames(&KEY, &data);
+    }
+
+    #[test]
+    fn encrypted_frames_round_trip(data in vec(any::<u8>(), 0..4096), cut in any::<prop::sample::Index>()) {
+        let encoded = fuzzing::encode_frames(&KEY, &data).unwrap();
+        prop_assert_eq!(fuzzing::decode_frames(&KEY, &encoded).unwrap(), data.clone());
+
+        // a truncated stream yields a prefix of the data
+        let cut = cut.index(encoded.len() + 1);
+        let decoded = fuzzing::decode_frames(&KEY, &encoded[..cut]).unwrap();
+        prop_assert!(data.starts_with(&decoded));
+    }
+
+    #[test]
+    fn fragmented_frames_round_trip(data in vec(any::<u8>(), 0..4096), fragment_len in 1usize..1100) {
+        let encoded = fuzzing::encode_frames(&KEY, &data).unwrap();
+        prop_assert_eq!(fuzzing::decode_fragmented_frames(&KEY, &encoded, fragment_len).unwrap(), data);
+    }
+
+    #[test]
+    fn tampered_frames_are_rejected(data in vec(any::<u8>(), 1..2048), pos in any::<prop::sample::Index>()) {
+        let mut encoded = fuzzing::encode_frames(&KEY, &data).unwrap();
+        let pos = pos.index(encoded.len());
+        encoded[pos] ^= 0x01;
+        prop_assert_ne!(fuzzing::decode_frames(&KEY, &encoded).ok(), Some(data));
+    }
+
+    #[test]
+    fn read_queries_dont_panic(query in ".*") {
+        let _ = fuzzing::parse_read_query(&query);
+    }
+
+    #[test]
+    fn read_queries_round_trip(ids in vec((any::<u64>(), any::<u64>()), 1..8), meta in any::<bool>()) {
+        let id = ids.iter().map(|(aid, iid)| format!("{}.{}", aid, iid)).collect::<Vec<_>>().join(",");
+        let query = format!("id={}&meta={}", id, meta as u8);
+
+        let parsed = fuzzing::parse_read_query(&query).unwrap();
+        prop_assert_eq!(parsed.ids, ids);
+        prop_assert_eq!(parsed.meta, meta);
+        prop_assert!(!parsed.perms && !parsed.hap_type && !parsed.ev);
+    }
+
+    #[test]
+    fn write_bodies_dont_panic(body in vec(any::<u8>(), 0..512)) {
+        let _ = fuzzing::write_characteristics(&body);
+    }
+
+    #[test]
+    fn write_objects_dont_panic(
+        aid in 0u64..3,
+        iid in 0u64..16,
+        ev in any::<Option<bool>>(),
+        value in prop_oneof![
+            Just(json!(null)),
+            any::<bool>().prop_map(|v| json!(v)),
+            any::<i64>().prop_map(|v| json!(v)),
+            any::<f64>().prop_map(|v| json!(v)),
+            ".{0,16}".prop_map(|v| json!(v)),
+            vec(any::<u8>(), 0..4).prop_map(|v| json!(v)),
+        ],
+    ) {
+        let body = json!({ "characteristics": [{ "aid": aid, "iid": iid, "ev": ev, "value": value }] });
+        let status = fuzzing::write_characteristics(&serde_json::to_vec(&body).unwrap()).unwrap();
+        prop_assert!(status == 204 || status == 207 || status == 400);
+    }
+}
+
+#[test]
+fn oversized_frames_are_rejected() {
+    // a length header above 1024 Bytes must be rejected before the frame is buffered
+    assert!(fuzzing::decode_frames(&KEY, &[0xFF, 0xFF]).is_err());
+}
+
+#[test]
+fn malformed_read_queries_are_rejected() {
+    for query in &["", "id=", "id=1", "id=1.2.3", "id=a.b", "id=1.-1", "id=1.2,", "meta=1"] {
+        assert!(fuzzing::parse_read_query(query).is_err(), "accepted {:?}", query);
+    }
+}
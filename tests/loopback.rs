@@ -0,0 +1,763 @@
+//! End-to-end tests running an `IpTransport` on a localhost port with in-memory storage and
+//! driving it through pairing and characteristic I/O with the built-in controller.
+
+use std::{
+    io::Read,
+    net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::json;
+use uuid::Uuid;
+
+use hap::{
+    accessory::{
+        adaptive_lighting::{self, Clock},
+        bridge,
+        dynamic,
+        lightbulb,
+        outlet,
+        Category,
+        Information,
+    },
+    characteristic::{
+        self,
+        tlv8::{Nested, Repeated},
+        transition::{
+            AdjustmentMultiplierRange,
+            SupportedTransitionConfig,
+            TransitionConfig,
+            TransitionControl,
+            TransitionControlResponse,
+            TransitionCurve,
+            TransitionEntry,
+            TransitionParameters,
+            UpdateTransition,
+        },
+        Characteristic,
+        Tlv8,
+        Updatable,
+    },
+    controller::{Controller, PairedAccessory, ResumableSession, Session},
+    db::{AccessoryListMember, MemoryStorage},
+    protocol::{tlv::Value, Authenticator, MockAuthenticator, Pairing, Permissions},
+    service,
+    transport::{IpTransport, Transport},
+    Config,
+    Error,
+    ErrorKind,
+    HapType,
+};
+
+const PIN: &str = "11122333";
+
+struct Harness {
+    addr: SocketAddr,
+    on: Characteristic<bool>,
+}
+
+fn lightbulb_config() -> Config {
+    Config {
+        ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        port: 0,
+        pin: PIN.into(),
+        name: "Loopback Lightbulb".into(),
+        category: Category::Lightbulb,
+        ..Default::default()
+    }
+}
+
+/// Starts a lightbulb accessory on an ephemeral port and waits until it's listening.
+fn start_accessory() -> Harness { start_accessory_with(lightbulb_config()) }
+
+/// Starts a lightbulb accessory with the given `Config` and waits until it's listening.
+fn start_accessory_with(config: Config) -> Harness {
+    let lightbulb = lightbulb::new(Information {
+        name: "Loopback Lightbulb".into(),
+        ..Default::default()
+    })
+    .unwrap();
+    let on = lightbulb.inner.lightbulb.inner.on.clone();
+
+    Harness {
+        addr: serve_accessories(config, vec![Box::new(lightbulb)]),
+        on,
+    }
+}
+
+/// Accessory of any type that can be added to an `IpTransport`, so that Accessories of different
+/// types can be listed together.
+trait AddTo {
+    fn add_to(self: Box<Self>, ip_transport: &mut IpTransport<MemoryStorage>);
+}
+
+impl<A: 'static + AccessoryListMember + Send> AddTo for A {
+    fn add_to(self: Box<Self>, ip_transport: &mut IpTransport<MemoryStorage>) {
+        ip_transport.add_accessory(*self).unwrap();
+    }
+}
+
+/// Starts a transport with the given `Config` and Accessories and waits until it's listening.
+fn serve_accessories(config: Config, accessories: Vec<Box<dyn AddTo>>) -> SocketAddr {
+    let mut ip_transport = IpTransport::new_in_memory(config).unwrap();
+    for accessory in accessories {
+        accessory.add_to(&mut ip_transport);
+    }
+    serve(ip_transport)
+}
+
+/// Starts a transport with the given `Config` and Accessories, pairs a new controller with it and
+/// returns a verified session.
+fn connect_to(config: Config, accessories: Vec<Box<dyn AddTo>>) -> Session {
+    let addr = serve_accessories(config, accessories);
+    let controller = Controller::new(Uuid::new_v4());
+    let accessory = controller.pair_setup(&addr, PIN).unwrap();
+    controller.connect(&addr, &accessory).unwrap()
+}
+
+/// Starts the given transport on a background thread and waits until it's listening.
+fn serve(ip_transport: IpTransport<MemoryStorage>) -> SocketAddr {
+    let mut server = ip_transport.clone();
+    thread::spawn(move || server.start().unwrap());
+
+    let start = Instant::now();
+    loop {
+        if let Some(addr) = ip_transport.local_addr() {
+            return addr;
+        }
+        if start.elapsed() > Duration::from_secs(5) {
+            panic!("accessory didn't start listening");
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Returns whether the accessory closes the given connection within a few seconds.
+fn is_closed_by_accessory(stream: &mut TcpStream) -> bool {
+    stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    match stream.read(&mut [0; 16]) {
+        Ok(0) => true,
+        Ok(_) => false,
+        // a reset counts as closed, a timeout doesn't
+        Err(e) => e.kind() != std::io::ErrorKind::WouldBlock && e.kind() != std::io::ErrorKind::TimedOut,
+    }
+}
+
+fn pair(harness: &Harness) -> (Controller, PairedAccessory) {
+    let controller = Controller::new(Uuid::new_v4());
+    let accessory = controller.pair_setup(&harness.addr, PIN).unwrap();
+    (controller, accessory)
+}
+
+#[test]
+fn pair_setup_and_verify() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+
+    let mut session = controller.connect(&harness.addr, &accessory).unwrap();
+    let accessories = session.get_accessories().unwrap();
+    let list = accessories["accessories"].as_array().unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0]["aid"], json!(1));
+}
+
+#[test]
+fn pair_setup_with_wrong_pin_fails() {
+    let harness = start_accessory();
+    let controller = Controller::new(Uuid::new_v4());
+
+    assert!(controller.pair_setup(&harness.addr, "11122334").is_err());
+}
+
+#[test]
+fn pair_setup_with_auth_is_signed_by_the_authenticator() {
+    let certificate = b"Loopback Certificate".to_vec();
+    let authenticator: Box<dyn Authenticator + Send> = Box::new(MockAuthenticator::new(certificate.clone(), [7; 32]));
+    let harness = start_accessory_with(Config {
+        authenticator: Some(Arc::new(Mutex::new(authenticator))),
+        ..lightbulb_config()
+    });
+    let controller = Controller::new(Uuid::new_v4());
+
+    let (accessory, authentication) = controller.pair_setup_with_auth(&harness.addr, PIN).unwrap();
+    assert_eq!(authentication.certificate, certificate);
+    assert_eq!(authentication.challenge.len(), 32);
+    let verifier = MockAuthenticator::new(certificate, [7; 32]);
+    assert!(verifier.verify(&authentication.challenge, &authentication.signature));
+
+    let mut session = controller.connect(&harness.addr, &accessory).unwrap();
+    assert!(session.get_accessories().is_ok());
+}
+
+#[test]
+fn pair_setup_with_auth_requires_an_authenticator() {
+    let harness = start_accessory();
+    let controller = Controller::new(Uuid::new_v4());
+
+    let err = controller.pair_setup_with_auth(&harness.addr, PIN).unwrap_err();
+    assert_eq!(err.to_string(), "Error Server pairing method is unavailable");
+}
+
+#[test]
+fn transient_pair_setup_opens_a_session_without_pairing() {
+    let mut ip_transport = IpTransport::new_in_memory(lightbulb_config()).unwrap();
+    ip_transport
+        .add_accessory(lightbulb::new(Information::default()).unwrap())
+        .unwrap();
+    let database = ip_transport.database().clone();
+    let addr = serve(ip_transport);
+    let controller = Controller::new(Uuid::new_v4());
+
+    let mut session = controller.transient_pair_setup(&addr, PIN, false).unwrap();
+    assert!(session.get_accessories().is_ok());
+    assert_eq!(database.lock().unwrap().count_pairings().unwrap(), 0);
+    assert!(database.lock().unwrap().get_split_setup().is_err());
+
+    // there are no long-term public keys to exchange
+    let err = session
+        .tlv_request("/pair-setup", vec![Value::State(5), Value::EncryptedData(vec![0; 32])])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Error Unknown error");
+    assert_eq!(database.lock().unwrap().count_pairings().unwrap(), 0);
+
+    assert!(controller.transient_pair_setup(&addr, "11122334", false).is_err());
+}
+
+#[test]
+fn pair_setup_is_unavailable_once_paired() {
+    let harness = start_accessory();
+    let (paired, accessory) = pair(&harness);
+    let controller = Controller::new(Uuid::new_v4());
+
+    let err = controller.pair_setup(&harness.addr, PIN).unwrap_err();
+    assert_eq!(err.to_string(), "Error Server pairing method is unavailable");
+    // transient Pair Setup would hand out a session without pairing, so it's refused as well
+    let err = controller.transient_pair_setup(&harness.addr, PIN, false).err().unwrap();
+    assert_eq!(err.to_string(), "Error Server pairing method is unavailable");
+    let err = controller.transient_pair_setup(&harness.addr, PIN, true).err().unwrap();
+    assert_eq!(err.to_string(), "Error Server pairing method is unavailable");
+
+    let mut session = paired.connect(&harness.addr, &accessory).unwrap();
+    assert!(session.get_accessories().is_ok());
+}
+
+#[test]
+fn split_pair_setup_reuses_the_stored_verifier() {
+    let mut ip_transport = IpTransport::new_in_memory(lightbulb_config()).unwrap();
+    ip_transport
+        .add_accessory(lightbulb::new(Information::default()).unwrap())
+        .unwrap();
+    let database = ip_transport.database().clone();
+    let addr = serve(ip_transport);
+    let controller = Controller::new(Uuid::new_v4());
+
+    // without a preceding transient split Pair Setup there's no verifier to reuse
+    let err = controller.split_pair_setup(&addr, PIN).unwrap_err();
+    assert_eq!(err.to_string(), "Error Setup code or signature verification failed");
+
+    let mut session = controller.transient_pair_setup(&addr, PIN, true).unwrap();
+    assert!(session.get_accessories().is_ok());
+    let record = database.lock().unwrap().get_split_setup().unwrap();
+    assert!(record.len() > 16);
+    assert_eq!(database.lock().unwrap().count_pairings().unwrap(), 0);
+
+    let accessory = controller.split_pair_setup(&addr, PIN).unwrap();
+    assert!(database.lock().unwrap().get_split_setup().is_err());
+    assert_eq!(database.lock().unwrap().count_pairings().unwrap(), 1);
+
+    let mut session = controller.connect(&addr, &accessory).unwrap();
+    assert!(session.get_accessories().is_ok());
+}
+
+#[test]
+fn split_pair_setup_verifier_is_cleared() {
+    let mut ip_transport = IpTransport::new_in_memory(lightbulb_config()).unwrap();
+    ip_transport
+        .add_accessory(lightbulb::new(Information::default()).unwrap())
+        .unwrap();
+    let database = ip_transport.database().clone();
+    let addr = serve(ip_transport);
+    let controller = Controller::new(Uuid::new_v4());
+
+    // the verifier is used up by a failed split Pair Setup
+    controller.transient_pair_setup(&addr, PIN, true).unwrap();
+    assert!(controller.split_pair_setup(&addr, "11122334").is_err());
+    assert!(database.lock().unwrap().get_split_setup().is_err());
+    let err = controller.split_pair_setup(&addr, PIN).unwrap_err();
+    assert_eq!(err.to_string(), "Error Setup code or signature verification failed");
+
+    // and dropped by a regular Pair Setup
+    controller.transient_pair_setup(&addr, PIN, true).unwrap();
+    let accessory = controller.pair_setup(&addr, PIN).unwrap();
+    assert!(database.lock().unwrap().get_split_setup().is_err());
+
+    // and by removing a pairing
+    let mut session = controller.connect(&addr, &accessory).unwrap();
+    let second = Controller::new(Uuid::new_v4());
+    let second_id = Uuid::parse_str(&second.device().id).unwrap();
+    session
+        .add_pairing(&Pairing::new(second_id, Permissions::User, second.device().public_key))
+        .unwrap();
+    database.lock().unwrap().set_split_setup(vec![0; 32]).unwrap();
+    session.remove_pairing(second_id).unwrap();
+    assert!(database.lock().unwrap().get_split_setup().is_err());
+}
+
+#[test]
+fn pair_verify_with_unknown_controller_fails() {
+    let harness = start_accessory();
+    let (_, accessory) = pair(&harness);
+
+    let stranger = Controller::new(Uuid::new_v4());
+    assert!(stranger.connect(&harness.addr, &accessory).is_err());
+}
+
+#[test]
+fn pair_resume_resumes_cached_sessions() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+    let session = controller.connect(&harness.addr, &accessory).unwrap();
+    assert!(!session.is_resumed());
+    let first = session.resumable().unwrap().clone();
+
+    let mut session = controller.resume(&harness.addr, &accessory, &first).unwrap();
+    assert!(session.is_resumed());
+    assert!(session.get_accessories().is_ok());
+    let second = session.resumable().unwrap().clone();
+    assert_ne!(second.id, first.id);
+
+    // resumed sessions can be resumed again, but each session ID only once
+    let mut session = controller.resume(&harness.addr, &accessory, &second).unwrap();
+    assert!(session.is_resumed());
+    assert!(session.get_accessories().is_ok());
+    let mut session = controller.resume(&harness.addr, &accessory, &first).unwrap();
+    assert!(!session.is_resumed());
+    assert!(session.get_accessories().is_ok());
+}
+
+#[test]
+fn pair_resume_falls_back_to_pair_verify() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+    let session = controller.connect(&harness.addr, &accessory).unwrap();
+    let resumable = session.resumable().unwrap().clone();
+
+    // unknown session ID
+    let unknown = ResumableSession::new([0; 8], *resumable.shared_secret());
+    let mut session = controller.resume(&harness.addr, &accessory, &unknown).unwrap();
+    assert!(!session.is_resumed());
+    assert!(session.get_accessories().is_ok());
+
+    // request that doesn't authenticate with the cached shared secret
+    let forged = ResumableSession::new(resumable.id, [0; 32]);
+    let mut session = controller.resume(&harness.addr, &accessory, &forged).unwrap();
+    assert!(!session.is_resumed());
+    assert!(session.get_accessories().is_ok());
+
+    // a controller that isn't paired can't fall back
+    let stranger = Controller::new(Uuid::new_v4());
+    assert!(stranger.resume(&harness.addr, &accessory, &unknown).is_err());
+}
+
+#[test]
+fn pair_resume_evicts_the_oldest_session() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+    let oldest = controller
+        .connect(&harness.addr, &accessory)
+        .unwrap()
+        .resumable()
+        .unwrap()
+        .clone();
+    let mut newest = None;
+    for _ in 0..32 {
+        let session = controller.connect(&harness.addr, &accessory).unwrap();
+        newest = session.resumable().cloned();
+    }
+
+    assert!(!controller.resume(&harness.addr, &accessory, &oldest).unwrap().is_resumed());
+    assert!(controller
+        .resume(&harness.addr, &accessory, &newest.unwrap())
+        .unwrap()
+        .is_resumed());
+}
+
+#[test]
+fn read_and_write_characteristics() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+    let mut session = controller.connect(&harness.addr, &accessory).unwrap();
+    let iid = harness.on.get_id().unwrap();
+
+    let values = session.read_characteristics(&[(1, iid)]).unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value, Some(json!(false)));
+
+    let statuses = session.write_characteristics(vec![(1, iid, json!(true))]).unwrap();
+    assert!(statuses.is_empty());
+
+    let values = session.read_characteristics(&[(1, iid)]).unwrap();
+    assert_eq!(values[0].value, Some(json!(true)));
+
+    let mut on = harness.on.clone();
+    assert!(on.get_value().unwrap());
+}
+
+struct RejectOn;
+
+impl Updatable<bool> for RejectOn {
+    fn try_on_update(&mut self, _: &bool, new_val: &bool, _: HapType) -> hap::Result<()> {
+        if *new_val {
+            return Err(Error::from_str("rejected"));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn rejected_writes_keep_the_old_value() {
+    let mut lightbulb = lightbulb::new(Information::default()).unwrap();
+    let mut brightness = characteristic::brightness::new();
+    lightbulb.inner.lightbulb.inner.brightness = Some(brightness.clone());
+    let mut on = lightbulb.inner.lightbulb.inner.on.clone();
+    on.set_updatable(RejectOn).unwrap();
+    let mut session = connect_to(lightbulb_config(), vec![Box::new(lightbulb)]);
+
+    let statuses = session
+        .write_characteristics(vec![
+            (1, on.get_id().unwrap(), json!(true)),
+            (1, brightness.get_id().unwrap(), json!(50)),
+        ])
+        .unwrap();
+    // -70410: invalid value in request
+    assert_eq!(statuses[0].status, -70410);
+    assert_eq!(statuses[1].status, 0);
+
+    let values = session.read_characteristics(&[(1, on.get_id().unwrap())]).unwrap();
+    assert_eq!(values[0].value, Some(json!(false)));
+    assert!(!on.get_value().unwrap());
+    assert_eq!(brightness.get_value().unwrap(), 50);
+}
+
+#[test]
+fn read_unknown_characteristic_reports_status() {
+    let lightbulb = lightbulb::new(Information::default()).unwrap();
+    let mut session = connect_to(lightbulb_config(), vec![Box::new(lightbulb)]);
+
+    let values = session.read_characteristics(&[(1, 1000)]).unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value, None);
+    assert_ne!(values[0].status, Some(0));
+}
+
+#[test]
+fn events_are_delivered_to_subscribers() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+    let mut session = controller.connect(&harness.addr, &accessory).unwrap();
+    let iid = harness.on.get_id().unwrap();
+
+    session.subscribe(1, iid, true).unwrap();
+
+    let mut on = harness.on.clone();
+    on.set_value(true).unwrap();
+
+    let events = session.next_event(Duration::from_secs(5)).unwrap().expect("no event received");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].aid, 1);
+    assert_eq!(events[0].iid, iid);
+    assert_eq!(events[0].value, json!(true));
+}
+
+#[test]
+fn manage_pairings() {
+    let harness = start_accessory();
+    let (controller, accessory) = pair(&harness);
+    let mut session = controller.connect(&harness.addr, &accessory).unwrap();
+
+    let second = Controller::new(Uuid::new_v4());
+    let second_id = Uuid::parse_str(&second.device().id).unwrap();
+    session
+        .add_pairing(&Pairing::new(second_id, Permissions::User, second.device().public_key))
+        .unwrap();
+
+    let pairings = session.list_pairings().unwrap();
+    assert_eq!(pairings.len(), 2);
+    assert!(pairings.iter().any(|p| p.id == second_id && p.permissions == Permissions::User));
+    assert!(pairings
+        .iter()
+        .any(|p| p.id.to_hyphenated().to_string() == controller.device().id && p.permissions == Permissions::Admin));
+
+    let mut second_session = second.connect(&harness.addr, &accessory).unwrap();
+    assert!(second_session.get_accessories().is_ok());
+    // only admins may manage pairings
+    assert!(second_session.list_pairings().is_err());
+
+    session.remove_pairing(second_id).unwrap();
+    assert_eq!(session.list_pairings().unwrap().len(), 1);
+    assert!(second.connect(&harness.addr, &accessory).is_err());
+}
+
+#[test]
+fn identify_only_while_unpaired() {
+    let harness = start_accessory();
+    let controller = Controller::new(Uuid::new_v4());
+
+    controller.identify(&harness.addr).unwrap();
+
+    pair(&harness);
+    match controller.identify(&harness.addr) {
+        Err(err) => match err.kind() {
+            ErrorKind::HttpStatus(status) => assert_eq!(status.as_u16(), 400),
+            _ => panic!("unexpected error: {}", err),
+        },
+        Ok(()) => panic!("identify succeeded on a paired accessory"),
+    }
+}
+
+#[test]
+fn large_responses_span_many_frames() {
+    let config = Config {
+        ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        port: 0,
+        pin: PIN.into(),
+        name: "Loopback Bridge".into(),
+        category: Category::Bridge,
+        ..Default::default()
+    };
+    let mut accessories: Vec<Box<dyn AddTo>> = vec![Box::new(
+        bridge::new(Information {
+            name: "Loopback Bridge".into(),
+            ..Default::default()
+        })
+        .unwrap(),
+    )];
+    for i in 0..150 {
+        accessories.push(Box::new(
+            outlet::new(Information {
+                name: format!("Outlet {}", i),
+                ..Default::default()
+            })
+            .unwrap(),
+        ));
+    }
+    let mut session = connect_to(config, accessories);
+
+    // the accessory database is far larger than a single frame and the socket buffers
+    let accessories = session.get_accessories().unwrap();
+    assert_eq!(accessories["accessories"].as_array().unwrap().len(), 151);
+    // the session stays usable afterwards
+    let accessories = session.get_accessories().unwrap();
+    assert_eq!(accessories["accessories"].as_array().unwrap().len(), 151);
+}
+
+#[test]
+fn dynamic_accessories_are_served() {
+    let mut accessory = dynamic::new(Information {
+        name: "Loopback Dynamic".into(),
+        ..Default::default()
+    })
+    .unwrap();
+    let mut lightbulb = service::dynamic::new(HapType::Lightbulb).unwrap();
+    let mut brightness = characteristic::brightness::new();
+    lightbulb.inner.add_characteristic(brightness.clone());
+    accessory.inner.add_service(lightbulb);
+    assert!(accessory.inner.add_service_by_type(HapType::On).is_err());
+
+    let mut session = connect_to(lightbulb_config(), vec![Box::new(accessory)]);
+
+    let accessories = session.get_accessories().unwrap();
+    let services = accessories["accessories"][0]["services"].as_array().unwrap();
+    assert_eq!(services.len(), 2);
+    let types = services[1]["characteristics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["type"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(types, vec![json!("25"), json!("8")]);
+
+    let iid = brightness.get_id().unwrap();
+    let statuses = session.write_characteristics(vec![(1, iid, json!(42))]).unwrap();
+    assert!(statuses.is_empty());
+    assert_eq!(brightness.get_value().unwrap(), 42);
+}
+
+/// `Clock` that only advances when told to.
+#[derive(Clone)]
+struct MockClock(Arc<Mutex<SystemTime>>);
+
+impl MockClock {
+    fn advance(&self, millis: u64) { *self.0.lock().unwrap() += Duration::from_millis(millis); }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime { *self.0.lock().unwrap() }
+}
+
+#[test]
+fn adaptive_lighting_follows_the_transition_curve() {
+    let mut lightbulb = lightbulb::new(Information {
+        name: "Loopback Lightbulb".into(),
+        ..Default::default()
+    })
+    .unwrap();
+    let mut brightness = characteristic::brightness::new();
+    let mut color_temperature = characteristic::color_temperature::new();
+    lightbulb.inner.lightbulb.inner.brightness = Some(brightness.clone());
+    lightbulb.inner.lightbulb.inner.color_temperature = Some(color_temperature.clone());
+    // 2019-01-01 00:00:00 UTC
+    let clock = MockClock(Arc::new(Mutex::new(UNIX_EPOCH + Duration::from_secs(1_546_300_800))));
+    let adaptive_lighting =
+        adaptive_lighting::attach_with_clock(&mut lightbulb.inner.lightbulb, clock.clone()).unwrap();
+    let inner = &lightbulb.inner.lightbulb.inner;
+    let transition_control = inner.characteristic_value_transition_control.as_ref().unwrap().clone();
+    let supported = inner
+        .supported_characteristic_value_transition_configuration
+        .as_ref()
+        .unwrap()
+        .clone();
+    let mut count = inner.characteristic_value_active_transition_count.as_ref().unwrap().clone();
+
+    let mut session = connect_to(lightbulb_config(), vec![Box::new(lightbulb)]);
+
+    let brightness_iid = brightness.get_id().unwrap();
+    let color_temperature_iid = color_temperature.get_id().unwrap();
+    let values = session.read_characteristics(&[(1, supported.get_id().unwrap())]).unwrap();
+    let value = base64::decode(values[0].value.as_ref().unwrap().as_str().unwrap()).unwrap();
+    let config = SupportedTransitionConfig::from_tlv8(&value).unwrap();
+    assert_eq!(config.transitions.0[0].0.iid, brightness_iid);
+    assert_eq!(config.transitions.0[1].0.iid, color_temperature_iid);
+
+    let start_time = clock.now().duration_since(UNIX_EPOCH).unwrap().as_secs() * 1000 - 978_307_200_000;
+    let control = TransitionControl {
+        read: None,
+        update: Some(Nested(UpdateTransition {
+            config: Nested(TransitionConfig {
+                iid: color_temperature_iid,
+                parameters: Some(Nested(TransitionParameters {
+                    transition_id: Uuid::new_v4().as_bytes().to_vec(),
+                    start_time,
+                    unknown: None,
+                })),
+                curve: Some(Nested(TransitionCurve {
+                    entries: Repeated(vec![
+                        Nested(TransitionEntry {
+                            adjustment_factor: 0.0,
+                            value: 200.0,
+                            transition_offset: 0,
+                            duration: Some(1000),
+                        }),
+                        Nested(TransitionEntry {
+                            adjustment_factor: 1.0,
+                            value: 400.0,
+                            transition_offset: 10_000,
+                            duration: None,
+                        }),
+                    ]),
+                    adjustment_iid: brightness_iid,
+                    adjustment_multiplier_range: Nested(AdjustmentMultiplierRange { min: 10, max: 100 }),
+                })),
+                update_interval: Some(1000),
+                notify_interval_threshold: None,
+            }),
+        })),
+    };
+    let status = session
+        .write_characteristic_with_response(
+            1,
+            transition_control.get_id().unwrap(),
+            json!(base64::encode(&control.to_tlv8())),
+        )
+        .unwrap();
+    assert_eq!(status.status, 0);
+    let value = base64::decode(status.value.unwrap().as_str().unwrap()).unwrap();
+    let response = TransitionControlResponse::from_tlv8(&value).unwrap();
+    let transition_status = response.status.unwrap().0;
+    assert_eq!(transition_status.iid, color_temperature_iid);
+    assert_eq!(transition_status.parameters.0.start_time, start_time);
+    assert_eq!(count.get_value().unwrap(), 1);
+    assert_eq!(color_temperature.get_value().unwrap(), 200);
+
+    // invalid transitions are rejected without ending the active one
+    let mut overflowing = control.clone();
+    if let Some(Nested(ref mut update)) = overflowing.update {
+        if let Some(Nested(ref mut parameters)) = update.config.0.parameters {
+            parameters.start_time = u64::MAX;
+        }
+    }
+    let statuses = session
+        .write_characteristics(vec![
+            (
+                1,
+                transition_control.get_id().unwrap(),
+                json!(base64::encode(&overflowing.to_tlv8())),
+            ),
+            (1, brightness_iid, json!(brightness.get_value().unwrap())),
+        ])
+        .unwrap();
+    // -70410: invalid value in request
+    assert_eq!(statuses[0].status, -70410);
+    assert_eq!(statuses[1].status, 0);
+    assert!(adaptive_lighting.lock().unwrap().is_active());
+
+    session.subscribe(1, color_temperature_iid, true).unwrap();
+
+    // halfway through the transition to the second entry, adjusted by the minimum multiplier
+    clock.advance(6000);
+    adaptive_lighting.lock().unwrap().update().unwrap();
+    assert_eq!(color_temperature.get_value().unwrap(), 305);
+    let events = session.next_event(Duration::from_secs(5)).unwrap().expect("no event received");
+    assert_eq!(events[0].iid, color_temperature_iid);
+    assert_eq!(events[0].value, json!(305));
+
+    // brightness changes are applied before the next update interval
+    brightness.set_value(50).unwrap();
+    adaptive_lighting.lock().unwrap().update().unwrap();
+    assert_eq!(color_temperature.get_value().unwrap(), 325);
+
+    clock.advance(5000);
+    adaptive_lighting.lock().unwrap().update().unwrap();
+    assert!(!adaptive_lighting.lock().unwrap().is_active());
+    assert_eq!(count.get_value().unwrap(), 0);
+}
+
+#[test]
+fn unverified_connections_time_out() {
+    let harness = start_accessory_with(Config {
+        handshake_timeout: Duration::from_millis(200),
+        ..lightbulb_config()
+    });
+
+    let mut stream = TcpStream::connect(harness.addr).unwrap();
+    assert!(is_closed_by_accessory(&mut stream));
+}
+
+#[test]
+fn unverified_connections_make_room_for_new_ones() {
+    let harness = start_accessory_with(Config {
+        max_connections: 1,
+        ..lightbulb_config()
+    });
+
+    let mut first = TcpStream::connect(harness.addr).unwrap();
+    let _second = TcpStream::connect(harness.addr).unwrap();
+    assert!(is_closed_by_accessory(&mut first));
+}
+
+#[test]
+fn oldest_connection_of_a_controller_is_evicted() {
+    let harness = start_accessory_with(Config {
+        max_controller_connections: Some(1),
+        ..lightbulb_config()
+    });
+    let (controller, accessory) = pair(&harness);
+
+    let mut first = controller.connect(&harness.addr, &accessory).unwrap();
+    let mut second = controller.connect(&harness.addr, &accessory).unwrap();
+
+    assert!(second.get_accessories().is_ok());
+    assert!(first.get_accessories().is_err());
+}
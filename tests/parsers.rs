@@ -0,0 +1,73 @@
+//! Property tests for the public parsers that process untrusted input. Arbitrary input must never
+//! make them panic, and valid input must round-trip.
+
+use proptest::{collection::vec, prelude::*};
+
+use hap::{
+    characteristic::{
+        stream::{RtpParameters, SelectedRtpStreamConfig, SupportedRtpConfig},
+        tlv8::Repeated,
+        Tlv8,
+    },
+    protocol::tlv::{self, Reader, Writer},
+};
+
+/// TLV8 items where consecutive items have different types, so that they can't be mistaken for
+/// fragments of a single item.
+fn tlv_items() -> impl Strategy<Value = Vec<(u8, Vec<u8>)>> {
+    vec((any::<u8>(), vec(any::<u8>(), 0..600)), 0..8)
+        .prop_filter("consecutive items of the same type", |items| {
+            items.windows(2).all(|w| w[0].0 != w[1].0)
+        })
+}
+
+proptest! {
+    #[test]
+    fn tlv_decode_doesnt_panic(data in vec(any::<u8>(), 0..1024)) {
+        let _ = tlv::decode(&data);
+        let _ = tlv::decode_list(&data);
+    }
+
+    #[test]
+    fn tlv_items_round_trip(items in tlv_items()) {
+        let mut writer = Writer::new();
+        for (t, v) in &items {
+            writer.push(*t, v);
+        }
+        let encoded = writer.into_bytes();
+
+        let decoded = Reader::new(&encoded).collect::<Result<Vec<_>, _>>().unwrap();
+        prop_assert_eq!(decoded, items);
+    }
+
+    #[test]
+    fn tlv8_values_dont_panic(data in vec(any::<u8>(), 0..512)) {
+        let _ = SelectedRtpStreamConfig::from_tlv8(&data);
+        let _ = SupportedRtpConfig::from_tlv8(&data);
+    }
+
+    #[test]
+    fn tlv8_values_round_trip(
+        payload_type in any::<u8>(),
+        ssrc in any::<u32>(),
+        max_bit_rate in any::<u16>(),
+        min_rtcp_interval in -1000.0f32..1000.0,
+        max_mtu in any::<Option<u16>>(),
+        crypto_suites in vec(any::<u8>(), 0..4),
+    ) {
+        let rtp_parameters = RtpParameters {
+            payload_type,
+            ssrc,
+            max_bit_rate,
+            min_rtcp_interval,
+            max_mtu,
+            comfort_noise_payload_type: None,
+        };
+        prop_assert_eq!(RtpParameters::from_tlv8(&rtp_parameters.to_tlv8()).unwrap(), rtp_parameters);
+
+        let rtp_config = SupportedRtpConfig {
+            srtp_crypto_suites: Repeated(crypto_suites),
+        };
+        prop_assert_eq!(SupportedRtpConfig::from_tlv8(&rtp_config.to_tlv8()).unwrap(), rtp_config);
+    }
+}